@@ -1,4 +1,5 @@
 use crate::{
+    body::deser,
     header::{key::HeaderKey, values::HeaderValues},
     AsserhttpError, AsserhttpResult,
 };
@@ -13,6 +14,12 @@ pub trait HeaderAccessor {
 
     fn get_raw_values(&self, key: &HeaderKey) -> Vec<String>;
 
+    /// First raw value of a header which has to be present
+    fn first_raw_value(&self, key: &HeaderKey) -> AsserhttpResult<String> {
+        key.try_assert_contained(self.get_keys())?;
+        self.get_raw_values(key).into_iter().next().ok_or(AsserhttpError::InternalError)
+    }
+
     fn get_values(&self, key: &HeaderKey) -> HeaderValues {
         self.get_raw_values(key)
             .iter()
@@ -36,7 +43,7 @@ pub trait BodyAccessor {
     where
         B: DeserializeOwned + Unpin,
     {
-        Ok(serde_json::from_slice::<B>(self.get_bytes()?.as_slice())?)
+        deser::from_slice(self.get_bytes()?.as_slice())
     }
 }
 
@@ -22,13 +22,11 @@ impl HeaderAccessor for ActixResponse {
     }
 
     fn get_raw_values(&self, key: &HeaderKey) -> Vec<String> {
-        let value = self
-            .headers()
-            .get(key.as_ref())
-            .and_then(|v| v.to_str().ok())
+        self.headers()
+            .get_all(key.as_ref())
+            .filter_map(|v| v.to_str().ok())
             .map(str::to_string)
-            .unwrap();
-        vec![value]
+            .collect()
     }
 }
 
@@ -38,13 +36,11 @@ impl HeaderAccessor for ActixServiceResponse {
     }
 
     fn get_raw_values(&self, key: &HeaderKey) -> Vec<String> {
-        let value = self
-            .headers()
-            .get(key.as_ref())
-            .and_then(|v| v.to_str().ok())
+        self.headers()
+            .get_all(key.as_ref())
+            .filter_map(|v| v.to_str().ok())
             .map(str::to_string)
-            .unwrap();
-        vec![value]
+            .collect()
     }
 }
 
@@ -61,20 +57,16 @@ impl BodyAccessor for ActixServiceResponse {
 }
 
 fn body_bytes(original: &mut ActixResponse) -> AsserhttpResult<Vec<u8>> {
-    let mut resp_cpy = ActixResponse::build(original.status());
-    original.headers().iter().for_each(|h| {
-        resp_cpy.insert_header(h);
-    });
-    let mut resp_cpy = resp_cpy.finish();
-    std::mem::swap(original, &mut resp_cpy);
-    let (_, body) = resp_cpy.into_parts();
+    let placeholder = ActixResponse::build(original.status()).finish();
+    let (head, body) = std::mem::replace(original, placeholder).into_parts();
     use actix_http::body::MessageBody as _;
     let buf = body
         .try_into_bytes()
-        .map(|b| b.to_vec())
         .map_err(|_| AsserhttpError::HttpError("Could not read actix response body".to_string()))?;
+    // puts the body back so that it can be read again
+    *original = head.set_body(actix_http::body::BoxBody::new(buf.clone()));
     if buf.is_empty() {
         return Err(AsserhttpError::BodyAbsent);
     }
-    Ok(buf)
+    Ok(buf.to_vec())
 }
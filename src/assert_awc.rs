@@ -15,19 +15,29 @@ impl HeaderAccessor for AwcResponse {
     }
 
     fn get_raw_values(&self, key: &HeaderKey) -> Vec<String> {
-        let value = self
-            .headers()
-            .get(key.as_ref())
-            .and_then(|v| v.to_str().ok())
+        self.headers()
+            .get_all(key.as_ref())
+            .filter_map(|v| v.to_str().ok())
             .map(str::to_string)
-            .unwrap();
-        vec![value]
+            .collect()
     }
 }
 
+/// Body already read by a previous assertion, kept in the response extensions since awc does not allow putting it back
+struct BufferedBody(Vec<u8>);
+
 impl BodyAccessor for AwcResponse {
     fn get_bytes(&mut self) -> AsserhttpResult<Vec<u8>> {
-        let buf = futures_lite::future::block_on(self.body()).map(|b| b.to_vec())?;
+        use actix_http::HttpMessage as _;
+        let buffered = self.extensions().get::<BufferedBody>().map(|b| b.0.clone());
+        let buf = match buffered {
+            Some(buf) => buf,
+            None => {
+                let buf = futures_lite::future::block_on(self.body()).map(|b| b.to_vec())?;
+                self.extensions_mut().insert(BufferedBody(buf.clone()));
+                buf
+            },
+        };
         if buf.is_empty() {
             return Err(AsserhttpError::BodyAbsent);
         }
@@ -15,13 +15,12 @@ impl HeaderAccessor for AxumResponse {
     }
 
     fn get_raw_values(&self, key: &HeaderKey) -> Vec<String> {
-        let value = self
-            .headers()
-            .get(key.as_ref())
-            .and_then(|v| v.to_str().ok())
+        self.headers()
+            .get_all(key.as_ref())
+            .into_iter()
+            .filter_map(|v| v.to_str().ok())
             .map(str::to_string)
-            .unwrap();
-        vec![value]
+            .collect()
     }
 }
 
@@ -29,6 +28,7 @@ impl BodyAccessor for AxumResponse {
     fn get_bytes(&mut self) -> AsserhttpResult<Vec<u8>> {
         use http_body_util::BodyExt as _;
         let buf = futures_lite::future::block_on(self.body_mut().collect())?.to_bytes().to_vec();
+        *self.body_mut() = axum::body::Body::from(buf.clone());
         if buf.is_empty() {
             return Err(AsserhttpError::BodyAbsent);
         }
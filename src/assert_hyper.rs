@@ -16,13 +16,12 @@ impl HeaderAccessor for HyperResponse {
     }
 
     fn get_raw_values(&self, key: &HeaderKey) -> Vec<String> {
-        let value = self
-            .headers()
-            .get(key.as_ref())
-            .and_then(|v| v.to_str().ok())
+        self.headers()
+            .get_all(key.as_ref())
+            .into_iter()
+            .filter_map(|v| v.to_str().ok())
             .map(str::to_string)
-            .unwrap();
-        vec![value]
+            .collect()
     }
 }
 
@@ -33,6 +32,7 @@ impl BodyAccessor for HyperResponse {
         while let Some(Ok(chunk)) = futures_lite::future::block_on(self.body_mut().data()) {
             chunk.into_iter().for_each(|b| buf.push(b));
         }
+        *self.body_mut() = hyper::Body::from(buf.clone());
         if buf.is_empty() {
             return Err(AsserhttpError::BodyAbsent);
         }
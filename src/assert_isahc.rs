@@ -23,13 +23,12 @@ impl HeaderAccessor for IsahcResponse {
     }
 
     fn get_raw_values(&self, key: &HeaderKey) -> Vec<String> {
-        let value = self
-            .headers()
-            .get(key.as_ref())
-            .and_then(|v| v.to_str().ok())
+        self.headers()
+            .get_all(key.as_ref())
+            .into_iter()
+            .filter_map(|v| v.to_str().ok())
             .map(str::to_string)
-            .unwrap();
-        vec![value]
+            .collect()
     }
 }
 
@@ -39,13 +38,12 @@ impl HeaderAccessor for AsyncIsahcResponse {
     }
 
     fn get_raw_values(&self, key: &HeaderKey) -> Vec<String> {
-        let value = self
-            .headers()
-            .get(key.as_ref())
-            .and_then(|v| v.to_str().ok())
+        self.headers()
+            .get_all(key.as_ref())
+            .into_iter()
+            .filter_map(|v| v.to_str().ok())
             .map(str::to_string)
-            .unwrap();
-        vec![value]
+            .collect()
     }
 }
 
@@ -53,6 +51,7 @@ impl BodyAccessor for IsahcResponse {
     fn get_bytes(&mut self) -> AsserhttpResult<Vec<u8>> {
         use isahc::ReadResponseExt as _;
         let buf = self.bytes()?;
+        *self.body_mut() = isahc::Body::from(buf.clone());
         if buf.is_empty() {
             return Err(AsserhttpError::BodyAbsent);
         }
@@ -64,6 +63,7 @@ impl BodyAccessor for AsyncIsahcResponse {
     fn get_bytes(&mut self) -> AsserhttpResult<Vec<u8>> {
         use isahc::AsyncReadResponseExt as _;
         let buf = futures_lite::future::block_on(self.bytes()).map_err(AsserhttpError::from)?;
+        *self.body_mut() = isahc::AsyncBody::from(buf.clone());
         if buf.is_empty() {
             return Err(AsserhttpError::BodyAbsent);
         }
@@ -22,13 +22,12 @@ impl HeaderAccessor for ReqwestResponse {
     }
 
     fn get_raw_values(&self, key: &HeaderKey) -> Vec<String> {
-        let value = self
-            .headers()
-            .get(key.as_ref())
-            .and_then(|v| v.to_str().ok())
+        self.headers()
+            .get_all(key.as_ref())
+            .into_iter()
+            .filter_map(|v| v.to_str().ok())
             .map(str::to_string)
-            .unwrap();
-        vec![value]
+            .collect()
     }
 }
 
@@ -38,13 +37,12 @@ impl HeaderAccessor for AsyncReqwestResponse {
     }
 
     fn get_raw_values(&self, key: &HeaderKey) -> Vec<String> {
-        let value = self
-            .headers()
-            .get(key.as_ref())
-            .and_then(|v| v.to_str().ok())
-            .map(|v| v.to_string())
-            .unwrap();
-        vec![value]
+        self.headers()
+            .get_all(key.as_ref())
+            .into_iter()
+            .filter_map(|v| v.to_str().ok())
+            .map(str::to_string)
+            .collect()
     }
 }
 
@@ -52,6 +50,16 @@ impl BodyAccessor for ReqwestResponse {
     fn get_bytes(&mut self) -> AsserhttpResult<Vec<u8>> {
         let mut buf = vec![];
         self.copy_to(&mut buf)?;
+        let (headers, extensions) = (std::mem::take(self.headers_mut()), std::mem::take(self.extensions_mut()));
+        *self = buffered(
+            self.status(),
+            self.version(),
+            self.url().clone(),
+            headers,
+            extensions,
+            buf.clone(),
+        )?
+        .into();
         if buf.is_empty() {
             return Err(AsserhttpError::BodyAbsent);
         }
@@ -65,9 +73,37 @@ impl BodyAccessor for AsyncReqwestResponse {
         while let Ok(Some(chunk)) = futures_lite::future::block_on(self.chunk()) {
             chunk.into_iter().for_each(|b| buf.push(b));
         }
+        let (headers, extensions) = (std::mem::take(self.headers_mut()), std::mem::take(self.extensions_mut()));
+        *self = buffered(
+            self.status(),
+            self.version(),
+            self.url().clone(),
+            headers,
+            extensions,
+            buf.clone(),
+        )?
+        .into();
         if buf.is_empty() {
             return Err(AsserhttpError::BodyAbsent);
         }
         Ok(buf)
     }
 }
+
+/// Rebuilds a response holding the already read body so that it can be read again.
+/// The url is carried over through the builder, the remote address through the extensions
+fn buffered(
+    status: reqwest::StatusCode, version: reqwest::Version, url: reqwest::Url, headers: reqwest::header::HeaderMap,
+    extensions: http::Extensions, buf: Vec<u8>,
+) -> AsserhttpResult<http::Response<Vec<u8>>> {
+    use reqwest::ResponseBuilderExt as _;
+    let mut response = http::response::Builder::new()
+        .status(status)
+        .version(version)
+        .url(url)
+        .body(buf)
+        .map_err(|e| AsserhttpError::HttpError(e.to_string()))?;
+    *response.headers_mut() = headers;
+    response.extensions_mut().extend(extensions);
+    Ok(response)
+}
@@ -26,6 +26,12 @@ impl HeaderAccessor for SurfResponse {
 impl BodyAccessor for SurfResponse {
     fn get_bytes(&mut self) -> AsserhttpResult<Vec<u8>> {
         let buf = futures_lite::future::block_on(self.body_bytes()).map_err(AsserhttpError::from)?;
+        let content_type = self.header(surf::http::headers::CONTENT_TYPE).is_some();
+        self.set_body(buf.clone());
+        // setting the body defaults the content type to 'application/octet-stream' when absent
+        if !content_type {
+            self.remove_header(surf::http::headers::CONTENT_TYPE);
+        }
         if buf.is_empty() {
             return Err(AsserhttpError::BodyAbsent);
         }
@@ -16,25 +16,20 @@ impl HeaderAccessor for UreqResponse {
     }
 
     fn get_raw_values(&self, key: &HeaderKey) -> Vec<String> {
-        self.header(key.as_ref()).map(|v| vec![v.to_string()]).unwrap_or_default()
+        self.all(key.as_ref()).into_iter().map(str::to_string).collect()
     }
 }
 
 impl BodyAccessor for UreqResponse {
     fn get_bytes(&mut self) -> AsserhttpResult<Vec<u8>> {
-        use itertools::Itertools as _;
-        let headers = self
-            .headers_names()
-            .iter()
-            .filter_map(|k| self.header(k).map(|v| (k, v)))
-            .map(|(k, v)| format!("{k}: {v}"))
-            .join("\r\n");
-        let headers = format!("{}\r\n{}", self.status_text(), headers);
-        let mut resp_cpy = UreqResponse::new(self.status(), &headers, "").unwrap();
-        std::mem::swap(self, &mut resp_cpy);
+        let placeholder = UreqResponse::new(self.status(), self.status_text(), "").unwrap();
+        let original = std::mem::replace(self, placeholder);
+        let (parts, mut reader) = http::Response::<Box<dyn std::io::Read + Send + Sync>>::from(original).into_parts();
         let mut buf: Vec<u8> = vec![];
         use std::io::Read as _;
-        resp_cpy.into_reader().read_to_end(&mut buf).map_err(AsserhttpError::from)?;
+        reader.read_to_end(&mut buf).map_err(AsserhttpError::from)?;
+        // puts the body back so that it can be read again
+        *self = http::Response::from_parts(parts, buf.clone()).into();
         if buf.is_empty() {
             return Err(AsserhttpError::BodyAbsent);
         }
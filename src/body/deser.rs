@@ -0,0 +1,72 @@
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+use serde_path_to_error::{Path, Segment};
+
+use crate::error::{AsserhttpError, AsserhttpResult};
+
+const EXCERPT_LINES: usize = 20;
+
+/// Deserializes a json body. When the body is valid json but does not match the expected type,
+/// the error points at the offending field
+pub fn from_slice<B>(bytes: &[u8]) -> AsserhttpResult<B>
+where
+    B: DeserializeOwned,
+{
+    let mut deserializer = serde_json::Deserializer::from_slice(bytes);
+    match serde_path_to_error::deserialize(&mut deserializer) {
+        Ok(body) => {
+            deserializer.end()?;
+            Ok(body)
+        },
+        Err(e) if e.inner().is_data() => Err(AsserhttpError::JsonBodyDeserialization {
+            path: e.path().to_string(),
+            message: message(e.inner()),
+            excerpt: excerpt(bytes, e.path()),
+        }),
+        Err(e) => Err(e.into_inner().into()),
+    }
+}
+
+/// Deserializes a value selected in a json body at the given path
+pub fn from_value<B>(value: &Value, path: &str) -> AsserhttpResult<B>
+where
+    B: DeserializeOwned,
+{
+    B::deserialize(value).map_err(|e| AsserhttpError::JsonBodyDeserialization {
+        path: path.to_string(),
+        message: e.to_string(),
+        excerpt: serde_json::to_string_pretty(value).unwrap_or_default(),
+    })
+}
+
+/// Error message without the position in the raw body which is superseded by the path
+fn message(e: &serde_json::Error) -> String {
+    let message = e.to_string();
+    let position = format!(" at line {} column {}", e.line(), e.column());
+    message.strip_suffix(&position).unwrap_or(&message).to_string()
+}
+
+/// Pretty-printed object or array holding the offending field
+fn excerpt(bytes: &[u8], path: &Path) -> String {
+    let Ok(root) = serde_json::from_slice::<Value>(bytes) else {
+        return String::new();
+    };
+    let (mut parent, mut node) = (&root, &root);
+    for segment in path.iter() {
+        let next = match segment {
+            Segment::Seq { index } => node.get(index),
+            Segment::Map { key } => node.get(key),
+            Segment::Enum { variant } => node.get(variant),
+            Segment::Unknown => None,
+        };
+        let Some(next) = next else { break };
+        (parent, node) = (node, next);
+    }
+    let container = if node.is_object() || node.is_array() { node } else { parent };
+    let pretty = serde_json::to_string_pretty(container).unwrap_or_default();
+    let mut lines = pretty.lines().take(EXCERPT_LINES + 1).collect::<Vec<_>>();
+    if lines.len() > EXCERPT_LINES {
+        lines[EXCERPT_LINES] = "...";
+    }
+    lines.join("\n")
+}
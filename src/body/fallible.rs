@@ -1,8 +1,12 @@
+use std::path::Path;
+
 use serde::{de::DeserializeOwned, Serialize};
+use serde_json::Value;
 
-use crate::{accessor::BodyAccessor, AsserhttpError, AsserhttpResult};
+use crate::{accessor::BodyAccessor, body::fixture, AsserhttpError, AsserhttpResult};
 
-/// For assertions on http response body returning an error instead of panicking
+/// For assertions on http response body returning an error instead of panicking.
+/// Assertions can be chained since the body is not consumed, except for Rocket's local responses whose body can only be read once
 pub trait FallibleAsserhttpBody<T> {
     /// Allows verifying json body in a closure
     /// * `asserter` - closure to verify json body
@@ -39,6 +43,7 @@ pub trait FallibleAsserhttpBody<T> {
     /// ```
     /// # Error
     /// * [AsserhttpError::BodyAbsent] when the response body is empty
+    /// * [AsserhttpError::JsonBodyDeserialization] when the response body cannot be deserialized into `B`
     fn try_expect_body_json<B, F>(&mut self, asserter: F) -> AsserhttpResult<&mut T>
     where
         B: DeserializeOwned + Serialize + PartialEq + std::fmt::Debug + Unpin,
@@ -86,6 +91,54 @@ pub trait FallibleAsserhttpBody<T> {
         })
     }
 
+    /// Expects response body to be json and equal to the content of a fixture file.
+    /// * `path` - path of the fixture file, relative to the crate root when running tests
+    ///
+    /// # Bless mode
+    /// When the `ASSERHTTP_BLESS` environment variable is set to anything but `0`, `false` or an empty string, the
+    /// actual body is pretty-printed into the file instead of being compared, creating the file and its parent
+    /// directories when missing. It applies to every fixture of the process, including the ones a test expects to
+    /// differ from the body: generate those outside of the fixtures directory rather than committing them next to the
+    /// others
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use ureq::OrAnyStatus;
+    /// use asserhttp::*;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     reqwest::blocking::get("http://localhost").try_expect_body_json_eq_file("tests/fixtures/user.json").unwrap();
+    ///     reqwest::get("http://localhost").await.try_expect_body_json_eq_file("tests/fixtures/user.json").unwrap();
+    ///
+    ///     isahc::get("http://localhost").try_expect_body_json_eq_file("tests/fixtures/user.json").unwrap();
+    ///     isahc::get_async("http://localhost").await.try_expect_body_json_eq_file("tests/fixtures/user.json").unwrap();
+    ///
+    ///     surf::get("http://localhost").await.try_expect_body_json_eq_file("tests/fixtures/user.json").unwrap();
+    ///
+    ///     ureq::get("http://localhost").call().or_any_status().try_expect_body_json_eq_file("tests/fixtures/user.json").unwrap();
+    ///
+    ///     hyper::Client::new().get("http://localhost".parse().unwrap()).await.try_expect_body_json_eq_file("tests/fixtures/user.json").unwrap();
+    ///
+    ///     awc::Client::default().get("http://localhost").send().await.try_expect_body_json_eq_file("tests/fixtures/user.json").unwrap();
+    /// }
+    /// ```
+    /// # Error
+    /// * [AsserhttpError::BodyAbsent] when the response body is empty
+    /// * [AsserhttpError::FixtureRead] when the fixture file cannot be read or is not json
+    /// * [AsserhttpError::FixtureWrite] when blessing and the fixture file cannot be written
+    /// * [AsserhttpError::JsonBodyMismatch] when the actual response body is different from the fixture
+    fn try_expect_body_json_eq_file<P>(&mut self, path: P) -> AsserhttpResult<&mut T>
+    where
+        P: AsRef<Path>,
+    {
+        let path = path.as_ref();
+        if fixture::bless() {
+            return self.try_expect_body_json(|actual: Value| fixture::write(path, &format!("{actual:#}\n")));
+        }
+        self.try_expect_body_json_eq(fixture::read_json(path)?)
+    }
+
     /// Allows verifying text body in a closure
     /// * `asserter` - closure to verify text body
     ///
@@ -159,6 +212,56 @@ pub trait FallibleAsserhttpBody<T> {
         })
     }
 
+    /// Expects response body to be text and equal to the content of a fixture file.
+    /// * `path` - path of the fixture file, relative to the crate root when running tests
+    ///
+    /// # Bless mode
+    /// When the `ASSERHTTP_BLESS` environment variable is set to anything but `0`, `false` or an empty string, the
+    /// actual body is written into the file instead of being compared, creating the file and its parent directories
+    /// when missing. It applies to every fixture of the process, including the ones a test expects to differ from
+    /// the body: generate those outside of the fixtures directory rather than committing them next to the others
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use ureq::OrAnyStatus;
+    /// use asserhttp::*;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     reqwest::blocking::get("http://localhost").try_expect_body_text_eq_file("tests/fixtures/user.txt").unwrap();
+    ///     reqwest::get("http://localhost").await.try_expect_body_text_eq_file("tests/fixtures/user.txt").unwrap();
+    ///
+    ///     isahc::get("http://localhost").try_expect_body_text_eq_file("tests/fixtures/user.txt").unwrap();
+    ///     isahc::get_async("http://localhost").await.try_expect_body_text_eq_file("tests/fixtures/user.txt").unwrap();
+    ///
+    ///     surf::get("http://localhost").await.try_expect_body_text_eq_file("tests/fixtures/user.txt").unwrap();
+    ///
+    ///     ureq::get("http://localhost").call().or_any_status().try_expect_body_text_eq_file("tests/fixtures/user.txt").unwrap();
+    ///
+    ///     hyper::Client::new().get("http://localhost".parse().unwrap()).await.try_expect_body_text_eq_file("tests/fixtures/user.txt").unwrap();
+    ///
+    ///     awc::Client::default().get("http://localhost").send().await.try_expect_body_text_eq_file("tests/fixtures/user.txt").unwrap();
+    /// }
+    /// ```
+    /// # Error
+    /// * [AsserhttpError::BodyAbsent] when the response body is empty
+    /// * [AsserhttpError::FixtureRead] when the fixture file cannot be read
+    /// * [AsserhttpError::FixtureWrite] when blessing and the fixture file cannot be written
+    /// * [AsserhttpError::TextBodyMismatch] when the actual response body is different from the fixture
+    fn try_expect_body_text_eq_file<P>(&mut self, path: P) -> AsserhttpResult<&mut T>
+    where
+        P: AsRef<Path>,
+    {
+        let path = path.as_ref();
+        if fixture::bless() {
+            return self.try_expect_body_text(|actual| match actual.as_str() {
+                "" => Err(AsserhttpError::BodyAbsent),
+                _ => fixture::write(path, &actual),
+            });
+        }
+        self.try_expect_body_text_eq(fixture::read(path)?)
+    }
+
     /// Expects response body to be text and to match provided regex
     /// * `regex` - must match text response body
     ///
@@ -205,6 +308,174 @@ pub trait FallibleAsserhttpBody<T> {
         })
     }
 
+    /// Expects response body to be text and to contain the provided text
+    /// * `text` - must be contained in text response body
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use ureq::OrAnyStatus;
+    /// use asserhttp::*;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     reqwest::blocking::get("http://localhost").try_expect_body_text_contains("bc").unwrap();
+    ///     reqwest::get("http://localhost").await.try_expect_body_text_contains("bc").unwrap();
+    ///
+    ///     isahc::get("http://localhost").try_expect_body_text_contains("bc").unwrap();
+    ///     isahc::get_async("http://localhost").await.try_expect_body_text_contains("bc").unwrap();
+    ///
+    ///     surf::get("http://localhost").await.try_expect_body_text_contains("bc").unwrap();
+    ///
+    ///     ureq::get("http://localhost").call().or_any_status().try_expect_body_text_contains("bc").unwrap();
+    ///
+    ///     hyper::Client::new().get("http://localhost".parse().unwrap()).await.try_expect_body_text_contains("bc").unwrap();
+    ///
+    ///     awc::Client::default().get("http://localhost").send().await.try_expect_body_text_contains("bc").unwrap();
+    /// }
+    /// ```
+    /// # Error
+    /// * [AsserhttpError::BodyAbsent] when the response body is empty
+    /// * [AsserhttpError::TextBodyNotContaining] when the response body does not contain the expected text
+    fn try_expect_body_text_contains<B>(&mut self, text: B) -> AsserhttpResult<&mut T>
+    where
+        B: Into<String>,
+    {
+        self.try_expect_body_text(|actual| {
+            let text = text.into();
+            match actual.as_str() {
+                "" => Err(AsserhttpError::BodyAbsent),
+                _ if actual.contains(&text) => Ok(()),
+                _ => Err(AsserhttpError::TextBodyNotContaining { actual, expected: text }),
+            }
+        })
+    }
+
+    /// Expects response body to be text and not to contain the provided text
+    /// * `text` - must not be contained in text response body
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use ureq::OrAnyStatus;
+    /// use asserhttp::*;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     reqwest::blocking::get("http://localhost").try_expect_body_text_not_contains("xyz").unwrap();
+    ///     reqwest::get("http://localhost").await.try_expect_body_text_not_contains("xyz").unwrap();
+    ///
+    ///     isahc::get("http://localhost").try_expect_body_text_not_contains("xyz").unwrap();
+    ///     isahc::get_async("http://localhost").await.try_expect_body_text_not_contains("xyz").unwrap();
+    ///
+    ///     surf::get("http://localhost").await.try_expect_body_text_not_contains("xyz").unwrap();
+    ///
+    ///     ureq::get("http://localhost").call().or_any_status().try_expect_body_text_not_contains("xyz").unwrap();
+    ///
+    ///     hyper::Client::new().get("http://localhost".parse().unwrap()).await.try_expect_body_text_not_contains("xyz").unwrap();
+    ///
+    ///     awc::Client::default().get("http://localhost").send().await.try_expect_body_text_not_contains("xyz").unwrap();
+    /// }
+    /// ```
+    /// # Error
+    /// * [AsserhttpError::BodyAbsent] when the response body is empty
+    /// * [AsserhttpError::TextBodyContaining] when the response body contains the unexpected text
+    fn try_expect_body_text_not_contains<B>(&mut self, text: B) -> AsserhttpResult<&mut T>
+    where
+        B: Into<String>,
+    {
+        self.try_expect_body_text(|actual| {
+            let text = text.into();
+            match actual.as_str() {
+                "" => Err(AsserhttpError::BodyAbsent),
+                _ if !actual.contains(&text) => Ok(()),
+                _ => Err(AsserhttpError::TextBodyContaining { actual, unexpected: text }),
+            }
+        })
+    }
+
+    /// Expects response body to be text and to start with the provided prefix
+    /// * `prefix` - text response body must start with
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use ureq::OrAnyStatus;
+    /// use asserhttp::*;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     reqwest::blocking::get("http://localhost").try_expect_body_text_starts_with("ab").unwrap();
+    ///     reqwest::get("http://localhost").await.try_expect_body_text_starts_with("ab").unwrap();
+    ///
+    ///     isahc::get("http://localhost").try_expect_body_text_starts_with("ab").unwrap();
+    ///     isahc::get_async("http://localhost").await.try_expect_body_text_starts_with("ab").unwrap();
+    ///
+    ///     surf::get("http://localhost").await.try_expect_body_text_starts_with("ab").unwrap();
+    ///
+    ///     ureq::get("http://localhost").call().or_any_status().try_expect_body_text_starts_with("ab").unwrap();
+    ///
+    ///     hyper::Client::new().get("http://localhost".parse().unwrap()).await.try_expect_body_text_starts_with("ab").unwrap();
+    ///
+    ///     awc::Client::default().get("http://localhost").send().await.try_expect_body_text_starts_with("ab").unwrap();
+    /// }
+    /// ```
+    /// # Error
+    /// * [AsserhttpError::BodyAbsent] when the response body is empty
+    /// * [AsserhttpError::TextBodyPrefixMismatch] when the response body does not start with the expected prefix
+    fn try_expect_body_text_starts_with<B>(&mut self, prefix: B) -> AsserhttpResult<&mut T>
+    where
+        B: Into<String>,
+    {
+        self.try_expect_body_text(|actual| {
+            let prefix = prefix.into();
+            match actual.as_str() {
+                "" => Err(AsserhttpError::BodyAbsent),
+                _ if actual.starts_with(&prefix) => Ok(()),
+                _ => Err(AsserhttpError::TextBodyPrefixMismatch { actual, prefix }),
+            }
+        })
+    }
+
+    /// Expects response body to be text and to end with the provided suffix
+    /// * `suffix` - text response body must end with
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use ureq::OrAnyStatus;
+    /// use asserhttp::*;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     reqwest::blocking::get("http://localhost").try_expect_body_text_ends_with("cd").unwrap();
+    ///     reqwest::get("http://localhost").await.try_expect_body_text_ends_with("cd").unwrap();
+    ///
+    ///     isahc::get("http://localhost").try_expect_body_text_ends_with("cd").unwrap();
+    ///     isahc::get_async("http://localhost").await.try_expect_body_text_ends_with("cd").unwrap();
+    ///
+    ///     surf::get("http://localhost").await.try_expect_body_text_ends_with("cd").unwrap();
+    ///
+    ///     ureq::get("http://localhost").call().or_any_status().try_expect_body_text_ends_with("cd").unwrap();
+    ///
+    ///     hyper::Client::new().get("http://localhost".parse().unwrap()).await.try_expect_body_text_ends_with("cd").unwrap();
+    ///
+    ///     awc::Client::default().get("http://localhost").send().await.try_expect_body_text_ends_with("cd").unwrap();
+    /// }
+    /// ```
+    /// # Error
+    /// * [AsserhttpError::BodyAbsent] when the response body is empty
+    /// * [AsserhttpError::TextBodySuffixMismatch] when the response body does not end with the expected suffix
+    fn try_expect_body_text_ends_with<B>(&mut self, suffix: B) -> AsserhttpResult<&mut T>
+    where
+        B: Into<String>,
+    {
+        self.try_expect_body_text(|actual| {
+            let suffix = suffix.into();
+            match actual.as_str() {
+                "" => Err(AsserhttpError::BodyAbsent),
+                _ if actual.ends_with(&suffix) => Ok(()),
+                _ => Err(AsserhttpError::TextBodySuffixMismatch { actual, suffix }),
+            }
+        })
+    }
+
     /// Allows verifying response body bytes in a closure
     /// * `asserter` - closure to verify response body as bytes
     ///
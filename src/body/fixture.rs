@@ -0,0 +1,37 @@
+use std::path::Path;
+
+use crate::error::{AsserhttpError, AsserhttpResult};
+
+/// When set, the actual body is written to the fixture file instead of being compared to it
+pub const BLESS_ENV: &str = "ASSERHTTP_BLESS";
+
+/// Whether fixture files should be updated with the actual body. Enabled by `ASSERHTTP_BLESS` set to anything but
+/// `0`, `false` or an empty string
+pub fn bless() -> bool {
+    std::env::var(BLESS_ENV).is_ok_and(|v| !matches!(v.as_str(), "" | "0" | "false"))
+}
+
+pub fn read(path: &Path) -> AsserhttpResult<String> {
+    std::fs::read_to_string(path).map_err(|e| AsserhttpError::FixtureRead {
+        path: path.display().to_string(),
+        reason: e.to_string(),
+    })
+}
+
+pub fn read_json(path: &Path) -> AsserhttpResult<serde_json::Value> {
+    serde_json::from_str(&read(path)?).map_err(|e| AsserhttpError::FixtureRead {
+        path: path.display().to_string(),
+        reason: e.to_string(),
+    })
+}
+
+pub fn write(path: &Path, content: &str) -> AsserhttpResult<()> {
+    let write_error = |e: std::io::Error| AsserhttpError::FixtureWrite {
+        path: path.display().to_string(),
+        reason: e.to_string(),
+    };
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent).map_err(write_error)?;
+    }
+    std::fs::write(path, content).map_err(write_error)
+}
@@ -0,0 +1,95 @@
+use std::fmt::{Display, Formatter};
+
+const BYTES_PER_ROW: usize = 16;
+const CONTEXT_ROWS: usize = 2;
+const HEX_WIDTH: usize = BYTES_PER_ROW * 3 - 1;
+// "00000000 > "
+const OFFSET_WIDTH: usize = 11;
+// " |<ascii>|  "
+const ASCII_WIDTH: usize = BYTES_PER_ROW + 5;
+
+/// Side-by-side hexdump of two payloads, centered on the first offset where they differ
+pub struct HexdumpDiff<'a> {
+    actual: &'a [u8],
+    expected: &'a [u8],
+}
+
+impl<'a> HexdumpDiff<'a> {
+    pub fn new(actual: &'a [u8], expected: &'a [u8]) -> Self {
+        Self { actual, expected }
+    }
+
+    /// Offset of the first differing byte. When one payload is a prefix of the other, that's the
+    /// length of the shortest one
+    pub fn first_difference(&self) -> usize {
+        self.actual
+            .iter()
+            .zip(self.expected.iter())
+            .position(|(a, e)| a != e)
+            .unwrap_or_else(|| self.actual.len().min(self.expected.len()))
+    }
+
+    fn row(bytes: &[u8], row: usize) -> &[u8] {
+        let start = (row * BYTES_PER_ROW).min(bytes.len());
+        let end = (start + BYTES_PER_ROW).min(bytes.len());
+        &bytes[start..end]
+    }
+
+    fn hex(bytes: &[u8]) -> String {
+        bytes.iter().map(|b| format!("{b:02x}")).collect::<Vec<_>>().join(" ")
+    }
+
+    fn ascii(bytes: &[u8]) -> String {
+        bytes
+            .iter()
+            .map(|&b| if b.is_ascii_graphic() || b == b' ' { b as char } else { '.' })
+            .collect()
+    }
+}
+
+impl Display for HexdumpDiff<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let offset = self.first_difference();
+        writeln!(
+            f,
+            "they differ at offset {offset} (actual length: {}, expected length: {})",
+            self.actual.len(),
+            self.expected.len()
+        )?;
+        let rows = self.actual.len().max(self.expected.len()).div_ceil(BYTES_PER_ROW);
+        let diff_row = offset / BYTES_PER_ROW;
+        let first = diff_row.saturating_sub(CONTEXT_ROWS);
+        let last = (diff_row + CONTEXT_ROWS + 1).min(rows);
+        write!(
+            f,
+            "{:<OFFSET_WIDTH$}{:<HEX_WIDTH$}{:ASCII_WIDTH$}expected",
+            "offset", "actual", ""
+        )?;
+        if first > 0 {
+            write!(f, "\n...")?;
+        }
+        for row in first..last {
+            let (actual, expected) = (Self::row(self.actual, row), Self::row(self.expected, row));
+            let marker = if actual != expected { '>' } else { ' ' };
+            let line = format!(
+                "{:08x} {marker} {:<HEX_WIDTH$} |{:<BYTES_PER_ROW$}|  {:<HEX_WIDTH$} |{:<BYTES_PER_ROW$}|",
+                row * BYTES_PER_ROW,
+                Self::hex(actual),
+                Self::ascii(actual),
+                Self::hex(expected),
+                Self::ascii(expected),
+            );
+            write!(f, "\n{}", line.trim_end())?;
+            if row == diff_row {
+                // points at the first differing byte in both columns
+                let column = OFFSET_WIDTH + (offset % BYTES_PER_ROW) * 3;
+                let gap = HEX_WIDTH + ASCII_WIDTH;
+                write!(f, "\n{:>width$}{:>gap$}", "^^", "^^", width = column + 2)?;
+            }
+        }
+        if last < rows {
+            write!(f, "\n...")?;
+        }
+        Ok(())
+    }
+}
@@ -1,8 +1,11 @@
+use std::path::Path;
+
 use serde::{de::DeserializeOwned, Serialize};
 
 use crate::{accessor::BodyAccessor, body::fallible::FallibleAsserhttpBody};
 
-/// For assertions on http response body
+/// For assertions on http response body.
+/// Assertions can be chained since the body is not consumed, except for Rocket's local responses whose body can only be read once
 pub trait AsserhttpBody<T> {
     /// Allows verifying json body in a closure
     /// * `asserter` - closure to verify json body
@@ -72,6 +75,42 @@ pub trait AsserhttpBody<T> {
     where
         B: DeserializeOwned + Serialize + PartialEq + std::fmt::Debug + Unpin + Sized;
 
+    /// Expects response body to be json and equal to the content of a fixture file.
+    /// * `path` - path of the fixture file, relative to the crate root when running tests
+    ///
+    /// # Bless mode
+    /// When the `ASSERHTTP_BLESS` environment variable is set to anything but `0`, `false` or an empty string, the
+    /// actual body is pretty-printed into the file instead of being compared, creating the file and its parent
+    /// directories when missing. It applies to every fixture of the process, including the ones a test expects to
+    /// differ from the body: generate those outside of the fixtures directory rather than committing them next to the
+    /// others
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use ureq::OrAnyStatus;
+    /// use asserhttp::*;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     reqwest::blocking::get("http://localhost").expect_body_json_eq_file("tests/fixtures/user.json");
+    ///     reqwest::get("http://localhost").await.expect_body_json_eq_file("tests/fixtures/user.json");
+    ///
+    ///     isahc::get("http://localhost").expect_body_json_eq_file("tests/fixtures/user.json");
+    ///     isahc::get_async("http://localhost").await.expect_body_json_eq_file("tests/fixtures/user.json");
+    ///
+    ///     surf::get("http://localhost").await.expect_body_json_eq_file("tests/fixtures/user.json");
+    ///
+    ///     ureq::get("http://localhost").call().or_any_status().expect_body_json_eq_file("tests/fixtures/user.json");
+    ///
+    ///     hyper::Client::new().get("http://localhost".parse().unwrap()).await.expect_body_json_eq_file("tests/fixtures/user.json");
+    ///
+    ///     awc::Client::default().get("http://localhost").send().await.expect_body_json_eq_file("tests/fixtures/user.json");
+    /// }
+    /// ```
+    fn expect_body_json_eq_file<P>(&mut self, path: P) -> &mut T
+    where
+        P: AsRef<Path>;
+
     /// Allows verifying text body in a closure
     /// * `asserter` - closure to verify text body
     ///
@@ -130,6 +169,41 @@ pub trait AsserhttpBody<T> {
     where
         B: Into<String>;
 
+    /// Expects response body to be text and equal to the content of a fixture file.
+    /// * `path` - path of the fixture file, relative to the crate root when running tests
+    ///
+    /// # Bless mode
+    /// When the `ASSERHTTP_BLESS` environment variable is set to anything but `0`, `false` or an empty string, the
+    /// actual body is written into the file instead of being compared, creating the file and its parent directories
+    /// when missing. It applies to every fixture of the process, including the ones a test expects to differ from
+    /// the body: generate those outside of the fixtures directory rather than committing them next to the others
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use ureq::OrAnyStatus;
+    /// use asserhttp::*;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     reqwest::blocking::get("http://localhost").expect_body_text_eq_file("tests/fixtures/user.txt");
+    ///     reqwest::get("http://localhost").await.expect_body_text_eq_file("tests/fixtures/user.txt");
+    ///
+    ///     isahc::get("http://localhost").expect_body_text_eq_file("tests/fixtures/user.txt");
+    ///     isahc::get_async("http://localhost").await.expect_body_text_eq_file("tests/fixtures/user.txt");
+    ///
+    ///     surf::get("http://localhost").await.expect_body_text_eq_file("tests/fixtures/user.txt");
+    ///
+    ///     ureq::get("http://localhost").call().or_any_status().expect_body_text_eq_file("tests/fixtures/user.txt");
+    ///
+    ///     hyper::Client::new().get("http://localhost".parse().unwrap()).await.expect_body_text_eq_file("tests/fixtures/user.txt");
+    ///
+    ///     awc::Client::default().get("http://localhost").send().await.expect_body_text_eq_file("tests/fixtures/user.txt");
+    /// }
+    /// ```
+    fn expect_body_text_eq_file<P>(&mut self, path: P) -> &mut T
+    where
+        P: AsRef<Path>;
+
     /// Expects response body to be text and to match provided regex
     /// * `regex` - must match text response body
     ///
@@ -159,6 +233,122 @@ pub trait AsserhttpBody<T> {
     where
         R: TryInto<regex::Regex, Error = regex::Error>;
 
+    /// Expects response body to be text and to contain the provided text
+    /// * `text` - must be contained in text response body
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use ureq::OrAnyStatus;
+    /// use asserhttp::*;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     reqwest::blocking::get("http://localhost").expect_body_text_contains("bc");
+    ///     reqwest::get("http://localhost").await.expect_body_text_contains("bc");
+    ///
+    ///     isahc::get("http://localhost").expect_body_text_contains("bc");
+    ///     isahc::get_async("http://localhost").await.expect_body_text_contains("bc");
+    ///
+    ///     surf::get("http://localhost").await.expect_body_text_contains("bc");
+    ///
+    ///     ureq::get("http://localhost").call().or_any_status().expect_body_text_contains("bc");
+    ///
+    ///     hyper::Client::new().get("http://localhost".parse().unwrap()).await.expect_body_text_contains("bc");
+    ///
+    ///     awc::Client::default().get("http://localhost").send().await.expect_body_text_contains("bc");
+    /// }
+    /// ```
+    fn expect_body_text_contains<B>(&mut self, text: B) -> &mut T
+    where
+        B: Into<String>;
+
+    /// Expects response body to be text and not to contain the provided text
+    /// * `text` - must not be contained in text response body
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use ureq::OrAnyStatus;
+    /// use asserhttp::*;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     reqwest::blocking::get("http://localhost").expect_body_text_not_contains("xyz");
+    ///     reqwest::get("http://localhost").await.expect_body_text_not_contains("xyz");
+    ///
+    ///     isahc::get("http://localhost").expect_body_text_not_contains("xyz");
+    ///     isahc::get_async("http://localhost").await.expect_body_text_not_contains("xyz");
+    ///
+    ///     surf::get("http://localhost").await.expect_body_text_not_contains("xyz");
+    ///
+    ///     ureq::get("http://localhost").call().or_any_status().expect_body_text_not_contains("xyz");
+    ///
+    ///     hyper::Client::new().get("http://localhost".parse().unwrap()).await.expect_body_text_not_contains("xyz");
+    ///
+    ///     awc::Client::default().get("http://localhost").send().await.expect_body_text_not_contains("xyz");
+    /// }
+    /// ```
+    fn expect_body_text_not_contains<B>(&mut self, text: B) -> &mut T
+    where
+        B: Into<String>;
+
+    /// Expects response body to be text and to start with the provided prefix
+    /// * `prefix` - text response body must start with
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use ureq::OrAnyStatus;
+    /// use asserhttp::*;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     reqwest::blocking::get("http://localhost").expect_body_text_starts_with("ab");
+    ///     reqwest::get("http://localhost").await.expect_body_text_starts_with("ab");
+    ///
+    ///     isahc::get("http://localhost").expect_body_text_starts_with("ab");
+    ///     isahc::get_async("http://localhost").await.expect_body_text_starts_with("ab");
+    ///
+    ///     surf::get("http://localhost").await.expect_body_text_starts_with("ab");
+    ///
+    ///     ureq::get("http://localhost").call().or_any_status().expect_body_text_starts_with("ab");
+    ///
+    ///     hyper::Client::new().get("http://localhost".parse().unwrap()).await.expect_body_text_starts_with("ab");
+    ///
+    ///     awc::Client::default().get("http://localhost").send().await.expect_body_text_starts_with("ab");
+    /// }
+    /// ```
+    fn expect_body_text_starts_with<B>(&mut self, prefix: B) -> &mut T
+    where
+        B: Into<String>;
+
+    /// Expects response body to be text and to end with the provided suffix
+    /// * `suffix` - text response body must end with
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use ureq::OrAnyStatus;
+    /// use asserhttp::*;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     reqwest::blocking::get("http://localhost").expect_body_text_ends_with("cd");
+    ///     reqwest::get("http://localhost").await.expect_body_text_ends_with("cd");
+    ///
+    ///     isahc::get("http://localhost").expect_body_text_ends_with("cd");
+    ///     isahc::get_async("http://localhost").await.expect_body_text_ends_with("cd");
+    ///
+    ///     surf::get("http://localhost").await.expect_body_text_ends_with("cd");
+    ///
+    ///     ureq::get("http://localhost").call().or_any_status().expect_body_text_ends_with("cd");
+    ///
+    ///     hyper::Client::new().get("http://localhost".parse().unwrap()).await.expect_body_text_ends_with("cd");
+    ///
+    ///     awc::Client::default().get("http://localhost").send().await.expect_body_text_ends_with("cd");
+    /// }
+    /// ```
+    fn expect_body_text_ends_with<B>(&mut self, suffix: B) -> &mut T
+    where
+        B: Into<String>;
+
     /// Allows verifying response body bytes in a closure
     /// * `asserter` - closure to verify response body as bytes
     ///
@@ -307,6 +497,16 @@ where
         }
     }
 
+    fn expect_body_json_eq_file<P>(&mut self, path: P) -> &mut T
+    where
+        P: AsRef<Path>,
+    {
+        match self.try_expect_body_json_eq_file(path) {
+            Err(e) => panic!("{e}"),
+            Ok(r) => r,
+        }
+    }
+
     fn expect_body_text<F>(&mut self, asserter: F) -> &mut T
     where
         F: FnOnce(String),
@@ -331,6 +531,16 @@ where
         }
     }
 
+    fn expect_body_text_eq_file<P>(&mut self, path: P) -> &mut T
+    where
+        P: AsRef<Path>,
+    {
+        match self.try_expect_body_text_eq_file(path) {
+            Err(e) => panic!("{e}"),
+            Ok(r) => r,
+        }
+    }
+
     fn expect_body_text_matches<R>(&mut self, regex: R) -> &mut T
     where
         R: TryInto<regex::Regex, Error = regex::Error>,
@@ -345,6 +555,46 @@ where
         }
     }
 
+    fn expect_body_text_contains<B>(&mut self, text: B) -> &mut T
+    where
+        B: Into<String>,
+    {
+        match self.try_expect_body_text_contains(text) {
+            Err(e) => panic!("{e}"),
+            Ok(r) => r,
+        }
+    }
+
+    fn expect_body_text_not_contains<B>(&mut self, text: B) -> &mut T
+    where
+        B: Into<String>,
+    {
+        match self.try_expect_body_text_not_contains(text) {
+            Err(e) => panic!("{e}"),
+            Ok(r) => r,
+        }
+    }
+
+    fn expect_body_text_starts_with<B>(&mut self, prefix: B) -> &mut T
+    where
+        B: Into<String>,
+    {
+        match self.try_expect_body_text_starts_with(prefix) {
+            Err(e) => panic!("{e}"),
+            Ok(r) => r,
+        }
+    }
+
+    fn expect_body_text_ends_with<B>(&mut self, suffix: B) -> &mut T
+    where
+        B: Into<String>,
+    {
+        match self.try_expect_body_text_ends_with(suffix) {
+            Err(e) => panic!("{e}"),
+            Ok(r) => r,
+        }
+    }
+
     fn expect_body_bytes<F>(&mut self, asserter: F) -> &mut T
     where
         F: FnOnce(&[u8]),
@@ -401,6 +651,13 @@ where
         self.as_mut().unwrap().expect_body_json_eq(body)
     }
 
+    fn expect_body_json_eq_file<P>(&mut self, path: P) -> &mut T
+    where
+        P: AsRef<Path>,
+    {
+        self.as_mut().unwrap().expect_body_json_eq_file(path)
+    }
+
     fn expect_body_text<F>(&mut self, asserter: F) -> &mut T
     where
         F: FnOnce(String),
@@ -415,6 +672,13 @@ where
         self.as_mut().unwrap().expect_body_text_eq(body)
     }
 
+    fn expect_body_text_eq_file<P>(&mut self, path: P) -> &mut T
+    where
+        P: AsRef<Path>,
+    {
+        self.as_mut().unwrap().expect_body_text_eq_file(path)
+    }
+
     fn expect_body_text_matches<R>(&mut self, regex: R) -> &mut T
     where
         R: TryInto<regex::Regex, Error = regex::Error>,
@@ -422,6 +686,34 @@ where
         self.as_mut().unwrap().expect_body_text_matches(regex)
     }
 
+    fn expect_body_text_contains<B>(&mut self, text: B) -> &mut T
+    where
+        B: Into<String>,
+    {
+        self.as_mut().unwrap().expect_body_text_contains(text)
+    }
+
+    fn expect_body_text_not_contains<B>(&mut self, text: B) -> &mut T
+    where
+        B: Into<String>,
+    {
+        self.as_mut().unwrap().expect_body_text_not_contains(text)
+    }
+
+    fn expect_body_text_starts_with<B>(&mut self, prefix: B) -> &mut T
+    where
+        B: Into<String>,
+    {
+        self.as_mut().unwrap().expect_body_text_starts_with(prefix)
+    }
+
+    fn expect_body_text_ends_with<B>(&mut self, suffix: B) -> &mut T
+    where
+        B: Into<String>,
+    {
+        self.as_mut().unwrap().expect_body_text_ends_with(suffix)
+    }
+
     fn expect_body_bytes<F>(&mut self, asserter: F) -> &mut T
     where
         F: FnOnce(&[u8]),
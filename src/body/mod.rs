@@ -1,2 +1,6 @@
+pub(crate) mod deser;
 pub(crate) mod fallible;
+pub(crate) mod fixture;
+pub(crate) mod hexdump;
 pub(crate) mod infallible;
+pub(crate) mod snippet;
@@ -0,0 +1,44 @@
+const SNIPPET_LEN: usize = 64;
+const ELLIPSIS: &str = "...";
+
+/// Beginning of the body, truncated when too long to be displayed
+pub fn head(body: &str) -> String {
+    match body.char_indices().nth(SNIPPET_LEN) {
+        Some((end, _)) => format!("{}{ELLIPSIS}", &body[..end]),
+        None => body.to_string(),
+    }
+}
+
+/// End of the body, truncated when too long to be displayed
+pub fn tail(body: &str) -> String {
+    match body.char_indices().rev().nth(SNIPPET_LEN - 1) {
+        Some((start, _)) if start > 0 => format!("{ELLIPSIS}{}", &body[start..]),
+        _ => body.to_string(),
+    }
+}
+
+/// Part of the body surrounding the first occurrence of `text`
+pub fn around(body: &str, text: &str) -> String {
+    let Some(position) = body.find(text) else {
+        return head(body);
+    };
+    let context = SNIPPET_LEN.saturating_sub(text.chars().count()) / 2;
+    let start = match context {
+        0 => position,
+        _ => body[..position]
+            .char_indices()
+            .rev()
+            .nth(context - 1)
+            .map(|(i, _)| i)
+            .unwrap_or(0),
+    };
+    let after = position + text.len();
+    let end = body[after..]
+        .char_indices()
+        .nth(context)
+        .map(|(i, _)| after + i)
+        .unwrap_or(body.len());
+    let prefix = if start > 0 { ELLIPSIS } else { "" };
+    let suffix = if end < body.len() { ELLIPSIS } else { "" };
+    format!("{prefix}{}{suffix}", &body[start..end])
+}
@@ -0,0 +1,230 @@
+use std::time::Duration;
+
+use crate::{
+    accessor::HeaderAccessor,
+    cache_control::CacheControl,
+    error::{AsserhttpError, AsserhttpResult},
+    header::key::HeaderKey,
+};
+
+/// For assertions on the `Cache-Control` header returning an error instead of panicking
+pub trait FallibleAsserhttpCacheControl<T> {
+    /// Allows verifying the `Cache-Control` directives in a closure, whatever their order, spacing or case
+    /// * `asserter` - closure to verify the parsed directives
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use ureq::OrAnyStatus;
+    /// use asserhttp::*;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     reqwest::blocking::get("http://localhost").try_expect_cache_control(|cc| { assert!(cc.no_cache() && cc.must_revalidate()); Ok(()) }).unwrap();
+    ///     reqwest::get("http://localhost").await.try_expect_cache_control(|cc| { assert!(cc.no_cache() && cc.must_revalidate()); Ok(()) }).unwrap();
+    ///
+    ///     isahc::get("http://localhost").try_expect_cache_control(|cc| { assert!(cc.no_cache() && cc.must_revalidate()); Ok(()) }).unwrap();
+    ///     isahc::get_async("http://localhost").await.try_expect_cache_control(|cc| { assert!(cc.no_cache() && cc.must_revalidate()); Ok(()) }).unwrap();
+    ///
+    ///     surf::get("http://localhost").await.try_expect_cache_control(|cc| { assert!(cc.no_cache() && cc.must_revalidate()); Ok(()) }).unwrap();
+    ///
+    ///     ureq::get("http://localhost").call().or_any_status().try_expect_cache_control(|cc| { assert!(cc.no_cache() && cc.must_revalidate()); Ok(()) }).unwrap();
+    ///
+    ///     hyper::Client::new().get("http://localhost".parse().unwrap()).await.try_expect_cache_control(|cc| { assert!(cc.no_cache() && cc.must_revalidate()); Ok(()) }).unwrap();
+    ///
+    ///     awc::Client::default().get("http://localhost").send().await.try_expect_cache_control(|cc| { assert!(cc.no_cache() && cc.must_revalidate()); Ok(()) }).unwrap();
+    /// }
+    /// ```
+    /// # Error
+    /// * [AsserhttpError::HeaderAbsent] when the response has no `Cache-Control` header
+    fn try_expect_cache_control<F>(&mut self, asserter: F) -> AsserhttpResult<&mut T>
+    where
+        F: FnOnce(CacheControl) -> AsserhttpResult<()>;
+
+    /// Expects the `Cache-Control` header to contain the `no-store` directive
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use ureq::OrAnyStatus;
+    /// use asserhttp::*;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     reqwest::blocking::get("http://localhost").try_expect_no_store().unwrap();
+    ///     reqwest::get("http://localhost").await.try_expect_no_store().unwrap();
+    ///
+    ///     isahc::get("http://localhost").try_expect_no_store().unwrap();
+    ///     isahc::get_async("http://localhost").await.try_expect_no_store().unwrap();
+    ///
+    ///     surf::get("http://localhost").await.try_expect_no_store().unwrap();
+    ///
+    ///     ureq::get("http://localhost").call().or_any_status().try_expect_no_store().unwrap();
+    ///
+    ///     hyper::Client::new().get("http://localhost".parse().unwrap()).await.try_expect_no_store().unwrap();
+    ///
+    ///     awc::Client::default().get("http://localhost").send().await.try_expect_no_store().unwrap();
+    /// }
+    /// ```
+    /// # Error
+    /// * [AsserhttpError::HeaderAbsent] when the response has no `Cache-Control` header
+    /// * [AsserhttpError::CacheControlMismatch] when the directive is absent
+    fn try_expect_no_store(&mut self) -> AsserhttpResult<&mut T> {
+        self.try_expect_cache_control(|cc| {
+            if cc.has("no-store") {
+                return Ok(());
+            }
+            Err(AsserhttpError::CacheControlMismatch {
+                expected: "no-store".to_string(),
+                actual: cc.to_string(),
+            })
+        })
+    }
+
+    /// Expects the `Cache-Control` header `max-age` directive to be the given duration, truncated to seconds
+    /// * `max_age` - expected `max-age`
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use ureq::OrAnyStatus;
+    /// use asserhttp::*;
+    /// use std::time::Duration;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     reqwest::blocking::get("http://localhost").try_expect_max_age(Duration::from_secs(3600)).unwrap();
+    ///     reqwest::get("http://localhost").await.try_expect_max_age(Duration::from_secs(3600)).unwrap();
+    ///
+    ///     isahc::get("http://localhost").try_expect_max_age(Duration::from_secs(3600)).unwrap();
+    ///     isahc::get_async("http://localhost").await.try_expect_max_age(Duration::from_secs(3600)).unwrap();
+    ///
+    ///     surf::get("http://localhost").await.try_expect_max_age(Duration::from_secs(3600)).unwrap();
+    ///
+    ///     ureq::get("http://localhost").call().or_any_status().try_expect_max_age(Duration::from_secs(3600)).unwrap();
+    ///
+    ///     hyper::Client::new().get("http://localhost".parse().unwrap()).await.try_expect_max_age(Duration::from_secs(3600)).unwrap();
+    ///
+    ///     awc::Client::default().get("http://localhost").send().await.try_expect_max_age(Duration::from_secs(3600)).unwrap();
+    /// }
+    /// ```
+    /// # Error
+    /// * [AsserhttpError::HeaderAbsent] when the response has no `Cache-Control` header
+    /// * [AsserhttpError::CacheControlMismatch] when the directive is absent or has another value
+    fn try_expect_max_age(&mut self, max_age: Duration) -> AsserhttpResult<&mut T> {
+        self.try_expect_cache_control(|cc| {
+            if cc.max_age().map(|d| d.as_secs()) == Some(max_age.as_secs()) {
+                return Ok(());
+            }
+            Err(AsserhttpError::CacheControlMismatch {
+                expected: format!("max-age={}", max_age.as_secs()),
+                actual: cc.to_string(),
+            })
+        })
+    }
+
+    /// Expects the `Cache-Control` header to contain the `private` directive
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use ureq::OrAnyStatus;
+    /// use asserhttp::*;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     reqwest::blocking::get("http://localhost").try_expect_cache_private().unwrap();
+    ///     reqwest::get("http://localhost").await.try_expect_cache_private().unwrap();
+    ///
+    ///     isahc::get("http://localhost").try_expect_cache_private().unwrap();
+    ///     isahc::get_async("http://localhost").await.try_expect_cache_private().unwrap();
+    ///
+    ///     surf::get("http://localhost").await.try_expect_cache_private().unwrap();
+    ///
+    ///     ureq::get("http://localhost").call().or_any_status().try_expect_cache_private().unwrap();
+    ///
+    ///     hyper::Client::new().get("http://localhost".parse().unwrap()).await.try_expect_cache_private().unwrap();
+    ///
+    ///     awc::Client::default().get("http://localhost").send().await.try_expect_cache_private().unwrap();
+    /// }
+    /// ```
+    /// # Error
+    /// * [AsserhttpError::HeaderAbsent] when the response has no `Cache-Control` header
+    /// * [AsserhttpError::CacheControlMismatch] when the directive is absent
+    fn try_expect_cache_private(&mut self) -> AsserhttpResult<&mut T> {
+        self.try_expect_cache_control(|cc| {
+            if cc.has("private") {
+                return Ok(());
+            }
+            Err(AsserhttpError::CacheControlMismatch {
+                expected: "private".to_string(),
+                actual: cc.to_string(),
+            })
+        })
+    }
+
+    /// Expects the `Cache-Control` header to contain the `must-revalidate` directive
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use ureq::OrAnyStatus;
+    /// use asserhttp::*;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     reqwest::blocking::get("http://localhost").try_expect_must_revalidate().unwrap();
+    ///     reqwest::get("http://localhost").await.try_expect_must_revalidate().unwrap();
+    ///
+    ///     isahc::get("http://localhost").try_expect_must_revalidate().unwrap();
+    ///     isahc::get_async("http://localhost").await.try_expect_must_revalidate().unwrap();
+    ///
+    ///     surf::get("http://localhost").await.try_expect_must_revalidate().unwrap();
+    ///
+    ///     ureq::get("http://localhost").call().or_any_status().try_expect_must_revalidate().unwrap();
+    ///
+    ///     hyper::Client::new().get("http://localhost".parse().unwrap()).await.try_expect_must_revalidate().unwrap();
+    ///
+    ///     awc::Client::default().get("http://localhost").send().await.try_expect_must_revalidate().unwrap();
+    /// }
+    /// ```
+    /// # Error
+    /// * [AsserhttpError::HeaderAbsent] when the response has no `Cache-Control` header
+    /// * [AsserhttpError::CacheControlMismatch] when the directive is absent
+    fn try_expect_must_revalidate(&mut self) -> AsserhttpResult<&mut T> {
+        self.try_expect_cache_control(|cc| {
+            if cc.has("must-revalidate") {
+                return Ok(());
+            }
+            Err(AsserhttpError::CacheControlMismatch {
+                expected: "must-revalidate".to_string(),
+                actual: cc.to_string(),
+            })
+        })
+    }
+}
+
+impl<T> FallibleAsserhttpCacheControl<T> for T
+where
+    T: HeaderAccessor,
+{
+    fn try_expect_cache_control<F>(&mut self, asserter: F) -> AsserhttpResult<&mut T>
+    where
+        F: FnOnce(CacheControl) -> AsserhttpResult<()>,
+    {
+        let key = HeaderKey::from(crate::headers::CACHE_CONTROL);
+        key.try_assert_contained(self.get_keys())?;
+        asserter(CacheControl::parse(&self.get_raw_values(&key)))?;
+        Ok(self)
+    }
+}
+
+impl<T, E> FallibleAsserhttpCacheControl<T> for Result<T, E>
+where
+    T: HeaderAccessor,
+    E: std::fmt::Debug,
+{
+    fn try_expect_cache_control<F>(&mut self, asserter: F) -> AsserhttpResult<&mut T>
+    where
+        F: FnOnce(CacheControl) -> AsserhttpResult<()>,
+    {
+        self.as_mut()
+            .map_err(|e| AsserhttpError::HttpError(format!("{e:?}")))?
+            .try_expect_cache_control(asserter)
+    }
+}
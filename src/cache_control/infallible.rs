@@ -0,0 +1,220 @@
+use std::time::Duration;
+
+use crate::{
+    accessor::HeaderAccessor,
+    cache_control::{fallible::FallibleAsserhttpCacheControl, CacheControl},
+};
+
+/// For assertions on the `Cache-Control` header
+pub trait AsserhttpCacheControl<T> {
+    /// Allows verifying the `Cache-Control` directives in a closure, whatever their order, spacing or case
+    /// * `asserter` - closure to verify the parsed directives
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use ureq::OrAnyStatus;
+    /// use asserhttp::*;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     reqwest::blocking::get("http://localhost").expect_cache_control(|cc| assert!(cc.no_cache() && cc.must_revalidate()));
+    ///     reqwest::get("http://localhost").await.expect_cache_control(|cc| assert!(cc.no_cache() && cc.must_revalidate()));
+    ///
+    ///     isahc::get("http://localhost").expect_cache_control(|cc| assert!(cc.no_cache() && cc.must_revalidate()));
+    ///     isahc::get_async("http://localhost").await.expect_cache_control(|cc| assert!(cc.no_cache() && cc.must_revalidate()));
+    ///
+    ///     surf::get("http://localhost").await.expect_cache_control(|cc| assert!(cc.no_cache() && cc.must_revalidate()));
+    ///
+    ///     ureq::get("http://localhost").call().or_any_status().expect_cache_control(|cc| assert!(cc.no_cache() && cc.must_revalidate()));
+    ///
+    ///     hyper::Client::new().get("http://localhost".parse().unwrap()).await.expect_cache_control(|cc| assert!(cc.no_cache() && cc.must_revalidate()));
+    ///
+    ///     awc::Client::default().get("http://localhost").send().await.expect_cache_control(|cc| assert!(cc.no_cache() && cc.must_revalidate()));
+    /// }
+    /// ```
+    fn expect_cache_control<F>(&mut self, asserter: F) -> &mut T
+    where
+        F: FnOnce(CacheControl);
+
+    /// Expects the `Cache-Control` header to contain the `no-store` directive
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use ureq::OrAnyStatus;
+    /// use asserhttp::*;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     reqwest::blocking::get("http://localhost").expect_no_store();
+    ///     reqwest::get("http://localhost").await.expect_no_store();
+    ///
+    ///     isahc::get("http://localhost").expect_no_store();
+    ///     isahc::get_async("http://localhost").await.expect_no_store();
+    ///
+    ///     surf::get("http://localhost").await.expect_no_store();
+    ///
+    ///     ureq::get("http://localhost").call().or_any_status().expect_no_store();
+    ///
+    ///     hyper::Client::new().get("http://localhost".parse().unwrap()).await.expect_no_store();
+    ///
+    ///     awc::Client::default().get("http://localhost").send().await.expect_no_store();
+    /// }
+    /// ```
+    fn expect_no_store(&mut self) -> &mut T;
+
+    /// Expects the `Cache-Control` header `max-age` directive to be the given duration, truncated to seconds
+    /// * `max_age` - expected `max-age`
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use ureq::OrAnyStatus;
+    /// use asserhttp::*;
+    /// use std::time::Duration;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     reqwest::blocking::get("http://localhost").expect_max_age(Duration::from_secs(3600));
+    ///     reqwest::get("http://localhost").await.expect_max_age(Duration::from_secs(3600));
+    ///
+    ///     isahc::get("http://localhost").expect_max_age(Duration::from_secs(3600));
+    ///     isahc::get_async("http://localhost").await.expect_max_age(Duration::from_secs(3600));
+    ///
+    ///     surf::get("http://localhost").await.expect_max_age(Duration::from_secs(3600));
+    ///
+    ///     ureq::get("http://localhost").call().or_any_status().expect_max_age(Duration::from_secs(3600));
+    ///
+    ///     hyper::Client::new().get("http://localhost".parse().unwrap()).await.expect_max_age(Duration::from_secs(3600));
+    ///
+    ///     awc::Client::default().get("http://localhost").send().await.expect_max_age(Duration::from_secs(3600));
+    /// }
+    /// ```
+    fn expect_max_age(&mut self, max_age: Duration) -> &mut T;
+
+    /// Expects the `Cache-Control` header to contain the `private` directive
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use ureq::OrAnyStatus;
+    /// use asserhttp::*;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     reqwest::blocking::get("http://localhost").expect_cache_private();
+    ///     reqwest::get("http://localhost").await.expect_cache_private();
+    ///
+    ///     isahc::get("http://localhost").expect_cache_private();
+    ///     isahc::get_async("http://localhost").await.expect_cache_private();
+    ///
+    ///     surf::get("http://localhost").await.expect_cache_private();
+    ///
+    ///     ureq::get("http://localhost").call().or_any_status().expect_cache_private();
+    ///
+    ///     hyper::Client::new().get("http://localhost".parse().unwrap()).await.expect_cache_private();
+    ///
+    ///     awc::Client::default().get("http://localhost").send().await.expect_cache_private();
+    /// }
+    /// ```
+    fn expect_cache_private(&mut self) -> &mut T;
+
+    /// Expects the `Cache-Control` header to contain the `must-revalidate` directive
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use ureq::OrAnyStatus;
+    /// use asserhttp::*;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     reqwest::blocking::get("http://localhost").expect_must_revalidate();
+    ///     reqwest::get("http://localhost").await.expect_must_revalidate();
+    ///
+    ///     isahc::get("http://localhost").expect_must_revalidate();
+    ///     isahc::get_async("http://localhost").await.expect_must_revalidate();
+    ///
+    ///     surf::get("http://localhost").await.expect_must_revalidate();
+    ///
+    ///     ureq::get("http://localhost").call().or_any_status().expect_must_revalidate();
+    ///
+    ///     hyper::Client::new().get("http://localhost".parse().unwrap()).await.expect_must_revalidate();
+    ///
+    ///     awc::Client::default().get("http://localhost").send().await.expect_must_revalidate();
+    /// }
+    /// ```
+    fn expect_must_revalidate(&mut self) -> &mut T;
+}
+
+impl<T> AsserhttpCacheControl<T> for T
+where
+    T: HeaderAccessor,
+{
+    fn expect_cache_control<F>(&mut self, asserter: F) -> &mut T
+    where
+        F: FnOnce(CacheControl),
+    {
+        #[allow(clippy::blocks_in_conditions)]
+        match self.try_expect_cache_control(|v| {
+            asserter(v);
+            Ok(())
+        }) {
+            Err(e) => panic!("{e}"),
+            Ok(r) => r,
+        }
+    }
+
+    fn expect_no_store(&mut self) -> &mut T {
+        match self.try_expect_no_store() {
+            Err(e) => panic!("{e}"),
+            Ok(r) => r,
+        }
+    }
+
+    fn expect_max_age(&mut self, max_age: Duration) -> &mut T {
+        match self.try_expect_max_age(max_age) {
+            Err(e) => panic!("{e}"),
+            Ok(r) => r,
+        }
+    }
+
+    fn expect_cache_private(&mut self) -> &mut T {
+        match self.try_expect_cache_private() {
+            Err(e) => panic!("{e}"),
+            Ok(r) => r,
+        }
+    }
+
+    fn expect_must_revalidate(&mut self) -> &mut T {
+        match self.try_expect_must_revalidate() {
+            Err(e) => panic!("{e}"),
+            Ok(r) => r,
+        }
+    }
+}
+
+impl<T, E> AsserhttpCacheControl<T> for Result<T, E>
+where
+    T: HeaderAccessor,
+    E: std::fmt::Debug,
+{
+    fn expect_cache_control<F>(&mut self, asserter: F) -> &mut T
+    where
+        F: FnOnce(CacheControl),
+    {
+        self.as_mut().unwrap().expect_cache_control(asserter)
+    }
+
+    fn expect_no_store(&mut self) -> &mut T {
+        self.as_mut().unwrap().expect_no_store()
+    }
+
+    fn expect_max_age(&mut self, max_age: Duration) -> &mut T {
+        self.as_mut().unwrap().expect_max_age(max_age)
+    }
+
+    fn expect_cache_private(&mut self) -> &mut T {
+        self.as_mut().unwrap().expect_cache_private()
+    }
+
+    fn expect_must_revalidate(&mut self) -> &mut T {
+        self.as_mut().unwrap().expect_must_revalidate()
+    }
+}
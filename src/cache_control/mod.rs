@@ -0,0 +1,115 @@
+use crate::header::quoted::{split_unquoted, unquote};
+use std::time::Duration;
+
+pub(crate) mod fallible;
+pub(crate) mod infallible;
+
+/// Directives of a [Cache-Control](https://www.rfc-editor.org/rfc/rfc9111#name-cache-control) header, in order
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+pub struct CacheControl(Vec<Directive>);
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+struct Directive {
+    name: String,
+    value: Option<String>,
+    /// Whether the value was a quoted string e.g. `no-cache="set-cookie"`, so that it is displayed the same way
+    quoted: bool,
+}
+
+impl CacheControl {
+    /// Parses the comma separated directives of every `Cache-Control` line. Names are lowercased, quoted values unquoted
+    pub(crate) fn parse(lines: &[String]) -> Self {
+        Self(
+            lines
+                .iter()
+                .flat_map(|line| split_unquoted(line, ','))
+                .map(|directive| match directive.split_once('=') {
+                    Some((name, value)) => Directive {
+                        name: name.trim().to_lowercase(),
+                        value: Some(unquote(value.trim())),
+                        quoted: value.trim().starts_with('"'),
+                    },
+                    None => Directive {
+                        name: directive.trim().to_lowercase(),
+                        value: None,
+                        quoted: false,
+                    },
+                })
+                .filter(|directive| !directive.name.is_empty())
+                .collect(),
+        )
+    }
+
+    /// Whether the directive is present, with or without a value
+    pub fn has(&self, name: &str) -> bool {
+        self.0.iter().any(|d| d.name.eq_ignore_ascii_case(name))
+    }
+
+    /// Value of the directive e.g. `Some("60")` for `max-age=60`. `None` when absent or valueless
+    pub fn value(&self, name: &str) -> Option<&str> {
+        self.0
+            .iter()
+            .find(|d| d.name.eq_ignore_ascii_case(name))
+            .and_then(|d| d.value.as_deref())
+    }
+
+    /// Directive names in order
+    pub fn directives(&self) -> Vec<&str> {
+        self.0.iter().map(|d| d.name.as_str()).collect()
+    }
+
+    pub fn max_age(&self) -> Option<Duration> {
+        self.seconds("max-age")
+    }
+
+    pub fn s_maxage(&self) -> Option<Duration> {
+        self.seconds("s-maxage")
+    }
+
+    pub fn stale_while_revalidate(&self) -> Option<Duration> {
+        self.seconds("stale-while-revalidate")
+    }
+
+    pub fn no_store(&self) -> bool {
+        self.has("no-store")
+    }
+
+    pub fn no_cache(&self) -> bool {
+        self.has("no-cache")
+    }
+
+    pub fn private(&self) -> bool {
+        self.has("private")
+    }
+
+    pub fn public(&self) -> bool {
+        self.has("public")
+    }
+
+    pub fn must_revalidate(&self) -> bool {
+        self.has("must-revalidate")
+    }
+
+    pub fn immutable(&self) -> bool {
+        self.has("immutable")
+    }
+
+    fn seconds(&self, name: &str) -> Option<Duration> {
+        self.value(name).and_then(|v| v.parse().ok()).map(Duration::from_secs)
+    }
+}
+
+impl std::fmt::Display for CacheControl {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let directives = self
+            .0
+            .iter()
+            .map(|Directive { name, value, quoted }| match value {
+                Some(value) if *quoted => format!("{name}=\"{}\"", value.replace('\\', "\\\\").replace('"', "\\\"")),
+                Some(value) => format!("{name}={value}"),
+                None => name.to_string(),
+            })
+            .collect::<Vec<_>>();
+        write!(f, "{}", directives.join(", "))
+    }
+}
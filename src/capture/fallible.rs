@@ -0,0 +1,186 @@
+use serde::de::DeserializeOwned;
+
+use crate::{
+    accessor::{BodyAccessor, HeaderAccessor},
+    body::deser,
+    capture::JsonPath,
+    error::{AsserhttpError, AsserhttpResult},
+    header::key::HeaderKey,
+};
+
+/// For capturing values from the response to reuse them in later requests, returning an error instead of panicking.
+/// Reading the body does not consume it so captures can follow body assertions, except for Rocket's local responses
+/// whose body can only be read once
+pub trait FallibleAsserhttpCapture<T> {
+    /// Captures the value selected by a JSONPath in the json body
+    /// Only simple paths selecting a single value are supported e.g. `$.items[0].id`
+    /// * `path` - JSONPath of the value to capture
+    /// * `into` - where to store the captured value
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use ureq::OrAnyStatus;
+    /// use asserhttp::*;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let mut id: u64 = 0;
+    ///     reqwest::blocking::get("http://localhost").try_capture_json_path("$.id", &mut id).unwrap();
+    ///     reqwest::get("http://localhost").await.try_capture_json_path("$.id", &mut id).unwrap();
+    ///
+    ///     isahc::get("http://localhost").try_capture_json_path("$.id", &mut id).unwrap();
+    ///     isahc::get_async("http://localhost").await.try_capture_json_path("$.id", &mut id).unwrap();
+    ///
+    ///     surf::get("http://localhost").await.try_capture_json_path("$.id", &mut id).unwrap();
+    ///
+    ///     ureq::get("http://localhost").call().or_any_status().try_capture_json_path("$.id", &mut id).unwrap();
+    ///
+    ///     hyper::Client::new().get("http://localhost".parse().unwrap()).await.try_capture_json_path("$.id", &mut id).unwrap();
+    ///
+    ///     awc::Client::default().get("http://localhost").send().await.try_capture_json_path("$.id", &mut id).unwrap();
+    /// }
+    /// ```
+    /// # Error
+    /// * [AsserhttpError::BodyAbsent] when the response body is empty
+    /// * [AsserhttpError::JsonPathInvalid] when the path is not supported
+    /// * [AsserhttpError::JsonPathAbsent] when the path does not select any value
+    /// * [AsserhttpError::JsonBodyDeserialization] when the selected value cannot be deserialized into `V`
+    fn try_capture_json_path<V>(&mut self, path: &str, into: &mut V) -> AsserhttpResult<&mut T>
+    where
+        V: DeserializeOwned;
+
+    /// Captures the value of a single valued header
+    /// * `key` - header key
+    /// * `into` - where to store the captured value
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use ureq::OrAnyStatus;
+    /// use asserhttp::*;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let mut request_id = String::new();
+    ///     reqwest::blocking::get("http://localhost").try_capture_header("x-request-id", &mut request_id).unwrap();
+    ///     reqwest::get("http://localhost").await.try_capture_header("x-request-id", &mut request_id).unwrap();
+    ///
+    ///     isahc::get("http://localhost").try_capture_header("x-request-id", &mut request_id).unwrap();
+    ///     isahc::get_async("http://localhost").await.try_capture_header("x-request-id", &mut request_id).unwrap();
+    ///
+    ///     surf::get("http://localhost").await.try_capture_header("x-request-id", &mut request_id).unwrap();
+    ///
+    ///     ureq::get("http://localhost").call().or_any_status().try_capture_header("x-request-id", &mut request_id).unwrap();
+    ///
+    ///     hyper::Client::new().get("http://localhost".parse().unwrap()).await.try_capture_header("x-request-id", &mut request_id).unwrap();
+    ///
+    ///     awc::Client::default().get("http://localhost").send().await.try_capture_header("x-request-id", &mut request_id).unwrap();
+    /// }
+    /// ```
+    /// # Error
+    /// * [AsserhttpError::HeaderAbsent] when the header is absent
+    /// * [AsserhttpError::MultivaluedHeader] when the header has many values
+    fn try_capture_header(&mut self, key: impl Into<HeaderKey>, into: &mut String) -> AsserhttpResult<&mut T>;
+
+    /// Deserializes the json body, usually as the last step after assertions
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use ureq::OrAnyStatus;
+    /// use asserhttp::*;
+    /// use serde_json::Value;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     reqwest::blocking::get("http://localhost").try_expect_status(200).unwrap().try_extract_json::<Value>().unwrap();
+    ///     reqwest::get("http://localhost").await.try_expect_status(200).unwrap().try_extract_json::<Value>().unwrap();
+    ///
+    ///     isahc::get("http://localhost").try_expect_status(200).unwrap().try_extract_json::<Value>().unwrap();
+    ///     isahc::get_async("http://localhost").await.try_expect_status(200).unwrap().try_extract_json::<Value>().unwrap();
+    ///
+    ///     surf::get("http://localhost").await.try_expect_status(200).unwrap().try_extract_json::<Value>().unwrap();
+    ///
+    ///     ureq::get("http://localhost").call().or_any_status().try_expect_status(200).unwrap().try_extract_json::<Value>().unwrap();
+    ///
+    ///     hyper::Client::new().get("http://localhost".parse().unwrap()).await.try_expect_status(200).unwrap().try_extract_json::<Value>().unwrap();
+    ///
+    ///     awc::Client::default().get("http://localhost").send().await.try_expect_status(200).unwrap().try_extract_json::<Value>().unwrap();
+    /// }
+    /// ```
+    /// # Error
+    /// * [AsserhttpError::BodyAbsent] when the response body is empty
+    /// * [AsserhttpError::JsonBodyDeserialization] when the body cannot be deserialized into `B`
+    fn try_extract_json<B>(&mut self) -> AsserhttpResult<B>
+    where
+        B: DeserializeOwned + Unpin;
+}
+
+impl<T> FallibleAsserhttpCapture<T> for T
+where
+    T: HeaderAccessor + BodyAccessor,
+{
+    fn try_capture_json_path<V>(&mut self, path: &str, into: &mut V) -> AsserhttpResult<&mut T>
+    where
+        V: DeserializeOwned,
+    {
+        let json_path = JsonPath::parse(path)?;
+        let body = self.get_json::<serde_json::Value>()?;
+        let value = json_path
+            .select(&body)
+            .ok_or_else(|| AsserhttpError::JsonPathAbsent { path: path.to_string() })?;
+        *into = deser::from_value(value, path)?;
+        Ok(self)
+    }
+
+    fn try_capture_header(&mut self, key: impl Into<HeaderKey>, into: &mut String) -> AsserhttpResult<&mut T> {
+        let key = key.into();
+        key.try_assert_contained(self.get_keys())?;
+        match self.get_raw_values(&key).as_slice() {
+            [value] => *into = value.to_string(),
+            values => {
+                return Err(AsserhttpError::MultivaluedHeader {
+                    key,
+                    values_count: values.len(),
+                    actual_values: values.to_vec().into(),
+                })
+            },
+        }
+        Ok(self)
+    }
+
+    fn try_extract_json<B>(&mut self) -> AsserhttpResult<B>
+    where
+        B: DeserializeOwned + Unpin,
+    {
+        self.get_json()
+    }
+}
+
+impl<T, E> FallibleAsserhttpCapture<T> for Result<T, E>
+where
+    T: HeaderAccessor + BodyAccessor,
+    E: std::fmt::Debug,
+{
+    fn try_capture_json_path<V>(&mut self, path: &str, into: &mut V) -> AsserhttpResult<&mut T>
+    where
+        V: DeserializeOwned,
+    {
+        self.as_mut()
+            .map_err(|e| AsserhttpError::HttpError(format!("{e:?}")))?
+            .try_capture_json_path(path, into)
+    }
+
+    fn try_capture_header(&mut self, key: impl Into<HeaderKey>, into: &mut String) -> AsserhttpResult<&mut T> {
+        self.as_mut()
+            .map_err(|e| AsserhttpError::HttpError(format!("{e:?}")))?
+            .try_capture_header(key, into)
+    }
+
+    fn try_extract_json<B>(&mut self) -> AsserhttpResult<B>
+    where
+        B: DeserializeOwned + Unpin,
+    {
+        self.as_mut()
+            .map_err(|e| AsserhttpError::HttpError(format!("{e:?}")))?
+            .try_extract_json()
+    }
+}
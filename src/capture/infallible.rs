@@ -0,0 +1,158 @@
+use serde::de::DeserializeOwned;
+
+use crate::{
+    accessor::{BodyAccessor, HeaderAccessor},
+    capture::fallible::FallibleAsserhttpCapture,
+    header::key::HeaderKey,
+};
+
+/// For capturing values from the response to reuse them in later requests.
+/// Reading the body does not consume it so captures can follow body assertions, except for Rocket's local responses
+/// whose body can only be read once
+pub trait AsserhttpCapture<T> {
+    /// Captures the value selected by a JSONPath in the json body
+    /// Only simple paths selecting a single value are supported e.g. `$.items[0].id`
+    /// * `path` - JSONPath of the value to capture
+    /// * `into` - where to store the captured value
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use ureq::OrAnyStatus;
+    /// use asserhttp::*;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let mut id: u64 = 0;
+    ///     reqwest::blocking::get("http://localhost").capture_json_path("$.id", &mut id);
+    ///     reqwest::get("http://localhost").await.capture_json_path("$.id", &mut id);
+    ///
+    ///     isahc::get("http://localhost").capture_json_path("$.id", &mut id);
+    ///     isahc::get_async("http://localhost").await.capture_json_path("$.id", &mut id);
+    ///
+    ///     surf::get("http://localhost").await.capture_json_path("$.id", &mut id);
+    ///
+    ///     ureq::get("http://localhost").call().or_any_status().capture_json_path("$.id", &mut id);
+    ///
+    ///     hyper::Client::new().get("http://localhost".parse().unwrap()).await.capture_json_path("$.id", &mut id);
+    ///
+    ///     awc::Client::default().get("http://localhost").send().await.capture_json_path("$.id", &mut id);
+    /// }
+    /// ```
+    fn capture_json_path<V>(&mut self, path: &str, into: &mut V) -> &mut T
+    where
+        V: DeserializeOwned;
+
+    /// Captures the value of a single valued header
+    /// * `key` - header key
+    /// * `into` - where to store the captured value
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use ureq::OrAnyStatus;
+    /// use asserhttp::*;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let mut request_id = String::new();
+    ///     reqwest::blocking::get("http://localhost").capture_header("x-request-id", &mut request_id);
+    ///     reqwest::get("http://localhost").await.capture_header("x-request-id", &mut request_id);
+    ///
+    ///     isahc::get("http://localhost").capture_header("x-request-id", &mut request_id);
+    ///     isahc::get_async("http://localhost").await.capture_header("x-request-id", &mut request_id);
+    ///
+    ///     surf::get("http://localhost").await.capture_header("x-request-id", &mut request_id);
+    ///
+    ///     ureq::get("http://localhost").call().or_any_status().capture_header("x-request-id", &mut request_id);
+    ///
+    ///     hyper::Client::new().get("http://localhost".parse().unwrap()).await.capture_header("x-request-id", &mut request_id);
+    ///
+    ///     awc::Client::default().get("http://localhost").send().await.capture_header("x-request-id", &mut request_id);
+    /// }
+    /// ```
+    fn capture_header(&mut self, key: impl Into<HeaderKey>, into: &mut String) -> &mut T;
+
+    /// Deserializes the json body, usually as the last step after assertions
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use ureq::OrAnyStatus;
+    /// use asserhttp::*;
+    /// use serde_json::Value;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     reqwest::blocking::get("http://localhost").expect_status_ok().extract_json::<Value>();
+    ///     reqwest::get("http://localhost").await.expect_status_ok().extract_json::<Value>();
+    ///
+    ///     isahc::get("http://localhost").expect_status_ok().extract_json::<Value>();
+    ///     isahc::get_async("http://localhost").await.expect_status_ok().extract_json::<Value>();
+    ///
+    ///     surf::get("http://localhost").await.expect_status_ok().extract_json::<Value>();
+    ///
+    ///     ureq::get("http://localhost").call().or_any_status().expect_status_ok().extract_json::<Value>();
+    ///
+    ///     hyper::Client::new().get("http://localhost".parse().unwrap()).await.expect_status_ok().extract_json::<Value>();
+    ///
+    ///     awc::Client::default().get("http://localhost").send().await.expect_status_ok().extract_json::<Value>();
+    /// }
+    /// ```
+    fn extract_json<B>(&mut self) -> B
+    where
+        B: DeserializeOwned + Unpin;
+}
+
+impl<T> AsserhttpCapture<T> for T
+where
+    T: HeaderAccessor + BodyAccessor,
+{
+    fn capture_json_path<V>(&mut self, path: &str, into: &mut V) -> &mut T
+    where
+        V: DeserializeOwned,
+    {
+        match self.try_capture_json_path(path, into) {
+            Err(e) => panic!("{e}"),
+            Ok(r) => r,
+        }
+    }
+
+    fn capture_header(&mut self, key: impl Into<HeaderKey>, into: &mut String) -> &mut T {
+        match self.try_capture_header(key, into) {
+            Err(e) => panic!("{e}"),
+            Ok(r) => r,
+        }
+    }
+
+    fn extract_json<B>(&mut self) -> B
+    where
+        B: DeserializeOwned + Unpin,
+    {
+        match self.try_extract_json() {
+            Err(e) => panic!("{e}"),
+            Ok(r) => r,
+        }
+    }
+}
+
+impl<T, E> AsserhttpCapture<T> for Result<T, E>
+where
+    T: HeaderAccessor + BodyAccessor,
+    E: std::fmt::Debug,
+{
+    fn capture_json_path<V>(&mut self, path: &str, into: &mut V) -> &mut T
+    where
+        V: DeserializeOwned,
+    {
+        self.as_mut().unwrap().capture_json_path(path, into)
+    }
+
+    fn capture_header(&mut self, key: impl Into<HeaderKey>, into: &mut String) -> &mut T {
+        self.as_mut().unwrap().capture_header(key, into)
+    }
+
+    fn extract_json<B>(&mut self) -> B
+    where
+        B: DeserializeOwned + Unpin,
+    {
+        self.as_mut().unwrap().extract_json()
+    }
+}
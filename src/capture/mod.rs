@@ -0,0 +1,61 @@
+use serde_json::Value;
+
+use crate::error::{AsserhttpError, AsserhttpResult};
+
+pub(crate) mod fallible;
+pub(crate) mod infallible;
+
+/// Subset of [JSONPath](https://www.rfc-editor.org/rfc/rfc9535) selecting a single value
+/// e.g. `$.items[0].id` or `$['items'][0]['id']`
+pub(crate) struct JsonPath(Vec<Segment>);
+
+enum Segment {
+    Key(String),
+    Index(usize),
+}
+
+impl JsonPath {
+    pub(crate) fn parse(path: &str) -> AsserhttpResult<Self> {
+        let invalid = || AsserhttpError::JsonPathInvalid { path: path.to_string() };
+        let mut rest = path.strip_prefix('$').ok_or_else(invalid)?;
+        let mut segments = vec![];
+        while !rest.is_empty() {
+            if let Some(member) = rest.strip_prefix('.') {
+                let end = member.find(['.', '[']).unwrap_or(member.len());
+                if end == 0 {
+                    return Err(invalid());
+                }
+                segments.push(Segment::Key(member[..end].to_string()));
+                rest = &member[end..];
+            } else if let Some(selector) = rest.strip_prefix('[') {
+                let end = selector.find(']').ok_or_else(invalid)?;
+                let inner = &selector[..end];
+                let quoted = ['\'', '"']
+                    .iter()
+                    .find_map(|q| inner.strip_prefix(*q).and_then(|i| i.strip_suffix(*q)));
+                segments.push(match quoted {
+                    Some(key) => Segment::Key(key.to_string()),
+                    None => Segment::Index(inner.parse().map_err(|_| invalid())?),
+                });
+                rest = &selector[end + 1..];
+            } else {
+                return Err(invalid());
+            }
+        }
+        Ok(Self(segments))
+    }
+
+    pub(crate) fn select<'a>(&self, value: &'a Value) -> Option<&'a Value> {
+        self.0.iter().try_fold(value, |node, segment| match segment {
+            Segment::Key(key) => node.get(key),
+            Segment::Index(index) => node.get(index),
+        })
+    }
+
+    pub(crate) fn select_mut<'a>(&self, value: &'a mut Value) -> Option<&'a mut Value> {
+        self.0.iter().try_fold(value, |node, segment| match segment {
+            Segment::Key(key) => node.get_mut(key),
+            Segment::Index(index) => node.get_mut(index),
+        })
+    }
+}
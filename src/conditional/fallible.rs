@@ -0,0 +1,266 @@
+use std::time::SystemTime;
+
+use crate::{
+    accessor::AllAccessors,
+    body::fallible::FallibleAsserhttpBody,
+    conditional::EntityTag,
+    error::{AsserhttpError, AsserhttpResult},
+    header::{fallible::FallibleAsserhttpHeader, key::HeaderKey},
+    headers,
+    status::fallible::FallibleAsserhttpStatus,
+};
+
+/// Validators and caching headers a `304 Not Modified` has to repeat from the `200 OK` response it stands for
+const REPEATED_HEADERS: [&str; 6] = ["cache-control", "content-location", "etag", "expires", "last-modified", "vary"];
+
+/// For assertions on validators and conditional requests returning an error instead of panicking
+pub trait FallibleAsserhttpConditional<T> {
+    /// Allows verifying the `ETag` header in a closure, parsed as an entity tag aware of its weakness
+    /// * `asserter` - closure to verify the entity tag
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use ureq::OrAnyStatus;
+    /// use asserhttp::*;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     reqwest::blocking::get("http://localhost").try_expect_etag(|e| { assert!(e.is_weak() && e.tag() == "0815"); Ok(()) }).unwrap();
+    ///     reqwest::get("http://localhost").await.try_expect_etag(|e| { assert!(e.is_weak() && e.tag() == "0815"); Ok(()) }).unwrap();
+    ///
+    ///     isahc::get("http://localhost").try_expect_etag(|e| { assert!(e.is_weak() && e.tag() == "0815"); Ok(()) }).unwrap();
+    ///     isahc::get_async("http://localhost").await.try_expect_etag(|e| { assert!(e.is_weak() && e.tag() == "0815"); Ok(()) }).unwrap();
+    ///
+    ///     surf::get("http://localhost").await.try_expect_etag(|e| { assert!(e.is_weak() && e.tag() == "0815"); Ok(()) }).unwrap();
+    ///
+    ///     ureq::get("http://localhost").call().or_any_status().try_expect_etag(|e| { assert!(e.is_weak() && e.tag() == "0815"); Ok(()) }).unwrap();
+    ///
+    ///     hyper::Client::new().get("http://localhost".parse().unwrap()).await.try_expect_etag(|e| { assert!(e.is_weak() && e.tag() == "0815"); Ok(()) }).unwrap();
+    ///
+    ///     awc::Client::default().get("http://localhost").send().await.try_expect_etag(|e| { assert!(e.is_weak() && e.tag() == "0815"); Ok(()) }).unwrap();
+    /// }
+    /// ```
+    /// # Error
+    /// * [AsserhttpError::HeaderAbsent] when there is no `ETag` header
+    /// * [AsserhttpError::InvalidEntityTag] when the `ETag` is not a valid entity tag
+    fn try_expect_etag<F>(&mut self, asserter: F) -> AsserhttpResult<&mut T>
+    where
+        F: FnOnce(EntityTag) -> AsserhttpResult<()>;
+
+    /// Expects a strong `ETag` i.e. without the `W/` weakness indicator
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use ureq::OrAnyStatus;
+    /// use asserhttp::*;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     reqwest::blocking::get("http://localhost").try_expect_etag_strong().unwrap();
+    ///     reqwest::get("http://localhost").await.try_expect_etag_strong().unwrap();
+    ///
+    ///     isahc::get("http://localhost").try_expect_etag_strong().unwrap();
+    ///     isahc::get_async("http://localhost").await.try_expect_etag_strong().unwrap();
+    ///
+    ///     surf::get("http://localhost").await.try_expect_etag_strong().unwrap();
+    ///
+    ///     ureq::get("http://localhost").call().or_any_status().try_expect_etag_strong().unwrap();
+    ///
+    ///     hyper::Client::new().get("http://localhost".parse().unwrap()).await.try_expect_etag_strong().unwrap();
+    ///
+    ///     awc::Client::default().get("http://localhost").send().await.try_expect_etag_strong().unwrap();
+    /// }
+    /// ```
+    /// # Error
+    /// * [AsserhttpError::HeaderAbsent] when there is no `ETag` header
+    /// * [AsserhttpError::InvalidEntityTag] when the `ETag` is not a valid entity tag
+    /// * [AsserhttpError::EntityTagWeak] when the entity tag is weak
+    fn try_expect_etag_strong(&mut self) -> AsserhttpResult<&mut T> {
+        self.try_expect_etag(|etag| {
+            if etag.is_strong() {
+                return Ok(());
+            }
+            Err(AsserhttpError::EntityTagWeak { actual: etag.to_string() })
+        })
+    }
+
+    /// Allows verifying the `Last-Modified` header in a closure, parsed from an HTTP-date
+    /// * `asserter` - closure to verify the date
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use ureq::OrAnyStatus;
+    /// use asserhttp::*;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     reqwest::blocking::get("http://localhost").try_expect_last_modified(|t| { assert!(t < std::time::SystemTime::now()); Ok(()) }).unwrap();
+    ///     reqwest::get("http://localhost").await.try_expect_last_modified(|t| { assert!(t < std::time::SystemTime::now()); Ok(()) }).unwrap();
+    ///
+    ///     isahc::get("http://localhost").try_expect_last_modified(|t| { assert!(t < std::time::SystemTime::now()); Ok(()) }).unwrap();
+    ///     isahc::get_async("http://localhost").await.try_expect_last_modified(|t| { assert!(t < std::time::SystemTime::now()); Ok(()) }).unwrap();
+    ///
+    ///     surf::get("http://localhost").await.try_expect_last_modified(|t| { assert!(t < std::time::SystemTime::now()); Ok(()) }).unwrap();
+    ///
+    ///     ureq::get("http://localhost").call().or_any_status().try_expect_last_modified(|t| { assert!(t < std::time::SystemTime::now()); Ok(()) }).unwrap();
+    ///
+    ///     hyper::Client::new().get("http://localhost".parse().unwrap()).await.try_expect_last_modified(|t| { assert!(t < std::time::SystemTime::now()); Ok(()) }).unwrap();
+    ///
+    ///     awc::Client::default().get("http://localhost").send().await.try_expect_last_modified(|t| { assert!(t < std::time::SystemTime::now()); Ok(()) }).unwrap();
+    /// }
+    /// ```
+    /// # Error
+    /// * [AsserhttpError::HeaderAbsent] when there is no `Last-Modified` header
+    /// * [AsserhttpError::InvalidHttpDate] when the header is not a valid HTTP-date
+    fn try_expect_last_modified<F>(&mut self, asserter: F) -> AsserhttpResult<&mut T>
+    where
+        F: FnOnce(SystemTime) -> AsserhttpResult<()>;
+
+    /// Expects a `304 Not Modified` response to a conditional request: no body and the validators and caching
+    /// headers of the cached response repeated i.e. `Cache-Control`, `Content-Location`, `ETag`, `Expires`,
+    /// `Last-Modified` and `Vary`. Any other header is rejected rather than ignored, so that a misspelled one cannot go
+    /// unchecked. Entity tags are compared weakly, as for `If-None-Match`, other values as they are
+    /// * `cached` - headers of the `200 OK` response initially cached e.g. `[("etag", "\"33a64df5\"")]`
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use ureq::OrAnyStatus;
+    /// use asserhttp::*;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     reqwest::blocking::get("http://localhost").try_expect_not_modified([("etag", "\"33a64df5\""), ("cache-control", "max-age=60")]).unwrap();
+    ///     reqwest::get("http://localhost").await.try_expect_not_modified([("etag", "\"33a64df5\""), ("cache-control", "max-age=60")]).unwrap();
+    ///
+    ///     isahc::get("http://localhost").try_expect_not_modified([("etag", "\"33a64df5\""), ("cache-control", "max-age=60")]).unwrap();
+    ///     isahc::get_async("http://localhost").await.try_expect_not_modified([("etag", "\"33a64df5\""), ("cache-control", "max-age=60")]).unwrap();
+    ///
+    ///     surf::get("http://localhost").await.try_expect_not_modified([("etag", "\"33a64df5\""), ("cache-control", "max-age=60")]).unwrap();
+    ///
+    ///     ureq::get("http://localhost").call().or_any_status().try_expect_not_modified([("etag", "\"33a64df5\""), ("cache-control", "max-age=60")]).unwrap();
+    ///
+    ///     hyper::Client::new().get("http://localhost".parse().unwrap()).await.try_expect_not_modified([("etag", "\"33a64df5\""), ("cache-control", "max-age=60")]).unwrap();
+    ///
+    ///     awc::Client::default().get("http://localhost").send().await.try_expect_not_modified([("etag", "\"33a64df5\""), ("cache-control", "max-age=60")]).unwrap();
+    /// }
+    /// ```
+    /// # Error
+    /// * [AsserhttpError::NotModifiedHeaderUnrepeated] when a supplied header is not among the repeated ones
+    /// * [AsserhttpError::StatusMismatch] when the status is not `304`
+    /// * [AsserhttpError::BodyPresent] when the response has a body
+    /// * [AsserhttpError::HeaderAbsent] when one of the cached validators or caching headers is not repeated
+    /// * [AsserhttpError::InvalidEntityTag] when the expected or actual `ETag` is not a valid entity tag
+    /// * [AsserhttpError::EntityTagMismatch] when the `ETag` does not match the cached one
+    /// * [AsserhttpError::HeaderValueMismatch] when another repeated header differs from the cached one
+    fn try_expect_not_modified<I, K, V>(&mut self, cached: I) -> AsserhttpResult<&mut T>
+    where
+        I: IntoIterator<Item = (K, V)>,
+        K: Into<HeaderKey>,
+        V: AsRef<str>;
+}
+
+impl<T> FallibleAsserhttpConditional<T> for T
+where
+    T: AllAccessors,
+{
+    fn try_expect_etag<F>(&mut self, asserter: F) -> AsserhttpResult<&mut T>
+    where
+        F: FnOnce(EntityTag) -> AsserhttpResult<()>,
+    {
+        let key = HeaderKey::from(headers::ETAG);
+        let etag = self.first_raw_value(&key)?;
+        asserter(EntityTag::parse(&etag)?)?;
+        Ok(self)
+    }
+
+    fn try_expect_last_modified<F>(&mut self, asserter: F) -> AsserhttpResult<&mut T>
+    where
+        F: FnOnce(SystemTime) -> AsserhttpResult<()>,
+    {
+        self.try_expect_header_date(headers::LAST_MODIFIED, asserter)
+    }
+
+    fn try_expect_not_modified<I, K, V>(&mut self, cached: I) -> AsserhttpResult<&mut T>
+    where
+        I: IntoIterator<Item = (K, V)>,
+        K: Into<HeaderKey>,
+        V: AsRef<str>,
+    {
+        let cached = cached
+            .into_iter()
+            .map(|(key, value)| (key.into(), value.as_ref().trim().to_string()))
+            .collect::<Vec<(HeaderKey, String)>>();
+        if let Some((key, _)) = cached
+            .iter()
+            .find(|(key, _)| !REPEATED_HEADERS.iter().any(|h| HeaderKey::from(*h) == *key))
+        {
+            return Err(AsserhttpError::NotModifiedHeaderUnrepeated { key: key.clone() });
+        }
+        self.try_expect_status(304)?.try_expect_body_absent()?;
+        for (key, expected) in cached {
+            if key == HeaderKey::from(headers::ETAG) {
+                let expected = EntityTag::parse(&expected)?;
+                self.try_expect_etag(|actual| {
+                    if actual.weak_eq(&expected) {
+                        return Ok(());
+                    }
+                    Err(AsserhttpError::EntityTagMismatch {
+                        expected: expected.to_string(),
+                        actual: actual.to_string(),
+                    })
+                })?;
+                continue;
+            }
+            key.try_assert_contained(self.get_keys())?;
+            let actual = self
+                .get_raw_values(&key)
+                .iter()
+                .map(|v| v.trim())
+                .collect::<Vec<_>>()
+                .join(", ");
+            if actual != expected {
+                return Err(AsserhttpError::HeaderValueMismatch {
+                    key,
+                    actual: actual.into(),
+                    expected: expected.into(),
+                });
+            }
+        }
+        Ok(self)
+    }
+}
+
+impl<T, E> FallibleAsserhttpConditional<T> for Result<T, E>
+where
+    T: AllAccessors,
+    E: std::fmt::Debug,
+{
+    fn try_expect_etag<F>(&mut self, asserter: F) -> AsserhttpResult<&mut T>
+    where
+        F: FnOnce(EntityTag) -> AsserhttpResult<()>,
+    {
+        self.as_mut()
+            .map_err(|e| AsserhttpError::HttpError(format!("{e:?}")))?
+            .try_expect_etag(asserter)
+    }
+
+    fn try_expect_last_modified<F>(&mut self, asserter: F) -> AsserhttpResult<&mut T>
+    where
+        F: FnOnce(SystemTime) -> AsserhttpResult<()>,
+    {
+        self.as_mut()
+            .map_err(|e| AsserhttpError::HttpError(format!("{e:?}")))?
+            .try_expect_last_modified(asserter)
+    }
+
+    fn try_expect_not_modified<I, K, V>(&mut self, cached: I) -> AsserhttpResult<&mut T>
+    where
+        I: IntoIterator<Item = (K, V)>,
+        K: Into<HeaderKey>,
+        V: AsRef<str>,
+    {
+        self.as_mut()
+            .map_err(|e| AsserhttpError::HttpError(format!("{e:?}")))?
+            .try_expect_not_modified(cached)
+    }
+}
@@ -0,0 +1,213 @@
+use std::time::SystemTime;
+
+use crate::{
+    accessor::AllAccessors,
+    conditional::{fallible::FallibleAsserhttpConditional, EntityTag},
+    header::key::HeaderKey,
+};
+
+/// For assertions on validators and conditional requests
+pub trait AsserhttpConditional<T> {
+    /// Allows verifying the `ETag` header in a closure, parsed as an entity tag aware of its weakness
+    /// * `asserter` - closure to verify the entity tag
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use ureq::OrAnyStatus;
+    /// use asserhttp::*;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     reqwest::blocking::get("http://localhost").expect_etag(|e| assert!(e.is_weak() && e.tag() == "0815"));
+    ///     reqwest::get("http://localhost").await.expect_etag(|e| assert!(e.is_weak() && e.tag() == "0815"));
+    ///
+    ///     isahc::get("http://localhost").expect_etag(|e| assert!(e.is_weak() && e.tag() == "0815"));
+    ///     isahc::get_async("http://localhost").await.expect_etag(|e| assert!(e.is_weak() && e.tag() == "0815"));
+    ///
+    ///     surf::get("http://localhost").await.expect_etag(|e| assert!(e.is_weak() && e.tag() == "0815"));
+    ///
+    ///     ureq::get("http://localhost").call().or_any_status().expect_etag(|e| assert!(e.is_weak() && e.tag() == "0815"));
+    ///
+    ///     hyper::Client::new().get("http://localhost".parse().unwrap()).await.expect_etag(|e| assert!(e.is_weak() && e.tag() == "0815"));
+    ///
+    ///     awc::Client::default().get("http://localhost").send().await.expect_etag(|e| assert!(e.is_weak() && e.tag() == "0815"));
+    /// }
+    /// ```
+    fn expect_etag<F>(&mut self, asserter: F) -> &mut T
+    where
+        F: FnOnce(EntityTag);
+
+    /// Expects a strong `ETag` i.e. without the `W/` weakness indicator
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use ureq::OrAnyStatus;
+    /// use asserhttp::*;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     reqwest::blocking::get("http://localhost").expect_etag_strong();
+    ///     reqwest::get("http://localhost").await.expect_etag_strong();
+    ///
+    ///     isahc::get("http://localhost").expect_etag_strong();
+    ///     isahc::get_async("http://localhost").await.expect_etag_strong();
+    ///
+    ///     surf::get("http://localhost").await.expect_etag_strong();
+    ///
+    ///     ureq::get("http://localhost").call().or_any_status().expect_etag_strong();
+    ///
+    ///     hyper::Client::new().get("http://localhost".parse().unwrap()).await.expect_etag_strong();
+    ///
+    ///     awc::Client::default().get("http://localhost").send().await.expect_etag_strong();
+    /// }
+    /// ```
+    fn expect_etag_strong(&mut self) -> &mut T;
+
+    /// Allows verifying the `Last-Modified` header in a closure, parsed from an HTTP-date
+    /// * `asserter` - closure to verify the date
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use ureq::OrAnyStatus;
+    /// use asserhttp::*;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     reqwest::blocking::get("http://localhost").expect_last_modified(|t| assert!(t < std::time::SystemTime::now()));
+    ///     reqwest::get("http://localhost").await.expect_last_modified(|t| assert!(t < std::time::SystemTime::now()));
+    ///
+    ///     isahc::get("http://localhost").expect_last_modified(|t| assert!(t < std::time::SystemTime::now()));
+    ///     isahc::get_async("http://localhost").await.expect_last_modified(|t| assert!(t < std::time::SystemTime::now()));
+    ///
+    ///     surf::get("http://localhost").await.expect_last_modified(|t| assert!(t < std::time::SystemTime::now()));
+    ///
+    ///     ureq::get("http://localhost").call().or_any_status().expect_last_modified(|t| assert!(t < std::time::SystemTime::now()));
+    ///
+    ///     hyper::Client::new().get("http://localhost".parse().unwrap()).await.expect_last_modified(|t| assert!(t < std::time::SystemTime::now()));
+    ///
+    ///     awc::Client::default().get("http://localhost").send().await.expect_last_modified(|t| assert!(t < std::time::SystemTime::now()));
+    /// }
+    /// ```
+    fn expect_last_modified<F>(&mut self, asserter: F) -> &mut T
+    where
+        F: FnOnce(SystemTime);
+
+    /// Expects a `304 Not Modified` response to a conditional request: no body and the validators and caching
+    /// headers of the cached response repeated i.e. `Cache-Control`, `Content-Location`, `ETag`, `Expires`,
+    /// `Last-Modified` and `Vary`. Any other header is rejected rather than ignored, so that a misspelled one cannot go
+    /// unchecked. Entity tags are compared weakly, as for `If-None-Match`, other values as they are
+    /// * `cached` - headers of the `200 OK` response initially cached e.g. `[("etag", "\"33a64df5\"")]`
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use ureq::OrAnyStatus;
+    /// use asserhttp::*;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     reqwest::blocking::get("http://localhost").expect_not_modified([("etag", "\"33a64df5\""), ("cache-control", "max-age=60")]);
+    ///     reqwest::get("http://localhost").await.expect_not_modified([("etag", "\"33a64df5\""), ("cache-control", "max-age=60")]);
+    ///
+    ///     isahc::get("http://localhost").expect_not_modified([("etag", "\"33a64df5\""), ("cache-control", "max-age=60")]);
+    ///     isahc::get_async("http://localhost").await.expect_not_modified([("etag", "\"33a64df5\""), ("cache-control", "max-age=60")]);
+    ///
+    ///     surf::get("http://localhost").await.expect_not_modified([("etag", "\"33a64df5\""), ("cache-control", "max-age=60")]);
+    ///
+    ///     ureq::get("http://localhost").call().or_any_status().expect_not_modified([("etag", "\"33a64df5\""), ("cache-control", "max-age=60")]);
+    ///
+    ///     hyper::Client::new().get("http://localhost".parse().unwrap()).await.expect_not_modified([("etag", "\"33a64df5\""), ("cache-control", "max-age=60")]);
+    ///
+    ///     awc::Client::default().get("http://localhost").send().await.expect_not_modified([("etag", "\"33a64df5\""), ("cache-control", "max-age=60")]);
+    /// }
+    /// ```
+    fn expect_not_modified<I, K, V>(&mut self, cached: I) -> &mut T
+    where
+        I: IntoIterator<Item = (K, V)>,
+        K: Into<HeaderKey>,
+        V: AsRef<str>;
+}
+
+impl<T> AsserhttpConditional<T> for T
+where
+    T: AllAccessors,
+{
+    fn expect_etag<F>(&mut self, asserter: F) -> &mut T
+    where
+        F: FnOnce(EntityTag),
+    {
+        #[allow(clippy::blocks_in_conditions)]
+        match self.try_expect_etag(|v| {
+            asserter(v);
+            Ok(())
+        }) {
+            Err(e) => panic!("{e}"),
+            Ok(r) => r,
+        }
+    }
+
+    fn expect_etag_strong(&mut self) -> &mut T {
+        match self.try_expect_etag_strong() {
+            Err(e) => panic!("{e}"),
+            Ok(r) => r,
+        }
+    }
+
+    fn expect_last_modified<F>(&mut self, asserter: F) -> &mut T
+    where
+        F: FnOnce(SystemTime),
+    {
+        #[allow(clippy::blocks_in_conditions)]
+        match self.try_expect_last_modified(|v| {
+            asserter(v);
+            Ok(())
+        }) {
+            Err(e) => panic!("{e}"),
+            Ok(r) => r,
+        }
+    }
+
+    fn expect_not_modified<I, K, V>(&mut self, cached: I) -> &mut T
+    where
+        I: IntoIterator<Item = (K, V)>,
+        K: Into<HeaderKey>,
+        V: AsRef<str>,
+    {
+        match self.try_expect_not_modified(cached) {
+            Err(e) => panic!("{e}"),
+            Ok(r) => r,
+        }
+    }
+}
+
+impl<T, E> AsserhttpConditional<T> for Result<T, E>
+where
+    T: AllAccessors,
+    E: std::fmt::Debug,
+{
+    fn expect_etag<F>(&mut self, asserter: F) -> &mut T
+    where
+        F: FnOnce(EntityTag),
+    {
+        self.as_mut().unwrap().expect_etag(asserter)
+    }
+
+    fn expect_etag_strong(&mut self) -> &mut T {
+        self.as_mut().unwrap().expect_etag_strong()
+    }
+
+    fn expect_last_modified<F>(&mut self, asserter: F) -> &mut T
+    where
+        F: FnOnce(SystemTime),
+    {
+        self.as_mut().unwrap().expect_last_modified(asserter)
+    }
+
+    fn expect_not_modified<I, K, V>(&mut self, cached: I) -> &mut T
+    where
+        I: IntoIterator<Item = (K, V)>,
+        K: Into<HeaderKey>,
+        V: AsRef<str>,
+    {
+        self.as_mut().unwrap().expect_not_modified(cached)
+    }
+}
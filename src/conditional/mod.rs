@@ -0,0 +1,64 @@
+use crate::error::{AsserhttpError, AsserhttpResult};
+
+pub(crate) mod fallible;
+pub(crate) mod infallible;
+
+const WEAK_PREFIX: &str = "W/";
+
+/// An [entity tag](https://www.rfc-editor.org/rfc/rfc9110#name-etag) e.g. `"33a64df5"` or the weak `W/"0815"`
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct EntityTag {
+    weak: bool,
+    tag: String,
+}
+
+impl EntityTag {
+    pub(crate) fn parse(value: &str) -> AsserhttpResult<Self> {
+        let value = value.trim();
+        let (weak, quoted) = match value.strip_prefix(WEAK_PREFIX) {
+            Some(quoted) => (true, quoted),
+            None => (false, value),
+        };
+        quoted
+            .strip_prefix('"')
+            .and_then(|t| t.strip_suffix('"'))
+            .filter(|tag| !tag.contains('"'))
+            .map(|tag| Self {
+                weak,
+                tag: tag.to_string(),
+            })
+            .ok_or_else(|| AsserhttpError::InvalidEntityTag { value: value.to_string() })
+    }
+
+    pub fn is_weak(&self) -> bool {
+        self.weak
+    }
+
+    pub fn is_strong(&self) -> bool {
+        !self.weak
+    }
+
+    /// Opaque tag, without quotes nor weakness indicator
+    pub fn tag(&self) -> &str {
+        &self.tag
+    }
+
+    /// [Strong comparison](https://www.rfc-editor.org/rfc/rfc9110#name-comparison-2): both are strong and have the same tag
+    pub fn strong_eq(&self, other: &Self) -> bool {
+        self.is_strong() && other.is_strong() && self.tag == other.tag
+    }
+
+    /// [Weak comparison](https://www.rfc-editor.org/rfc/rfc9110#name-comparison-2): both have the same tag, whatever their weakness
+    pub fn weak_eq(&self, other: &Self) -> bool {
+        self.tag == other.tag
+    }
+}
+
+impl std::fmt::Display for EntityTag {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.weak {
+            true => write!(f, "{WEAK_PREFIX}\"{}\"", self.tag),
+            false => write!(f, "\"{}\"", self.tag),
+        }
+    }
+}
@@ -0,0 +1,166 @@
+use crate::{
+    accessor::HeaderAccessor,
+    content_disposition::{content_disposition, ContentDisposition},
+    error::{AsserhttpError, AsserhttpResult},
+};
+
+/// For assertions on the `Content-Disposition` header of downloads returning an error instead of panicking
+pub trait FallibleAsserhttpContentDisposition<T> {
+    /// Allows verifying the `Content-Disposition` header in a closure, with its `filename*` parameter decoded
+    /// * `asserter` - closure to verify the disposition
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use ureq::OrAnyStatus;
+    /// use asserhttp::*;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     reqwest::blocking::get("http://localhost").try_expect_content_disposition(|cd| { assert!(cd.is_attachment() && cd.filename.unwrap().ends_with(".pdf")); Ok(()) }).unwrap();
+    ///     reqwest::get("http://localhost").await.try_expect_content_disposition(|cd| { assert!(cd.is_attachment() && cd.filename.unwrap().ends_with(".pdf")); Ok(()) }).unwrap();
+    ///
+    ///     isahc::get("http://localhost").try_expect_content_disposition(|cd| { assert!(cd.is_attachment() && cd.filename.unwrap().ends_with(".pdf")); Ok(()) }).unwrap();
+    ///     isahc::get_async("http://localhost").await.try_expect_content_disposition(|cd| { assert!(cd.is_attachment() && cd.filename.unwrap().ends_with(".pdf")); Ok(()) }).unwrap();
+    ///
+    ///     surf::get("http://localhost").await.try_expect_content_disposition(|cd| { assert!(cd.is_attachment() && cd.filename.unwrap().ends_with(".pdf")); Ok(()) }).unwrap();
+    ///
+    ///     ureq::get("http://localhost").call().or_any_status().try_expect_content_disposition(|cd| { assert!(cd.is_attachment() && cd.filename.unwrap().ends_with(".pdf")); Ok(()) }).unwrap();
+    ///
+    ///     hyper::Client::new().get("http://localhost".parse().unwrap()).await.try_expect_content_disposition(|cd| { assert!(cd.is_attachment() && cd.filename.unwrap().ends_with(".pdf")); Ok(()) }).unwrap();
+    ///
+    ///     awc::Client::default().get("http://localhost").send().await.try_expect_content_disposition(|cd| { assert!(cd.is_attachment() && cd.filename.unwrap().ends_with(".pdf")); Ok(()) }).unwrap();
+    /// }
+    /// ```
+    /// # Error
+    /// * [AsserhttpError::HeaderAbsent] when there is no `Content-Disposition` header
+    /// * [AsserhttpError::InvalidContentDisposition] when the header cannot be parsed, including an undecodable `filename*`
+    fn try_expect_content_disposition<F>(&mut self, asserter: F) -> AsserhttpResult<&mut T>
+    where
+        F: FnOnce(ContentDisposition) -> AsserhttpResult<()>;
+
+    /// Expects the response to be downloaded as an attachment with this file name, whether it is sent as a
+    /// quoted `filename`, a token or an encoded `filename*`
+    /// * `filename` - expected file name, compared exactly
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use ureq::OrAnyStatus;
+    /// use asserhttp::*;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     reqwest::blocking::get("http://localhost").try_expect_attachment("report.pdf").unwrap();
+    ///     reqwest::get("http://localhost").await.try_expect_attachment("report.pdf").unwrap();
+    ///
+    ///     isahc::get("http://localhost").try_expect_attachment("report.pdf").unwrap();
+    ///     isahc::get_async("http://localhost").await.try_expect_attachment("report.pdf").unwrap();
+    ///
+    ///     surf::get("http://localhost").await.try_expect_attachment("report.pdf").unwrap();
+    ///
+    ///     ureq::get("http://localhost").call().or_any_status().try_expect_attachment("report.pdf").unwrap();
+    ///
+    ///     hyper::Client::new().get("http://localhost".parse().unwrap()).await.try_expect_attachment("report.pdf").unwrap();
+    ///
+    ///     awc::Client::default().get("http://localhost").send().await.try_expect_attachment("report.pdf").unwrap();
+    /// }
+    /// ```
+    /// # Error
+    /// * [AsserhttpError::HeaderAbsent] when there is no `Content-Disposition` header
+    /// * [AsserhttpError::InvalidContentDisposition] when the header cannot be parsed, including an undecodable `filename*`
+    /// * [AsserhttpError::ContentDispositionMismatch] when not an attachment or named otherwise
+    fn try_expect_attachment(&mut self, filename: impl AsRef<str>) -> AsserhttpResult<&mut T>;
+
+    /// Expects the response to be displayed inline rather than downloaded
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use ureq::OrAnyStatus;
+    /// use asserhttp::*;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     reqwest::blocking::get("http://localhost").try_expect_inline().unwrap();
+    ///     reqwest::get("http://localhost").await.try_expect_inline().unwrap();
+    ///
+    ///     isahc::get("http://localhost").try_expect_inline().unwrap();
+    ///     isahc::get_async("http://localhost").await.try_expect_inline().unwrap();
+    ///
+    ///     surf::get("http://localhost").await.try_expect_inline().unwrap();
+    ///
+    ///     ureq::get("http://localhost").call().or_any_status().try_expect_inline().unwrap();
+    ///
+    ///     hyper::Client::new().get("http://localhost".parse().unwrap()).await.try_expect_inline().unwrap();
+    ///
+    ///     awc::Client::default().get("http://localhost").send().await.try_expect_inline().unwrap();
+    /// }
+    /// ```
+    /// # Error
+    /// * [AsserhttpError::HeaderAbsent] when there is no `Content-Disposition` header
+    /// * [AsserhttpError::InvalidContentDisposition] when the header cannot be parsed, including an undecodable `filename*`
+    /// * [AsserhttpError::ContentDispositionMismatch] when the disposition type is not `inline`
+    fn try_expect_inline(&mut self) -> AsserhttpResult<&mut T>;
+}
+
+impl<T> FallibleAsserhttpContentDisposition<T> for T
+where
+    T: HeaderAccessor,
+{
+    fn try_expect_content_disposition<F>(&mut self, asserter: F) -> AsserhttpResult<&mut T>
+    where
+        F: FnOnce(ContentDisposition) -> AsserhttpResult<()>,
+    {
+        let (_, disposition) = content_disposition(self)?;
+        asserter(disposition)?;
+        Ok(self)
+    }
+
+    fn try_expect_attachment(&mut self, filename: impl AsRef<str>) -> AsserhttpResult<&mut T> {
+        let filename = filename.as_ref();
+        let (actual, disposition) = content_disposition(self)?;
+        if !disposition.is_attachment() || disposition.filename.as_deref() != Some(filename) {
+            return Err(AsserhttpError::ContentDispositionMismatch {
+                expected: format!("an attachment named '{filename}'"),
+                actual,
+            });
+        }
+        Ok(self)
+    }
+
+    fn try_expect_inline(&mut self) -> AsserhttpResult<&mut T> {
+        let (actual, disposition) = content_disposition(self)?;
+        if !disposition.is_inline() {
+            return Err(AsserhttpError::ContentDispositionMismatch {
+                expected: "inline".to_string(),
+                actual,
+            });
+        }
+        Ok(self)
+    }
+}
+
+impl<T, E> FallibleAsserhttpContentDisposition<T> for Result<T, E>
+where
+    T: HeaderAccessor,
+    E: std::fmt::Debug,
+{
+    fn try_expect_content_disposition<F>(&mut self, asserter: F) -> AsserhttpResult<&mut T>
+    where
+        F: FnOnce(ContentDisposition) -> AsserhttpResult<()>,
+    {
+        self.as_mut()
+            .map_err(|e| AsserhttpError::HttpError(format!("{e:?}")))?
+            .try_expect_content_disposition(asserter)
+    }
+
+    fn try_expect_attachment(&mut self, filename: impl AsRef<str>) -> AsserhttpResult<&mut T> {
+        self.as_mut()
+            .map_err(|e| AsserhttpError::HttpError(format!("{e:?}")))?
+            .try_expect_attachment(filename)
+    }
+
+    fn try_expect_inline(&mut self) -> AsserhttpResult<&mut T> {
+        self.as_mut()
+            .map_err(|e| AsserhttpError::HttpError(format!("{e:?}")))?
+            .try_expect_inline()
+    }
+}
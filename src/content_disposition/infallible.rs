@@ -0,0 +1,144 @@
+use crate::{
+    accessor::HeaderAccessor,
+    content_disposition::{fallible::FallibleAsserhttpContentDisposition, ContentDisposition},
+};
+
+/// For assertions on the `Content-Disposition` header of downloads
+pub trait AsserhttpContentDisposition<T> {
+    /// Allows verifying the `Content-Disposition` header in a closure, with its `filename*` parameter decoded
+    /// * `asserter` - closure to verify the disposition
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use ureq::OrAnyStatus;
+    /// use asserhttp::*;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     reqwest::blocking::get("http://localhost").expect_content_disposition(|cd| assert!(cd.is_attachment() && cd.filename.unwrap().ends_with(".pdf")));
+    ///     reqwest::get("http://localhost").await.expect_content_disposition(|cd| assert!(cd.is_attachment() && cd.filename.unwrap().ends_with(".pdf")));
+    ///
+    ///     isahc::get("http://localhost").expect_content_disposition(|cd| assert!(cd.is_attachment() && cd.filename.unwrap().ends_with(".pdf")));
+    ///     isahc::get_async("http://localhost").await.expect_content_disposition(|cd| assert!(cd.is_attachment() && cd.filename.unwrap().ends_with(".pdf")));
+    ///
+    ///     surf::get("http://localhost").await.expect_content_disposition(|cd| assert!(cd.is_attachment() && cd.filename.unwrap().ends_with(".pdf")));
+    ///
+    ///     ureq::get("http://localhost").call().or_any_status().expect_content_disposition(|cd| assert!(cd.is_attachment() && cd.filename.unwrap().ends_with(".pdf")));
+    ///
+    ///     hyper::Client::new().get("http://localhost".parse().unwrap()).await.expect_content_disposition(|cd| assert!(cd.is_attachment() && cd.filename.unwrap().ends_with(".pdf")));
+    ///
+    ///     awc::Client::default().get("http://localhost").send().await.expect_content_disposition(|cd| assert!(cd.is_attachment() && cd.filename.unwrap().ends_with(".pdf")));
+    /// }
+    /// ```
+    fn expect_content_disposition<F>(&mut self, asserter: F) -> &mut T
+    where
+        F: FnOnce(ContentDisposition);
+
+    /// Expects the response to be downloaded as an attachment with this file name, whether it is sent as a
+    /// quoted `filename`, a token or an encoded `filename*`
+    /// * `filename` - expected file name, compared exactly
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use ureq::OrAnyStatus;
+    /// use asserhttp::*;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     reqwest::blocking::get("http://localhost").expect_attachment("report.pdf");
+    ///     reqwest::get("http://localhost").await.expect_attachment("report.pdf");
+    ///
+    ///     isahc::get("http://localhost").expect_attachment("report.pdf");
+    ///     isahc::get_async("http://localhost").await.expect_attachment("report.pdf");
+    ///
+    ///     surf::get("http://localhost").await.expect_attachment("report.pdf");
+    ///
+    ///     ureq::get("http://localhost").call().or_any_status().expect_attachment("report.pdf");
+    ///
+    ///     hyper::Client::new().get("http://localhost".parse().unwrap()).await.expect_attachment("report.pdf");
+    ///
+    ///     awc::Client::default().get("http://localhost").send().await.expect_attachment("report.pdf");
+    /// }
+    /// ```
+    fn expect_attachment(&mut self, filename: impl AsRef<str>) -> &mut T;
+
+    /// Expects the response to be displayed inline rather than downloaded
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use ureq::OrAnyStatus;
+    /// use asserhttp::*;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     reqwest::blocking::get("http://localhost").expect_inline();
+    ///     reqwest::get("http://localhost").await.expect_inline();
+    ///
+    ///     isahc::get("http://localhost").expect_inline();
+    ///     isahc::get_async("http://localhost").await.expect_inline();
+    ///
+    ///     surf::get("http://localhost").await.expect_inline();
+    ///
+    ///     ureq::get("http://localhost").call().or_any_status().expect_inline();
+    ///
+    ///     hyper::Client::new().get("http://localhost".parse().unwrap()).await.expect_inline();
+    ///
+    ///     awc::Client::default().get("http://localhost").send().await.expect_inline();
+    /// }
+    /// ```
+    fn expect_inline(&mut self) -> &mut T;
+}
+
+impl<T> AsserhttpContentDisposition<T> for T
+where
+    T: HeaderAccessor,
+{
+    fn expect_content_disposition<F>(&mut self, asserter: F) -> &mut T
+    where
+        F: FnOnce(ContentDisposition),
+    {
+        #[allow(clippy::blocks_in_conditions)]
+        match self.try_expect_content_disposition(|v| {
+            asserter(v);
+            Ok(())
+        }) {
+            Err(e) => panic!("{e}"),
+            Ok(r) => r,
+        }
+    }
+
+    fn expect_attachment(&mut self, filename: impl AsRef<str>) -> &mut T {
+        match self.try_expect_attachment(filename) {
+            Err(e) => panic!("{e}"),
+            Ok(r) => r,
+        }
+    }
+
+    fn expect_inline(&mut self) -> &mut T {
+        match self.try_expect_inline() {
+            Err(e) => panic!("{e}"),
+            Ok(r) => r,
+        }
+    }
+}
+
+impl<T, E> AsserhttpContentDisposition<T> for Result<T, E>
+where
+    T: HeaderAccessor,
+    E: std::fmt::Debug,
+{
+    fn expect_content_disposition<F>(&mut self, asserter: F) -> &mut T
+    where
+        F: FnOnce(ContentDisposition),
+    {
+        self.as_mut().unwrap().expect_content_disposition(asserter)
+    }
+
+    fn expect_attachment(&mut self, filename: impl AsRef<str>) -> &mut T {
+        self.as_mut().unwrap().expect_attachment(filename)
+    }
+
+    fn expect_inline(&mut self) -> &mut T {
+        self.as_mut().unwrap().expect_inline()
+    }
+}
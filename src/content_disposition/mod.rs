@@ -0,0 +1,104 @@
+use std::collections::BTreeMap;
+
+use crate::{
+    accessor::HeaderAccessor,
+    error::{AsserhttpError, AsserhttpResult},
+    header::{
+        key::HeaderKey,
+        quoted::{split_unquoted, unquote},
+    },
+};
+
+pub(crate) mod fallible;
+pub(crate) mod infallible;
+
+/// Not among the `http_types` header constants
+const CONTENT_DISPOSITION: &str = "content-disposition";
+
+/// A parsed [Content-Disposition](https://www.rfc-editor.org/rfc/rfc6266#section-4) header
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct ContentDisposition {
+    /// Disposition type, lowercased e.g. `attachment` or `inline`
+    pub disposition: String,
+    /// Decoded `filename*` parameter, preferred as recipients should, or else the `filename` parameter.
+    /// The latter is also used when `filename*` has an unsupported charset or cannot be decoded
+    pub filename: Option<String>,
+    /// Every parameter, keyed by lowercased name and unquoted. Extended values like `filename*` are left encoded
+    pub params: BTreeMap<String, String>,
+}
+
+impl ContentDisposition {
+    pub(crate) fn parse(value: &str) -> AsserhttpResult<Self> {
+        let invalid = || AsserhttpError::InvalidContentDisposition { value: value.to_string() };
+        let mut parts = split_unquoted(value, ';').into_iter();
+        let disposition = parts.next().unwrap_or_default().trim().to_lowercase();
+        if disposition.is_empty() || disposition.contains(['=', '"', ' ']) {
+            return Err(invalid());
+        }
+        let mut params = BTreeMap::new();
+        for param in parts.filter(|p| !p.trim().is_empty()) {
+            let (name, value) = param.split_once('=').ok_or_else(invalid)?;
+            params
+                .entry(name.trim().to_lowercase())
+                .or_insert_with(|| unquote(value.trim()));
+        }
+        // as recipients should, falls back to 'filename' when 'filename*' cannot be decoded
+        let filename = match params.get("filename*").map(|extended| decode_ext_value(extended)) {
+            Some(Some(decoded)) => Some(decoded),
+            Some(None) => Some(params.get("filename").cloned().ok_or_else(invalid)?),
+            None => params.get("filename").cloned(),
+        };
+        Ok(Self {
+            disposition,
+            filename,
+            params,
+        })
+    }
+
+    pub fn is_attachment(&self) -> bool {
+        self.disposition == "attachment"
+    }
+
+    pub fn is_inline(&self) -> bool {
+        self.disposition == "inline"
+    }
+}
+
+/// Expects a `Content-Disposition` header, then returns it as sent and parsed
+pub(crate) fn content_disposition(accessor: &impl HeaderAccessor) -> AsserhttpResult<(String, ContentDisposition)> {
+    let key = HeaderKey::from(CONTENT_DISPOSITION);
+    let value = accessor.first_raw_value(&key)?;
+    let parsed = ContentDisposition::parse(&value)?;
+    Ok((value, parsed))
+}
+
+/// Decodes an [RFC 8187](https://www.rfc-editor.org/rfc/rfc8187#section-3.2) `charset'language'value-chars`
+/// extended value, percent encoded in either UTF-8 or ISO-8859-1
+fn decode_ext_value(value: &str) -> Option<String> {
+    let mut parts = value.splitn(3, '\'');
+    let (charset, _language, encoded) = (parts.next()?, parts.next()?, parts.next()?);
+    let bytes = percent_decode(encoded)?;
+    match charset.to_lowercase().as_str() {
+        "utf-8" => String::from_utf8(bytes).ok(),
+        "iso-8859-1" => Some(bytes.into_iter().map(char::from).collect()),
+        _ => None,
+    }
+}
+
+fn percent_decode(value: &str) -> Option<Vec<u8>> {
+    let mut bytes = Vec::with_capacity(value.len());
+    let mut iter = value.bytes();
+    while let Some(b) = iter.next() {
+        if b == b'%' {
+            let hex = [iter.next()?, iter.next()?];
+            // 'from_str_radix' would accept a sign like in '%+f'
+            if !hex.iter().all(u8::is_ascii_hexdigit) {
+                return None;
+            }
+            bytes.push(u8::from_str_radix(std::str::from_utf8(&hex).ok()?, 16).ok()?);
+        } else {
+            bytes.push(b);
+        }
+    }
+    Some(bytes)
+}
@@ -0,0 +1,204 @@
+use crate::{
+    accessor::HeaderAccessor,
+    cookie::Cookie,
+    error::{AsserhttpError, AsserhttpResult},
+    header::key::HeaderKey,
+};
+
+/// For assertions on cookies set by `Set-Cookie` headers returning an error instead of panicking
+pub trait FallibleAsserhttpCookie<T> {
+    /// Allows verifying in a closure a cookie set by a `Set-Cookie` header, with its attributes.
+    /// When the cookie is set more than once, the last one is verified
+    /// * `name` - name of the cookie
+    /// * `asserter` - closure to verify the cookie
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use ureq::OrAnyStatus;
+    /// use asserhttp::*;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     reqwest::blocking::get("http://localhost").try_expect_cookie("session", |c| { assert!(c.http_only && c.secure); Ok(()) }).unwrap();
+    ///     reqwest::get("http://localhost").await.try_expect_cookie("session", |c| { assert!(c.http_only && c.secure); Ok(()) }).unwrap();
+    ///
+    ///     isahc::get("http://localhost").try_expect_cookie("session", |c| { assert!(c.http_only && c.secure); Ok(()) }).unwrap();
+    ///     isahc::get_async("http://localhost").await.try_expect_cookie("session", |c| { assert!(c.http_only && c.secure); Ok(()) }).unwrap();
+    ///
+    ///     surf::get("http://localhost").await.try_expect_cookie("session", |c| { assert!(c.http_only && c.secure); Ok(()) }).unwrap();
+    ///
+    ///     ureq::get("http://localhost").call().or_any_status().try_expect_cookie("session", |c| { assert!(c.http_only && c.secure); Ok(()) }).unwrap();
+    ///
+    ///     hyper::Client::new().get("http://localhost".parse().unwrap()).await.try_expect_cookie("session", |c| { assert!(c.http_only && c.secure); Ok(()) }).unwrap();
+    ///
+    ///     awc::Client::default().get("http://localhost").send().await.try_expect_cookie("session", |c| { assert!(c.http_only && c.secure); Ok(()) }).unwrap();
+    /// }
+    /// ```
+    /// # Error
+    /// * [AsserhttpError::CookieAbsent] when no `Set-Cookie` header sets a cookie with this name
+    fn try_expect_cookie<F>(&mut self, name: impl AsRef<str>, asserter: F) -> AsserhttpResult<&mut T>
+    where
+        F: FnOnce(Cookie) -> AsserhttpResult<()>;
+
+    /// Expects a cookie to be set with the `Secure` attribute
+    /// * `name` - name of the cookie
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use ureq::OrAnyStatus;
+    /// use asserhttp::*;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     reqwest::blocking::get("http://localhost").try_expect_cookie_secure("session").unwrap();
+    ///     reqwest::get("http://localhost").await.try_expect_cookie_secure("session").unwrap();
+    ///
+    ///     isahc::get("http://localhost").try_expect_cookie_secure("session").unwrap();
+    ///     isahc::get_async("http://localhost").await.try_expect_cookie_secure("session").unwrap();
+    ///
+    ///     surf::get("http://localhost").await.try_expect_cookie_secure("session").unwrap();
+    ///
+    ///     ureq::get("http://localhost").call().or_any_status().try_expect_cookie_secure("session").unwrap();
+    ///
+    ///     hyper::Client::new().get("http://localhost".parse().unwrap()).await.try_expect_cookie_secure("session").unwrap();
+    ///
+    ///     awc::Client::default().get("http://localhost").send().await.try_expect_cookie_secure("session").unwrap();
+    /// }
+    /// ```
+    /// # Error
+    /// * [AsserhttpError::CookieAbsent] when no `Set-Cookie` header sets a cookie with this name
+    /// * [AsserhttpError::CookieMismatch] when the cookie has no `Secure` attribute
+    fn try_expect_cookie_secure(&mut self, name: impl AsRef<str>) -> AsserhttpResult<&mut T> {
+        self.try_expect_cookie(name, |cookie| {
+            if cookie.secure {
+                return Ok(());
+            }
+            Err(AsserhttpError::CookieMismatch {
+                name: cookie.name,
+                expected: "Secure".to_string(),
+            })
+        })
+    }
+
+    /// Expects a cookie to be set with the `HttpOnly` attribute
+    /// * `name` - name of the cookie
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use ureq::OrAnyStatus;
+    /// use asserhttp::*;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     reqwest::blocking::get("http://localhost").try_expect_cookie_http_only("session").unwrap();
+    ///     reqwest::get("http://localhost").await.try_expect_cookie_http_only("session").unwrap();
+    ///
+    ///     isahc::get("http://localhost").try_expect_cookie_http_only("session").unwrap();
+    ///     isahc::get_async("http://localhost").await.try_expect_cookie_http_only("session").unwrap();
+    ///
+    ///     surf::get("http://localhost").await.try_expect_cookie_http_only("session").unwrap();
+    ///
+    ///     ureq::get("http://localhost").call().or_any_status().try_expect_cookie_http_only("session").unwrap();
+    ///
+    ///     hyper::Client::new().get("http://localhost".parse().unwrap()).await.try_expect_cookie_http_only("session").unwrap();
+    ///
+    ///     awc::Client::default().get("http://localhost").send().await.try_expect_cookie_http_only("session").unwrap();
+    /// }
+    /// ```
+    /// # Error
+    /// * [AsserhttpError::CookieAbsent] when no `Set-Cookie` header sets a cookie with this name
+    /// * [AsserhttpError::CookieMismatch] when the cookie has no `HttpOnly` attribute
+    fn try_expect_cookie_http_only(&mut self, name: impl AsRef<str>) -> AsserhttpResult<&mut T> {
+        self.try_expect_cookie(name, |cookie| {
+            if cookie.http_only {
+                return Ok(());
+            }
+            Err(AsserhttpError::CookieMismatch {
+                name: cookie.name,
+                expected: "HttpOnly".to_string(),
+            })
+        })
+    }
+
+    /// Expects a cookie to be removed, with a zero or negative `Max-Age` or an `Expires` date in the past
+    /// * `name` - name of the cookie
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use ureq::OrAnyStatus;
+    /// use asserhttp::*;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     reqwest::blocking::get("http://localhost").try_expect_cookie_removed("session").unwrap();
+    ///     reqwest::get("http://localhost").await.try_expect_cookie_removed("session").unwrap();
+    ///
+    ///     isahc::get("http://localhost").try_expect_cookie_removed("session").unwrap();
+    ///     isahc::get_async("http://localhost").await.try_expect_cookie_removed("session").unwrap();
+    ///
+    ///     surf::get("http://localhost").await.try_expect_cookie_removed("session").unwrap();
+    ///
+    ///     ureq::get("http://localhost").call().or_any_status().try_expect_cookie_removed("session").unwrap();
+    ///
+    ///     hyper::Client::new().get("http://localhost".parse().unwrap()).await.try_expect_cookie_removed("session").unwrap();
+    ///
+    ///     awc::Client::default().get("http://localhost").send().await.try_expect_cookie_removed("session").unwrap();
+    /// }
+    /// ```
+    /// # Error
+    /// * [AsserhttpError::CookieAbsent] when no `Set-Cookie` header sets a cookie with this name
+    /// * [AsserhttpError::CookieMismatch] when the cookie is not removed
+    fn try_expect_cookie_removed(&mut self, name: impl AsRef<str>) -> AsserhttpResult<&mut T> {
+        self.try_expect_cookie(name, |cookie| {
+            if cookie.is_removed() {
+                return Ok(());
+            }
+            Err(AsserhttpError::CookieMismatch {
+                name: cookie.name,
+                expected: "removed".to_string(),
+            })
+        })
+    }
+}
+
+impl<T> FallibleAsserhttpCookie<T> for T
+where
+    T: HeaderAccessor,
+{
+    fn try_expect_cookie<F>(&mut self, name: impl AsRef<str>, asserter: F) -> AsserhttpResult<&mut T>
+    where
+        F: FnOnce(Cookie) -> AsserhttpResult<()>,
+    {
+        let name = name.as_ref();
+        let cookies = self
+            .get_raw_values(&HeaderKey::from(crate::headers::SET_COOKIE))
+            .iter()
+            .filter_map(|line| Cookie::parse(line))
+            .collect::<Vec<_>>();
+        let cookie = cookies
+            .iter()
+            .rev()
+            .find(|c| c.name == name)
+            .ok_or_else(|| AsserhttpError::CookieAbsent {
+                name: name.to_string(),
+                actual: cookies.iter().map(|c| c.name.to_string()).collect(),
+            })?;
+        asserter(cookie.clone())?;
+        Ok(self)
+    }
+}
+
+impl<T, E> FallibleAsserhttpCookie<T> for Result<T, E>
+where
+    T: HeaderAccessor,
+    E: std::fmt::Debug,
+{
+    fn try_expect_cookie<F>(&mut self, name: impl AsRef<str>, asserter: F) -> AsserhttpResult<&mut T>
+    where
+        F: FnOnce(Cookie) -> AsserhttpResult<()>,
+    {
+        self.as_mut()
+            .map_err(|e| AsserhttpError::HttpError(format!("{e:?}")))?
+            .try_expect_cookie(name, asserter)
+    }
+}
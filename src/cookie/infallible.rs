@@ -0,0 +1,184 @@
+use crate::{
+    accessor::HeaderAccessor,
+    cookie::{fallible::FallibleAsserhttpCookie, Cookie},
+};
+
+/// For assertions on cookies set by `Set-Cookie` headers
+pub trait AsserhttpCookie<T> {
+    /// Allows verifying in a closure a cookie set by a `Set-Cookie` header, with its attributes.
+    /// When the cookie is set more than once, the last one is verified
+    /// * `name` - name of the cookie
+    /// * `asserter` - closure to verify the cookie
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use ureq::OrAnyStatus;
+    /// use asserhttp::*;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     reqwest::blocking::get("http://localhost").expect_cookie("session", |c| assert!(c.http_only && c.secure));
+    ///     reqwest::get("http://localhost").await.expect_cookie("session", |c| assert!(c.http_only && c.secure));
+    ///
+    ///     isahc::get("http://localhost").expect_cookie("session", |c| assert!(c.http_only && c.secure));
+    ///     isahc::get_async("http://localhost").await.expect_cookie("session", |c| assert!(c.http_only && c.secure));
+    ///
+    ///     surf::get("http://localhost").await.expect_cookie("session", |c| assert!(c.http_only && c.secure));
+    ///
+    ///     ureq::get("http://localhost").call().or_any_status().expect_cookie("session", |c| assert!(c.http_only && c.secure));
+    ///
+    ///     hyper::Client::new().get("http://localhost".parse().unwrap()).await.expect_cookie("session", |c| assert!(c.http_only && c.secure));
+    ///
+    ///     awc::Client::default().get("http://localhost").send().await.expect_cookie("session", |c| assert!(c.http_only && c.secure));
+    /// }
+    /// ```
+    fn expect_cookie<F>(&mut self, name: impl AsRef<str>, asserter: F) -> &mut T
+    where
+        F: FnOnce(Cookie);
+
+    /// Expects a cookie to be set with the `Secure` attribute
+    /// * `name` - name of the cookie
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use ureq::OrAnyStatus;
+    /// use asserhttp::*;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     reqwest::blocking::get("http://localhost").expect_cookie_secure("session");
+    ///     reqwest::get("http://localhost").await.expect_cookie_secure("session");
+    ///
+    ///     isahc::get("http://localhost").expect_cookie_secure("session");
+    ///     isahc::get_async("http://localhost").await.expect_cookie_secure("session");
+    ///
+    ///     surf::get("http://localhost").await.expect_cookie_secure("session");
+    ///
+    ///     ureq::get("http://localhost").call().or_any_status().expect_cookie_secure("session");
+    ///
+    ///     hyper::Client::new().get("http://localhost".parse().unwrap()).await.expect_cookie_secure("session");
+    ///
+    ///     awc::Client::default().get("http://localhost").send().await.expect_cookie_secure("session");
+    /// }
+    /// ```
+    fn expect_cookie_secure(&mut self, name: impl AsRef<str>) -> &mut T;
+
+    /// Expects a cookie to be set with the `HttpOnly` attribute
+    /// * `name` - name of the cookie
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use ureq::OrAnyStatus;
+    /// use asserhttp::*;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     reqwest::blocking::get("http://localhost").expect_cookie_http_only("session");
+    ///     reqwest::get("http://localhost").await.expect_cookie_http_only("session");
+    ///
+    ///     isahc::get("http://localhost").expect_cookie_http_only("session");
+    ///     isahc::get_async("http://localhost").await.expect_cookie_http_only("session");
+    ///
+    ///     surf::get("http://localhost").await.expect_cookie_http_only("session");
+    ///
+    ///     ureq::get("http://localhost").call().or_any_status().expect_cookie_http_only("session");
+    ///
+    ///     hyper::Client::new().get("http://localhost".parse().unwrap()).await.expect_cookie_http_only("session");
+    ///
+    ///     awc::Client::default().get("http://localhost").send().await.expect_cookie_http_only("session");
+    /// }
+    /// ```
+    fn expect_cookie_http_only(&mut self, name: impl AsRef<str>) -> &mut T;
+
+    /// Expects a cookie to be removed, with a zero or negative `Max-Age` or an `Expires` date in the past
+    /// * `name` - name of the cookie
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use ureq::OrAnyStatus;
+    /// use asserhttp::*;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     reqwest::blocking::get("http://localhost").expect_cookie_removed("session");
+    ///     reqwest::get("http://localhost").await.expect_cookie_removed("session");
+    ///
+    ///     isahc::get("http://localhost").expect_cookie_removed("session");
+    ///     isahc::get_async("http://localhost").await.expect_cookie_removed("session");
+    ///
+    ///     surf::get("http://localhost").await.expect_cookie_removed("session");
+    ///
+    ///     ureq::get("http://localhost").call().or_any_status().expect_cookie_removed("session");
+    ///
+    ///     hyper::Client::new().get("http://localhost".parse().unwrap()).await.expect_cookie_removed("session");
+    ///
+    ///     awc::Client::default().get("http://localhost").send().await.expect_cookie_removed("session");
+    /// }
+    /// ```
+    fn expect_cookie_removed(&mut self, name: impl AsRef<str>) -> &mut T;
+}
+
+impl<T> AsserhttpCookie<T> for T
+where
+    T: HeaderAccessor,
+{
+    fn expect_cookie<F>(&mut self, name: impl AsRef<str>, asserter: F) -> &mut T
+    where
+        F: FnOnce(Cookie),
+    {
+        #[allow(clippy::blocks_in_conditions)]
+        match self.try_expect_cookie(name, |v| {
+            asserter(v);
+            Ok(())
+        }) {
+            Err(e) => panic!("{e}"),
+            Ok(r) => r,
+        }
+    }
+
+    fn expect_cookie_secure(&mut self, name: impl AsRef<str>) -> &mut T {
+        match self.try_expect_cookie_secure(name) {
+            Err(e) => panic!("{e}"),
+            Ok(r) => r,
+        }
+    }
+
+    fn expect_cookie_http_only(&mut self, name: impl AsRef<str>) -> &mut T {
+        match self.try_expect_cookie_http_only(name) {
+            Err(e) => panic!("{e}"),
+            Ok(r) => r,
+        }
+    }
+
+    fn expect_cookie_removed(&mut self, name: impl AsRef<str>) -> &mut T {
+        match self.try_expect_cookie_removed(name) {
+            Err(e) => panic!("{e}"),
+            Ok(r) => r,
+        }
+    }
+}
+
+impl<T, E> AsserhttpCookie<T> for Result<T, E>
+where
+    T: HeaderAccessor,
+    E: std::fmt::Debug,
+{
+    fn expect_cookie<F>(&mut self, name: impl AsRef<str>, asserter: F) -> &mut T
+    where
+        F: FnOnce(Cookie),
+    {
+        self.as_mut().unwrap().expect_cookie(name, asserter)
+    }
+
+    fn expect_cookie_secure(&mut self, name: impl AsRef<str>) -> &mut T {
+        self.as_mut().unwrap().expect_cookie_secure(name)
+    }
+
+    fn expect_cookie_http_only(&mut self, name: impl AsRef<str>) -> &mut T {
+        self.as_mut().unwrap().expect_cookie_http_only(name)
+    }
+
+    fn expect_cookie_removed(&mut self, name: impl AsRef<str>) -> &mut T {
+        self.as_mut().unwrap().expect_cookie_removed(name)
+    }
+}
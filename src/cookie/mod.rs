@@ -0,0 +1,76 @@
+use std::time::SystemTime;
+
+pub(crate) mod fallible;
+pub(crate) mod infallible;
+
+/// A cookie set by a [Set-Cookie](https://www.rfc-editor.org/rfc/rfc6265#section-4.1) header, with its attributes
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Cookie {
+    pub name: String,
+    pub value: String,
+    pub http_only: bool,
+    pub secure: bool,
+    /// `Strict`, `Lax` or `None` as sent by the server
+    pub same_site: Option<String>,
+    pub path: Option<String>,
+    pub domain: Option<String>,
+    /// In seconds, zero or negative when the cookie is removed
+    pub max_age: Option<i64>,
+    pub expires: Option<SystemTime>,
+}
+
+impl Cookie {
+    /// Parses a single `Set-Cookie` line. Attributes are separated by `;` so the commas of `Expires` are preserved
+    pub(crate) fn parse(line: &str) -> Option<Self> {
+        let mut parts = line.split(';');
+        let (name, value) = parts.next()?.split_once('=')?;
+        let mut cookie = Self {
+            name: name.trim().to_string(),
+            value: value.trim().trim_matches('"').to_string(),
+            http_only: false,
+            secure: false,
+            same_site: None,
+            path: None,
+            domain: None,
+            max_age: None,
+            expires: None,
+        };
+        if cookie.name.is_empty() {
+            return None;
+        }
+        for attribute in parts {
+            let (key, value) = match attribute.split_once('=') {
+                Some((key, value)) => (key.trim(), Some(value.trim().to_string())),
+                None => (attribute.trim(), None),
+            };
+            match key.to_lowercase().as_str() {
+                "httponly" => cookie.http_only = true,
+                "secure" => cookie.secure = true,
+                "samesite" => cookie.same_site = value,
+                "path" => cookie.path = value,
+                "domain" => cookie.domain = value,
+                "max-age" => cookie.max_age = value.and_then(|v| v.parse().ok()),
+                "expires" => cookie.expires = value.as_deref().and_then(parse_expires),
+                _ => {},
+            }
+        }
+        Some(cookie)
+    }
+
+    /// Whether the server asks the client to remove this cookie, either with a zero or negative `Max-Age` or with an
+    /// `Expires` date in the past
+    pub fn is_removed(&self) -> bool {
+        match (self.max_age, self.expires) {
+            (Some(max_age), _) => max_age <= 0,
+            (None, Some(expires)) => expires <= SystemTime::now(),
+            (None, None) => false,
+        }
+    }
+}
+
+/// Accepts HTTP dates as well as the `Wed, 21-Oct-2015 07:28:00 GMT` format still emitted by some servers
+fn parse_expires(value: &str) -> Option<SystemTime> {
+    httpdate::parse_http_date(value)
+        .or_else(|_| httpdate::parse_http_date(&value.replace('-', " ")))
+        .ok()
+}
@@ -0,0 +1,373 @@
+use std::time::Duration;
+
+use crate::{
+    accessor::{HeaderAccessor, StatusAccessor},
+    cors::{allows, allows_header, any_origin, with_credentials, SAFELISTED_METHODS, WILDCARD},
+    error::{AsserhttpError, AsserhttpResult},
+    header::fallible::FallibleAsserhttpHeader,
+    headers,
+    status::fallible::FallibleAsserhttpStatus,
+};
+
+/// For assertions on the CORS headers of a response returning an error instead of panicking
+pub trait FallibleAsserhttpCors<T> {
+    /// Expects `Access-Control-Allow-Origin` to allow the origin, either explicitly or with `*` when no credentials
+    /// are allowed. The origin is compared exactly, as browsers do. When the origin is echoed back, `Vary` has to
+    /// contain `Origin` so that caches do not mix origins
+    /// * `origin` - origin of the request e.g. `https://app.example`
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use ureq::OrAnyStatus;
+    /// use asserhttp::*;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     reqwest::blocking::get("http://localhost").try_expect_cors_allowed_origin("https://app.example").unwrap();
+    ///     reqwest::get("http://localhost").await.try_expect_cors_allowed_origin("https://app.example").unwrap();
+    ///
+    ///     isahc::get("http://localhost").try_expect_cors_allowed_origin("https://app.example").unwrap();
+    ///     isahc::get_async("http://localhost").await.try_expect_cors_allowed_origin("https://app.example").unwrap();
+    ///
+    ///     surf::get("http://localhost").await.try_expect_cors_allowed_origin("https://app.example").unwrap();
+    ///
+    ///     ureq::get("http://localhost").call().or_any_status().try_expect_cors_allowed_origin("https://app.example").unwrap();
+    ///
+    ///     hyper::Client::new().get("http://localhost".parse().unwrap()).await.try_expect_cors_allowed_origin("https://app.example").unwrap();
+    ///
+    ///     awc::Client::default().get("http://localhost").send().await.try_expect_cors_allowed_origin("https://app.example").unwrap();
+    /// }
+    /// ```
+    /// # Error
+    /// * [AsserhttpError::HeaderAbsent] when there is no `Access-Control-Allow-Origin` header
+    /// * [AsserhttpError::CorsMismatch] when the origin is not allowed
+    /// * [AsserhttpError::HeaderAbsent] when there is no `Vary` header
+    /// * [AsserhttpError::CorsMismatch] when `Vary` does not contain `Origin`
+    fn try_expect_cors_allowed_origin(&mut self, origin: impl AsRef<str>) -> AsserhttpResult<&mut T>;
+
+    /// Expects `Access-Control-Allow-Methods` to allow every method, either explicitly or with `*` when no
+    /// credentials are allowed. Methods are case-sensitive
+    /// * `methods` - methods the response should allow
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use ureq::OrAnyStatus;
+    /// use asserhttp::*;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     reqwest::blocking::get("http://localhost").try_expect_cors_allowed_methods(["GET", "PUT"]).unwrap();
+    ///     reqwest::get("http://localhost").await.try_expect_cors_allowed_methods(["GET", "PUT"]).unwrap();
+    ///
+    ///     isahc::get("http://localhost").try_expect_cors_allowed_methods(["GET", "PUT"]).unwrap();
+    ///     isahc::get_async("http://localhost").await.try_expect_cors_allowed_methods(["GET", "PUT"]).unwrap();
+    ///
+    ///     surf::get("http://localhost").await.try_expect_cors_allowed_methods(["GET", "PUT"]).unwrap();
+    ///
+    ///     ureq::get("http://localhost").call().or_any_status().try_expect_cors_allowed_methods(["GET", "PUT"]).unwrap();
+    ///
+    ///     hyper::Client::new().get("http://localhost".parse().unwrap()).await.try_expect_cors_allowed_methods(["GET", "PUT"]).unwrap();
+    ///
+    ///     awc::Client::default().get("http://localhost").send().await.try_expect_cors_allowed_methods(["GET", "PUT"]).unwrap();
+    /// }
+    /// ```
+    /// # Error
+    /// * [AsserhttpError::HeaderAbsent] when there is no `Access-Control-Allow-Methods` header
+    /// * [AsserhttpError::CorsMismatch] when one of the methods is not allowed
+    fn try_expect_cors_allowed_methods<I, S>(&mut self, methods: I) -> AsserhttpResult<&mut T>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>;
+
+    /// Expects `Access-Control-Allow-Headers` to allow every request header, ignoring case. `*` allows any header
+    /// but `Authorization` when no credentials are allowed
+    /// * `headers` - request headers the response should allow
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use ureq::OrAnyStatus;
+    /// use asserhttp::*;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     reqwest::blocking::get("http://localhost").try_expect_cors_allowed_headers(["content-type", "x-request-id"]).unwrap();
+    ///     reqwest::get("http://localhost").await.try_expect_cors_allowed_headers(["content-type", "x-request-id"]).unwrap();
+    ///
+    ///     isahc::get("http://localhost").try_expect_cors_allowed_headers(["content-type", "x-request-id"]).unwrap();
+    ///     isahc::get_async("http://localhost").await.try_expect_cors_allowed_headers(["content-type", "x-request-id"]).unwrap();
+    ///
+    ///     surf::get("http://localhost").await.try_expect_cors_allowed_headers(["content-type", "x-request-id"]).unwrap();
+    ///
+    ///     ureq::get("http://localhost").call().or_any_status().try_expect_cors_allowed_headers(["content-type", "x-request-id"]).unwrap();
+    ///
+    ///     hyper::Client::new().get("http://localhost".parse().unwrap()).await.try_expect_cors_allowed_headers(["content-type", "x-request-id"]).unwrap();
+    ///
+    ///     awc::Client::default().get("http://localhost").send().await.try_expect_cors_allowed_headers(["content-type", "x-request-id"]).unwrap();
+    /// }
+    /// ```
+    /// # Error
+    /// * [AsserhttpError::HeaderAbsent] when there is no `Access-Control-Allow-Headers` header
+    /// * [AsserhttpError::CorsMismatch] when one of the headers is not allowed
+    fn try_expect_cors_allowed_headers<I, S>(&mut self, headers: I) -> AsserhttpResult<&mut T>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>;
+
+    /// Expects `Access-Control-Allow-Credentials: true` along with an explicit `Access-Control-Allow-Origin`,
+    /// since browsers reject credentials with `*`
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use ureq::OrAnyStatus;
+    /// use asserhttp::*;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     reqwest::blocking::get("http://localhost").try_expect_cors_credentials().unwrap();
+    ///     reqwest::get("http://localhost").await.try_expect_cors_credentials().unwrap();
+    ///
+    ///     isahc::get("http://localhost").try_expect_cors_credentials().unwrap();
+    ///     isahc::get_async("http://localhost").await.try_expect_cors_credentials().unwrap();
+    ///
+    ///     surf::get("http://localhost").await.try_expect_cors_credentials().unwrap();
+    ///
+    ///     ureq::get("http://localhost").call().or_any_status().try_expect_cors_credentials().unwrap();
+    ///
+    ///     hyper::Client::new().get("http://localhost".parse().unwrap()).await.try_expect_cors_credentials().unwrap();
+    ///
+    ///     awc::Client::default().get("http://localhost").send().await.try_expect_cors_credentials().unwrap();
+    /// }
+    /// ```
+    /// # Error
+    /// * [AsserhttpError::HeaderAbsent] when there is no `Access-Control-Allow-Credentials` header
+    /// * [AsserhttpError::HeaderValueMismatch] when `Access-Control-Allow-Credentials` is not `true`
+    /// * [AsserhttpError::HeaderAbsent] when there is no `Access-Control-Allow-Origin` header
+    /// * [AsserhttpError::CorsMismatch] when `Access-Control-Allow-Origin` is `*`
+    fn try_expect_cors_credentials(&mut self) -> AsserhttpResult<&mut T>;
+
+    /// Expects `Access-Control-Max-Age`, how long a preflight response can be cached
+    /// * `max_age` - expected duration, in seconds
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use ureq::OrAnyStatus;
+    /// use asserhttp::*;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     reqwest::blocking::get("http://localhost").try_expect_cors_max_age(std::time::Duration::from_secs(600)).unwrap();
+    ///     reqwest::get("http://localhost").await.try_expect_cors_max_age(std::time::Duration::from_secs(600)).unwrap();
+    ///
+    ///     isahc::get("http://localhost").try_expect_cors_max_age(std::time::Duration::from_secs(600)).unwrap();
+    ///     isahc::get_async("http://localhost").await.try_expect_cors_max_age(std::time::Duration::from_secs(600)).unwrap();
+    ///
+    ///     surf::get("http://localhost").await.try_expect_cors_max_age(std::time::Duration::from_secs(600)).unwrap();
+    ///
+    ///     ureq::get("http://localhost").call().or_any_status().try_expect_cors_max_age(std::time::Duration::from_secs(600)).unwrap();
+    ///
+    ///     hyper::Client::new().get("http://localhost".parse().unwrap()).await.try_expect_cors_max_age(std::time::Duration::from_secs(600)).unwrap();
+    ///
+    ///     awc::Client::default().get("http://localhost").send().await.try_expect_cors_max_age(std::time::Duration::from_secs(600)).unwrap();
+    /// }
+    /// ```
+    /// # Error
+    /// * [AsserhttpError::HeaderAbsent] when there is no `Access-Control-Max-Age` header
+    /// * [AsserhttpError::HeaderValueMismatch] when the duration is different
+    fn try_expect_cors_max_age(&mut self, max_age: Duration) -> AsserhttpResult<&mut T>;
+
+    /// Expects a successful response to a preflight `OPTIONS` request: a 2xx status and an allowed origin and method.
+    /// `GET`, `HEAD` and `POST` do not need to be listed in `Access-Control-Allow-Methods`
+    /// * `origin` - `Origin` of the preflight request
+    /// * `method` - `Access-Control-Request-Method` of the preflight request
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use ureq::OrAnyStatus;
+    /// use asserhttp::*;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     reqwest::blocking::get("http://localhost").try_expect_cors_preflight_ok("https://app.example", "PUT").unwrap();
+    ///     reqwest::get("http://localhost").await.try_expect_cors_preflight_ok("https://app.example", "PUT").unwrap();
+    ///
+    ///     isahc::get("http://localhost").try_expect_cors_preflight_ok("https://app.example", "PUT").unwrap();
+    ///     isahc::get_async("http://localhost").await.try_expect_cors_preflight_ok("https://app.example", "PUT").unwrap();
+    ///
+    ///     surf::get("http://localhost").await.try_expect_cors_preflight_ok("https://app.example", "PUT").unwrap();
+    ///
+    ///     ureq::get("http://localhost").call().or_any_status().try_expect_cors_preflight_ok("https://app.example", "PUT").unwrap();
+    ///
+    ///     hyper::Client::new().get("http://localhost".parse().unwrap()).await.try_expect_cors_preflight_ok("https://app.example", "PUT").unwrap();
+    ///
+    ///     awc::Client::default().get("http://localhost").send().await.try_expect_cors_preflight_ok("https://app.example", "PUT").unwrap();
+    /// }
+    /// ```
+    /// # Error
+    /// * [AsserhttpError::StatusRangeMismatch] when the status is not 2xx
+    /// * [AsserhttpError::HeaderAbsent] when there is no `Access-Control-Allow-Origin` header
+    /// * [AsserhttpError::CorsMismatch] when the origin or the method is not allowed
+    /// * [AsserhttpError::HeaderAbsent] when there is no `Access-Control-Allow-Methods` header
+    fn try_expect_cors_preflight_ok(&mut self, origin: impl AsRef<str>, method: impl AsRef<str>) -> AsserhttpResult<&mut T>;
+}
+
+impl<T> FallibleAsserhttpCors<T> for T
+where
+    T: HeaderAccessor + StatusAccessor,
+{
+    fn try_expect_cors_allowed_origin(&mut self, origin: impl AsRef<str>) -> AsserhttpResult<&mut T> {
+        let origin = origin.as_ref().to_string();
+        let credentials = with_credentials(self);
+        self.try_expect_header(headers::ACCESS_CONTROL_ALLOW_ORIGIN, move |actual: &str| {
+            if allows(&[actual], &origin, !credentials) {
+                return Ok(());
+            }
+            Err(AsserhttpError::CorsMismatch {
+                expected: match credentials {
+                    true => format!("allow origin '{origin}' with credentials"),
+                    false => format!("allow origin '{origin}'"),
+                },
+                key: headers::ACCESS_CONTROL_ALLOW_ORIGIN.into(),
+                actual: actual.to_string(),
+            })
+        })?;
+        if any_origin(self) {
+            return Ok(self);
+        }
+        self.try_expect_headers(headers::VARY, |vary: Vec<&str>| {
+            if allows_header(&vary, "origin", true) {
+                return Ok(());
+            }
+            Err(AsserhttpError::CorsMismatch {
+                expected: "vary on 'origin'".to_string(),
+                key: headers::VARY.into(),
+                actual: vary.join(", "),
+            })
+        })
+    }
+
+    fn try_expect_cors_allowed_methods<I, S>(&mut self, methods: I) -> AsserhttpResult<&mut T>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let credentials = with_credentials(self);
+        let methods = methods.into_iter().map(|m| m.as_ref().to_string()).collect::<Vec<_>>();
+        self.try_expect_headers(headers::ACCESS_CONTROL_ALLOW_METHODS, move |allowed: Vec<&str>| {
+            match methods.iter().find(|m| !allows(&allowed, m, !credentials)) {
+                None => Ok(()),
+                Some(method) => Err(AsserhttpError::CorsMismatch {
+                    expected: format!("allow method '{method}'"),
+                    key: headers::ACCESS_CONTROL_ALLOW_METHODS.into(),
+                    actual: allowed.join(", "),
+                }),
+            }
+        })
+    }
+
+    fn try_expect_cors_allowed_headers<I, S>(&mut self, headers: I) -> AsserhttpResult<&mut T>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let credentials = with_credentials(self);
+        let expected = headers.into_iter().map(|h| h.as_ref().to_string()).collect::<Vec<_>>();
+        self.try_expect_headers(headers::ACCESS_CONTROL_ALLOW_HEADERS, move |allowed: Vec<&str>| {
+            let wildcard = |h: &str| !credentials && !h.eq_ignore_ascii_case("authorization");
+            match expected.iter().find(|h| !allows_header(&allowed, h, wildcard(h))) {
+                None => Ok(()),
+                Some(header) => Err(AsserhttpError::CorsMismatch {
+                    expected: format!("allow header '{header}'"),
+                    key: headers::ACCESS_CONTROL_ALLOW_HEADERS.into(),
+                    actual: allowed.join(", "),
+                }),
+            }
+        })
+    }
+
+    fn try_expect_cors_credentials(&mut self) -> AsserhttpResult<&mut T> {
+        self.try_expect_header(headers::ACCESS_CONTROL_ALLOW_CREDENTIALS, "true")?;
+        self.try_expect_header(headers::ACCESS_CONTROL_ALLOW_ORIGIN, |origin: &str| {
+            if origin.trim() != WILDCARD {
+                return Ok(());
+            }
+            Err(AsserhttpError::CorsMismatch {
+                expected: "allow credentials".to_string(),
+                key: headers::ACCESS_CONTROL_ALLOW_ORIGIN.into(),
+                actual: origin.to_string(),
+            })
+        })
+    }
+
+    fn try_expect_cors_max_age(&mut self, max_age: Duration) -> AsserhttpResult<&mut T> {
+        self.try_expect_header(headers::ACCESS_CONTROL_MAX_AGE, max_age.as_secs().to_string())
+    }
+
+    fn try_expect_cors_preflight_ok(&mut self, origin: impl AsRef<str>, method: impl AsRef<str>) -> AsserhttpResult<&mut T> {
+        self.try_expect_status(|status: u16| {
+            if (200..300).contains(&status) {
+                return Ok(());
+            }
+            Err(AsserhttpError::StatusRangeMismatch {
+                lower: 200,
+                upper: 300,
+                actual: status,
+            })
+        })?;
+        self.try_expect_cors_allowed_origin(origin)?;
+        let method = method.as_ref();
+        if SAFELISTED_METHODS.contains(&method) {
+            return Ok(self);
+        }
+        self.try_expect_cors_allowed_methods([method])
+    }
+}
+
+impl<T, E> FallibleAsserhttpCors<T> for Result<T, E>
+where
+    T: HeaderAccessor + StatusAccessor,
+    E: std::fmt::Debug,
+{
+    fn try_expect_cors_allowed_origin(&mut self, origin: impl AsRef<str>) -> AsserhttpResult<&mut T> {
+        self.as_mut()
+            .map_err(|e| AsserhttpError::HttpError(format!("{e:?}")))?
+            .try_expect_cors_allowed_origin(origin)
+    }
+
+    fn try_expect_cors_allowed_methods<I, S>(&mut self, methods: I) -> AsserhttpResult<&mut T>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        self.as_mut()
+            .map_err(|e| AsserhttpError::HttpError(format!("{e:?}")))?
+            .try_expect_cors_allowed_methods(methods)
+    }
+
+    fn try_expect_cors_allowed_headers<I, S>(&mut self, headers: I) -> AsserhttpResult<&mut T>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        self.as_mut()
+            .map_err(|e| AsserhttpError::HttpError(format!("{e:?}")))?
+            .try_expect_cors_allowed_headers(headers)
+    }
+
+    fn try_expect_cors_credentials(&mut self) -> AsserhttpResult<&mut T> {
+        self.as_mut()
+            .map_err(|e| AsserhttpError::HttpError(format!("{e:?}")))?
+            .try_expect_cors_credentials()
+    }
+
+    fn try_expect_cors_max_age(&mut self, max_age: Duration) -> AsserhttpResult<&mut T> {
+        self.as_mut()
+            .map_err(|e| AsserhttpError::HttpError(format!("{e:?}")))?
+            .try_expect_cors_max_age(max_age)
+    }
+
+    fn try_expect_cors_preflight_ok(&mut self, origin: impl AsRef<str>, method: impl AsRef<str>) -> AsserhttpResult<&mut T> {
+        self.as_mut()
+            .map_err(|e| AsserhttpError::HttpError(format!("{e:?}")))?
+            .try_expect_cors_preflight_ok(origin, method)
+    }
+}
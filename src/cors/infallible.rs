@@ -0,0 +1,276 @@
+use std::time::Duration;
+
+use crate::{
+    accessor::{HeaderAccessor, StatusAccessor},
+    cors::fallible::FallibleAsserhttpCors,
+};
+
+/// For assertions on the CORS headers of a response
+pub trait AsserhttpCors<T> {
+    /// Expects `Access-Control-Allow-Origin` to allow the origin, either explicitly or with `*` when no credentials
+    /// are allowed. The origin is compared exactly, as browsers do. When the origin is echoed back, `Vary` has to
+    /// contain `Origin` so that caches do not mix origins
+    /// * `origin` - origin of the request e.g. `https://app.example`
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use ureq::OrAnyStatus;
+    /// use asserhttp::*;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     reqwest::blocking::get("http://localhost").expect_cors_allowed_origin("https://app.example");
+    ///     reqwest::get("http://localhost").await.expect_cors_allowed_origin("https://app.example");
+    ///
+    ///     isahc::get("http://localhost").expect_cors_allowed_origin("https://app.example");
+    ///     isahc::get_async("http://localhost").await.expect_cors_allowed_origin("https://app.example");
+    ///
+    ///     surf::get("http://localhost").await.expect_cors_allowed_origin("https://app.example");
+    ///
+    ///     ureq::get("http://localhost").call().or_any_status().expect_cors_allowed_origin("https://app.example");
+    ///
+    ///     hyper::Client::new().get("http://localhost".parse().unwrap()).await.expect_cors_allowed_origin("https://app.example");
+    ///
+    ///     awc::Client::default().get("http://localhost").send().await.expect_cors_allowed_origin("https://app.example");
+    /// }
+    /// ```
+    fn expect_cors_allowed_origin(&mut self, origin: impl AsRef<str>) -> &mut T;
+
+    /// Expects `Access-Control-Allow-Methods` to allow every method, either explicitly or with `*` when no
+    /// credentials are allowed. Methods are case-sensitive
+    /// * `methods` - methods the response should allow
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use ureq::OrAnyStatus;
+    /// use asserhttp::*;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     reqwest::blocking::get("http://localhost").expect_cors_allowed_methods(["GET", "PUT"]);
+    ///     reqwest::get("http://localhost").await.expect_cors_allowed_methods(["GET", "PUT"]);
+    ///
+    ///     isahc::get("http://localhost").expect_cors_allowed_methods(["GET", "PUT"]);
+    ///     isahc::get_async("http://localhost").await.expect_cors_allowed_methods(["GET", "PUT"]);
+    ///
+    ///     surf::get("http://localhost").await.expect_cors_allowed_methods(["GET", "PUT"]);
+    ///
+    ///     ureq::get("http://localhost").call().or_any_status().expect_cors_allowed_methods(["GET", "PUT"]);
+    ///
+    ///     hyper::Client::new().get("http://localhost".parse().unwrap()).await.expect_cors_allowed_methods(["GET", "PUT"]);
+    ///
+    ///     awc::Client::default().get("http://localhost").send().await.expect_cors_allowed_methods(["GET", "PUT"]);
+    /// }
+    /// ```
+    fn expect_cors_allowed_methods<I, S>(&mut self, methods: I) -> &mut T
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>;
+
+    /// Expects `Access-Control-Allow-Headers` to allow every request header, ignoring case. `*` allows any header
+    /// but `Authorization` when no credentials are allowed
+    /// * `headers` - request headers the response should allow
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use ureq::OrAnyStatus;
+    /// use asserhttp::*;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     reqwest::blocking::get("http://localhost").expect_cors_allowed_headers(["content-type", "x-request-id"]);
+    ///     reqwest::get("http://localhost").await.expect_cors_allowed_headers(["content-type", "x-request-id"]);
+    ///
+    ///     isahc::get("http://localhost").expect_cors_allowed_headers(["content-type", "x-request-id"]);
+    ///     isahc::get_async("http://localhost").await.expect_cors_allowed_headers(["content-type", "x-request-id"]);
+    ///
+    ///     surf::get("http://localhost").await.expect_cors_allowed_headers(["content-type", "x-request-id"]);
+    ///
+    ///     ureq::get("http://localhost").call().or_any_status().expect_cors_allowed_headers(["content-type", "x-request-id"]);
+    ///
+    ///     hyper::Client::new().get("http://localhost".parse().unwrap()).await.expect_cors_allowed_headers(["content-type", "x-request-id"]);
+    ///
+    ///     awc::Client::default().get("http://localhost").send().await.expect_cors_allowed_headers(["content-type", "x-request-id"]);
+    /// }
+    /// ```
+    fn expect_cors_allowed_headers<I, S>(&mut self, headers: I) -> &mut T
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>;
+
+    /// Expects `Access-Control-Allow-Credentials: true` along with an explicit `Access-Control-Allow-Origin`,
+    /// since browsers reject credentials with `*`
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use ureq::OrAnyStatus;
+    /// use asserhttp::*;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     reqwest::blocking::get("http://localhost").expect_cors_credentials();
+    ///     reqwest::get("http://localhost").await.expect_cors_credentials();
+    ///
+    ///     isahc::get("http://localhost").expect_cors_credentials();
+    ///     isahc::get_async("http://localhost").await.expect_cors_credentials();
+    ///
+    ///     surf::get("http://localhost").await.expect_cors_credentials();
+    ///
+    ///     ureq::get("http://localhost").call().or_any_status().expect_cors_credentials();
+    ///
+    ///     hyper::Client::new().get("http://localhost".parse().unwrap()).await.expect_cors_credentials();
+    ///
+    ///     awc::Client::default().get("http://localhost").send().await.expect_cors_credentials();
+    /// }
+    /// ```
+    fn expect_cors_credentials(&mut self) -> &mut T;
+
+    /// Expects `Access-Control-Max-Age`, how long a preflight response can be cached
+    /// * `max_age` - expected duration, in seconds
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use ureq::OrAnyStatus;
+    /// use asserhttp::*;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     reqwest::blocking::get("http://localhost").expect_cors_max_age(std::time::Duration::from_secs(600));
+    ///     reqwest::get("http://localhost").await.expect_cors_max_age(std::time::Duration::from_secs(600));
+    ///
+    ///     isahc::get("http://localhost").expect_cors_max_age(std::time::Duration::from_secs(600));
+    ///     isahc::get_async("http://localhost").await.expect_cors_max_age(std::time::Duration::from_secs(600));
+    ///
+    ///     surf::get("http://localhost").await.expect_cors_max_age(std::time::Duration::from_secs(600));
+    ///
+    ///     ureq::get("http://localhost").call().or_any_status().expect_cors_max_age(std::time::Duration::from_secs(600));
+    ///
+    ///     hyper::Client::new().get("http://localhost".parse().unwrap()).await.expect_cors_max_age(std::time::Duration::from_secs(600));
+    ///
+    ///     awc::Client::default().get("http://localhost").send().await.expect_cors_max_age(std::time::Duration::from_secs(600));
+    /// }
+    /// ```
+    fn expect_cors_max_age(&mut self, max_age: Duration) -> &mut T;
+
+    /// Expects a successful response to a preflight `OPTIONS` request: a 2xx status and an allowed origin and method.
+    /// `GET`, `HEAD` and `POST` do not need to be listed in `Access-Control-Allow-Methods`
+    /// * `origin` - `Origin` of the preflight request
+    /// * `method` - `Access-Control-Request-Method` of the preflight request
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use ureq::OrAnyStatus;
+    /// use asserhttp::*;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     reqwest::blocking::get("http://localhost").expect_cors_preflight_ok("https://app.example", "PUT");
+    ///     reqwest::get("http://localhost").await.expect_cors_preflight_ok("https://app.example", "PUT");
+    ///
+    ///     isahc::get("http://localhost").expect_cors_preflight_ok("https://app.example", "PUT");
+    ///     isahc::get_async("http://localhost").await.expect_cors_preflight_ok("https://app.example", "PUT");
+    ///
+    ///     surf::get("http://localhost").await.expect_cors_preflight_ok("https://app.example", "PUT");
+    ///
+    ///     ureq::get("http://localhost").call().or_any_status().expect_cors_preflight_ok("https://app.example", "PUT");
+    ///
+    ///     hyper::Client::new().get("http://localhost".parse().unwrap()).await.expect_cors_preflight_ok("https://app.example", "PUT");
+    ///
+    ///     awc::Client::default().get("http://localhost").send().await.expect_cors_preflight_ok("https://app.example", "PUT");
+    /// }
+    /// ```
+    fn expect_cors_preflight_ok(&mut self, origin: impl AsRef<str>, method: impl AsRef<str>) -> &mut T;
+}
+
+impl<T> AsserhttpCors<T> for T
+where
+    T: HeaderAccessor + StatusAccessor,
+{
+    fn expect_cors_allowed_origin(&mut self, origin: impl AsRef<str>) -> &mut T {
+        match self.try_expect_cors_allowed_origin(origin) {
+            Err(e) => panic!("{e}"),
+            Ok(r) => r,
+        }
+    }
+
+    fn expect_cors_allowed_methods<I, S>(&mut self, methods: I) -> &mut T
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        match self.try_expect_cors_allowed_methods(methods) {
+            Err(e) => panic!("{e}"),
+            Ok(r) => r,
+        }
+    }
+
+    fn expect_cors_allowed_headers<I, S>(&mut self, headers: I) -> &mut T
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        match self.try_expect_cors_allowed_headers(headers) {
+            Err(e) => panic!("{e}"),
+            Ok(r) => r,
+        }
+    }
+
+    fn expect_cors_credentials(&mut self) -> &mut T {
+        match self.try_expect_cors_credentials() {
+            Err(e) => panic!("{e}"),
+            Ok(r) => r,
+        }
+    }
+
+    fn expect_cors_max_age(&mut self, max_age: Duration) -> &mut T {
+        match self.try_expect_cors_max_age(max_age) {
+            Err(e) => panic!("{e}"),
+            Ok(r) => r,
+        }
+    }
+
+    fn expect_cors_preflight_ok(&mut self, origin: impl AsRef<str>, method: impl AsRef<str>) -> &mut T {
+        match self.try_expect_cors_preflight_ok(origin, method) {
+            Err(e) => panic!("{e}"),
+            Ok(r) => r,
+        }
+    }
+}
+
+impl<T, E> AsserhttpCors<T> for Result<T, E>
+where
+    T: HeaderAccessor + StatusAccessor,
+    E: std::fmt::Debug,
+{
+    fn expect_cors_allowed_origin(&mut self, origin: impl AsRef<str>) -> &mut T {
+        self.as_mut().unwrap().expect_cors_allowed_origin(origin)
+    }
+
+    fn expect_cors_allowed_methods<I, S>(&mut self, methods: I) -> &mut T
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        self.as_mut().unwrap().expect_cors_allowed_methods(methods)
+    }
+
+    fn expect_cors_allowed_headers<I, S>(&mut self, headers: I) -> &mut T
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        self.as_mut().unwrap().expect_cors_allowed_headers(headers)
+    }
+
+    fn expect_cors_credentials(&mut self) -> &mut T {
+        self.as_mut().unwrap().expect_cors_credentials()
+    }
+
+    fn expect_cors_max_age(&mut self, max_age: Duration) -> &mut T {
+        self.as_mut().unwrap().expect_cors_max_age(max_age)
+    }
+
+    fn expect_cors_preflight_ok(&mut self, origin: impl AsRef<str>, method: impl AsRef<str>) -> &mut T {
+        self.as_mut().unwrap().expect_cors_preflight_ok(origin, method)
+    }
+}
@@ -0,0 +1,38 @@
+use crate::{accessor::HeaderAccessor, header::key::HeaderKey};
+
+pub(crate) mod fallible;
+pub(crate) mod infallible;
+
+/// Allows any origin, method or header, but only for requests without credentials
+const WILDCARD: &str = "*";
+
+/// Methods a preflight request never has to be explicitly allowed for
+const SAFELISTED_METHODS: [&str; 3] = ["GET", "HEAD", "POST"];
+
+/// Whether `Access-Control-Allow-Credentials: true` is sent, which disables wildcards
+fn with_credentials(accessor: &impl HeaderAccessor) -> bool {
+    accessor
+        .get_raw_values(&HeaderKey::from(crate::headers::ACCESS_CONTROL_ALLOW_CREDENTIALS))
+        .iter()
+        .any(|v| v.trim() == "true")
+}
+
+/// Whether `Access-Control-Allow-Origin` is the wildcard
+fn any_origin(accessor: &impl HeaderAccessor) -> bool {
+    accessor
+        .get_raw_values(&HeaderKey::from(crate::headers::ACCESS_CONTROL_ALLOW_ORIGIN))
+        .iter()
+        .any(|v| v.trim() == WILDCARD)
+}
+
+/// Whether an allow list contains the value, origins and methods being case-sensitive, or the wildcard when it applies
+fn allows(allowed: &[&str], value: &str, wildcard: bool) -> bool {
+    allowed.iter().any(|a| *a == value || (wildcard && *a == WILDCARD))
+}
+
+/// Whether an allow list contains the header name, ignoring case, or the wildcard when it applies
+fn allows_header(allowed: &[&str], name: &str, wildcard: bool) -> bool {
+    allowed
+        .iter()
+        .any(|a| a.eq_ignore_ascii_case(name) || (wildcard && *a == WILDCARD))
+}
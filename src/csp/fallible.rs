@@ -0,0 +1,188 @@
+use crate::{
+    accessor::HeaderAccessor,
+    csp::Csp,
+    error::{AsserhttpError, AsserhttpResult},
+    header::key::HeaderKey,
+};
+
+const UNSAFE_INLINE: &str = "'unsafe-inline'";
+
+/// Whether the policy blocks inline code for a fetch directive like `script-src`: the directive, or else `default-src`,
+/// is present and either lacks `'unsafe-inline'` or has a nonce or hash source, which make browsers ignore it
+fn blocks_inline(csp: &Csp, name: &str) -> bool {
+    let directive = csp.effective_directive(name);
+    let nonce_or_hash = |source: &&str| {
+        let source = source.to_lowercase();
+        ["'nonce-", "'sha256-", "'sha384-", "'sha512-"]
+            .iter()
+            .any(|prefix| source.starts_with(prefix))
+    };
+    (csp.has(name) || csp.has("default-src")) && (!directive.contains(UNSAFE_INLINE) || directive.sources().iter().any(nonce_or_hash))
+}
+
+/// For assertions on the `Content-Security-Policy` header returning an error instead of panicking
+pub trait FallibleAsserhttpCsp<T> {
+    /// Allows verifying the `Content-Security-Policy` in a closure, whatever the order of its directives and sources.
+    /// The closure is called once per policy when several are sent since every one of them is enforced
+    /// * `asserter` - closure to verify each parsed policy
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use ureq::OrAnyStatus;
+    /// use asserhttp::*;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     reqwest::blocking::get("http://localhost").try_expect_csp(|csp| { assert!(csp.directive("script-src").contains("'self'")); Ok(()) }).unwrap();
+    ///     reqwest::get("http://localhost").await.try_expect_csp(|csp| { assert!(csp.directive("script-src").contains("'self'")); Ok(()) }).unwrap();
+    ///
+    ///     isahc::get("http://localhost").try_expect_csp(|csp| { assert!(csp.directive("script-src").contains("'self'")); Ok(()) }).unwrap();
+    ///     isahc::get_async("http://localhost").await.try_expect_csp(|csp| { assert!(csp.directive("script-src").contains("'self'")); Ok(()) }).unwrap();
+    ///
+    ///     surf::get("http://localhost").await.try_expect_csp(|csp| { assert!(csp.directive("script-src").contains("'self'")); Ok(()) }).unwrap();
+    ///
+    ///     ureq::get("http://localhost").call().or_any_status().try_expect_csp(|csp| { assert!(csp.directive("script-src").contains("'self'")); Ok(()) }).unwrap();
+    ///
+    ///     hyper::Client::new().get("http://localhost".parse().unwrap()).await.try_expect_csp(|csp| { assert!(csp.directive("script-src").contains("'self'")); Ok(()) }).unwrap();
+    ///
+    ///     awc::Client::default().get("http://localhost").send().await.try_expect_csp(|csp| { assert!(csp.directive("script-src").contains("'self'")); Ok(()) }).unwrap();
+    /// }
+    /// ```
+    /// # Error
+    /// * [AsserhttpError::HeaderAbsent] when there is no `Content-Security-Policy` header
+    fn try_expect_csp<F>(&mut self, asserter: F) -> AsserhttpResult<&mut T>
+    where
+        F: FnMut(Csp) -> AsserhttpResult<()>;
+
+    /// Allows verifying the `Content-Security-Policy-Report-Only` in a closure, whatever the order of its
+    /// directives and sources. The closure is called once per policy when several are sent
+    /// * `asserter` - closure to verify each parsed policy
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use ureq::OrAnyStatus;
+    /// use asserhttp::*;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     reqwest::blocking::get("http://localhost").try_expect_csp_report_only(|csp| { assert!(csp.has("report-uri")); Ok(()) }).unwrap();
+    ///     reqwest::get("http://localhost").await.try_expect_csp_report_only(|csp| { assert!(csp.has("report-uri")); Ok(()) }).unwrap();
+    ///
+    ///     isahc::get("http://localhost").try_expect_csp_report_only(|csp| { assert!(csp.has("report-uri")); Ok(()) }).unwrap();
+    ///     isahc::get_async("http://localhost").await.try_expect_csp_report_only(|csp| { assert!(csp.has("report-uri")); Ok(()) }).unwrap();
+    ///
+    ///     surf::get("http://localhost").await.try_expect_csp_report_only(|csp| { assert!(csp.has("report-uri")); Ok(()) }).unwrap();
+    ///
+    ///     ureq::get("http://localhost").call().or_any_status().try_expect_csp_report_only(|csp| { assert!(csp.has("report-uri")); Ok(()) }).unwrap();
+    ///
+    ///     hyper::Client::new().get("http://localhost".parse().unwrap()).await.try_expect_csp_report_only(|csp| { assert!(csp.has("report-uri")); Ok(()) }).unwrap();
+    ///
+    ///     awc::Client::default().get("http://localhost").send().await.try_expect_csp_report_only(|csp| { assert!(csp.has("report-uri")); Ok(()) }).unwrap();
+    /// }
+    /// ```
+    /// # Error
+    /// * [AsserhttpError::HeaderAbsent] when there is no `Content-Security-Policy-Report-Only` header
+    fn try_expect_csp_report_only<F>(&mut self, asserter: F) -> AsserhttpResult<&mut T>
+    where
+        F: FnMut(Csp) -> AsserhttpResult<()>;
+
+    /// Expects inline scripts and styles to be blocked by at least one of the `Content-Security-Policy` policies: its
+    /// `script-src`, respectively `style-src`, falling back to `default-src` when absent, does not allow
+    /// `'unsafe-inline'`. Since browsers ignore `'unsafe-inline'` next to a `'nonce-*'` or `'sha*-*'` source, such a
+    /// directive blocks inline code as well
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use ureq::OrAnyStatus;
+    /// use asserhttp::*;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     reqwest::blocking::get("http://localhost").try_expect_csp_no_unsafe_inline().unwrap();
+    ///     reqwest::get("http://localhost").await.try_expect_csp_no_unsafe_inline().unwrap();
+    ///
+    ///     isahc::get("http://localhost").try_expect_csp_no_unsafe_inline().unwrap();
+    ///     isahc::get_async("http://localhost").await.try_expect_csp_no_unsafe_inline().unwrap();
+    ///
+    ///     surf::get("http://localhost").await.try_expect_csp_no_unsafe_inline().unwrap();
+    ///
+    ///     ureq::get("http://localhost").call().or_any_status().try_expect_csp_no_unsafe_inline().unwrap();
+    ///
+    ///     hyper::Client::new().get("http://localhost".parse().unwrap()).await.try_expect_csp_no_unsafe_inline().unwrap();
+    ///
+    ///     awc::Client::default().get("http://localhost").send().await.try_expect_csp_no_unsafe_inline().unwrap();
+    /// }
+    /// ```
+    /// # Error
+    /// * [AsserhttpError::HeaderAbsent] when there is no `Content-Security-Policy` header
+    /// * [AsserhttpError::CspMismatch] when no policy blocks inline scripts or styles
+    fn try_expect_csp_no_unsafe_inline(&mut self) -> AsserhttpResult<&mut T> {
+        let mut policies = vec![];
+        let response = self.try_expect_csp(|csp| {
+            policies.push(csp);
+            Ok(())
+        })?;
+        for name in ["script-src", "style-src"] {
+            if !policies.iter().any(|csp| blocks_inline(csp, name)) {
+                return Err(AsserhttpError::CspMismatch {
+                    expected: format!("no {UNSAFE_INLINE} in '{name}'"),
+                    actual: policies.iter().map(ToString::to_string).collect::<Vec<_>>().join(", "),
+                });
+            }
+        }
+        Ok(response)
+    }
+}
+
+impl<T> FallibleAsserhttpCsp<T> for T
+where
+    T: HeaderAccessor,
+{
+    fn try_expect_csp<F>(&mut self, mut asserter: F) -> AsserhttpResult<&mut T>
+    where
+        F: FnMut(Csp) -> AsserhttpResult<()>,
+    {
+        let key = HeaderKey::from("content-security-policy");
+        key.try_assert_contained(self.get_keys())?;
+        for csp in Csp::parse(&self.get_raw_values(&key), false) {
+            asserter(csp)?;
+        }
+        Ok(self)
+    }
+
+    fn try_expect_csp_report_only<F>(&mut self, mut asserter: F) -> AsserhttpResult<&mut T>
+    where
+        F: FnMut(Csp) -> AsserhttpResult<()>,
+    {
+        let key = HeaderKey::from("content-security-policy-report-only");
+        key.try_assert_contained(self.get_keys())?;
+        for csp in Csp::parse(&self.get_raw_values(&key), true) {
+            asserter(csp)?;
+        }
+        Ok(self)
+    }
+}
+
+impl<T, E> FallibleAsserhttpCsp<T> for Result<T, E>
+where
+    T: HeaderAccessor,
+    E: std::fmt::Debug,
+{
+    fn try_expect_csp<F>(&mut self, asserter: F) -> AsserhttpResult<&mut T>
+    where
+        F: FnMut(Csp) -> AsserhttpResult<()>,
+    {
+        self.as_mut()
+            .map_err(|e| AsserhttpError::HttpError(format!("{e:?}")))?
+            .try_expect_csp(asserter)
+    }
+
+    fn try_expect_csp_report_only<F>(&mut self, asserter: F) -> AsserhttpResult<&mut T>
+    where
+        F: FnMut(Csp) -> AsserhttpResult<()>,
+    {
+        self.as_mut()
+            .map_err(|e| AsserhttpError::HttpError(format!("{e:?}")))?
+            .try_expect_csp_report_only(asserter)
+    }
+}
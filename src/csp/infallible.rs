@@ -0,0 +1,160 @@
+use crate::{
+    accessor::HeaderAccessor,
+    csp::{fallible::FallibleAsserhttpCsp, Csp},
+};
+
+/// For assertions on the `Content-Security-Policy` header
+pub trait AsserhttpCsp<T> {
+    /// Allows verifying the `Content-Security-Policy` in a closure, whatever the order of its directives and sources.
+    /// The closure is called once per policy when several are sent since every one of them is enforced
+    /// * `asserter` - closure to verify each parsed policy
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use ureq::OrAnyStatus;
+    /// use asserhttp::*;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     reqwest::blocking::get("http://localhost").expect_csp(|csp| assert!(csp.directive("script-src").contains("'self'")));
+    ///     reqwest::get("http://localhost").await.expect_csp(|csp| assert!(csp.directive("script-src").contains("'self'")));
+    ///
+    ///     isahc::get("http://localhost").expect_csp(|csp| assert!(csp.directive("script-src").contains("'self'")));
+    ///     isahc::get_async("http://localhost").await.expect_csp(|csp| assert!(csp.directive("script-src").contains("'self'")));
+    ///
+    ///     surf::get("http://localhost").await.expect_csp(|csp| assert!(csp.directive("script-src").contains("'self'")));
+    ///
+    ///     ureq::get("http://localhost").call().or_any_status().expect_csp(|csp| assert!(csp.directive("script-src").contains("'self'")));
+    ///
+    ///     hyper::Client::new().get("http://localhost".parse().unwrap()).await.expect_csp(|csp| assert!(csp.directive("script-src").contains("'self'")));
+    ///
+    ///     awc::Client::default().get("http://localhost").send().await.expect_csp(|csp| assert!(csp.directive("script-src").contains("'self'")));
+    /// }
+    /// ```
+    fn expect_csp<F>(&mut self, asserter: F) -> &mut T
+    where
+        F: FnMut(Csp);
+
+    /// Allows verifying the `Content-Security-Policy-Report-Only` in a closure, whatever the order of its
+    /// directives and sources. The closure is called once per policy when several are sent
+    /// * `asserter` - closure to verify each parsed policy
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use ureq::OrAnyStatus;
+    /// use asserhttp::*;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     reqwest::blocking::get("http://localhost").expect_csp_report_only(|csp| assert!(csp.has("report-uri")));
+    ///     reqwest::get("http://localhost").await.expect_csp_report_only(|csp| assert!(csp.has("report-uri")));
+    ///
+    ///     isahc::get("http://localhost").expect_csp_report_only(|csp| assert!(csp.has("report-uri")));
+    ///     isahc::get_async("http://localhost").await.expect_csp_report_only(|csp| assert!(csp.has("report-uri")));
+    ///
+    ///     surf::get("http://localhost").await.expect_csp_report_only(|csp| assert!(csp.has("report-uri")));
+    ///
+    ///     ureq::get("http://localhost").call().or_any_status().expect_csp_report_only(|csp| assert!(csp.has("report-uri")));
+    ///
+    ///     hyper::Client::new().get("http://localhost".parse().unwrap()).await.expect_csp_report_only(|csp| assert!(csp.has("report-uri")));
+    ///
+    ///     awc::Client::default().get("http://localhost").send().await.expect_csp_report_only(|csp| assert!(csp.has("report-uri")));
+    /// }
+    /// ```
+    fn expect_csp_report_only<F>(&mut self, asserter: F) -> &mut T
+    where
+        F: FnMut(Csp);
+
+    /// Expects inline scripts and styles to be blocked by at least one of the `Content-Security-Policy` policies: its
+    /// `script-src`, respectively `style-src`, falling back to `default-src` when absent, does not allow
+    /// `'unsafe-inline'`. Since browsers ignore `'unsafe-inline'` next to a `'nonce-*'` or `'sha*-*'` source, such a
+    /// directive blocks inline code as well
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use ureq::OrAnyStatus;
+    /// use asserhttp::*;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     reqwest::blocking::get("http://localhost").expect_csp_no_unsafe_inline();
+    ///     reqwest::get("http://localhost").await.expect_csp_no_unsafe_inline();
+    ///
+    ///     isahc::get("http://localhost").expect_csp_no_unsafe_inline();
+    ///     isahc::get_async("http://localhost").await.expect_csp_no_unsafe_inline();
+    ///
+    ///     surf::get("http://localhost").await.expect_csp_no_unsafe_inline();
+    ///
+    ///     ureq::get("http://localhost").call().or_any_status().expect_csp_no_unsafe_inline();
+    ///
+    ///     hyper::Client::new().get("http://localhost".parse().unwrap()).await.expect_csp_no_unsafe_inline();
+    ///
+    ///     awc::Client::default().get("http://localhost").send().await.expect_csp_no_unsafe_inline();
+    /// }
+    /// ```
+    fn expect_csp_no_unsafe_inline(&mut self) -> &mut T;
+}
+
+impl<T> AsserhttpCsp<T> for T
+where
+    T: HeaderAccessor,
+{
+    fn expect_csp<F>(&mut self, mut asserter: F) -> &mut T
+    where
+        F: FnMut(Csp),
+    {
+        #[allow(clippy::blocks_in_conditions)]
+        match self.try_expect_csp(|v| {
+            asserter(v);
+            Ok(())
+        }) {
+            Err(e) => panic!("{e}"),
+            Ok(r) => r,
+        }
+    }
+
+    fn expect_csp_report_only<F>(&mut self, mut asserter: F) -> &mut T
+    where
+        F: FnMut(Csp),
+    {
+        #[allow(clippy::blocks_in_conditions)]
+        match self.try_expect_csp_report_only(|v| {
+            asserter(v);
+            Ok(())
+        }) {
+            Err(e) => panic!("{e}"),
+            Ok(r) => r,
+        }
+    }
+
+    fn expect_csp_no_unsafe_inline(&mut self) -> &mut T {
+        match self.try_expect_csp_no_unsafe_inline() {
+            Err(e) => panic!("{e}"),
+            Ok(r) => r,
+        }
+    }
+}
+
+impl<T, E> AsserhttpCsp<T> for Result<T, E>
+where
+    T: HeaderAccessor,
+    E: std::fmt::Debug,
+{
+    fn expect_csp<F>(&mut self, asserter: F) -> &mut T
+    where
+        F: FnMut(Csp),
+    {
+        self.as_mut().unwrap().expect_csp(asserter)
+    }
+
+    fn expect_csp_report_only<F>(&mut self, asserter: F) -> &mut T
+    where
+        F: FnMut(Csp),
+    {
+        self.as_mut().unwrap().expect_csp_report_only(asserter)
+    }
+
+    fn expect_csp_no_unsafe_inline(&mut self) -> &mut T {
+        self.as_mut().unwrap().expect_csp_no_unsafe_inline()
+    }
+}
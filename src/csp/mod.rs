@@ -0,0 +1,119 @@
+pub(crate) mod fallible;
+pub(crate) mod infallible;
+
+/// A [Content-Security-Policy](https://www.w3.org/TR/CSP3/) parsed into directives and their sources.
+/// A response may send several policies, on distinct header lines or comma separated, and every one of them is enforced
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+pub struct Csp {
+    directives: Vec<CspDirective>,
+    report_only: bool,
+}
+
+/// A directive of a [Csp] e.g. `script-src 'self' https://cdn.example`
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+pub struct CspDirective {
+    name: String,
+    sources: Vec<String>,
+}
+
+impl Csp {
+    /// Parses every comma separated policy of every header line, in order
+    pub(crate) fn parse(lines: &[String], report_only: bool) -> Vec<Self> {
+        lines
+            .iter()
+            .flat_map(|line| line.split(','))
+            .filter(|policy| !policy.trim().is_empty())
+            .map(|policy| Self::parse_policy(policy, report_only))
+            .collect()
+    }
+
+    /// Parses the directives of a single policy. Names are lowercased and the first occurrence of a directive wins
+    fn parse_policy(policy: &str, report_only: bool) -> Self {
+        let mut directives: Vec<CspDirective> = vec![];
+        for directive in policy.split(';') {
+            let mut tokens = directive.split_whitespace();
+            let Some(name) = tokens.next().map(str::to_lowercase) else {
+                continue;
+            };
+            if directives.iter().all(|d| d.name != name) {
+                directives.push(CspDirective {
+                    name,
+                    sources: tokens.map(str::to_string).collect(),
+                });
+            }
+        }
+        Self { directives, report_only }
+    }
+
+    /// Whether the directive is present, with or without sources
+    pub fn has(&self, name: &str) -> bool {
+        self.directives.iter().any(|d| d.name.eq_ignore_ascii_case(name))
+    }
+
+    /// The directive with this name, empty when absent
+    pub fn directive(&self, name: &str) -> CspDirective {
+        self.directives
+            .iter()
+            .find(|d| d.name.eq_ignore_ascii_case(name))
+            .cloned()
+            .unwrap_or_else(|| CspDirective {
+                name: name.to_lowercase(),
+                sources: vec![],
+            })
+    }
+
+    /// The directive actually enforced for a fetch directive: itself when present, `default-src` otherwise
+    pub fn effective_directive(&self, name: &str) -> CspDirective {
+        if self.has(name) || !name.to_lowercase().ends_with("-src") {
+            return self.directive(name);
+        }
+        self.directive("default-src")
+    }
+
+    /// Directive names in order
+    pub fn directives(&self) -> Vec<&str> {
+        self.directives.iter().map(|d| d.name.as_str()).collect()
+    }
+
+    /// Whether the policy comes from `Content-Security-Policy-Report-Only` and is only reported, not enforced
+    pub fn is_report_only(&self) -> bool {
+        self.report_only
+    }
+}
+
+impl std::fmt::Display for Csp {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let directives = self.directives.iter().map(ToString::to_string).collect::<Vec<_>>();
+        write!(f, "{}", directives.join("; "))
+    }
+}
+
+impl CspDirective {
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Sources in order e.g. `["'self'", "https://cdn.example"]`
+    pub fn sources(&self) -> Vec<&str> {
+        self.sources.iter().map(String::as_str).collect()
+    }
+
+    /// Whether the source is listed, ignoring case e.g. `'self'`, `'nonce-abc'` or `https://cdn.example`
+    pub fn contains(&self, source: &str) -> bool {
+        self.sources.iter().any(|s| s.eq_ignore_ascii_case(source))
+    }
+
+    /// Whether the directive has no source, either because it is absent or valueless like `upgrade-insecure-requests`
+    pub fn is_empty(&self) -> bool {
+        self.sources.is_empty()
+    }
+}
+
+impl std::fmt::Display for CspDirective {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.sources.as_slice() {
+            [] => write!(f, "{}", self.name),
+            sources => write!(f, "{} {}", self.name, sources.join(" ")),
+        }
+    }
+}
@@ -1,4 +1,9 @@
-use crate::header::{key::HeaderKey, value::HeaderValue, values::HeaderValues};
+use std::time::Duration;
+
+use crate::{
+    body::{hexdump::HexdumpDiff, snippet},
+    header::{key::HeaderKey, value::HeaderValue, values::HeaderValues},
+};
 
 pub type AsserhttpResult<T> = Result<T, AsserhttpError>;
 
@@ -7,6 +12,10 @@ pub type AsserhttpResult<T> = Result<T, AsserhttpError>;
 pub enum AsserhttpError {
     #[error("expected status to be '{expected}' but was '{actual}'")]
     StatusMismatch { actual: u16, expected: u16 },
+    #[error("expected status to be in [{lower};{upper}[ but was '{actual}'")]
+    StatusRangeMismatch { lower: u16, upper: u16, actual: u16 },
+    #[error("expected status to be one of {expected:?} but was '{actual}'")]
+    StatusNotOneOf { expected: Vec<u16>, actual: u16 },
     #[error("expected header '{key}' to be equal to '{expected}' but was '{actual}'")]
     HeaderValueMismatch {
         key: HeaderKey,
@@ -17,6 +26,20 @@ pub enum AsserhttpError {
     HeaderAbsent { key: HeaderKey },
     #[error("expected no header named '{key}' but one found")]
     HeaderPresent { key: HeaderKey },
+    #[error("expected a header matching '{regex}' but none found in {keys:?}")]
+    HeaderAbsentMatching { regex: String, keys: Vec<String> },
+    #[error("expected no header matching '{regex}' but found {keys:?}")]
+    HeaderPresentMatching { regex: String, keys: Vec<String> },
+    #[error("expected no other headers but found {unexpected:?}")]
+    UnexpectedHeaders { unexpected: Vec<String> },
+    #[error("expected '{expected}' headers but found '{actual}' {keys:?}")]
+    HeaderCountMismatch { expected: usize, actual: usize, keys: Vec<String> },
+    #[error("expected header '{key}' to have '{expected}' values but had '{actual_values}'")]
+    HeaderValuesCountMismatch {
+        key: HeaderKey,
+        expected: usize,
+        actual_values: HeaderValues,
+    },
     #[error("expected header '{key}' to be single valued. Had '{values_count}' values '{actual_values}'. Use 'expect_headers' instead")]
     MultivaluedHeader {
         key: HeaderKey,
@@ -31,14 +54,140 @@ pub enum AsserhttpError {
         actual_values: HeaderValues,
         expected: HeaderValues,
     },
+    #[error("expected header '{key}' to match regex '{regex}' but was '{actual}'")]
+    HeaderRegexMismatch {
+        key: HeaderKey,
+        actual: HeaderValue,
+        regex: String,
+    },
+    #[error("'{value}' is not a valid media type")]
+    InvalidMediaType { value: String },
+    #[error("expected content type to be '{expected}' but was '{actual}'")]
+    ContentTypeMismatch { expected: String, actual: String },
+    #[error("expected cache-control to contain '{expected}' but was '{actual}'")]
+    CacheControlMismatch { expected: String, actual: String },
+    #[error("expected a cookie named '{name}' but found {actual:?}")]
+    CookieAbsent { name: String, actual: Vec<String> },
+    #[error("expected cookie '{name}' to be {expected}")]
+    CookieMismatch { name: String, expected: String },
+    #[error("expected response to comply with the security headers policy but:\n- {}", .violations.join("\n- "))]
+    SecurityHeadersViolations { violations: Vec<String> },
+    #[error("expected content-security-policy to have {expected} but was '{actual}'")]
+    CspMismatch { expected: String, actual: String },
+    #[error("expected cors to {expected} but '{key}' was '{actual}'")]
+    CorsMismatch { expected: String, key: HeaderKey, actual: String },
+    #[error("'{value}' is not a valid url: {message}")]
+    InvalidUrl { value: String, message: String },
+    #[error("expected redirect to '{expected}' but was '{actual}'")]
+    RedirectMismatch { expected: String, actual: String },
+    #[error("'{value}' is not a valid entity tag")]
+    InvalidEntityTag { value: String },
+    #[error("expected a strong entity tag but was '{actual}'")]
+    EntityTagWeak { actual: String },
+    #[error("expected entity tag to match '{expected}' but was '{actual}'")]
+    EntityTagMismatch { expected: String, actual: String },
+    #[error("'{key}' is not repeated by a 304 response, only cache-control, content-location, etag, expires, last-modified and vary are")]
+    NotModifiedHeaderUnrepeated { key: HeaderKey },
+    #[error("'{value}' is not a valid http date")]
+    InvalidHttpDate { value: String },
+    #[error("'{value}' is neither a delay in seconds nor a valid http date")]
+    InvalidRetryAfter { value: String },
+    #[error("expected 'date' to be within {tolerance:?} of now but was '{actual}' ({drift:?} apart)")]
+    DateNotWithin {
+        tolerance: Duration,
+        drift: Duration,
+        actual: String,
+    },
+    #[error("'{value}' is not a valid link")]
+    InvalidLink { value: String },
+    #[error("expected a link with rel '{rel}' but none was present")]
+    LinkAbsent { rel: String },
+    #[error("expected no link with rel '{rel}' but found '{actual}'")]
+    LinkPresent { rel: String, actual: String },
+    #[error("expected pagination links to be consistent but:\n- {}", .violations.join("\n- "))]
+    PaginationInconsistent { violations: Vec<String> },
+    #[error("'{value}' is not a valid content-disposition")]
+    InvalidContentDisposition { value: String },
+    #[error("expected content-disposition to be {expected} but was '{actual}'")]
+    ContentDispositionMismatch { expected: String, actual: String },
+    #[error("'{value}' is not a valid content-range")]
+    InvalidContentRange { value: String },
+    #[error("expected content-range to be '{expected}' but was '{actual}'")]
+    ContentRangeMismatch { expected: String, actual: String },
+    #[error("expected range response to be consistent but:\n- {}", .violations.join("\n- "))]
+    RangeInconsistent { violations: Vec<String> },
+    #[error("'{key}' has an invalid rate limit value '{value}'")]
+    InvalidRateLimit { key: HeaderKey, value: String },
+    #[error("expected rate limit headers to advertise the {field} but none found")]
+    RateLimitAbsent { field: String },
+    #[error("'{key}' resets at the Unix timestamp {timestamp} which has already elapsed")]
+    RateLimitResetElapsed { key: HeaderKey, timestamp: u64 },
     #[error("{0}")]
     JsonBodyMismatch(String),
+    #[error("failed to deserialize json body at {path}: {message}\n{excerpt}")]
+    JsonBodyDeserialization { path: String, message: String, excerpt: String },
+    #[error("'{path}' is not a supported json path. Expected one selecting a single value e.g. '$.items[0].id'")]
+    JsonPathInvalid { path: String },
+    #[error("expected a value at json path '{path}' but none found")]
+    JsonPathAbsent { path: String },
     #[error("expected body to be '{expected}' but was '{actual}'")]
     TextBodyMismatch { actual: String, expected: String },
-    #[error("expected body to be '{expected:?}' but was '{actual:?}'")]
+    #[error("expected body to contain '{expected}' but was '{}'", snippet::head(.actual))]
+    TextBodyNotContaining { actual: String, expected: String },
+    #[error("expected body not to contain '{unexpected}' but found it in '{}'", snippet::around(.actual, .unexpected))]
+    TextBodyContaining { actual: String, unexpected: String },
+    #[error("expected body to start with '{prefix}' but was '{}'", snippet::head(.actual))]
+    TextBodyPrefixMismatch { actual: String, prefix: String },
+    #[error("expected body to end with '{suffix}' but was '{}'", snippet::tail(.actual))]
+    TextBodySuffixMismatch { actual: String, suffix: String },
+    #[error("expected body bytes to be equal but {}", HexdumpDiff::new(.actual, .expected))]
     BytesBodyMismatch { actual: Vec<u8>, expected: Vec<u8> },
     #[error("expected body to match regex '{regex}' but was '{actual}'")]
     RegexBodyMismatch { actual: String, regex: String },
+    #[error("expected problem 'status' member to be the response status '{status}' but was '{member}'")]
+    ProblemStatusMismatch { status: u16, member: u16 },
+    #[error("expected problem type to be '{expected}' but was '{actual}'")]
+    ProblemTypeMismatch { actual: String, expected: String },
+    #[error("expected problem title to be '{expected}' but was '{actual}'")]
+    ProblemTitleMismatch { actual: String, expected: String },
+    #[error("expected problem detail to match regex '{regex}' but was '{actual}'")]
+    ProblemDetailMismatch { actual: String, regex: String },
+    #[error("expected problem member '{member}' but none found")]
+    ProblemMemberAbsent { member: String },
+    #[error("expected graphql data to be equal but {0}")]
+    GraphqlDataMismatch(String),
+    #[error("expected no graphql errors but found {messages:?}")]
+    GraphqlErrorsPresent { messages: Vec<String> },
+    #[error("expected a graphql error with code '{expected}' but found codes {actual:?}")]
+    GraphqlErrorCodeAbsent { expected: String, actual: Vec<String> },
+    #[error("expected a graphql error at path '{expected}' but found paths {actual:?}")]
+    GraphqlErrorPathAbsent { expected: String, actual: Vec<String> },
+    #[error("expected a jsonrpc {expected} response but was a {actual} one")]
+    JsonRpcShapeMismatch { expected: String, actual: String },
+    #[error("expected jsonrpc member '{member}' to be '{expected}' but was '{actual}'")]
+    JsonRpcMemberMismatch { member: String, actual: String, expected: String },
+    #[error("expected jsonrpc member '{member}' but none found")]
+    JsonRpcMemberAbsent { member: String },
+    #[error("expected jsonrpc member '{member}' to be {expected} but was '{actual}'")]
+    JsonRpcMemberInvalid { member: String, expected: String, actual: String },
+    #[error("expected jsonrpc member '{prefix}result' or '{prefix}error' but none found")]
+    JsonRpcOutcomeAbsent { prefix: String },
+    #[error("expected only one of jsonrpc members '{prefix}result' and '{prefix}error' but both found")]
+    JsonRpcOutcomeConflict { prefix: String },
+    #[error("expected a jsonrpc batch entry with id '{id}' but none found")]
+    JsonRpcEntryAbsent { id: String },
+    #[error("could not read fixture '{path}': {reason}")]
+    FixtureRead { path: String, reason: String },
+    #[error("could not write fixture '{path}': {reason}")]
+    FixtureWrite { path: String, reason: String },
+    #[error("no snapshot found at '{path}', run with ASSERHTTP_BLESS=1 to create it from:\n{actual}")]
+    SnapshotAbsent { path: String, actual: String },
+    #[error("snapshot '{path}' differs, run with ASSERHTTP_BLESS=1 to update it:\n{diff}")]
+    SnapshotMismatch { path: String, diff: String },
+    #[error("cannot redact json path '{path}' from the snapshot since it selects nothing in the body")]
+    SnapshotRedactionUnmatched { path: String },
+    #[error("cannot redact json path '{path}' from the snapshot since the body is not json")]
+    SnapshotRedactionNotJson { path: String },
     #[error("expected a response body but none was present")]
     BodyAbsent,
     #[error("expected no response body but one was present")]
@@ -0,0 +1,247 @@
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::{
+    accessor::BodyAccessor,
+    error::{AsserhttpError, AsserhttpResult},
+    graphql::{GraphqlError, GraphqlResponse},
+};
+
+/// For assertions on GraphQL responses returning an error instead of panicking
+pub trait FallibleAsserhttpGraphql<T> {
+    /// Allows verifying a GraphQL response envelope in a closure
+    /// * `asserter` - closure to verify the `{data, errors}` envelope
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use ureq::OrAnyStatus;
+    /// use asserhttp::*;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     reqwest::blocking::get("http://localhost").try_expect_graphql(|r| { assert!(r.errors.is_empty()); Ok(()) }).unwrap();
+    ///     reqwest::get("http://localhost").await.try_expect_graphql(|r| { assert!(r.errors.is_empty()); Ok(()) }).unwrap();
+    ///
+    ///     isahc::get("http://localhost").try_expect_graphql(|r| { assert!(r.errors.is_empty()); Ok(()) }).unwrap();
+    ///     isahc::get_async("http://localhost").await.try_expect_graphql(|r| { assert!(r.errors.is_empty()); Ok(()) }).unwrap();
+    ///
+    ///     surf::get("http://localhost").await.try_expect_graphql(|r| { assert!(r.errors.is_empty()); Ok(()) }).unwrap();
+    ///
+    ///     ureq::get("http://localhost").call().or_any_status().try_expect_graphql(|r| { assert!(r.errors.is_empty()); Ok(()) }).unwrap();
+    ///
+    ///     hyper::Client::new().get("http://localhost".parse().unwrap()).await.try_expect_graphql(|r| { assert!(r.errors.is_empty()); Ok(()) }).unwrap();
+    ///
+    ///     awc::Client::default().get("http://localhost").send().await.try_expect_graphql(|r| { assert!(r.errors.is_empty()); Ok(()) }).unwrap();
+    /// }
+    /// ```
+    /// # Error
+    /// * [AsserhttpError::BodyAbsent] when the response body is empty
+    fn try_expect_graphql<F>(&mut self, asserter: F) -> AsserhttpResult<&mut T>
+    where
+        F: FnOnce(GraphqlResponse) -> AsserhttpResult<()>;
+
+    /// Expects the GraphQL response `data` member to be equal
+    /// * `data` - expected `data` member
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use ureq::OrAnyStatus;
+    /// use asserhttp::*;
+    /// use serde_json::json;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     reqwest::blocking::get("http://localhost").try_expect_graphql_data(json!({"user": {"name": "jdoe"}})).unwrap();
+    ///     reqwest::get("http://localhost").await.try_expect_graphql_data(json!({"user": {"name": "jdoe"}})).unwrap();
+    ///
+    ///     isahc::get("http://localhost").try_expect_graphql_data(json!({"user": {"name": "jdoe"}})).unwrap();
+    ///     isahc::get_async("http://localhost").await.try_expect_graphql_data(json!({"user": {"name": "jdoe"}})).unwrap();
+    ///
+    ///     surf::get("http://localhost").await.try_expect_graphql_data(json!({"user": {"name": "jdoe"}})).unwrap();
+    ///
+    ///     ureq::get("http://localhost").call().or_any_status().try_expect_graphql_data(json!({"user": {"name": "jdoe"}})).unwrap();
+    ///
+    ///     hyper::Client::new().get("http://localhost".parse().unwrap()).await.try_expect_graphql_data(json!({"user": {"name": "jdoe"}})).unwrap();
+    ///
+    ///     awc::Client::default().get("http://localhost").send().await.try_expect_graphql_data(json!({"user": {"name": "jdoe"}})).unwrap();
+    /// }
+    /// ```
+    /// # Error
+    /// * [AsserhttpError::BodyAbsent] when the response body is empty
+    /// * [AsserhttpError::GraphqlDataMismatch] when the actual `data` member is different from the expected one
+    fn try_expect_graphql_data<B>(&mut self, data: B) -> AsserhttpResult<&mut T>
+    where
+        B: Serialize,
+    {
+        self.try_expect_graphql(|response| {
+            let actual = response.data.unwrap_or(Value::Null);
+            let expected = serde_json::to_value(&data)?;
+            let cfg = assert_json_diff::Config::new(assert_json_diff::CompareMode::Strict);
+            assert_json_diff::assert_json_matches_no_panic(&actual, &expected, cfg).map_err(AsserhttpError::GraphqlDataMismatch)
+        })
+    }
+
+    /// Expects the GraphQL response to have no `errors` member or an empty one
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use ureq::OrAnyStatus;
+    /// use asserhttp::*;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     reqwest::blocking::get("http://localhost").try_expect_graphql_no_errors().unwrap();
+    ///     reqwest::get("http://localhost").await.try_expect_graphql_no_errors().unwrap();
+    ///
+    ///     isahc::get("http://localhost").try_expect_graphql_no_errors().unwrap();
+    ///     isahc::get_async("http://localhost").await.try_expect_graphql_no_errors().unwrap();
+    ///
+    ///     surf::get("http://localhost").await.try_expect_graphql_no_errors().unwrap();
+    ///
+    ///     ureq::get("http://localhost").call().or_any_status().try_expect_graphql_no_errors().unwrap();
+    ///
+    ///     hyper::Client::new().get("http://localhost".parse().unwrap()).await.try_expect_graphql_no_errors().unwrap();
+    ///
+    ///     awc::Client::default().get("http://localhost").send().await.try_expect_graphql_no_errors().unwrap();
+    /// }
+    /// ```
+    /// # Error
+    /// * [AsserhttpError::BodyAbsent] when the response body is empty
+    /// * [AsserhttpError::GraphqlErrorsPresent] when the response contains errors
+    fn try_expect_graphql_no_errors(&mut self) -> AsserhttpResult<&mut T> {
+        self.try_expect_graphql(|response| match response.errors.as_slice() {
+            [] => Ok(()),
+            errors => Err(AsserhttpError::GraphqlErrorsPresent {
+                messages: errors.iter().map(|e| e.message.to_string()).collect(),
+            }),
+        })
+    }
+
+    /// Expects one of the GraphQL errors to have the given code in its `extensions`
+    /// * `code` - expected `extensions.code` of an error
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use ureq::OrAnyStatus;
+    /// use asserhttp::*;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     reqwest::blocking::get("http://localhost").try_expect_graphql_error_code("FORBIDDEN").unwrap();
+    ///     reqwest::get("http://localhost").await.try_expect_graphql_error_code("FORBIDDEN").unwrap();
+    ///
+    ///     isahc::get("http://localhost").try_expect_graphql_error_code("FORBIDDEN").unwrap();
+    ///     isahc::get_async("http://localhost").await.try_expect_graphql_error_code("FORBIDDEN").unwrap();
+    ///
+    ///     surf::get("http://localhost").await.try_expect_graphql_error_code("FORBIDDEN").unwrap();
+    ///
+    ///     ureq::get("http://localhost").call().or_any_status().try_expect_graphql_error_code("FORBIDDEN").unwrap();
+    ///
+    ///     hyper::Client::new().get("http://localhost".parse().unwrap()).await.try_expect_graphql_error_code("FORBIDDEN").unwrap();
+    ///
+    ///     awc::Client::default().get("http://localhost").send().await.try_expect_graphql_error_code("FORBIDDEN").unwrap();
+    /// }
+    /// ```
+    /// # Error
+    /// * [AsserhttpError::BodyAbsent] when the response body is empty
+    /// * [AsserhttpError::GraphqlErrorCodeAbsent] when no error has the expected code
+    fn try_expect_graphql_error_code<C>(&mut self, code: C) -> AsserhttpResult<&mut T>
+    where
+        C: Into<String>,
+    {
+        self.try_expect_graphql(|response| {
+            let expected = code.into();
+            if response.errors.iter().any(|e| e.code() == Some(expected.as_str())) {
+                return Ok(());
+            }
+            Err(AsserhttpError::GraphqlErrorCodeAbsent {
+                expected,
+                actual: response
+                    .errors
+                    .iter()
+                    .filter_map(GraphqlError::code)
+                    .map(str::to_string)
+                    .collect(),
+            })
+        })
+    }
+
+    /// Expects one of the GraphQL errors to be located at the given path
+    /// * `path` - expected `path` of an error. List indices are supplied as strings e.g. `["users", "0", "email"]`
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use ureq::OrAnyStatus;
+    /// use asserhttp::*;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     reqwest::blocking::get("http://localhost").try_expect_graphql_error_path(["user", "email"]).unwrap();
+    ///     reqwest::get("http://localhost").await.try_expect_graphql_error_path(["user", "email"]).unwrap();
+    ///
+    ///     isahc::get("http://localhost").try_expect_graphql_error_path(["user", "email"]).unwrap();
+    ///     isahc::get_async("http://localhost").await.try_expect_graphql_error_path(["user", "email"]).unwrap();
+    ///
+    ///     surf::get("http://localhost").await.try_expect_graphql_error_path(["user", "email"]).unwrap();
+    ///
+    ///     ureq::get("http://localhost").call().or_any_status().try_expect_graphql_error_path(["user", "email"]).unwrap();
+    ///
+    ///     hyper::Client::new().get("http://localhost".parse().unwrap()).await.try_expect_graphql_error_path(["user", "email"]).unwrap();
+    ///
+    ///     awc::Client::default().get("http://localhost").send().await.try_expect_graphql_error_path(["user", "email"]).unwrap();
+    /// }
+    /// ```
+    /// # Error
+    /// * [AsserhttpError::BodyAbsent] when the response body is empty
+    /// * [AsserhttpError::GraphqlErrorPathAbsent] when no error is located at the expected path
+    fn try_expect_graphql_error_path<P, S>(&mut self, path: P) -> AsserhttpResult<&mut T>
+    where
+        P: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        self.try_expect_graphql(|response| {
+            let expected = path.into_iter().map(|s| s.as_ref().to_string()).collect::<Vec<_>>();
+            if response.errors.iter().any(|e| e.path_segments() == expected) {
+                return Ok(());
+            }
+            Err(AsserhttpError::GraphqlErrorPathAbsent {
+                expected: expected.join("."),
+                actual: response
+                    .errors
+                    .iter()
+                    .map(GraphqlError::path_segments)
+                    .filter(|p| !p.is_empty())
+                    .map(|p| p.join("."))
+                    .collect(),
+            })
+        })
+    }
+}
+
+impl<T> FallibleAsserhttpGraphql<T> for T
+where
+    T: BodyAccessor,
+{
+    fn try_expect_graphql<F>(&mut self, asserter: F) -> AsserhttpResult<&mut T>
+    where
+        F: FnOnce(GraphqlResponse) -> AsserhttpResult<()>,
+    {
+        asserter(self.get_json()?)?;
+        Ok(self)
+    }
+}
+
+impl<T, E> FallibleAsserhttpGraphql<T> for Result<T, E>
+where
+    T: BodyAccessor,
+    E: std::fmt::Debug,
+{
+    fn try_expect_graphql<F>(&mut self, asserter: F) -> AsserhttpResult<&mut T>
+    where
+        F: FnOnce(GraphqlResponse) -> AsserhttpResult<()>,
+    {
+        self.as_mut()
+            .map_err(|e| AsserhttpError::HttpError(format!("{e:?}")))?
+            .try_expect_graphql(asserter)
+    }
+}
@@ -0,0 +1,249 @@
+use serde::Serialize;
+
+use crate::{
+    accessor::BodyAccessor,
+    graphql::{fallible::FallibleAsserhttpGraphql, GraphqlResponse},
+};
+
+/// For assertions on GraphQL responses
+pub trait AsserhttpGraphql<T> {
+    /// Allows verifying a GraphQL response envelope in a closure
+    /// * `asserter` - closure to verify the `{data, errors}` envelope
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use ureq::OrAnyStatus;
+    /// use asserhttp::*;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     reqwest::blocking::get("http://localhost").expect_graphql(|r| assert!(r.errors.is_empty()));
+    ///     reqwest::get("http://localhost").await.expect_graphql(|r| assert!(r.errors.is_empty()));
+    ///
+    ///     isahc::get("http://localhost").expect_graphql(|r| assert!(r.errors.is_empty()));
+    ///     isahc::get_async("http://localhost").await.expect_graphql(|r| assert!(r.errors.is_empty()));
+    ///
+    ///     surf::get("http://localhost").await.expect_graphql(|r| assert!(r.errors.is_empty()));
+    ///
+    ///     ureq::get("http://localhost").call().or_any_status().expect_graphql(|r| assert!(r.errors.is_empty()));
+    ///
+    ///     hyper::Client::new().get("http://localhost".parse().unwrap()).await.expect_graphql(|r| assert!(r.errors.is_empty()));
+    ///
+    ///     awc::Client::default().get("http://localhost").send().await.expect_graphql(|r| assert!(r.errors.is_empty()));
+    /// }
+    /// ```
+    fn expect_graphql<F>(&mut self, asserter: F) -> &mut T
+    where
+        F: FnOnce(GraphqlResponse);
+
+    /// Expects the GraphQL response `data` member to be equal
+    /// * `data` - expected `data` member
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use ureq::OrAnyStatus;
+    /// use asserhttp::*;
+    /// use serde_json::json;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     reqwest::blocking::get("http://localhost").expect_graphql_data(json!({"user": {"name": "jdoe"}}));
+    ///     reqwest::get("http://localhost").await.expect_graphql_data(json!({"user": {"name": "jdoe"}}));
+    ///
+    ///     isahc::get("http://localhost").expect_graphql_data(json!({"user": {"name": "jdoe"}}));
+    ///     isahc::get_async("http://localhost").await.expect_graphql_data(json!({"user": {"name": "jdoe"}}));
+    ///
+    ///     surf::get("http://localhost").await.expect_graphql_data(json!({"user": {"name": "jdoe"}}));
+    ///
+    ///     ureq::get("http://localhost").call().or_any_status().expect_graphql_data(json!({"user": {"name": "jdoe"}}));
+    ///
+    ///     hyper::Client::new().get("http://localhost".parse().unwrap()).await.expect_graphql_data(json!({"user": {"name": "jdoe"}}));
+    ///
+    ///     awc::Client::default().get("http://localhost").send().await.expect_graphql_data(json!({"user": {"name": "jdoe"}}));
+    /// }
+    /// ```
+    fn expect_graphql_data<B>(&mut self, data: B) -> &mut T
+    where
+        B: Serialize;
+
+    /// Expects the GraphQL response to have no `errors` member or an empty one
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use ureq::OrAnyStatus;
+    /// use asserhttp::*;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     reqwest::blocking::get("http://localhost").expect_graphql_no_errors();
+    ///     reqwest::get("http://localhost").await.expect_graphql_no_errors();
+    ///
+    ///     isahc::get("http://localhost").expect_graphql_no_errors();
+    ///     isahc::get_async("http://localhost").await.expect_graphql_no_errors();
+    ///
+    ///     surf::get("http://localhost").await.expect_graphql_no_errors();
+    ///
+    ///     ureq::get("http://localhost").call().or_any_status().expect_graphql_no_errors();
+    ///
+    ///     hyper::Client::new().get("http://localhost".parse().unwrap()).await.expect_graphql_no_errors();
+    ///
+    ///     awc::Client::default().get("http://localhost").send().await.expect_graphql_no_errors();
+    /// }
+    /// ```
+    fn expect_graphql_no_errors(&mut self) -> &mut T;
+
+    /// Expects one of the GraphQL errors to have the given code in its `extensions`
+    /// * `code` - expected `extensions.code` of an error
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use ureq::OrAnyStatus;
+    /// use asserhttp::*;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     reqwest::blocking::get("http://localhost").expect_graphql_error_code("FORBIDDEN");
+    ///     reqwest::get("http://localhost").await.expect_graphql_error_code("FORBIDDEN");
+    ///
+    ///     isahc::get("http://localhost").expect_graphql_error_code("FORBIDDEN");
+    ///     isahc::get_async("http://localhost").await.expect_graphql_error_code("FORBIDDEN");
+    ///
+    ///     surf::get("http://localhost").await.expect_graphql_error_code("FORBIDDEN");
+    ///
+    ///     ureq::get("http://localhost").call().or_any_status().expect_graphql_error_code("FORBIDDEN");
+    ///
+    ///     hyper::Client::new().get("http://localhost".parse().unwrap()).await.expect_graphql_error_code("FORBIDDEN");
+    ///
+    ///     awc::Client::default().get("http://localhost").send().await.expect_graphql_error_code("FORBIDDEN");
+    /// }
+    /// ```
+    fn expect_graphql_error_code<C>(&mut self, code: C) -> &mut T
+    where
+        C: Into<String>;
+
+    /// Expects one of the GraphQL errors to be located at the given path
+    /// * `path` - expected `path` of an error. List indices are supplied as strings e.g. `["users", "0", "email"]`
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use ureq::OrAnyStatus;
+    /// use asserhttp::*;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     reqwest::blocking::get("http://localhost").expect_graphql_error_path(["user", "email"]);
+    ///     reqwest::get("http://localhost").await.expect_graphql_error_path(["user", "email"]);
+    ///
+    ///     isahc::get("http://localhost").expect_graphql_error_path(["user", "email"]);
+    ///     isahc::get_async("http://localhost").await.expect_graphql_error_path(["user", "email"]);
+    ///
+    ///     surf::get("http://localhost").await.expect_graphql_error_path(["user", "email"]);
+    ///
+    ///     ureq::get("http://localhost").call().or_any_status().expect_graphql_error_path(["user", "email"]);
+    ///
+    ///     hyper::Client::new().get("http://localhost".parse().unwrap()).await.expect_graphql_error_path(["user", "email"]);
+    ///
+    ///     awc::Client::default().get("http://localhost").send().await.expect_graphql_error_path(["user", "email"]);
+    /// }
+    /// ```
+    fn expect_graphql_error_path<P, S>(&mut self, path: P) -> &mut T
+    where
+        P: IntoIterator<Item = S>,
+        S: AsRef<str>;
+}
+
+impl<T> AsserhttpGraphql<T> for T
+where
+    T: BodyAccessor,
+{
+    fn expect_graphql<F>(&mut self, asserter: F) -> &mut T
+    where
+        F: FnOnce(GraphqlResponse),
+    {
+        #[allow(clippy::blocks_in_conditions)]
+        match self.try_expect_graphql(|v| {
+            asserter(v);
+            Ok(())
+        }) {
+            Err(e) => panic!("{e}"),
+            Ok(r) => r,
+        }
+    }
+
+    fn expect_graphql_data<B>(&mut self, data: B) -> &mut T
+    where
+        B: Serialize,
+    {
+        match self.try_expect_graphql_data(data) {
+            Err(e) => panic!("{e}"),
+            Ok(r) => r,
+        }
+    }
+
+    fn expect_graphql_no_errors(&mut self) -> &mut T {
+        match self.try_expect_graphql_no_errors() {
+            Err(e) => panic!("{e}"),
+            Ok(r) => r,
+        }
+    }
+
+    fn expect_graphql_error_code<C>(&mut self, code: C) -> &mut T
+    where
+        C: Into<String>,
+    {
+        match self.try_expect_graphql_error_code(code) {
+            Err(e) => panic!("{e}"),
+            Ok(r) => r,
+        }
+    }
+
+    fn expect_graphql_error_path<P, S>(&mut self, path: P) -> &mut T
+    where
+        P: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        match self.try_expect_graphql_error_path(path) {
+            Err(e) => panic!("{e}"),
+            Ok(r) => r,
+        }
+    }
+}
+
+impl<T, E> AsserhttpGraphql<T> for Result<T, E>
+where
+    T: BodyAccessor,
+    E: std::fmt::Debug,
+{
+    fn expect_graphql<F>(&mut self, asserter: F) -> &mut T
+    where
+        F: FnOnce(GraphqlResponse),
+    {
+        self.as_mut().unwrap().expect_graphql(asserter)
+    }
+
+    fn expect_graphql_data<B>(&mut self, data: B) -> &mut T
+    where
+        B: Serialize,
+    {
+        self.as_mut().unwrap().expect_graphql_data(data)
+    }
+
+    fn expect_graphql_no_errors(&mut self) -> &mut T {
+        self.as_mut().unwrap().expect_graphql_no_errors()
+    }
+
+    fn expect_graphql_error_code<C>(&mut self, code: C) -> &mut T
+    where
+        C: Into<String>,
+    {
+        self.as_mut().unwrap().expect_graphql_error_code(code)
+    }
+
+    fn expect_graphql_error_path<P, S>(&mut self, path: P) -> &mut T
+    where
+        P: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        self.as_mut().unwrap().expect_graphql_error_path(path)
+    }
+}
@@ -0,0 +1,54 @@
+use serde_json::{Map, Value};
+
+pub(crate) mod fallible;
+pub(crate) mod infallible;
+
+/// Standard [GraphQL response](https://spec.graphql.org/October2021/#sec-Response-Format) envelope
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct GraphqlResponse {
+    /// Result of the execution, `None` when absent or `null`
+    #[serde(default)]
+    pub data: Option<Value>,
+    /// Errors raised during the execution, empty when absent
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub errors: Vec<GraphqlError>,
+    #[serde(default, skip_serializing_if = "Map::is_empty")]
+    pub extensions: Map<String, Value>,
+}
+
+/// A single entry of the `errors` member
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct GraphqlError {
+    pub message: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub locations: Vec<GraphqlLocation>,
+    /// Path of the response field which experienced the error, made of field names and list indices
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub path: Vec<Value>,
+    #[serde(default, skip_serializing_if = "Map::is_empty")]
+    pub extensions: Map<String, Value>,
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct GraphqlLocation {
+    pub line: u32,
+    pub column: u32,
+}
+
+impl GraphqlError {
+    /// Error code conventionally found in `extensions.code`
+    pub fn code(&self) -> Option<&str> {
+        self.extensions.get("code").and_then(Value::as_str)
+    }
+
+    /// Path segments as strings, list indices being rendered as numbers e.g. `["users", "0", "email"]`
+    pub fn path_segments(&self) -> Vec<String> {
+        self.path
+            .iter()
+            .map(|segment| match segment {
+                Value::String(s) => s.to_string(),
+                other => other.to_string(),
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,43 @@
+use std::time::{Duration, SystemTime};
+
+use crate::error::{AsserhttpError, AsserhttpResult};
+
+/// Parses an [HTTP-date](https://www.rfc-editor.org/rfc/rfc9110#name-date-time-formats), either in its preferred
+/// `Sun, 06 Nov 1994 08:49:37 GMT` form or in one of the obsolete rfc850 and asctime forms
+pub(crate) fn parse(value: &str) -> AsserhttpResult<SystemTime> {
+    httpdate::parse_http_date(value.trim()).map_err(|_| AsserhttpError::InvalidHttpDate { value: value.to_string() })
+}
+
+/// Absolute difference between two instants, whichever comes first
+pub(crate) fn drift(a: SystemTime, b: SystemTime) -> Duration {
+    a.duration_since(b).or_else(|_| b.duration_since(a)).unwrap_or_default()
+}
+
+/// A parsed [Retry-After](https://www.rfc-editor.org/rfc/rfc9110#name-retry-after) header
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum RetryAfter {
+    /// Number of seconds to wait e.g. `Retry-After: 120`
+    Delay(Duration),
+    /// Date after which to retry e.g. `Retry-After: Wed, 21 Oct 2015 07:28:00 GMT`
+    Date(SystemTime),
+}
+
+impl RetryAfter {
+    pub(crate) fn parse(value: &str) -> AsserhttpResult<Self> {
+        let trimmed = value.trim();
+        if let Ok(seconds) = trimmed.parse::<u64>() {
+            return Ok(Self::Delay(Duration::from_secs(seconds)));
+        }
+        httpdate::parse_http_date(trimmed)
+            .map(Self::Date)
+            .map_err(|_| AsserhttpError::InvalidRetryAfter { value: value.to_string() })
+    }
+
+    /// Time left to wait from now, zero when the date is already past
+    pub fn delay(&self) -> Duration {
+        match self {
+            Self::Delay(delay) => *delay,
+            Self::Date(date) => date.duration_since(SystemTime::now()).unwrap_or_default(),
+        }
+    }
+}
@@ -1,12 +1,21 @@
+use std::{
+    collections::BTreeMap,
+    time::{Duration, SystemTime},
+};
+
 use crate::{
     accessor::HeaderAccessor,
     error::{AsserhttpError, AsserhttpResult},
     header::{
+        date::{self, RetryAfter},
         infallible::{HeaderValueAsserter, HeaderValuesAsserter},
         key::HeaderKey,
+        media_type::MediaType,
         value::HeaderValue,
         values::HeaderValues,
+        IMPLICIT_HEADERS,
     },
+    headers,
 };
 
 /// For assertions on http response headers returning an error instead of panicking
@@ -198,6 +207,533 @@ pub trait FallibleAsserhttpHeader<T> {
     /// # Error
     /// * [AsserhttpError::HeaderPresent] when expected header is found by key
     fn try_expect_header_absent(&mut self, key: impl Into<HeaderKey>) -> AsserhttpResult<&mut T>;
+
+    /// Expects response header to match the provided regex
+    /// * `key` - expected header key
+    /// * `regex` - must match the header value
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use ureq::OrAnyStatus;
+    /// use asserhttp::*;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     reqwest::blocking::get("http://localhost").try_expect_header_matches("location", "^/users/[0-9a-f-]{36}$").unwrap();
+    ///     reqwest::get("http://localhost").await.try_expect_header_matches("location", "^/users/[0-9a-f-]{36}$").unwrap();
+    ///
+    ///     isahc::get("http://localhost").try_expect_header_matches("location", "^/users/[0-9a-f-]{36}$").unwrap();
+    ///     isahc::get_async("http://localhost").await.try_expect_header_matches("location", "^/users/[0-9a-f-]{36}$").unwrap();
+    ///
+    ///     surf::get("http://localhost").await.try_expect_header_matches("location", "^/users/[0-9a-f-]{36}$").unwrap();
+    ///
+    ///     ureq::get("http://localhost").call().or_any_status().try_expect_header_matches("location", "^/users/[0-9a-f-]{36}$").unwrap();
+    ///
+    ///     hyper::Client::new().get("http://localhost".parse().unwrap()).await.try_expect_header_matches("location", "^/users/[0-9a-f-]{36}$").unwrap();
+    ///
+    ///     awc::Client::default().get("http://localhost").send().await.try_expect_header_matches("location", "^/users/[0-9a-f-]{36}$").unwrap();
+    /// }
+    /// ```
+    /// # Error
+    /// * [AsserhttpError::HeaderAbsent] when expected header is not found by key
+    /// * [AsserhttpError::MultivaluedHeader] when a multivalued header is found
+    /// * [AsserhttpError::HeaderRegexMismatch] when the header value does not match the regex
+    /// * [AsserhttpError::RegexError] when the supplied Regex is invalid
+    fn try_expect_header_matches<R>(&mut self, key: impl Into<HeaderKey>, regex: R) -> AsserhttpResult<&mut T>
+    where
+        R: TryInto<regex::Regex, Error = regex::Error>;
+
+    /// Expects every value of a multivalued response header to match the provided regex
+    /// * `key` - expected header key
+    /// * `regex` - must match each header value
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use ureq::OrAnyStatus;
+    /// use asserhttp::*;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     reqwest::blocking::get("http://localhost").try_expect_headers_match("vary", "^(accept|origin)").unwrap();
+    ///     reqwest::get("http://localhost").await.try_expect_headers_match("vary", "^(accept|origin)").unwrap();
+    ///
+    ///     isahc::get("http://localhost").try_expect_headers_match("vary", "^(accept|origin)").unwrap();
+    ///     isahc::get_async("http://localhost").await.try_expect_headers_match("vary", "^(accept|origin)").unwrap();
+    ///
+    ///     surf::get("http://localhost").await.try_expect_headers_match("vary", "^(accept|origin)").unwrap();
+    ///
+    ///     ureq::get("http://localhost").call().or_any_status().try_expect_headers_match("vary", "^(accept|origin)").unwrap();
+    ///
+    ///     hyper::Client::new().get("http://localhost".parse().unwrap()).await.try_expect_headers_match("vary", "^(accept|origin)").unwrap();
+    ///
+    ///     awc::Client::default().get("http://localhost").send().await.try_expect_headers_match("vary", "^(accept|origin)").unwrap();
+    /// }
+    /// ```
+    /// # Error
+    /// * [AsserhttpError::HeaderAbsent] when expected header is not found by key
+    /// * [AsserhttpError::HeaderRegexMismatch] when one of the header values does not match the regex
+    /// * [AsserhttpError::RegexError] when the supplied Regex is invalid
+    fn try_expect_headers_match<R>(&mut self, key: impl Into<HeaderKey>, regex: R) -> AsserhttpResult<&mut T>
+    where
+        R: TryInto<regex::Regex, Error = regex::Error>;
+
+    /// Expects at least one response header key to match the provided regex. Keys are matched lowercase
+    /// * `regex` - must match a header key
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use ureq::OrAnyStatus;
+    /// use asserhttp::*;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     reqwest::blocking::get("http://localhost").try_expect_header_present_matching("^x-ratelimit-").unwrap();
+    ///     reqwest::get("http://localhost").await.try_expect_header_present_matching("^x-ratelimit-").unwrap();
+    ///
+    ///     isahc::get("http://localhost").try_expect_header_present_matching("^x-ratelimit-").unwrap();
+    ///     isahc::get_async("http://localhost").await.try_expect_header_present_matching("^x-ratelimit-").unwrap();
+    ///
+    ///     surf::get("http://localhost").await.try_expect_header_present_matching("^x-ratelimit-").unwrap();
+    ///
+    ///     ureq::get("http://localhost").call().or_any_status().try_expect_header_present_matching("^x-ratelimit-").unwrap();
+    ///
+    ///     hyper::Client::new().get("http://localhost".parse().unwrap()).await.try_expect_header_present_matching("^x-ratelimit-").unwrap();
+    ///
+    ///     awc::Client::default().get("http://localhost").send().await.try_expect_header_present_matching("^x-ratelimit-").unwrap();
+    /// }
+    /// ```
+    /// # Error
+    /// * [AsserhttpError::HeaderAbsentMatching] when no header key matches the regex
+    /// * [AsserhttpError::RegexError] when the supplied Regex is invalid
+    fn try_expect_header_present_matching<R>(&mut self, regex: R) -> AsserhttpResult<&mut T>
+    where
+        R: TryInto<regex::Regex, Error = regex::Error>;
+
+    /// Expects no response header key to match the provided regex. Keys are matched lowercase
+    /// * `regex` - must not match any header key
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use ureq::OrAnyStatus;
+    /// use asserhttp::*;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     reqwest::blocking::get("http://localhost").try_expect_header_absent_matching("^x-internal-").unwrap();
+    ///     reqwest::get("http://localhost").await.try_expect_header_absent_matching("^x-internal-").unwrap();
+    ///
+    ///     isahc::get("http://localhost").try_expect_header_absent_matching("^x-internal-").unwrap();
+    ///     isahc::get_async("http://localhost").await.try_expect_header_absent_matching("^x-internal-").unwrap();
+    ///
+    ///     surf::get("http://localhost").await.try_expect_header_absent_matching("^x-internal-").unwrap();
+    ///
+    ///     ureq::get("http://localhost").call().or_any_status().try_expect_header_absent_matching("^x-internal-").unwrap();
+    ///
+    ///     hyper::Client::new().get("http://localhost".parse().unwrap()).await.try_expect_header_absent_matching("^x-internal-").unwrap();
+    ///
+    ///     awc::Client::default().get("http://localhost").send().await.try_expect_header_absent_matching("^x-internal-").unwrap();
+    /// }
+    /// ```
+    /// # Error
+    /// * [AsserhttpError::HeaderPresentMatching] when some header keys match the regex
+    /// * [AsserhttpError::RegexError] when the supplied Regex is invalid
+    fn try_expect_header_absent_matching<R>(&mut self, regex: R) -> AsserhttpResult<&mut T>
+    where
+        R: TryInto<regex::Regex, Error = regex::Error>;
+
+    /// Allows verifying in a closure all the response headers whose key starts with a prefix, ignoring case.
+    /// The closure receives a map of lowercase keys to their values, empty when no header has the prefix
+    /// * `prefix` - header key prefix e.g. `x-amz-`
+    /// * `asserter` - closure to verify the matching headers
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use ureq::OrAnyStatus;
+    /// use asserhttp::*;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     reqwest::blocking::get("http://localhost").try_expect_headers_with_prefix("x-amz-", |h| { assert!(h.contains_key("x-amz-request-id")); Ok(()) }).unwrap();
+    ///     reqwest::get("http://localhost").await.try_expect_headers_with_prefix("x-amz-", |h| { assert!(h.contains_key("x-amz-request-id")); Ok(()) }).unwrap();
+    ///
+    ///     isahc::get("http://localhost").try_expect_headers_with_prefix("x-amz-", |h| { assert!(h.contains_key("x-amz-request-id")); Ok(()) }).unwrap();
+    ///     isahc::get_async("http://localhost").await.try_expect_headers_with_prefix("x-amz-", |h| { assert!(h.contains_key("x-amz-request-id")); Ok(()) }).unwrap();
+    ///
+    ///     surf::get("http://localhost").await.try_expect_headers_with_prefix("x-amz-", |h| { assert!(h.contains_key("x-amz-request-id")); Ok(()) }).unwrap();
+    ///
+    ///     ureq::get("http://localhost").call().or_any_status().try_expect_headers_with_prefix("x-amz-", |h| { assert!(h.contains_key("x-amz-request-id")); Ok(()) }).unwrap();
+    ///
+    ///     hyper::Client::new().get("http://localhost".parse().unwrap()).await.try_expect_headers_with_prefix("x-amz-", |h| { assert!(h.contains_key("x-amz-request-id")); Ok(()) }).unwrap();
+    ///
+    ///     awc::Client::default().get("http://localhost").send().await.try_expect_headers_with_prefix("x-amz-", |h| { assert!(h.contains_key("x-amz-request-id")); Ok(()) }).unwrap();
+    /// }
+    /// ```
+    fn try_expect_headers_with_prefix<F>(&mut self, prefix: impl AsRef<str>, asserter: F) -> AsserhttpResult<&mut T>
+    where
+        F: FnOnce(BTreeMap<String, Vec<String>>) -> AsserhttpResult<()>;
+
+    /// Expects response headers to be exactly the given ones.
+    /// The headers `connection`, `content-length`, `date`, `keep-alive`, `server` and `transfer-encoding` are
+    /// always tolerated since the server or the transport may set them. Any other header, including one added by a
+    /// framework such as Rocket's default `Shield` fairing, has to be expected
+    /// * `keys` - expected header keys
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use ureq::OrAnyStatus;
+    /// use asserhttp::*;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     reqwest::blocking::get("http://localhost").try_expect_only_headers(["content-type", "x-request-id"]).unwrap();
+    ///     reqwest::get("http://localhost").await.try_expect_only_headers(["content-type", "x-request-id"]).unwrap();
+    ///
+    ///     isahc::get("http://localhost").try_expect_only_headers(["content-type", "x-request-id"]).unwrap();
+    ///     isahc::get_async("http://localhost").await.try_expect_only_headers(["content-type", "x-request-id"]).unwrap();
+    ///
+    ///     surf::get("http://localhost").await.try_expect_only_headers(["content-type", "x-request-id"]).unwrap();
+    ///
+    ///     ureq::get("http://localhost").call().or_any_status().try_expect_only_headers(["content-type", "x-request-id"]).unwrap();
+    ///
+    ///     hyper::Client::new().get("http://localhost".parse().unwrap()).await.try_expect_only_headers(["content-type", "x-request-id"]).unwrap();
+    ///
+    ///     awc::Client::default().get("http://localhost").send().await.try_expect_only_headers(["content-type", "x-request-id"]).unwrap();
+    /// }
+    /// ```
+    /// # Error
+    /// * [AsserhttpError::HeaderAbsent] when one of the expected headers is not found by key
+    /// * [AsserhttpError::UnexpectedHeaders] when headers other than the expected ones are found
+    fn try_expect_only_headers<I, K>(&mut self, keys: I) -> AsserhttpResult<&mut T>
+    where
+        I: IntoIterator<Item = K>,
+        K: Into<HeaderKey>;
+
+    /// Expects the number of distinct response header keys.
+    /// The headers `connection`, `content-length`, `date`, `keep-alive`, `server` and `transfer-encoding` are
+    /// never counted since the server or the transport may set them. Any other header, including one added by a
+    /// framework such as Rocket's default `Shield` fairing, is counted
+    /// * `count` - expected number of headers
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use ureq::OrAnyStatus;
+    /// use asserhttp::*;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     reqwest::blocking::get("http://localhost").try_expect_header_count(3).unwrap();
+    ///     reqwest::get("http://localhost").await.try_expect_header_count(3).unwrap();
+    ///
+    ///     isahc::get("http://localhost").try_expect_header_count(3).unwrap();
+    ///     isahc::get_async("http://localhost").await.try_expect_header_count(3).unwrap();
+    ///
+    ///     surf::get("http://localhost").await.try_expect_header_count(3).unwrap();
+    ///
+    ///     ureq::get("http://localhost").call().or_any_status().try_expect_header_count(3).unwrap();
+    ///
+    ///     hyper::Client::new().get("http://localhost".parse().unwrap()).await.try_expect_header_count(3).unwrap();
+    ///
+    ///     awc::Client::default().get("http://localhost").send().await.try_expect_header_count(3).unwrap();
+    /// }
+    /// ```
+    /// # Error
+    /// * [AsserhttpError::HeaderCountMismatch] when the number of headers is different
+    fn try_expect_header_count(&mut self, count: usize) -> AsserhttpResult<&mut T>;
+
+    /// Expects the number of values of a response header, be they comma separated or on distinct lines
+    /// * `key` - expected header key
+    /// * `count` - expected number of values
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use ureq::OrAnyStatus;
+    /// use asserhttp::*;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     reqwest::blocking::get("http://localhost").try_expect_header_values_count("vary", 2).unwrap();
+    ///     reqwest::get("http://localhost").await.try_expect_header_values_count("vary", 2).unwrap();
+    ///
+    ///     isahc::get("http://localhost").try_expect_header_values_count("vary", 2).unwrap();
+    ///     isahc::get_async("http://localhost").await.try_expect_header_values_count("vary", 2).unwrap();
+    ///
+    ///     surf::get("http://localhost").await.try_expect_header_values_count("vary", 2).unwrap();
+    ///
+    ///     ureq::get("http://localhost").call().or_any_status().try_expect_header_values_count("vary", 2).unwrap();
+    ///
+    ///     hyper::Client::new().get("http://localhost".parse().unwrap()).await.try_expect_header_values_count("vary", 2).unwrap();
+    ///
+    ///     awc::Client::default().get("http://localhost").send().await.try_expect_header_values_count("vary", 2).unwrap();
+    /// }
+    /// ```
+    /// # Error
+    /// * [AsserhttpError::HeaderAbsent] when expected header is not found by key
+    /// * [AsserhttpError::HeaderValuesCountMismatch] when the header has a different number of values
+    fn try_expect_header_values_count(&mut self, key: impl Into<HeaderKey>, count: usize) -> AsserhttpResult<&mut T>;
+
+    /// Expects response header `Content-Type` to be the given media type. Type, subtype and suffix are compared
+    /// ignoring case and every expected parameter has to be present, other parameters being ignored. `*` matches any type
+    /// or subtype e.g. `application/*` or `*/*+json`
+    /// * `mime` - expected media type e.g. `application/json; charset=utf-8`
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use ureq::OrAnyStatus;
+    /// use asserhttp::*;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     reqwest::blocking::get("http://localhost").try_expect_content_type("application/json; charset=utf-8").unwrap();
+    ///     reqwest::get("http://localhost").await.try_expect_content_type("application/json; charset=utf-8").unwrap();
+    ///
+    ///     isahc::get("http://localhost").try_expect_content_type("application/json; charset=utf-8").unwrap();
+    ///     isahc::get_async("http://localhost").await.try_expect_content_type("application/json; charset=utf-8").unwrap();
+    ///
+    ///     surf::get("http://localhost").await.try_expect_content_type("application/json; charset=utf-8").unwrap();
+    ///
+    ///     ureq::get("http://localhost").call().or_any_status().try_expect_content_type("application/json; charset=utf-8").unwrap();
+    ///
+    ///     hyper::Client::new().get("http://localhost".parse().unwrap()).await.try_expect_content_type("application/json; charset=utf-8").unwrap();
+    ///
+    ///     awc::Client::default().get("http://localhost").send().await.try_expect_content_type("application/json; charset=utf-8").unwrap();
+    /// }
+    /// ```
+    /// # Error
+    /// * [AsserhttpError::HeaderAbsent] when the response has no `Content-Type` header
+    /// * [AsserhttpError::InvalidMediaType] when the `Content-Type` header is not a valid media type
+    /// * [AsserhttpError::ContentTypeMismatch] when the media type is not the expected one
+    fn try_expect_content_type(&mut self, mime: impl AsRef<str>) -> AsserhttpResult<&mut T>;
+
+    /// Expects response header `Content-Type` to be `application/json` or any media type with a `+json` suffix
+    /// such as `application/problem+json`, whatever its parameters
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use ureq::OrAnyStatus;
+    /// use asserhttp::*;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     reqwest::blocking::get("http://localhost").try_expect_content_type_any_json().unwrap();
+    ///     reqwest::get("http://localhost").await.try_expect_content_type_any_json().unwrap();
+    ///
+    ///     isahc::get("http://localhost").try_expect_content_type_any_json().unwrap();
+    ///     isahc::get_async("http://localhost").await.try_expect_content_type_any_json().unwrap();
+    ///
+    ///     surf::get("http://localhost").await.try_expect_content_type_any_json().unwrap();
+    ///
+    ///     ureq::get("http://localhost").call().or_any_status().try_expect_content_type_any_json().unwrap();
+    ///
+    ///     hyper::Client::new().get("http://localhost".parse().unwrap()).await.try_expect_content_type_any_json().unwrap();
+    ///
+    ///     awc::Client::default().get("http://localhost").send().await.try_expect_content_type_any_json().unwrap();
+    /// }
+    /// ```
+    /// # Error
+    /// * [AsserhttpError::HeaderAbsent] when the response has no `Content-Type` header
+    /// * [AsserhttpError::InvalidMediaType] when the `Content-Type` header is not a valid media type
+    /// * [AsserhttpError::ContentTypeMismatch] when the media type is not a json one
+    fn try_expect_content_type_any_json(&mut self) -> AsserhttpResult<&mut T>;
+
+    /// Expects response header `Content-Type` to be `application/xml`, `text/xml` or any media type with a `+xml`
+    /// suffix such as `application/atom+xml`, whatever its parameters
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use ureq::OrAnyStatus;
+    /// use asserhttp::*;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     reqwest::blocking::get("http://localhost").try_expect_content_type_any_xml().unwrap();
+    ///     reqwest::get("http://localhost").await.try_expect_content_type_any_xml().unwrap();
+    ///
+    ///     isahc::get("http://localhost").try_expect_content_type_any_xml().unwrap();
+    ///     isahc::get_async("http://localhost").await.try_expect_content_type_any_xml().unwrap();
+    ///
+    ///     surf::get("http://localhost").await.try_expect_content_type_any_xml().unwrap();
+    ///
+    ///     ureq::get("http://localhost").call().or_any_status().try_expect_content_type_any_xml().unwrap();
+    ///
+    ///     hyper::Client::new().get("http://localhost".parse().unwrap()).await.try_expect_content_type_any_xml().unwrap();
+    ///
+    ///     awc::Client::default().get("http://localhost").send().await.try_expect_content_type_any_xml().unwrap();
+    /// }
+    /// ```
+    /// # Error
+    /// * [AsserhttpError::HeaderAbsent] when the response has no `Content-Type` header
+    /// * [AsserhttpError::InvalidMediaType] when the `Content-Type` header is not a valid media type
+    /// * [AsserhttpError::ContentTypeMismatch] when the media type is not a xml one
+    fn try_expect_content_type_any_xml(&mut self) -> AsserhttpResult<&mut T>;
+
+    /// Expects response header `Content-Type` to be a `multipart/*` media type e.g. `multipart/form-data`
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use ureq::OrAnyStatus;
+    /// use asserhttp::*;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     reqwest::blocking::get("http://localhost").try_expect_content_type_multipart().unwrap();
+    ///     reqwest::get("http://localhost").await.try_expect_content_type_multipart().unwrap();
+    ///
+    ///     isahc::get("http://localhost").try_expect_content_type_multipart().unwrap();
+    ///     isahc::get_async("http://localhost").await.try_expect_content_type_multipart().unwrap();
+    ///
+    ///     surf::get("http://localhost").await.try_expect_content_type_multipart().unwrap();
+    ///
+    ///     ureq::get("http://localhost").call().or_any_status().try_expect_content_type_multipart().unwrap();
+    ///
+    ///     hyper::Client::new().get("http://localhost".parse().unwrap()).await.try_expect_content_type_multipart().unwrap();
+    ///
+    ///     awc::Client::default().get("http://localhost").send().await.try_expect_content_type_multipart().unwrap();
+    /// }
+    /// ```
+    /// # Error
+    /// * [AsserhttpError::HeaderAbsent] when the response has no `Content-Type` header
+    /// * [AsserhttpError::InvalidMediaType] when the `Content-Type` header is not a valid media type
+    /// * [AsserhttpError::ContentTypeMismatch] when the media type is not a multipart one
+    fn try_expect_content_type_multipart(&mut self) -> AsserhttpResult<&mut T>;
+
+    /// Expects response header `Content-Type` to have an explicit `charset` parameter, compared ignoring case
+    /// * `charset` - expected charset e.g. `utf-8`
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use ureq::OrAnyStatus;
+    /// use asserhttp::*;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     reqwest::blocking::get("http://localhost").try_expect_content_type_charset("utf-8").unwrap();
+    ///     reqwest::get("http://localhost").await.try_expect_content_type_charset("utf-8").unwrap();
+    ///
+    ///     isahc::get("http://localhost").try_expect_content_type_charset("utf-8").unwrap();
+    ///     isahc::get_async("http://localhost").await.try_expect_content_type_charset("utf-8").unwrap();
+    ///
+    ///     surf::get("http://localhost").await.try_expect_content_type_charset("utf-8").unwrap();
+    ///
+    ///     ureq::get("http://localhost").call().or_any_status().try_expect_content_type_charset("utf-8").unwrap();
+    ///
+    ///     hyper::Client::new().get("http://localhost".parse().unwrap()).await.try_expect_content_type_charset("utf-8").unwrap();
+    ///
+    ///     awc::Client::default().get("http://localhost").send().await.try_expect_content_type_charset("utf-8").unwrap();
+    /// }
+    /// ```
+    /// # Error
+    /// * [AsserhttpError::HeaderAbsent] when the response has no `Content-Type` header
+    /// * [AsserhttpError::InvalidMediaType] when the `Content-Type` header is not a valid media type
+    /// * [AsserhttpError::ContentTypeMismatch] when the media type has no or another charset
+    fn try_expect_content_type_charset(&mut self, charset: impl AsRef<str>) -> AsserhttpResult<&mut T>;
+
+    /// Allows verifying a response header holding an HTTP-date, like `Date`, `Expires` or `Last-Modified`, in a closure
+    /// * `key` - expected header key
+    /// * `asserter` - closure to verify the parsed date
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use ureq::OrAnyStatus;
+    /// use asserhttp::*;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     reqwest::blocking::get("http://localhost").try_expect_header_date("expires", |t| { assert!(t > std::time::SystemTime::now()); Ok(()) }).unwrap();
+    ///     reqwest::get("http://localhost").await.try_expect_header_date("expires", |t| { assert!(t > std::time::SystemTime::now()); Ok(()) }).unwrap();
+    ///
+    ///     isahc::get("http://localhost").try_expect_header_date("expires", |t| { assert!(t > std::time::SystemTime::now()); Ok(()) }).unwrap();
+    ///     isahc::get_async("http://localhost").await.try_expect_header_date("expires", |t| { assert!(t > std::time::SystemTime::now()); Ok(()) }).unwrap();
+    ///
+    ///     surf::get("http://localhost").await.try_expect_header_date("expires", |t| { assert!(t > std::time::SystemTime::now()); Ok(()) }).unwrap();
+    ///
+    ///     ureq::get("http://localhost").call().or_any_status().try_expect_header_date("expires", |t| { assert!(t > std::time::SystemTime::now()); Ok(()) }).unwrap();
+    ///
+    ///     hyper::Client::new().get("http://localhost".parse().unwrap()).await.try_expect_header_date("expires", |t| { assert!(t > std::time::SystemTime::now()); Ok(()) }).unwrap();
+    ///
+    ///     awc::Client::default().get("http://localhost").send().await.try_expect_header_date("expires", |t| { assert!(t > std::time::SystemTime::now()); Ok(()) }).unwrap();
+    /// }
+    /// ```
+    /// # Error
+    /// * [AsserhttpError::HeaderAbsent] when the header is absent
+    /// * [AsserhttpError::InvalidHttpDate] when the header is not a valid HTTP-date
+    fn try_expect_header_date<F>(&mut self, key: impl Into<HeaderKey>, asserter: F) -> AsserhttpResult<&mut T>
+    where
+        F: FnOnce(SystemTime) -> AsserhttpResult<()>;
+
+    /// Expects the `Date` header to be close to the current time, revealing a server clock drift otherwise
+    /// * `tolerance` - maximum accepted difference with now, in either direction
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use ureq::OrAnyStatus;
+    /// use asserhttp::*;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     reqwest::blocking::get("http://localhost").try_expect_date_within(std::time::Duration::from_secs(60)).unwrap();
+    ///     reqwest::get("http://localhost").await.try_expect_date_within(std::time::Duration::from_secs(60)).unwrap();
+    ///
+    ///     isahc::get("http://localhost").try_expect_date_within(std::time::Duration::from_secs(60)).unwrap();
+    ///     isahc::get_async("http://localhost").await.try_expect_date_within(std::time::Duration::from_secs(60)).unwrap();
+    ///
+    ///     surf::get("http://localhost").await.try_expect_date_within(std::time::Duration::from_secs(60)).unwrap();
+    ///
+    ///     ureq::get("http://localhost").call().or_any_status().try_expect_date_within(std::time::Duration::from_secs(60)).unwrap();
+    ///
+    ///     hyper::Client::new().get("http://localhost".parse().unwrap()).await.try_expect_date_within(std::time::Duration::from_secs(60)).unwrap();
+    ///
+    ///     awc::Client::default().get("http://localhost").send().await.try_expect_date_within(std::time::Duration::from_secs(60)).unwrap();
+    /// }
+    /// ```
+    /// # Error
+    /// * [AsserhttpError::HeaderAbsent] when there is no `Date` header
+    /// * [AsserhttpError::InvalidHttpDate] when the header is not a valid HTTP-date
+    /// * [AsserhttpError::DateNotWithin] when the date is further than `tolerance` from now
+    fn try_expect_date_within(&mut self, tolerance: Duration) -> AsserhttpResult<&mut T> {
+        self.try_expect_header_date(headers::DATE, |actual| {
+            let drift = date::drift(actual, SystemTime::now());
+            if drift <= tolerance {
+                return Ok(());
+            }
+            Err(AsserhttpError::DateNotWithin {
+                tolerance,
+                drift,
+                actual: httpdate::fmt_http_date(actual),
+            })
+        })
+    }
+
+    /// Allows verifying the `Retry-After` header in a closure, parsed either as a delay in seconds or as an HTTP-date
+    /// * `asserter` - closure to verify the delay or date
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use ureq::OrAnyStatus;
+    /// use asserhttp::*;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     reqwest::blocking::get("http://localhost").try_expect_retry_after(|r| { assert!(r.delay() <= std::time::Duration::from_secs(120)); Ok(()) }).unwrap();
+    ///     reqwest::get("http://localhost").await.try_expect_retry_after(|r| { assert!(r.delay() <= std::time::Duration::from_secs(120)); Ok(()) }).unwrap();
+    ///
+    ///     isahc::get("http://localhost").try_expect_retry_after(|r| { assert!(r.delay() <= std::time::Duration::from_secs(120)); Ok(()) }).unwrap();
+    ///     isahc::get_async("http://localhost").await.try_expect_retry_after(|r| { assert!(r.delay() <= std::time::Duration::from_secs(120)); Ok(()) }).unwrap();
+    ///
+    ///     surf::get("http://localhost").await.try_expect_retry_after(|r| { assert!(r.delay() <= std::time::Duration::from_secs(120)); Ok(()) }).unwrap();
+    ///
+    ///     ureq::get("http://localhost").call().or_any_status().try_expect_retry_after(|r| { assert!(r.delay() <= std::time::Duration::from_secs(120)); Ok(()) }).unwrap();
+    ///
+    ///     hyper::Client::new().get("http://localhost".parse().unwrap()).await.try_expect_retry_after(|r| { assert!(r.delay() <= std::time::Duration::from_secs(120)); Ok(()) }).unwrap();
+    ///
+    ///     awc::Client::default().get("http://localhost").send().await.try_expect_retry_after(|r| { assert!(r.delay() <= std::time::Duration::from_secs(120)); Ok(()) }).unwrap();
+    /// }
+    /// ```
+    /// # Error
+    /// * [AsserhttpError::HeaderAbsent] when there is no `Retry-After` header
+    /// * [AsserhttpError::InvalidRetryAfter] when the header is neither a delay nor an HTTP-date
+    fn try_expect_retry_after<F>(&mut self, asserter: F) -> AsserhttpResult<&mut T>
+    where
+        F: FnOnce(RetryAfter) -> AsserhttpResult<()>;
 }
 
 impl<T> FallibleAsserhttpHeader<T> for T
@@ -242,6 +778,188 @@ where
         key.try_assert_absent(self.get_keys())?;
         Ok(self)
     }
+
+    fn try_expect_header_matches<R>(&mut self, key: impl Into<HeaderKey>, regex: R) -> AsserhttpResult<&mut T>
+    where
+        R: TryInto<regex::Regex, Error = regex::Error>,
+    {
+        let key = key.into();
+        key.try_assert_contained(self.get_keys())?;
+        let regex = regex.try_into()?;
+        let actual_values = self.get_values(&key);
+        let values_count = actual_values.len();
+        if values_count > 1 {
+            return Err(AsserhttpError::MultivaluedHeader {
+                key,
+                values_count,
+                actual_values,
+            });
+        }
+        let actual = actual_values.first().ok_or(AsserhttpError::InternalError)?;
+        if !regex.is_match(actual) {
+            return Err(AsserhttpError::HeaderRegexMismatch {
+                key,
+                actual: actual.into(),
+                regex: regex.to_string(),
+            });
+        }
+        Ok(self)
+    }
+
+    fn try_expect_headers_match<R>(&mut self, key: impl Into<HeaderKey>, regex: R) -> AsserhttpResult<&mut T>
+    where
+        R: TryInto<regex::Regex, Error = regex::Error>,
+    {
+        let key = key.into();
+        key.try_assert_contained(self.get_keys())?;
+        let regex = regex.try_into()?;
+        if let Some(actual) = self.get_values(&key).iter().find(|v| !regex.is_match(v)) {
+            return Err(AsserhttpError::HeaderRegexMismatch {
+                key,
+                actual: actual.into(),
+                regex: regex.to_string(),
+            });
+        }
+        Ok(self)
+    }
+
+    fn try_expect_header_present_matching<R>(&mut self, regex: R) -> AsserhttpResult<&mut T>
+    where
+        R: TryInto<regex::Regex, Error = regex::Error>,
+    {
+        let regex = regex.try_into()?;
+        let keys = lowercase_keys(self);
+        if !keys.iter().any(|k| regex.is_match(k)) {
+            return Err(AsserhttpError::HeaderAbsentMatching {
+                regex: regex.to_string(),
+                keys,
+            });
+        }
+        Ok(self)
+    }
+
+    fn try_expect_header_absent_matching<R>(&mut self, regex: R) -> AsserhttpResult<&mut T>
+    where
+        R: TryInto<regex::Regex, Error = regex::Error>,
+    {
+        let regex = regex.try_into()?;
+        let keys = lowercase_keys(self)
+            .into_iter()
+            .filter(|k| regex.is_match(k))
+            .collect::<Vec<_>>();
+        if !keys.is_empty() {
+            return Err(AsserhttpError::HeaderPresentMatching {
+                regex: regex.to_string(),
+                keys,
+            });
+        }
+        Ok(self)
+    }
+
+    fn try_expect_headers_with_prefix<F>(&mut self, prefix: impl AsRef<str>, asserter: F) -> AsserhttpResult<&mut T>
+    where
+        F: FnOnce(BTreeMap<String, Vec<String>>) -> AsserhttpResult<()>,
+    {
+        let prefix = prefix.as_ref().to_lowercase();
+        let headers = self
+            .get_keys()
+            .into_iter()
+            .filter(|k| k.to_lowercase().starts_with(&prefix))
+            .map(|k| (k.to_lowercase(), self.get_values(&k).to_vec()))
+            .collect();
+        asserter(headers)?;
+        Ok(self)
+    }
+
+    fn try_expect_only_headers<I, K>(&mut self, keys: I) -> AsserhttpResult<&mut T>
+    where
+        I: IntoIterator<Item = K>,
+        K: Into<HeaderKey>,
+    {
+        let expected = keys.into_iter().map(Into::into).collect::<Vec<HeaderKey>>();
+        for key in &expected {
+            key.try_assert_contained(self.get_keys())?;
+        }
+        let unexpected = application_keys(self)
+            .into_iter()
+            .filter(|k| !expected.contains(&HeaderKey::from(k)))
+            .collect::<Vec<_>>();
+        if !unexpected.is_empty() {
+            return Err(AsserhttpError::UnexpectedHeaders { unexpected });
+        }
+        Ok(self)
+    }
+
+    fn try_expect_header_count(&mut self, count: usize) -> AsserhttpResult<&mut T> {
+        let keys = application_keys(self);
+        if keys.len() != count {
+            return Err(AsserhttpError::HeaderCountMismatch {
+                expected: count,
+                actual: keys.len(),
+                keys,
+            });
+        }
+        Ok(self)
+    }
+
+    fn try_expect_header_values_count(&mut self, key: impl Into<HeaderKey>, count: usize) -> AsserhttpResult<&mut T> {
+        let key = key.into();
+        key.try_assert_contained(self.get_keys())?;
+        let actual_values = self.get_values(&key);
+        if actual_values.len() != count {
+            return Err(AsserhttpError::HeaderValuesCountMismatch {
+                key,
+                expected: count,
+                actual_values,
+            });
+        }
+        Ok(self)
+    }
+
+    fn try_expect_content_type(&mut self, mime: impl AsRef<str>) -> AsserhttpResult<&mut T> {
+        assert_content_type(self, &[mime.as_ref()])?;
+        Ok(self)
+    }
+
+    fn try_expect_content_type_any_json(&mut self) -> AsserhttpResult<&mut T> {
+        assert_content_type(self, &["application/json", "*/*+json"])?;
+        Ok(self)
+    }
+
+    fn try_expect_content_type_any_xml(&mut self) -> AsserhttpResult<&mut T> {
+        assert_content_type(self, &["application/xml", "text/xml", "*/*+xml"])?;
+        Ok(self)
+    }
+
+    fn try_expect_content_type_multipart(&mut self) -> AsserhttpResult<&mut T> {
+        assert_content_type(self, &["multipart/*"])?;
+        Ok(self)
+    }
+
+    fn try_expect_content_type_charset(&mut self, charset: impl AsRef<str>) -> AsserhttpResult<&mut T> {
+        assert_content_type(self, &[&format!("*/*; charset={}", charset.as_ref())])?;
+        Ok(self)
+    }
+
+    fn try_expect_header_date<F>(&mut self, key: impl Into<HeaderKey>, asserter: F) -> AsserhttpResult<&mut T>
+    where
+        F: FnOnce(SystemTime) -> AsserhttpResult<()>,
+    {
+        let key = key.into();
+        let value = self.first_raw_value(&key)?;
+        asserter(date::parse(&value)?)?;
+        Ok(self)
+    }
+
+    fn try_expect_retry_after<F>(&mut self, asserter: F) -> AsserhttpResult<&mut T>
+    where
+        F: FnOnce(RetryAfter) -> AsserhttpResult<()>,
+    {
+        let key = HeaderKey::from(headers::RETRY_AFTER);
+        let value = self.first_raw_value(&key)?;
+        asserter(RetryAfter::parse(&value)?)?;
+        Ok(self)
+    }
 }
 
 impl<T, E> FallibleAsserhttpHeader<T> for Result<T, E>
@@ -274,6 +992,161 @@ where
             .map_err(|e| AsserhttpError::HttpError(format!("{e:?}")))?
             .try_expect_header_absent(key)
     }
+
+    fn try_expect_header_matches<R>(&mut self, key: impl Into<HeaderKey>, regex: R) -> AsserhttpResult<&mut T>
+    where
+        R: TryInto<regex::Regex, Error = regex::Error>,
+    {
+        self.as_mut()
+            .map_err(|e| AsserhttpError::HttpError(format!("{e:?}")))?
+            .try_expect_header_matches(key, regex)
+    }
+
+    fn try_expect_headers_match<R>(&mut self, key: impl Into<HeaderKey>, regex: R) -> AsserhttpResult<&mut T>
+    where
+        R: TryInto<regex::Regex, Error = regex::Error>,
+    {
+        self.as_mut()
+            .map_err(|e| AsserhttpError::HttpError(format!("{e:?}")))?
+            .try_expect_headers_match(key, regex)
+    }
+
+    fn try_expect_header_present_matching<R>(&mut self, regex: R) -> AsserhttpResult<&mut T>
+    where
+        R: TryInto<regex::Regex, Error = regex::Error>,
+    {
+        self.as_mut()
+            .map_err(|e| AsserhttpError::HttpError(format!("{e:?}")))?
+            .try_expect_header_present_matching(regex)
+    }
+
+    fn try_expect_header_absent_matching<R>(&mut self, regex: R) -> AsserhttpResult<&mut T>
+    where
+        R: TryInto<regex::Regex, Error = regex::Error>,
+    {
+        self.as_mut()
+            .map_err(|e| AsserhttpError::HttpError(format!("{e:?}")))?
+            .try_expect_header_absent_matching(regex)
+    }
+
+    fn try_expect_headers_with_prefix<F>(&mut self, prefix: impl AsRef<str>, asserter: F) -> AsserhttpResult<&mut T>
+    where
+        F: FnOnce(BTreeMap<String, Vec<String>>) -> AsserhttpResult<()>,
+    {
+        self.as_mut()
+            .map_err(|e| AsserhttpError::HttpError(format!("{e:?}")))?
+            .try_expect_headers_with_prefix(prefix, asserter)
+    }
+
+    fn try_expect_only_headers<I, K>(&mut self, keys: I) -> AsserhttpResult<&mut T>
+    where
+        I: IntoIterator<Item = K>,
+        K: Into<HeaderKey>,
+    {
+        self.as_mut()
+            .map_err(|e| AsserhttpError::HttpError(format!("{e:?}")))?
+            .try_expect_only_headers(keys)
+    }
+
+    fn try_expect_header_count(&mut self, count: usize) -> AsserhttpResult<&mut T> {
+        self.as_mut()
+            .map_err(|e| AsserhttpError::HttpError(format!("{e:?}")))?
+            .try_expect_header_count(count)
+    }
+
+    fn try_expect_header_values_count(&mut self, key: impl Into<HeaderKey>, count: usize) -> AsserhttpResult<&mut T> {
+        self.as_mut()
+            .map_err(|e| AsserhttpError::HttpError(format!("{e:?}")))?
+            .try_expect_header_values_count(key, count)
+    }
+
+    fn try_expect_content_type(&mut self, mime: impl AsRef<str>) -> AsserhttpResult<&mut T> {
+        self.as_mut()
+            .map_err(|e| AsserhttpError::HttpError(format!("{e:?}")))?
+            .try_expect_content_type(mime)
+    }
+
+    fn try_expect_content_type_any_json(&mut self) -> AsserhttpResult<&mut T> {
+        self.as_mut()
+            .map_err(|e| AsserhttpError::HttpError(format!("{e:?}")))?
+            .try_expect_content_type_any_json()
+    }
+
+    fn try_expect_content_type_any_xml(&mut self) -> AsserhttpResult<&mut T> {
+        self.as_mut()
+            .map_err(|e| AsserhttpError::HttpError(format!("{e:?}")))?
+            .try_expect_content_type_any_xml()
+    }
+
+    fn try_expect_content_type_multipart(&mut self) -> AsserhttpResult<&mut T> {
+        self.as_mut()
+            .map_err(|e| AsserhttpError::HttpError(format!("{e:?}")))?
+            .try_expect_content_type_multipart()
+    }
+
+    fn try_expect_content_type_charset(&mut self, charset: impl AsRef<str>) -> AsserhttpResult<&mut T> {
+        self.as_mut()
+            .map_err(|e| AsserhttpError::HttpError(format!("{e:?}")))?
+            .try_expect_content_type_charset(charset)
+    }
+
+    fn try_expect_header_date<F>(&mut self, key: impl Into<HeaderKey>, asserter: F) -> AsserhttpResult<&mut T>
+    where
+        F: FnOnce(SystemTime) -> AsserhttpResult<()>,
+    {
+        self.as_mut()
+            .map_err(|e| AsserhttpError::HttpError(format!("{e:?}")))?
+            .try_expect_header_date(key, asserter)
+    }
+
+    fn try_expect_retry_after<F>(&mut self, asserter: F) -> AsserhttpResult<&mut T>
+    where
+        F: FnOnce(RetryAfter) -> AsserhttpResult<()>,
+    {
+        self.as_mut()
+            .map_err(|e| AsserhttpError::HttpError(format!("{e:?}")))?
+            .try_expect_retry_after(asserter)
+    }
+}
+
+/// Verifies the `Content-Type` header satisfies one of the expected media types
+fn assert_content_type(accessor: &impl HeaderAccessor, expected: &[&str]) -> AsserhttpResult<()> {
+    let key = HeaderKey::from(crate::headers::CONTENT_TYPE);
+    key.try_assert_contained(accessor.get_keys())?;
+    let raw_values = accessor.get_raw_values(&key);
+    let [value] = raw_values.as_slice() else {
+        return Err(AsserhttpError::MultivaluedHeader {
+            values_count: raw_values.len(),
+            actual_values: raw_values.into(),
+            key,
+        });
+    };
+    let actual = MediaType::parse(value)?;
+    for mime in expected {
+        if actual.satisfies(&MediaType::parse(mime)?) {
+            return Ok(());
+        }
+    }
+    Err(AsserhttpError::ContentTypeMismatch {
+        expected: expected.join(" or "),
+        actual: value.to_string(),
+    })
+}
+
+/// Distinct header keys, lowercase
+fn lowercase_keys(accessor: &impl HeaderAccessor) -> Vec<String> {
+    let mut keys = accessor.get_keys().iter().map(|k| k.to_lowercase()).collect::<Vec<_>>();
+    keys.sort();
+    keys.dedup();
+    keys
+}
+
+/// Distinct header keys, lowercase, without the ones set by the server or the transport
+fn application_keys(accessor: &impl HeaderAccessor) -> Vec<String> {
+    lowercase_keys(accessor)
+        .into_iter()
+        .filter(|k| !IMPLICIT_HEADERS.contains(&k.as_str()))
+        .collect()
 }
 
 pub struct FallibleHeaderValueAsserter(Box<dyn Fn(HeaderKey, HeaderValue) -> AsserhttpResult<()>>);
@@ -1,6 +1,12 @@
+use std::{
+    collections::BTreeMap,
+    time::{Duration, SystemTime},
+};
+
 use crate::{
     accessor::HeaderAccessor,
     header::{
+        date::RetryAfter,
         fallible::FallibleAsserhttpHeader,
         fallible::{FallibleHeaderValueAsserter, FallibleHeaderValuesAsserter},
         key::HeaderKey,
@@ -243,6 +249,468 @@ pub trait AsserhttpHeader<T> {
     fn expect_content_type_text(&mut self) -> &mut T {
         self.expect_header(crate::headers::CONTENT_TYPE, Self::TEXT_PLAIN)
     }
+
+    /// Expects response header to match the provided regex
+    /// * `key` - expected header key
+    /// * `regex` - must match the header value
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use ureq::OrAnyStatus;
+    /// use asserhttp::*;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     reqwest::blocking::get("http://localhost").expect_header_matches("location", "^/users/[0-9a-f-]{36}$");
+    ///     reqwest::get("http://localhost").await.expect_header_matches("location", "^/users/[0-9a-f-]{36}$");
+    ///
+    ///     isahc::get("http://localhost").expect_header_matches("location", "^/users/[0-9a-f-]{36}$");
+    ///     isahc::get_async("http://localhost").await.expect_header_matches("location", "^/users/[0-9a-f-]{36}$");
+    ///
+    ///     surf::get("http://localhost").await.expect_header_matches("location", "^/users/[0-9a-f-]{36}$");
+    ///
+    ///     ureq::get("http://localhost").call().or_any_status().expect_header_matches("location", "^/users/[0-9a-f-]{36}$");
+    ///
+    ///     hyper::Client::new().get("http://localhost".parse().unwrap()).await.expect_header_matches("location", "^/users/[0-9a-f-]{36}$");
+    ///
+    ///     awc::Client::default().get("http://localhost").send().await.expect_header_matches("location", "^/users/[0-9a-f-]{36}$");
+    /// }
+    /// ```
+    fn expect_header_matches<R>(&mut self, key: impl Into<HeaderKey>, regex: R) -> &mut T
+    where
+        R: TryInto<regex::Regex, Error = regex::Error>;
+
+    /// Expects every value of a multivalued response header to match the provided regex
+    /// * `key` - expected header key
+    /// * `regex` - must match each header value
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use ureq::OrAnyStatus;
+    /// use asserhttp::*;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     reqwest::blocking::get("http://localhost").expect_headers_match("vary", "^(accept|origin)");
+    ///     reqwest::get("http://localhost").await.expect_headers_match("vary", "^(accept|origin)");
+    ///
+    ///     isahc::get("http://localhost").expect_headers_match("vary", "^(accept|origin)");
+    ///     isahc::get_async("http://localhost").await.expect_headers_match("vary", "^(accept|origin)");
+    ///
+    ///     surf::get("http://localhost").await.expect_headers_match("vary", "^(accept|origin)");
+    ///
+    ///     ureq::get("http://localhost").call().or_any_status().expect_headers_match("vary", "^(accept|origin)");
+    ///
+    ///     hyper::Client::new().get("http://localhost".parse().unwrap()).await.expect_headers_match("vary", "^(accept|origin)");
+    ///
+    ///     awc::Client::default().get("http://localhost").send().await.expect_headers_match("vary", "^(accept|origin)");
+    /// }
+    /// ```
+    fn expect_headers_match<R>(&mut self, key: impl Into<HeaderKey>, regex: R) -> &mut T
+    where
+        R: TryInto<regex::Regex, Error = regex::Error>;
+
+    /// Expects at least one response header key to match the provided regex. Keys are matched lowercase
+    /// * `regex` - must match a header key
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use ureq::OrAnyStatus;
+    /// use asserhttp::*;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     reqwest::blocking::get("http://localhost").expect_header_present_matching("^x-ratelimit-");
+    ///     reqwest::get("http://localhost").await.expect_header_present_matching("^x-ratelimit-");
+    ///
+    ///     isahc::get("http://localhost").expect_header_present_matching("^x-ratelimit-");
+    ///     isahc::get_async("http://localhost").await.expect_header_present_matching("^x-ratelimit-");
+    ///
+    ///     surf::get("http://localhost").await.expect_header_present_matching("^x-ratelimit-");
+    ///
+    ///     ureq::get("http://localhost").call().or_any_status().expect_header_present_matching("^x-ratelimit-");
+    ///
+    ///     hyper::Client::new().get("http://localhost".parse().unwrap()).await.expect_header_present_matching("^x-ratelimit-");
+    ///
+    ///     awc::Client::default().get("http://localhost").send().await.expect_header_present_matching("^x-ratelimit-");
+    /// }
+    /// ```
+    fn expect_header_present_matching<R>(&mut self, regex: R) -> &mut T
+    where
+        R: TryInto<regex::Regex, Error = regex::Error>;
+
+    /// Expects no response header key to match the provided regex. Keys are matched lowercase
+    /// * `regex` - must not match any header key
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use ureq::OrAnyStatus;
+    /// use asserhttp::*;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     reqwest::blocking::get("http://localhost").expect_header_absent_matching("^x-internal-");
+    ///     reqwest::get("http://localhost").await.expect_header_absent_matching("^x-internal-");
+    ///
+    ///     isahc::get("http://localhost").expect_header_absent_matching("^x-internal-");
+    ///     isahc::get_async("http://localhost").await.expect_header_absent_matching("^x-internal-");
+    ///
+    ///     surf::get("http://localhost").await.expect_header_absent_matching("^x-internal-");
+    ///
+    ///     ureq::get("http://localhost").call().or_any_status().expect_header_absent_matching("^x-internal-");
+    ///
+    ///     hyper::Client::new().get("http://localhost".parse().unwrap()).await.expect_header_absent_matching("^x-internal-");
+    ///
+    ///     awc::Client::default().get("http://localhost").send().await.expect_header_absent_matching("^x-internal-");
+    /// }
+    /// ```
+    fn expect_header_absent_matching<R>(&mut self, regex: R) -> &mut T
+    where
+        R: TryInto<regex::Regex, Error = regex::Error>;
+
+    /// Allows verifying in a closure all the response headers whose key starts with a prefix, ignoring case.
+    /// The closure receives a map of lowercase keys to their values, empty when no header has the prefix
+    /// * `prefix` - header key prefix e.g. `x-amz-`
+    /// * `asserter` - closure to verify the matching headers
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use ureq::OrAnyStatus;
+    /// use asserhttp::*;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     reqwest::blocking::get("http://localhost").expect_headers_with_prefix("x-amz-", |h| assert!(h.contains_key("x-amz-request-id")));
+    ///     reqwest::get("http://localhost").await.expect_headers_with_prefix("x-amz-", |h| assert!(h.contains_key("x-amz-request-id")));
+    ///
+    ///     isahc::get("http://localhost").expect_headers_with_prefix("x-amz-", |h| assert!(h.contains_key("x-amz-request-id")));
+    ///     isahc::get_async("http://localhost").await.expect_headers_with_prefix("x-amz-", |h| assert!(h.contains_key("x-amz-request-id")));
+    ///
+    ///     surf::get("http://localhost").await.expect_headers_with_prefix("x-amz-", |h| assert!(h.contains_key("x-amz-request-id")));
+    ///
+    ///     ureq::get("http://localhost").call().or_any_status().expect_headers_with_prefix("x-amz-", |h| assert!(h.contains_key("x-amz-request-id")));
+    ///
+    ///     hyper::Client::new().get("http://localhost".parse().unwrap()).await.expect_headers_with_prefix("x-amz-", |h| assert!(h.contains_key("x-amz-request-id")));
+    ///
+    ///     awc::Client::default().get("http://localhost").send().await.expect_headers_with_prefix("x-amz-", |h| assert!(h.contains_key("x-amz-request-id")));
+    /// }
+    /// ```
+    fn expect_headers_with_prefix<F>(&mut self, prefix: impl AsRef<str>, asserter: F) -> &mut T
+    where
+        F: FnOnce(BTreeMap<String, Vec<String>>);
+
+    /// Expects response headers to be exactly the given ones.
+    /// The headers `connection`, `content-length`, `date`, `keep-alive`, `server` and `transfer-encoding` are
+    /// always tolerated since the server or the transport may set them. Any other header, including one added by a
+    /// framework such as Rocket's default `Shield` fairing, has to be expected
+    /// * `keys` - expected header keys
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use ureq::OrAnyStatus;
+    /// use asserhttp::*;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     reqwest::blocking::get("http://localhost").expect_only_headers(["content-type", "x-request-id"]);
+    ///     reqwest::get("http://localhost").await.expect_only_headers(["content-type", "x-request-id"]);
+    ///
+    ///     isahc::get("http://localhost").expect_only_headers(["content-type", "x-request-id"]);
+    ///     isahc::get_async("http://localhost").await.expect_only_headers(["content-type", "x-request-id"]);
+    ///
+    ///     surf::get("http://localhost").await.expect_only_headers(["content-type", "x-request-id"]);
+    ///
+    ///     ureq::get("http://localhost").call().or_any_status().expect_only_headers(["content-type", "x-request-id"]);
+    ///
+    ///     hyper::Client::new().get("http://localhost".parse().unwrap()).await.expect_only_headers(["content-type", "x-request-id"]);
+    ///
+    ///     awc::Client::default().get("http://localhost").send().await.expect_only_headers(["content-type", "x-request-id"]);
+    /// }
+    /// ```
+    fn expect_only_headers<I, K>(&mut self, keys: I) -> &mut T
+    where
+        I: IntoIterator<Item = K>,
+        K: Into<HeaderKey>;
+
+    /// Expects the number of distinct response header keys.
+    /// The headers `connection`, `content-length`, `date`, `keep-alive`, `server` and `transfer-encoding` are
+    /// never counted since the server or the transport may set them. Any other header, including one added by a
+    /// framework such as Rocket's default `Shield` fairing, is counted
+    /// * `count` - expected number of headers
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use ureq::OrAnyStatus;
+    /// use asserhttp::*;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     reqwest::blocking::get("http://localhost").expect_header_count(3);
+    ///     reqwest::get("http://localhost").await.expect_header_count(3);
+    ///
+    ///     isahc::get("http://localhost").expect_header_count(3);
+    ///     isahc::get_async("http://localhost").await.expect_header_count(3);
+    ///
+    ///     surf::get("http://localhost").await.expect_header_count(3);
+    ///
+    ///     ureq::get("http://localhost").call().or_any_status().expect_header_count(3);
+    ///
+    ///     hyper::Client::new().get("http://localhost".parse().unwrap()).await.expect_header_count(3);
+    ///
+    ///     awc::Client::default().get("http://localhost").send().await.expect_header_count(3);
+    /// }
+    /// ```
+    fn expect_header_count(&mut self, count: usize) -> &mut T;
+
+    /// Expects the number of values of a response header, be they comma separated or on distinct lines
+    /// * `key` - expected header key
+    /// * `count` - expected number of values
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use ureq::OrAnyStatus;
+    /// use asserhttp::*;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     reqwest::blocking::get("http://localhost").expect_header_values_count("vary", 2);
+    ///     reqwest::get("http://localhost").await.expect_header_values_count("vary", 2);
+    ///
+    ///     isahc::get("http://localhost").expect_header_values_count("vary", 2);
+    ///     isahc::get_async("http://localhost").await.expect_header_values_count("vary", 2);
+    ///
+    ///     surf::get("http://localhost").await.expect_header_values_count("vary", 2);
+    ///
+    ///     ureq::get("http://localhost").call().or_any_status().expect_header_values_count("vary", 2);
+    ///
+    ///     hyper::Client::new().get("http://localhost".parse().unwrap()).await.expect_header_values_count("vary", 2);
+    ///
+    ///     awc::Client::default().get("http://localhost").send().await.expect_header_values_count("vary", 2);
+    /// }
+    /// ```
+    fn expect_header_values_count(&mut self, key: impl Into<HeaderKey>, count: usize) -> &mut T;
+
+    /// Expects response header `Content-Type` to be the given media type. Type, subtype and suffix are compared
+    /// ignoring case and every expected parameter has to be present, other parameters being ignored. `*` matches any type
+    /// or subtype e.g. `application/*` or `*/*+json`
+    /// * `mime` - expected media type e.g. `application/json; charset=utf-8`
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use ureq::OrAnyStatus;
+    /// use asserhttp::*;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     reqwest::blocking::get("http://localhost").expect_content_type("application/json; charset=utf-8");
+    ///     reqwest::get("http://localhost").await.expect_content_type("application/json; charset=utf-8");
+    ///
+    ///     isahc::get("http://localhost").expect_content_type("application/json; charset=utf-8");
+    ///     isahc::get_async("http://localhost").await.expect_content_type("application/json; charset=utf-8");
+    ///
+    ///     surf::get("http://localhost").await.expect_content_type("application/json; charset=utf-8");
+    ///
+    ///     ureq::get("http://localhost").call().or_any_status().expect_content_type("application/json; charset=utf-8");
+    ///
+    ///     hyper::Client::new().get("http://localhost".parse().unwrap()).await.expect_content_type("application/json; charset=utf-8");
+    ///
+    ///     awc::Client::default().get("http://localhost").send().await.expect_content_type("application/json; charset=utf-8");
+    /// }
+    /// ```
+    fn expect_content_type(&mut self, mime: impl AsRef<str>) -> &mut T;
+
+    /// Expects response header `Content-Type` to be `application/json` or any media type with a `+json` suffix
+    /// such as `application/problem+json`, whatever its parameters
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use ureq::OrAnyStatus;
+    /// use asserhttp::*;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     reqwest::blocking::get("http://localhost").expect_content_type_any_json();
+    ///     reqwest::get("http://localhost").await.expect_content_type_any_json();
+    ///
+    ///     isahc::get("http://localhost").expect_content_type_any_json();
+    ///     isahc::get_async("http://localhost").await.expect_content_type_any_json();
+    ///
+    ///     surf::get("http://localhost").await.expect_content_type_any_json();
+    ///
+    ///     ureq::get("http://localhost").call().or_any_status().expect_content_type_any_json();
+    ///
+    ///     hyper::Client::new().get("http://localhost".parse().unwrap()).await.expect_content_type_any_json();
+    ///
+    ///     awc::Client::default().get("http://localhost").send().await.expect_content_type_any_json();
+    /// }
+    /// ```
+    fn expect_content_type_any_json(&mut self) -> &mut T;
+
+    /// Expects response header `Content-Type` to be `application/xml`, `text/xml` or any media type with a `+xml`
+    /// suffix such as `application/atom+xml`, whatever its parameters
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use ureq::OrAnyStatus;
+    /// use asserhttp::*;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     reqwest::blocking::get("http://localhost").expect_content_type_any_xml();
+    ///     reqwest::get("http://localhost").await.expect_content_type_any_xml();
+    ///
+    ///     isahc::get("http://localhost").expect_content_type_any_xml();
+    ///     isahc::get_async("http://localhost").await.expect_content_type_any_xml();
+    ///
+    ///     surf::get("http://localhost").await.expect_content_type_any_xml();
+    ///
+    ///     ureq::get("http://localhost").call().or_any_status().expect_content_type_any_xml();
+    ///
+    ///     hyper::Client::new().get("http://localhost".parse().unwrap()).await.expect_content_type_any_xml();
+    ///
+    ///     awc::Client::default().get("http://localhost").send().await.expect_content_type_any_xml();
+    /// }
+    /// ```
+    fn expect_content_type_any_xml(&mut self) -> &mut T;
+
+    /// Expects response header `Content-Type` to be a `multipart/*` media type e.g. `multipart/form-data`
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use ureq::OrAnyStatus;
+    /// use asserhttp::*;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     reqwest::blocking::get("http://localhost").expect_content_type_multipart();
+    ///     reqwest::get("http://localhost").await.expect_content_type_multipart();
+    ///
+    ///     isahc::get("http://localhost").expect_content_type_multipart();
+    ///     isahc::get_async("http://localhost").await.expect_content_type_multipart();
+    ///
+    ///     surf::get("http://localhost").await.expect_content_type_multipart();
+    ///
+    ///     ureq::get("http://localhost").call().or_any_status().expect_content_type_multipart();
+    ///
+    ///     hyper::Client::new().get("http://localhost".parse().unwrap()).await.expect_content_type_multipart();
+    ///
+    ///     awc::Client::default().get("http://localhost").send().await.expect_content_type_multipart();
+    /// }
+    /// ```
+    fn expect_content_type_multipart(&mut self) -> &mut T;
+
+    /// Expects response header `Content-Type` to have an explicit `charset` parameter, compared ignoring case
+    /// * `charset` - expected charset e.g. `utf-8`
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use ureq::OrAnyStatus;
+    /// use asserhttp::*;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     reqwest::blocking::get("http://localhost").expect_content_type_charset("utf-8");
+    ///     reqwest::get("http://localhost").await.expect_content_type_charset("utf-8");
+    ///
+    ///     isahc::get("http://localhost").expect_content_type_charset("utf-8");
+    ///     isahc::get_async("http://localhost").await.expect_content_type_charset("utf-8");
+    ///
+    ///     surf::get("http://localhost").await.expect_content_type_charset("utf-8");
+    ///
+    ///     ureq::get("http://localhost").call().or_any_status().expect_content_type_charset("utf-8");
+    ///
+    ///     hyper::Client::new().get("http://localhost".parse().unwrap()).await.expect_content_type_charset("utf-8");
+    ///
+    ///     awc::Client::default().get("http://localhost").send().await.expect_content_type_charset("utf-8");
+    /// }
+    /// ```
+    fn expect_content_type_charset(&mut self, charset: impl AsRef<str>) -> &mut T;
+
+    /// Allows verifying a response header holding an HTTP-date, like `Date`, `Expires` or `Last-Modified`, in a closure
+    /// * `key` - expected header key
+    /// * `asserter` - closure to verify the parsed date
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use ureq::OrAnyStatus;
+    /// use asserhttp::*;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     reqwest::blocking::get("http://localhost").expect_header_date("expires", |t| assert!(t > std::time::SystemTime::now()));
+    ///     reqwest::get("http://localhost").await.expect_header_date("expires", |t| assert!(t > std::time::SystemTime::now()));
+    ///
+    ///     isahc::get("http://localhost").expect_header_date("expires", |t| assert!(t > std::time::SystemTime::now()));
+    ///     isahc::get_async("http://localhost").await.expect_header_date("expires", |t| assert!(t > std::time::SystemTime::now()));
+    ///
+    ///     surf::get("http://localhost").await.expect_header_date("expires", |t| assert!(t > std::time::SystemTime::now()));
+    ///
+    ///     ureq::get("http://localhost").call().or_any_status().expect_header_date("expires", |t| assert!(t > std::time::SystemTime::now()));
+    ///
+    ///     hyper::Client::new().get("http://localhost".parse().unwrap()).await.expect_header_date("expires", |t| assert!(t > std::time::SystemTime::now()));
+    ///
+    ///     awc::Client::default().get("http://localhost").send().await.expect_header_date("expires", |t| assert!(t > std::time::SystemTime::now()));
+    /// }
+    /// ```
+    fn expect_header_date<F>(&mut self, key: impl Into<HeaderKey>, asserter: F) -> &mut T
+    where
+        F: FnOnce(SystemTime);
+
+    /// Expects the `Date` header to be close to the current time, revealing a server clock drift otherwise
+    /// * `tolerance` - maximum accepted difference with now, in either direction
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use ureq::OrAnyStatus;
+    /// use asserhttp::*;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     reqwest::blocking::get("http://localhost").expect_date_within(std::time::Duration::from_secs(60));
+    ///     reqwest::get("http://localhost").await.expect_date_within(std::time::Duration::from_secs(60));
+    ///
+    ///     isahc::get("http://localhost").expect_date_within(std::time::Duration::from_secs(60));
+    ///     isahc::get_async("http://localhost").await.expect_date_within(std::time::Duration::from_secs(60));
+    ///
+    ///     surf::get("http://localhost").await.expect_date_within(std::time::Duration::from_secs(60));
+    ///
+    ///     ureq::get("http://localhost").call().or_any_status().expect_date_within(std::time::Duration::from_secs(60));
+    ///
+    ///     hyper::Client::new().get("http://localhost".parse().unwrap()).await.expect_date_within(std::time::Duration::from_secs(60));
+    ///
+    ///     awc::Client::default().get("http://localhost").send().await.expect_date_within(std::time::Duration::from_secs(60));
+    /// }
+    /// ```
+    fn expect_date_within(&mut self, tolerance: Duration) -> &mut T;
+
+    /// Allows verifying the `Retry-After` header in a closure, parsed either as a delay in seconds or as an HTTP-date
+    /// * `asserter` - closure to verify the delay or date
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use ureq::OrAnyStatus;
+    /// use asserhttp::*;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     reqwest::blocking::get("http://localhost").expect_retry_after(|r| assert!(r.delay() <= std::time::Duration::from_secs(120)));
+    ///     reqwest::get("http://localhost").await.expect_retry_after(|r| assert!(r.delay() <= std::time::Duration::from_secs(120)));
+    ///
+    ///     isahc::get("http://localhost").expect_retry_after(|r| assert!(r.delay() <= std::time::Duration::from_secs(120)));
+    ///     isahc::get_async("http://localhost").await.expect_retry_after(|r| assert!(r.delay() <= std::time::Duration::from_secs(120)));
+    ///
+    ///     surf::get("http://localhost").await.expect_retry_after(|r| assert!(r.delay() <= std::time::Duration::from_secs(120)));
+    ///
+    ///     ureq::get("http://localhost").call().or_any_status().expect_retry_after(|r| assert!(r.delay() <= std::time::Duration::from_secs(120)));
+    ///
+    ///     hyper::Client::new().get("http://localhost".parse().unwrap()).await.expect_retry_after(|r| assert!(r.delay() <= std::time::Duration::from_secs(120)));
+    ///
+    ///     awc::Client::default().get("http://localhost").send().await.expect_retry_after(|r| assert!(r.delay() <= std::time::Duration::from_secs(120)));
+    /// }
+    /// ```
+    fn expect_retry_after<F>(&mut self, asserter: F) -> &mut T
+    where
+        F: FnOnce(RetryAfter);
 }
 
 impl<T> AsserhttpHeader<T> for T
@@ -276,6 +744,155 @@ where
             Ok(r) => r,
         }
     }
+
+    fn expect_header_matches<R>(&mut self, key: impl Into<HeaderKey>, regex: R) -> &mut T
+    where
+        R: TryInto<regex::Regex, Error = regex::Error>,
+    {
+        match self.try_expect_header_matches(key, regex) {
+            Err(e) => panic!("{e}"),
+            Ok(r) => r,
+        }
+    }
+
+    fn expect_headers_match<R>(&mut self, key: impl Into<HeaderKey>, regex: R) -> &mut T
+    where
+        R: TryInto<regex::Regex, Error = regex::Error>,
+    {
+        match self.try_expect_headers_match(key, regex) {
+            Err(e) => panic!("{e}"),
+            Ok(r) => r,
+        }
+    }
+
+    fn expect_header_present_matching<R>(&mut self, regex: R) -> &mut T
+    where
+        R: TryInto<regex::Regex, Error = regex::Error>,
+    {
+        match self.try_expect_header_present_matching(regex) {
+            Err(e) => panic!("{e}"),
+            Ok(r) => r,
+        }
+    }
+
+    fn expect_header_absent_matching<R>(&mut self, regex: R) -> &mut T
+    where
+        R: TryInto<regex::Regex, Error = regex::Error>,
+    {
+        match self.try_expect_header_absent_matching(regex) {
+            Err(e) => panic!("{e}"),
+            Ok(r) => r,
+        }
+    }
+
+    fn expect_headers_with_prefix<F>(&mut self, prefix: impl AsRef<str>, asserter: F) -> &mut T
+    where
+        F: FnOnce(BTreeMap<String, Vec<String>>),
+    {
+        #[allow(clippy::blocks_in_conditions)]
+        match self.try_expect_headers_with_prefix(prefix, |v| {
+            asserter(v);
+            Ok(())
+        }) {
+            Err(e) => panic!("{e}"),
+            Ok(r) => r,
+        }
+    }
+
+    fn expect_only_headers<I, K>(&mut self, keys: I) -> &mut T
+    where
+        I: IntoIterator<Item = K>,
+        K: Into<HeaderKey>,
+    {
+        match self.try_expect_only_headers(keys) {
+            Err(e) => panic!("{e}"),
+            Ok(r) => r,
+        }
+    }
+
+    fn expect_header_count(&mut self, count: usize) -> &mut T {
+        match self.try_expect_header_count(count) {
+            Err(e) => panic!("{e}"),
+            Ok(r) => r,
+        }
+    }
+
+    fn expect_header_values_count(&mut self, key: impl Into<HeaderKey>, count: usize) -> &mut T {
+        match self.try_expect_header_values_count(key, count) {
+            Err(e) => panic!("{e}"),
+            Ok(r) => r,
+        }
+    }
+
+    fn expect_content_type(&mut self, mime: impl AsRef<str>) -> &mut T {
+        match self.try_expect_content_type(mime) {
+            Err(e) => panic!("{e}"),
+            Ok(r) => r,
+        }
+    }
+
+    fn expect_content_type_any_json(&mut self) -> &mut T {
+        match self.try_expect_content_type_any_json() {
+            Err(e) => panic!("{e}"),
+            Ok(r) => r,
+        }
+    }
+
+    fn expect_content_type_any_xml(&mut self) -> &mut T {
+        match self.try_expect_content_type_any_xml() {
+            Err(e) => panic!("{e}"),
+            Ok(r) => r,
+        }
+    }
+
+    fn expect_content_type_multipart(&mut self) -> &mut T {
+        match self.try_expect_content_type_multipart() {
+            Err(e) => panic!("{e}"),
+            Ok(r) => r,
+        }
+    }
+
+    fn expect_content_type_charset(&mut self, charset: impl AsRef<str>) -> &mut T {
+        match self.try_expect_content_type_charset(charset) {
+            Err(e) => panic!("{e}"),
+            Ok(r) => r,
+        }
+    }
+
+    fn expect_header_date<F>(&mut self, key: impl Into<HeaderKey>, asserter: F) -> &mut T
+    where
+        F: FnOnce(SystemTime),
+    {
+        #[allow(clippy::blocks_in_conditions)]
+        match self.try_expect_header_date(key, |v| {
+            asserter(v);
+            Ok(())
+        }) {
+            Err(e) => panic!("{e}"),
+            Ok(r) => r,
+        }
+    }
+
+    fn expect_date_within(&mut self, tolerance: Duration) -> &mut T {
+        match self.try_expect_date_within(tolerance) {
+            Err(e) => panic!("{e}"),
+            Ok(r) => r,
+        }
+    }
+
+    fn expect_retry_after<F>(&mut self, asserter: F) -> &mut T
+    where
+        F: FnOnce(RetryAfter),
+    {
+        #[allow(clippy::blocks_in_conditions)]
+        match self.try_expect_retry_after(|v| {
+            asserter(v);
+            Ok(())
+        }) {
+            Err(e) => panic!("{e}"),
+            Ok(r) => r,
+        }
+    }
 }
 
 impl<T, E> AsserhttpHeader<T> for Result<T, E>
@@ -298,6 +915,95 @@ where
     fn expect_header_absent(&mut self, key: impl Into<HeaderKey>) -> &mut T {
         self.as_mut().unwrap().expect_header_absent(key)
     }
+
+    fn expect_header_matches<R>(&mut self, key: impl Into<HeaderKey>, regex: R) -> &mut T
+    where
+        R: TryInto<regex::Regex, Error = regex::Error>,
+    {
+        self.as_mut().unwrap().expect_header_matches(key, regex)
+    }
+
+    fn expect_headers_match<R>(&mut self, key: impl Into<HeaderKey>, regex: R) -> &mut T
+    where
+        R: TryInto<regex::Regex, Error = regex::Error>,
+    {
+        self.as_mut().unwrap().expect_headers_match(key, regex)
+    }
+
+    fn expect_header_present_matching<R>(&mut self, regex: R) -> &mut T
+    where
+        R: TryInto<regex::Regex, Error = regex::Error>,
+    {
+        self.as_mut().unwrap().expect_header_present_matching(regex)
+    }
+
+    fn expect_header_absent_matching<R>(&mut self, regex: R) -> &mut T
+    where
+        R: TryInto<regex::Regex, Error = regex::Error>,
+    {
+        self.as_mut().unwrap().expect_header_absent_matching(regex)
+    }
+
+    fn expect_headers_with_prefix<F>(&mut self, prefix: impl AsRef<str>, asserter: F) -> &mut T
+    where
+        F: FnOnce(BTreeMap<String, Vec<String>>),
+    {
+        self.as_mut().unwrap().expect_headers_with_prefix(prefix, asserter)
+    }
+
+    fn expect_only_headers<I, K>(&mut self, keys: I) -> &mut T
+    where
+        I: IntoIterator<Item = K>,
+        K: Into<HeaderKey>,
+    {
+        self.as_mut().unwrap().expect_only_headers(keys)
+    }
+
+    fn expect_header_count(&mut self, count: usize) -> &mut T {
+        self.as_mut().unwrap().expect_header_count(count)
+    }
+
+    fn expect_header_values_count(&mut self, key: impl Into<HeaderKey>, count: usize) -> &mut T {
+        self.as_mut().unwrap().expect_header_values_count(key, count)
+    }
+
+    fn expect_content_type(&mut self, mime: impl AsRef<str>) -> &mut T {
+        self.as_mut().unwrap().expect_content_type(mime)
+    }
+
+    fn expect_content_type_any_json(&mut self) -> &mut T {
+        self.as_mut().unwrap().expect_content_type_any_json()
+    }
+
+    fn expect_content_type_any_xml(&mut self) -> &mut T {
+        self.as_mut().unwrap().expect_content_type_any_xml()
+    }
+
+    fn expect_content_type_multipart(&mut self) -> &mut T {
+        self.as_mut().unwrap().expect_content_type_multipart()
+    }
+
+    fn expect_content_type_charset(&mut self, charset: impl AsRef<str>) -> &mut T {
+        self.as_mut().unwrap().expect_content_type_charset(charset)
+    }
+
+    fn expect_header_date<F>(&mut self, key: impl Into<HeaderKey>, asserter: F) -> &mut T
+    where
+        F: FnOnce(SystemTime),
+    {
+        self.as_mut().unwrap().expect_header_date(key, asserter)
+    }
+
+    fn expect_date_within(&mut self, tolerance: Duration) -> &mut T {
+        self.as_mut().unwrap().expect_date_within(tolerance)
+    }
+
+    fn expect_retry_after<F>(&mut self, asserter: F) -> &mut T
+    where
+        F: FnOnce(RetryAfter),
+    {
+        self.as_mut().unwrap().expect_retry_after(asserter)
+    }
 }
 
 pub struct HeaderValueAsserter(Box<dyn Fn(HeaderKey, HeaderValue)>);
@@ -0,0 +1,60 @@
+use crate::{
+    error::{AsserhttpError, AsserhttpResult},
+    header::quoted::{split_unquoted, unquote},
+};
+
+/// A parsed `Content-Type` e.g. `application/problem+json; charset=utf-8`
+#[derive(Debug, Clone)]
+pub(crate) struct MediaType {
+    kind: String,
+    subtype: String,
+    suffix: Option<String>,
+    params: Vec<(String, String)>,
+}
+
+impl MediaType {
+    const WILDCARD: &'static str = "*";
+
+    pub(crate) fn parse(value: &str) -> AsserhttpResult<Self> {
+        let invalid = || AsserhttpError::InvalidMediaType { value: value.to_string() };
+        let mut parts = split_unquoted(value, ';').into_iter();
+        let essence = parts.next().ok_or_else(invalid)?.trim().to_lowercase();
+        let (kind, subtype) = essence.split_once('/').ok_or_else(invalid)?;
+        if kind.is_empty() || subtype.is_empty() || subtype.contains('/') {
+            return Err(invalid());
+        }
+        let (subtype, suffix) = match subtype.rsplit_once('+') {
+            Some((subtype, suffix)) if !subtype.is_empty() && !suffix.is_empty() => (subtype, Some(suffix.to_string())),
+            _ => (subtype, None),
+        };
+        let params = parts
+            .filter(|p| !p.trim().is_empty())
+            .map(|p| {
+                let (name, value) = p.split_once('=').ok_or_else(invalid)?;
+                Ok((name.trim().to_lowercase(), unquote(value.trim())))
+            })
+            .collect::<AsserhttpResult<Vec<_>>>()?;
+        Ok(Self {
+            kind: kind.to_string(),
+            subtype: subtype.to_string(),
+            suffix,
+            params,
+        })
+    }
+
+    /// Whether this media type satisfies the expected one. `*` in the expected type or subtype matches anything,
+    /// a suffix has to be the same and expected parameters have to be present. `charset` is compared ignoring case
+    pub(crate) fn satisfies(&self, expected: &Self) -> bool {
+        let wildcard_or = |expected: &str, actual: &str| expected == Self::WILDCARD || expected == actual;
+        let suffix = match (&expected.suffix, &self.suffix) {
+            (Some(expected), actual) => Some(expected) == actual.as_ref(),
+            (None, actual) => expected.subtype == Self::WILDCARD || actual.is_none(),
+        };
+        let params = expected.params.iter().all(|(name, expected)| {
+            self.params
+                .iter()
+                .any(|(n, actual)| n == name && (actual == expected || (name == "charset" && actual.eq_ignore_ascii_case(expected))))
+        });
+        wildcard_or(&expected.kind, &self.kind) && wildcard_or(&expected.subtype, &self.subtype) && suffix && params
+    }
+}
@@ -1,5 +1,11 @@
+pub(crate) mod date;
 pub(crate) mod fallible;
 pub(crate) mod infallible;
 pub(crate) mod key;
+pub(crate) mod media_type;
+pub(crate) mod quoted;
 pub(crate) mod value;
 pub(crate) mod values;
+
+/// Headers set by servers and transports rather than by the application, tolerated by exact header set assertions
+pub(crate) const IMPLICIT_HEADERS: [&str; 6] = ["connection", "content-length", "date", "keep-alive", "server", "transfer-encoding"];
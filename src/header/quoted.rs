@@ -0,0 +1,35 @@
+/// Splits on `separator` unless within a quoted string or an `<uri-reference>`
+pub(crate) fn split_unquoted(value: &str, separator: char) -> Vec<&str> {
+    let (mut parts, mut start, mut quoted, mut bracketed, mut escaped) = (vec![], 0, false, false, false);
+    for (i, c) in value.char_indices() {
+        match c {
+            _ if escaped => escaped = false,
+            '\\' if quoted => escaped = true,
+            '"' if !bracketed => quoted = !quoted,
+            '<' if !quoted => bracketed = true,
+            '>' if !quoted => bracketed = false,
+            c if c == separator && !quoted && !bracketed => {
+                parts.push(&value[start..i]);
+                start = i + c.len_utf8();
+            },
+            _ => {},
+        }
+    }
+    parts.push(&value[start..]);
+    parts
+}
+
+/// Strips the quotes of a quoted string and its backslash escapes, other values are left untouched
+pub(crate) fn unquote(value: &str) -> String {
+    match value.strip_prefix('"').and_then(|v| v.strip_suffix('"')) {
+        Some(quoted) => {
+            let mut unescaped = String::with_capacity(quoted.len());
+            let mut chars = quoted.chars();
+            while let Some(c) = chars.next() {
+                unescaped.push(if c == '\\' { chars.next().unwrap_or(c) } else { c });
+            }
+            unescaped
+        },
+        None => value.to_string(),
+    }
+}
@@ -0,0 +1,350 @@
+use serde::Serialize;
+
+use crate::{
+    accessor::BodyAccessor,
+    error::{AsserhttpError, AsserhttpResult},
+    jsonrpc::{JsonRpcBatch, JsonRpcBody, JsonRpcResponse},
+};
+
+/// For assertions on JSON-RPC 2.0 responses returning an error instead of panicking
+pub trait FallibleAsserhttpJsonRpc<T> {
+    /// Allows verifying a JSON-RPC 2.0 response in a closure
+    /// * `asserter` - closure to verify the response object
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use ureq::OrAnyStatus;
+    /// use asserhttp::*;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     reqwest::blocking::get("http://localhost").try_expect_jsonrpc(|r| { assert!(r.error.is_none()); Ok(()) }).unwrap();
+    ///     reqwest::get("http://localhost").await.try_expect_jsonrpc(|r| { assert!(r.error.is_none()); Ok(()) }).unwrap();
+    ///
+    ///     isahc::get("http://localhost").try_expect_jsonrpc(|r| { assert!(r.error.is_none()); Ok(()) }).unwrap();
+    ///     isahc::get_async("http://localhost").await.try_expect_jsonrpc(|r| { assert!(r.error.is_none()); Ok(()) }).unwrap();
+    ///
+    ///     surf::get("http://localhost").await.try_expect_jsonrpc(|r| { assert!(r.error.is_none()); Ok(()) }).unwrap();
+    ///
+    ///     ureq::get("http://localhost").call().or_any_status().try_expect_jsonrpc(|r| { assert!(r.error.is_none()); Ok(()) }).unwrap();
+    ///
+    ///     hyper::Client::new().get("http://localhost".parse().unwrap()).await.try_expect_jsonrpc(|r| { assert!(r.error.is_none()); Ok(()) }).unwrap();
+    ///
+    ///     awc::Client::default().get("http://localhost").send().await.try_expect_jsonrpc(|r| { assert!(r.error.is_none()); Ok(()) }).unwrap();
+    /// }
+    /// ```
+    /// # Error
+    /// * [AsserhttpError::BodyAbsent] when the response body is empty
+    /// * [AsserhttpError::JsonRpcShapeMismatch] when the response is a batch
+    /// * [AsserhttpError::JsonRpcMemberAbsent] when the `jsonrpc` or `id` member is missing
+    /// * [AsserhttpError::JsonRpcMemberMismatch] when the `jsonrpc` member is not `2.0`
+    /// * [AsserhttpError::JsonRpcOutcomeAbsent] when there is neither a `result` nor an `error` member
+    /// * [AsserhttpError::JsonRpcOutcomeConflict] when there are both a `result` and an `error` member
+    /// * [AsserhttpError::JsonRpcMemberInvalid] when the `id` or `error` member is not of the expected type
+    fn try_expect_jsonrpc<F>(&mut self, asserter: F) -> AsserhttpResult<&mut T>
+    where
+        F: FnOnce(JsonRpcResponse) -> AsserhttpResult<()>;
+
+    /// Expects the JSON-RPC response `result` member to be equal
+    /// * `result` - expected `result` member
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use ureq::OrAnyStatus;
+    /// use asserhttp::*;
+    /// use serde_json::json;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     reqwest::blocking::get("http://localhost").try_expect_jsonrpc_result(json!({"balance": 30})).unwrap();
+    ///     reqwest::get("http://localhost").await.try_expect_jsonrpc_result(json!({"balance": 30})).unwrap();
+    ///
+    ///     isahc::get("http://localhost").try_expect_jsonrpc_result(json!({"balance": 30})).unwrap();
+    ///     isahc::get_async("http://localhost").await.try_expect_jsonrpc_result(json!({"balance": 30})).unwrap();
+    ///
+    ///     surf::get("http://localhost").await.try_expect_jsonrpc_result(json!({"balance": 30})).unwrap();
+    ///
+    ///     ureq::get("http://localhost").call().or_any_status().try_expect_jsonrpc_result(json!({"balance": 30})).unwrap();
+    ///
+    ///     hyper::Client::new().get("http://localhost".parse().unwrap()).await.try_expect_jsonrpc_result(json!({"balance": 30})).unwrap();
+    ///
+    ///     awc::Client::default().get("http://localhost").send().await.try_expect_jsonrpc_result(json!({"balance": 30})).unwrap();
+    /// }
+    /// ```
+    /// # Error
+    /// * [AsserhttpError::BodyAbsent] when the response body is empty
+    /// * [AsserhttpError::JsonRpcShapeMismatch] when the response is a batch
+    /// * [AsserhttpError::JsonRpcMemberAbsent] when the `jsonrpc` or `id` member is missing
+    /// * [AsserhttpError::JsonRpcMemberMismatch] when the `jsonrpc` member is not `2.0`
+    /// * [AsserhttpError::JsonRpcOutcomeAbsent] when there is neither a `result` nor an `error` member
+    /// * [AsserhttpError::JsonRpcOutcomeConflict] when there are both a `result` and an `error` member
+    /// * [AsserhttpError::JsonRpcMemberInvalid] when the `id` or `error` member is not of the expected type
+    /// * [AsserhttpError::JsonRpcMemberAbsent] when there is no `result` member
+    /// * [AsserhttpError::JsonRpcMemberMismatch] when the `result` member is different from the expected one
+    fn try_expect_jsonrpc_result<B>(&mut self, result: B) -> AsserhttpResult<&mut T>
+    where
+        B: Serialize,
+    {
+        let expected = serde_json::to_value(result)?;
+        self.try_expect_jsonrpc(|response| response.check_result(expected, ""))
+    }
+
+    /// Expects the JSON-RPC response to be an error with the given code
+    /// * `code` - expected `error.code` member
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use ureq::OrAnyStatus;
+    /// use asserhttp::*;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     reqwest::blocking::get("http://localhost").try_expect_jsonrpc_error(-32601).unwrap();
+    ///     reqwest::get("http://localhost").await.try_expect_jsonrpc_error(-32601).unwrap();
+    ///
+    ///     isahc::get("http://localhost").try_expect_jsonrpc_error(-32601).unwrap();
+    ///     isahc::get_async("http://localhost").await.try_expect_jsonrpc_error(-32601).unwrap();
+    ///
+    ///     surf::get("http://localhost").await.try_expect_jsonrpc_error(-32601).unwrap();
+    ///
+    ///     ureq::get("http://localhost").call().or_any_status().try_expect_jsonrpc_error(-32601).unwrap();
+    ///
+    ///     hyper::Client::new().get("http://localhost".parse().unwrap()).await.try_expect_jsonrpc_error(-32601).unwrap();
+    ///
+    ///     awc::Client::default().get("http://localhost").send().await.try_expect_jsonrpc_error(-32601).unwrap();
+    /// }
+    /// ```
+    /// # Error
+    /// * [AsserhttpError::BodyAbsent] when the response body is empty
+    /// * [AsserhttpError::JsonRpcShapeMismatch] when the response is a batch
+    /// * [AsserhttpError::JsonRpcMemberAbsent] when the `jsonrpc` or `id` member is missing
+    /// * [AsserhttpError::JsonRpcMemberMismatch] when the `jsonrpc` member is not `2.0`
+    /// * [AsserhttpError::JsonRpcOutcomeAbsent] when there is neither a `result` nor an `error` member
+    /// * [AsserhttpError::JsonRpcOutcomeConflict] when there are both a `result` and an `error` member
+    /// * [AsserhttpError::JsonRpcMemberInvalid] when the `id` or `error` member is not of the expected type
+    /// * [AsserhttpError::JsonRpcMemberAbsent] when there is no `error` member
+    /// * [AsserhttpError::JsonRpcMemberMismatch] when the `error.code` member is different from the expected one
+    fn try_expect_jsonrpc_error(&mut self, code: i64) -> AsserhttpResult<&mut T> {
+        self.try_expect_jsonrpc(|response| response.check_error(code, ""))
+    }
+
+    /// Expects the JSON-RPC response to answer the request with the given id
+    /// * `id` - expected `id` member, either a number or a string
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use ureq::OrAnyStatus;
+    /// use asserhttp::*;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     reqwest::blocking::get("http://localhost").try_expect_jsonrpc_id(1).unwrap();
+    ///     reqwest::get("http://localhost").await.try_expect_jsonrpc_id(1).unwrap();
+    ///
+    ///     isahc::get("http://localhost").try_expect_jsonrpc_id(1).unwrap();
+    ///     isahc::get_async("http://localhost").await.try_expect_jsonrpc_id(1).unwrap();
+    ///
+    ///     surf::get("http://localhost").await.try_expect_jsonrpc_id(1).unwrap();
+    ///
+    ///     ureq::get("http://localhost").call().or_any_status().try_expect_jsonrpc_id(1).unwrap();
+    ///
+    ///     hyper::Client::new().get("http://localhost".parse().unwrap()).await.try_expect_jsonrpc_id(1).unwrap();
+    ///
+    ///     awc::Client::default().get("http://localhost").send().await.try_expect_jsonrpc_id(1).unwrap();
+    /// }
+    /// ```
+    /// # Error
+    /// * [AsserhttpError::BodyAbsent] when the response body is empty
+    /// * [AsserhttpError::JsonRpcShapeMismatch] when the response is a batch
+    /// * [AsserhttpError::JsonRpcMemberAbsent] when the `jsonrpc` or `id` member is missing
+    /// * [AsserhttpError::JsonRpcMemberMismatch] when the `jsonrpc` member is not `2.0`
+    /// * [AsserhttpError::JsonRpcOutcomeAbsent] when there is neither a `result` nor an `error` member
+    /// * [AsserhttpError::JsonRpcOutcomeConflict] when there are both a `result` and an `error` member
+    /// * [AsserhttpError::JsonRpcMemberInvalid] when the `id` or `error` member is not of the expected type
+    /// * [AsserhttpError::JsonRpcMemberMismatch] when the `id` member is different from the expected one
+    fn try_expect_jsonrpc_id<I>(&mut self, id: I) -> AsserhttpResult<&mut T>
+    where
+        I: Serialize,
+    {
+        let expected = serde_json::to_value(id)?;
+        self.try_expect_jsonrpc(|response| response.check_id(expected))
+    }
+
+    /// Allows verifying the responses to a JSON-RPC 2.0 batch request in a closure
+    /// * `asserter` - closure to verify the responses
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use ureq::OrAnyStatus;
+    /// use asserhttp::*;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     reqwest::blocking::get("http://localhost").try_expect_jsonrpc_batch(|b| { assert_eq!(b.0.len(), 2); Ok(()) }).unwrap();
+    ///     reqwest::get("http://localhost").await.try_expect_jsonrpc_batch(|b| { assert_eq!(b.0.len(), 2); Ok(()) }).unwrap();
+    ///
+    ///     isahc::get("http://localhost").try_expect_jsonrpc_batch(|b| { assert_eq!(b.0.len(), 2); Ok(()) }).unwrap();
+    ///     isahc::get_async("http://localhost").await.try_expect_jsonrpc_batch(|b| { assert_eq!(b.0.len(), 2); Ok(()) }).unwrap();
+    ///
+    ///     surf::get("http://localhost").await.try_expect_jsonrpc_batch(|b| { assert_eq!(b.0.len(), 2); Ok(()) }).unwrap();
+    ///
+    ///     ureq::get("http://localhost").call().or_any_status().try_expect_jsonrpc_batch(|b| { assert_eq!(b.0.len(), 2); Ok(()) }).unwrap();
+    ///
+    ///     hyper::Client::new().get("http://localhost".parse().unwrap()).await.try_expect_jsonrpc_batch(|b| { assert_eq!(b.0.len(), 2); Ok(()) }).unwrap();
+    ///
+    ///     awc::Client::default().get("http://localhost").send().await.try_expect_jsonrpc_batch(|b| { assert_eq!(b.0.len(), 2); Ok(()) }).unwrap();
+    /// }
+    /// ```
+    /// # Error
+    /// * [AsserhttpError::BodyAbsent] when the response body is empty
+    /// * [AsserhttpError::JsonRpcShapeMismatch] when the response is not a batch
+    /// * [AsserhttpError::JsonRpcMemberAbsent] when the `jsonrpc` or `id` member of an entry is missing
+    /// * [AsserhttpError::JsonRpcMemberMismatch] when the `jsonrpc` member of an entry is not `2.0`
+    /// * [AsserhttpError::JsonRpcOutcomeAbsent] when an entry has neither a `result` nor an `error` member
+    /// * [AsserhttpError::JsonRpcOutcomeConflict] when an entry has both a `result` and an `error` member
+    /// * [AsserhttpError::JsonRpcMemberInvalid] when an entry, its `id` or its `error` member is not of the expected type
+    fn try_expect_jsonrpc_batch<F>(&mut self, asserter: F) -> AsserhttpResult<&mut T>
+    where
+        F: FnOnce(JsonRpcBatch) -> AsserhttpResult<()>;
+
+    /// Expects the `result` member of the batch entry with the given id to be equal
+    /// * `id` - id of the entry, either a number or a string
+    /// * `result` - expected `result` member
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use ureq::OrAnyStatus;
+    /// use asserhttp::*;
+    /// use serde_json::json;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     reqwest::blocking::get("http://localhost").try_expect_jsonrpc_batch_result(1, json!({"balance": 30})).unwrap();
+    ///     reqwest::get("http://localhost").await.try_expect_jsonrpc_batch_result(1, json!({"balance": 30})).unwrap();
+    ///
+    ///     isahc::get("http://localhost").try_expect_jsonrpc_batch_result(1, json!({"balance": 30})).unwrap();
+    ///     isahc::get_async("http://localhost").await.try_expect_jsonrpc_batch_result(1, json!({"balance": 30})).unwrap();
+    ///
+    ///     surf::get("http://localhost").await.try_expect_jsonrpc_batch_result(1, json!({"balance": 30})).unwrap();
+    ///
+    ///     ureq::get("http://localhost").call().or_any_status().try_expect_jsonrpc_batch_result(1, json!({"balance": 30})).unwrap();
+    ///
+    ///     hyper::Client::new().get("http://localhost".parse().unwrap()).await.try_expect_jsonrpc_batch_result(1, json!({"balance": 30})).unwrap();
+    ///
+    ///     awc::Client::default().get("http://localhost").send().await.try_expect_jsonrpc_batch_result(1, json!({"balance": 30})).unwrap();
+    /// }
+    /// ```
+    /// # Error
+    /// * [AsserhttpError::BodyAbsent] when the response body is empty
+    /// * [AsserhttpError::JsonRpcShapeMismatch] when the response is not a batch
+    /// * [AsserhttpError::JsonRpcMemberAbsent] when the `jsonrpc` or `id` member of an entry is missing
+    /// * [AsserhttpError::JsonRpcMemberMismatch] when the `jsonrpc` member of an entry is not `2.0`
+    /// * [AsserhttpError::JsonRpcOutcomeAbsent] when an entry has neither a `result` nor an `error` member
+    /// * [AsserhttpError::JsonRpcOutcomeConflict] when an entry has both a `result` and an `error` member
+    /// * [AsserhttpError::JsonRpcMemberInvalid] when an entry, its `id` or its `error` member is not of the expected type
+    /// * [AsserhttpError::JsonRpcEntryAbsent] when no entry has the given id
+    /// * [AsserhttpError::JsonRpcMemberAbsent] when the entry has no `result` member
+    /// * [AsserhttpError::JsonRpcMemberMismatch] when the `result` member of the entry is different from the expected one
+    fn try_expect_jsonrpc_batch_result<I, B>(&mut self, id: I, result: B) -> AsserhttpResult<&mut T>
+    where
+        I: Serialize,
+        B: Serialize,
+    {
+        let (id, expected) = (serde_json::to_value(id)?, serde_json::to_value(result)?);
+        self.try_expect_jsonrpc_batch(|batch| {
+            batch
+                .try_entry(id.clone())?
+                .check_result(expected, &JsonRpcBody::entry_prefix(&id))
+        })
+    }
+
+    /// Expects the batch entry with the given id to be an error with the given code
+    /// * `id` - id of the entry, either a number or a string
+    /// * `code` - expected `error.code` member
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use ureq::OrAnyStatus;
+    /// use asserhttp::*;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     reqwest::blocking::get("http://localhost").try_expect_jsonrpc_batch_error(2, -32601).unwrap();
+    ///     reqwest::get("http://localhost").await.try_expect_jsonrpc_batch_error(2, -32601).unwrap();
+    ///
+    ///     isahc::get("http://localhost").try_expect_jsonrpc_batch_error(2, -32601).unwrap();
+    ///     isahc::get_async("http://localhost").await.try_expect_jsonrpc_batch_error(2, -32601).unwrap();
+    ///
+    ///     surf::get("http://localhost").await.try_expect_jsonrpc_batch_error(2, -32601).unwrap();
+    ///
+    ///     ureq::get("http://localhost").call().or_any_status().try_expect_jsonrpc_batch_error(2, -32601).unwrap();
+    ///
+    ///     hyper::Client::new().get("http://localhost".parse().unwrap()).await.try_expect_jsonrpc_batch_error(2, -32601).unwrap();
+    ///
+    ///     awc::Client::default().get("http://localhost").send().await.try_expect_jsonrpc_batch_error(2, -32601).unwrap();
+    /// }
+    /// ```
+    /// # Error
+    /// * [AsserhttpError::BodyAbsent] when the response body is empty
+    /// * [AsserhttpError::JsonRpcShapeMismatch] when the response is not a batch
+    /// * [AsserhttpError::JsonRpcMemberAbsent] when the `jsonrpc` or `id` member of an entry is missing
+    /// * [AsserhttpError::JsonRpcMemberMismatch] when the `jsonrpc` member of an entry is not `2.0`
+    /// * [AsserhttpError::JsonRpcOutcomeAbsent] when an entry has neither a `result` nor an `error` member
+    /// * [AsserhttpError::JsonRpcOutcomeConflict] when an entry has both a `result` and an `error` member
+    /// * [AsserhttpError::JsonRpcMemberInvalid] when an entry, its `id` or its `error` member is not of the expected type
+    /// * [AsserhttpError::JsonRpcEntryAbsent] when no entry has the given id
+    /// * [AsserhttpError::JsonRpcMemberAbsent] when the entry has no `error` member
+    /// * [AsserhttpError::JsonRpcMemberMismatch] when the `error.code` member of the entry is different from the expected one
+    fn try_expect_jsonrpc_batch_error<I>(&mut self, id: I, code: i64) -> AsserhttpResult<&mut T>
+    where
+        I: Serialize,
+    {
+        let id = serde_json::to_value(id)?;
+        self.try_expect_jsonrpc_batch(|batch| batch.try_entry(id.clone())?.check_error(code, &JsonRpcBody::entry_prefix(&id)))
+    }
+}
+
+impl<T> FallibleAsserhttpJsonRpc<T> for T
+where
+    T: BodyAccessor,
+{
+    fn try_expect_jsonrpc<F>(&mut self, asserter: F) -> AsserhttpResult<&mut T>
+    where
+        F: FnOnce(JsonRpcResponse) -> AsserhttpResult<()>,
+    {
+        asserter(self.get_json::<JsonRpcBody>()?.single()?)?;
+        Ok(self)
+    }
+
+    fn try_expect_jsonrpc_batch<F>(&mut self, asserter: F) -> AsserhttpResult<&mut T>
+    where
+        F: FnOnce(JsonRpcBatch) -> AsserhttpResult<()>,
+    {
+        asserter(self.get_json::<JsonRpcBody>()?.batch()?)?;
+        Ok(self)
+    }
+}
+
+impl<T, E> FallibleAsserhttpJsonRpc<T> for Result<T, E>
+where
+    T: BodyAccessor,
+    E: std::fmt::Debug,
+{
+    fn try_expect_jsonrpc<F>(&mut self, asserter: F) -> AsserhttpResult<&mut T>
+    where
+        F: FnOnce(JsonRpcResponse) -> AsserhttpResult<()>,
+    {
+        self.as_mut()
+            .map_err(|e| AsserhttpError::HttpError(format!("{e:?}")))?
+            .try_expect_jsonrpc(asserter)
+    }
+
+    fn try_expect_jsonrpc_batch<F>(&mut self, asserter: F) -> AsserhttpResult<&mut T>
+    where
+        F: FnOnce(JsonRpcBatch) -> AsserhttpResult<()>,
+    {
+        self.as_mut()
+            .map_err(|e| AsserhttpError::HttpError(format!("{e:?}")))?
+            .try_expect_jsonrpc_batch(asserter)
+    }
+}
@@ -0,0 +1,349 @@
+use serde::Serialize;
+
+use crate::{
+    accessor::BodyAccessor,
+    jsonrpc::{fallible::FallibleAsserhttpJsonRpc, JsonRpcBatch, JsonRpcResponse},
+};
+
+/// For assertions on JSON-RPC 2.0 responses
+pub trait AsserhttpJsonRpc<T> {
+    /// Allows verifying a JSON-RPC 2.0 response in a closure
+    /// * `asserter` - closure to verify the response object
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use ureq::OrAnyStatus;
+    /// use asserhttp::*;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     reqwest::blocking::get("http://localhost").expect_jsonrpc(|r| assert!(r.error.is_none()));
+    ///     reqwest::get("http://localhost").await.expect_jsonrpc(|r| assert!(r.error.is_none()));
+    ///
+    ///     isahc::get("http://localhost").expect_jsonrpc(|r| assert!(r.error.is_none()));
+    ///     isahc::get_async("http://localhost").await.expect_jsonrpc(|r| assert!(r.error.is_none()));
+    ///
+    ///     surf::get("http://localhost").await.expect_jsonrpc(|r| assert!(r.error.is_none()));
+    ///
+    ///     ureq::get("http://localhost").call().or_any_status().expect_jsonrpc(|r| assert!(r.error.is_none()));
+    ///
+    ///     hyper::Client::new().get("http://localhost".parse().unwrap()).await.expect_jsonrpc(|r| assert!(r.error.is_none()));
+    ///
+    ///     awc::Client::default().get("http://localhost").send().await.expect_jsonrpc(|r| assert!(r.error.is_none()));
+    /// }
+    /// ```
+    fn expect_jsonrpc<F>(&mut self, asserter: F) -> &mut T
+    where
+        F: FnOnce(JsonRpcResponse);
+
+    /// Expects the JSON-RPC response `result` member to be equal
+    /// * `result` - expected `result` member
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use ureq::OrAnyStatus;
+    /// use asserhttp::*;
+    /// use serde_json::json;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     reqwest::blocking::get("http://localhost").expect_jsonrpc_result(json!({"balance": 30}));
+    ///     reqwest::get("http://localhost").await.expect_jsonrpc_result(json!({"balance": 30}));
+    ///
+    ///     isahc::get("http://localhost").expect_jsonrpc_result(json!({"balance": 30}));
+    ///     isahc::get_async("http://localhost").await.expect_jsonrpc_result(json!({"balance": 30}));
+    ///
+    ///     surf::get("http://localhost").await.expect_jsonrpc_result(json!({"balance": 30}));
+    ///
+    ///     ureq::get("http://localhost").call().or_any_status().expect_jsonrpc_result(json!({"balance": 30}));
+    ///
+    ///     hyper::Client::new().get("http://localhost".parse().unwrap()).await.expect_jsonrpc_result(json!({"balance": 30}));
+    ///
+    ///     awc::Client::default().get("http://localhost").send().await.expect_jsonrpc_result(json!({"balance": 30}));
+    /// }
+    /// ```
+    fn expect_jsonrpc_result<B>(&mut self, result: B) -> &mut T
+    where
+        B: Serialize;
+
+    /// Expects the JSON-RPC response to be an error with the given code
+    /// * `code` - expected `error.code` member
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use ureq::OrAnyStatus;
+    /// use asserhttp::*;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     reqwest::blocking::get("http://localhost").expect_jsonrpc_error(-32601);
+    ///     reqwest::get("http://localhost").await.expect_jsonrpc_error(-32601);
+    ///
+    ///     isahc::get("http://localhost").expect_jsonrpc_error(-32601);
+    ///     isahc::get_async("http://localhost").await.expect_jsonrpc_error(-32601);
+    ///
+    ///     surf::get("http://localhost").await.expect_jsonrpc_error(-32601);
+    ///
+    ///     ureq::get("http://localhost").call().or_any_status().expect_jsonrpc_error(-32601);
+    ///
+    ///     hyper::Client::new().get("http://localhost".parse().unwrap()).await.expect_jsonrpc_error(-32601);
+    ///
+    ///     awc::Client::default().get("http://localhost").send().await.expect_jsonrpc_error(-32601);
+    /// }
+    /// ```
+    fn expect_jsonrpc_error(&mut self, code: i64) -> &mut T;
+
+    /// Expects the JSON-RPC response to answer the request with the given id
+    /// * `id` - expected `id` member, either a number or a string
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use ureq::OrAnyStatus;
+    /// use asserhttp::*;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     reqwest::blocking::get("http://localhost").expect_jsonrpc_id(1);
+    ///     reqwest::get("http://localhost").await.expect_jsonrpc_id(1);
+    ///
+    ///     isahc::get("http://localhost").expect_jsonrpc_id(1);
+    ///     isahc::get_async("http://localhost").await.expect_jsonrpc_id(1);
+    ///
+    ///     surf::get("http://localhost").await.expect_jsonrpc_id(1);
+    ///
+    ///     ureq::get("http://localhost").call().or_any_status().expect_jsonrpc_id(1);
+    ///
+    ///     hyper::Client::new().get("http://localhost".parse().unwrap()).await.expect_jsonrpc_id(1);
+    ///
+    ///     awc::Client::default().get("http://localhost").send().await.expect_jsonrpc_id(1);
+    /// }
+    /// ```
+    fn expect_jsonrpc_id<I>(&mut self, id: I) -> &mut T
+    where
+        I: Serialize;
+
+    /// Allows verifying the responses to a JSON-RPC 2.0 batch request in a closure
+    /// * `asserter` - closure to verify the responses
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use ureq::OrAnyStatus;
+    /// use asserhttp::*;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     reqwest::blocking::get("http://localhost").expect_jsonrpc_batch(|b| assert_eq!(b.0.len(), 2));
+    ///     reqwest::get("http://localhost").await.expect_jsonrpc_batch(|b| assert_eq!(b.0.len(), 2));
+    ///
+    ///     isahc::get("http://localhost").expect_jsonrpc_batch(|b| assert_eq!(b.0.len(), 2));
+    ///     isahc::get_async("http://localhost").await.expect_jsonrpc_batch(|b| assert_eq!(b.0.len(), 2));
+    ///
+    ///     surf::get("http://localhost").await.expect_jsonrpc_batch(|b| assert_eq!(b.0.len(), 2));
+    ///
+    ///     ureq::get("http://localhost").call().or_any_status().expect_jsonrpc_batch(|b| assert_eq!(b.0.len(), 2));
+    ///
+    ///     hyper::Client::new().get("http://localhost".parse().unwrap()).await.expect_jsonrpc_batch(|b| assert_eq!(b.0.len(), 2));
+    ///
+    ///     awc::Client::default().get("http://localhost").send().await.expect_jsonrpc_batch(|b| assert_eq!(b.0.len(), 2));
+    /// }
+    /// ```
+    fn expect_jsonrpc_batch<F>(&mut self, asserter: F) -> &mut T
+    where
+        F: FnOnce(JsonRpcBatch);
+
+    /// Expects the `result` member of the batch entry with the given id to be equal
+    /// * `id` - id of the entry, either a number or a string
+    /// * `result` - expected `result` member
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use ureq::OrAnyStatus;
+    /// use asserhttp::*;
+    /// use serde_json::json;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     reqwest::blocking::get("http://localhost").expect_jsonrpc_batch_result(1, json!({"balance": 30}));
+    ///     reqwest::get("http://localhost").await.expect_jsonrpc_batch_result(1, json!({"balance": 30}));
+    ///
+    ///     isahc::get("http://localhost").expect_jsonrpc_batch_result(1, json!({"balance": 30}));
+    ///     isahc::get_async("http://localhost").await.expect_jsonrpc_batch_result(1, json!({"balance": 30}));
+    ///
+    ///     surf::get("http://localhost").await.expect_jsonrpc_batch_result(1, json!({"balance": 30}));
+    ///
+    ///     ureq::get("http://localhost").call().or_any_status().expect_jsonrpc_batch_result(1, json!({"balance": 30}));
+    ///
+    ///     hyper::Client::new().get("http://localhost".parse().unwrap()).await.expect_jsonrpc_batch_result(1, json!({"balance": 30}));
+    ///
+    ///     awc::Client::default().get("http://localhost").send().await.expect_jsonrpc_batch_result(1, json!({"balance": 30}));
+    /// }
+    /// ```
+    fn expect_jsonrpc_batch_result<I, B>(&mut self, id: I, result: B) -> &mut T
+    where
+        I: Serialize,
+        B: Serialize;
+
+    /// Expects the batch entry with the given id to be an error with the given code
+    /// * `id` - id of the entry, either a number or a string
+    /// * `code` - expected `error.code` member
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use ureq::OrAnyStatus;
+    /// use asserhttp::*;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     reqwest::blocking::get("http://localhost").expect_jsonrpc_batch_error(2, -32601);
+    ///     reqwest::get("http://localhost").await.expect_jsonrpc_batch_error(2, -32601);
+    ///
+    ///     isahc::get("http://localhost").expect_jsonrpc_batch_error(2, -32601);
+    ///     isahc::get_async("http://localhost").await.expect_jsonrpc_batch_error(2, -32601);
+    ///
+    ///     surf::get("http://localhost").await.expect_jsonrpc_batch_error(2, -32601);
+    ///
+    ///     ureq::get("http://localhost").call().or_any_status().expect_jsonrpc_batch_error(2, -32601);
+    ///
+    ///     hyper::Client::new().get("http://localhost".parse().unwrap()).await.expect_jsonrpc_batch_error(2, -32601);
+    ///
+    ///     awc::Client::default().get("http://localhost").send().await.expect_jsonrpc_batch_error(2, -32601);
+    /// }
+    /// ```
+    fn expect_jsonrpc_batch_error<I>(&mut self, id: I, code: i64) -> &mut T
+    where
+        I: Serialize;
+}
+
+impl<T> AsserhttpJsonRpc<T> for T
+where
+    T: BodyAccessor,
+{
+    fn expect_jsonrpc<F>(&mut self, asserter: F) -> &mut T
+    where
+        F: FnOnce(JsonRpcResponse),
+    {
+        #[allow(clippy::blocks_in_conditions)]
+        match self.try_expect_jsonrpc(|v| {
+            asserter(v);
+            Ok(())
+        }) {
+            Err(e) => panic!("{e}"),
+            Ok(r) => r,
+        }
+    }
+
+    fn expect_jsonrpc_result<B>(&mut self, result: B) -> &mut T
+    where
+        B: Serialize,
+    {
+        match self.try_expect_jsonrpc_result(result) {
+            Err(e) => panic!("{e}"),
+            Ok(r) => r,
+        }
+    }
+
+    fn expect_jsonrpc_error(&mut self, code: i64) -> &mut T {
+        match self.try_expect_jsonrpc_error(code) {
+            Err(e) => panic!("{e}"),
+            Ok(r) => r,
+        }
+    }
+
+    fn expect_jsonrpc_id<I>(&mut self, id: I) -> &mut T
+    where
+        I: Serialize,
+    {
+        match self.try_expect_jsonrpc_id(id) {
+            Err(e) => panic!("{e}"),
+            Ok(r) => r,
+        }
+    }
+
+    fn expect_jsonrpc_batch<F>(&mut self, asserter: F) -> &mut T
+    where
+        F: FnOnce(JsonRpcBatch),
+    {
+        #[allow(clippy::blocks_in_conditions)]
+        match self.try_expect_jsonrpc_batch(|v| {
+            asserter(v);
+            Ok(())
+        }) {
+            Err(e) => panic!("{e}"),
+            Ok(r) => r,
+        }
+    }
+
+    fn expect_jsonrpc_batch_result<I, B>(&mut self, id: I, result: B) -> &mut T
+    where
+        I: Serialize,
+        B: Serialize,
+    {
+        match self.try_expect_jsonrpc_batch_result(id, result) {
+            Err(e) => panic!("{e}"),
+            Ok(r) => r,
+        }
+    }
+
+    fn expect_jsonrpc_batch_error<I>(&mut self, id: I, code: i64) -> &mut T
+    where
+        I: Serialize,
+    {
+        match self.try_expect_jsonrpc_batch_error(id, code) {
+            Err(e) => panic!("{e}"),
+            Ok(r) => r,
+        }
+    }
+}
+
+impl<T, E> AsserhttpJsonRpc<T> for Result<T, E>
+where
+    T: BodyAccessor,
+    E: std::fmt::Debug,
+{
+    fn expect_jsonrpc<F>(&mut self, asserter: F) -> &mut T
+    where
+        F: FnOnce(JsonRpcResponse),
+    {
+        self.as_mut().unwrap().expect_jsonrpc(asserter)
+    }
+
+    fn expect_jsonrpc_result<B>(&mut self, result: B) -> &mut T
+    where
+        B: Serialize,
+    {
+        self.as_mut().unwrap().expect_jsonrpc_result(result)
+    }
+
+    fn expect_jsonrpc_error(&mut self, code: i64) -> &mut T {
+        self.as_mut().unwrap().expect_jsonrpc_error(code)
+    }
+
+    fn expect_jsonrpc_id<I>(&mut self, id: I) -> &mut T
+    where
+        I: Serialize,
+    {
+        self.as_mut().unwrap().expect_jsonrpc_id(id)
+    }
+
+    fn expect_jsonrpc_batch<F>(&mut self, asserter: F) -> &mut T
+    where
+        F: FnOnce(JsonRpcBatch),
+    {
+        self.as_mut().unwrap().expect_jsonrpc_batch(asserter)
+    }
+
+    fn expect_jsonrpc_batch_result<I, B>(&mut self, id: I, result: B) -> &mut T
+    where
+        I: Serialize,
+        B: Serialize,
+    {
+        self.as_mut().unwrap().expect_jsonrpc_batch_result(id, result)
+    }
+
+    fn expect_jsonrpc_batch_error<I>(&mut self, id: I, code: i64) -> &mut T
+    where
+        I: Serialize,
+    {
+        self.as_mut().unwrap().expect_jsonrpc_batch_error(id, code)
+    }
+}
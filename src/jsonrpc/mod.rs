@@ -0,0 +1,271 @@
+use serde::{Deserialize, Deserializer, Serialize};
+use serde_json::Value;
+
+use crate::error::{AsserhttpError, AsserhttpResult};
+
+pub(crate) mod fallible;
+pub(crate) mod infallible;
+
+/// [JSON-RPC 2.0](https://www.jsonrpc.org/specification#response_object) response object
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct JsonRpcResponse {
+    /// Protocol version, must be exactly `2.0`
+    pub jsonrpc: String,
+    /// Result of a successful call. `Some(Value::Null)` when the member is present but `null`
+    #[serde(default, deserialize_with = "present", skip_serializing_if = "Option::is_none")]
+    pub result: Option<Value>,
+    /// Error of a failed call
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<JsonRpcError>,
+    /// Identifier of the request this response answers. `null` when it could not be determined
+    #[serde(default)]
+    pub id: Value,
+}
+
+/// The `error` member of a response
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct JsonRpcError {
+    pub code: i64,
+    pub message: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub data: Option<Value>,
+}
+
+/// Responses to a batch request, in any order
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct JsonRpcBatch(pub Vec<JsonRpcResponse>);
+
+impl JsonRpcBatch {
+    /// The response answering the request with the given id
+    pub fn entry<I: Serialize>(&self, id: I) -> Option<&JsonRpcResponse> {
+        let id = serde_json::to_value(id).ok()?;
+        self.0.iter().find(|r| r.id == id)
+    }
+
+    pub(crate) fn try_entry(&self, id: Value) -> AsserhttpResult<&JsonRpcResponse> {
+        self.0
+            .iter()
+            .find(|r| r.id == id)
+            .ok_or_else(|| AsserhttpError::JsonRpcEntryAbsent { id: id.to_string() })
+    }
+}
+
+/// Either a single response or the responses to a batch, validated member by member
+#[derive(Deserialize)]
+#[serde(transparent)]
+pub(crate) struct JsonRpcBody(Value);
+
+/// A response object as sent, before the envelope is validated
+#[derive(Deserialize)]
+struct RawJsonRpcResponse {
+    #[serde(default, deserialize_with = "present")]
+    jsonrpc: Option<Value>,
+    #[serde(default, deserialize_with = "present")]
+    result: Option<Value>,
+    #[serde(default, deserialize_with = "present")]
+    error: Option<Value>,
+    #[serde(default, deserialize_with = "present")]
+    id: Option<Value>,
+}
+
+const VERSION: &str = "2.0";
+
+impl JsonRpcBody {
+    pub(crate) fn single(self) -> AsserhttpResult<JsonRpcResponse> {
+        match self.0 {
+            Value::Array(_) => Err(Self::shape_mismatch("single", "batch")),
+            Value::Object(_) => RawJsonRpcResponse::parse(self.0, ""),
+            other => Err(Self::shape_mismatch("single", kind(&other))),
+        }
+    }
+
+    pub(crate) fn batch(self) -> AsserhttpResult<JsonRpcBatch> {
+        match self.0 {
+            Value::Array(entries) => entries
+                .into_iter()
+                .enumerate()
+                .map(|(i, entry)| {
+                    let prefix = match entry.get("id") {
+                        Some(id) => Self::entry_prefix(id),
+                        None => format!("[{i}]."),
+                    };
+                    if !entry.is_object() {
+                        return Err(AsserhttpError::JsonRpcMemberInvalid {
+                            member: prefix.trim_end_matches('.').to_string(),
+                            expected: "an object".to_string(),
+                            actual: entry.to_string(),
+                        });
+                    }
+                    RawJsonRpcResponse::parse(entry, &prefix)
+                })
+                .collect::<AsserhttpResult<Vec<_>>>()
+                .map(JsonRpcBatch),
+            Value::Object(_) => Err(Self::shape_mismatch("batch", "single")),
+            other => Err(Self::shape_mismatch("batch", kind(&other))),
+        }
+    }
+
+    /// Prefix of the members of a batch entry in error messages e.g. `[id=1].`
+    pub(crate) fn entry_prefix(id: &Value) -> String {
+        format!("[id={id}].")
+    }
+
+    fn shape_mismatch(expected: &str, actual: &str) -> AsserhttpError {
+        AsserhttpError::JsonRpcShapeMismatch {
+            expected: expected.to_string(),
+            actual: actual.to_string(),
+        }
+    }
+}
+
+impl RawJsonRpcResponse {
+    /// Validates the envelope, one error per violated rule, in the order they are listed in the specification
+    fn parse(value: Value, prefix: &str) -> AsserhttpResult<JsonRpcResponse> {
+        let raw = serde_json::from_value::<Self>(value)?;
+        let member = |name: &str| format!("{prefix}{name}");
+        let jsonrpc = raw
+            .jsonrpc
+            .ok_or_else(|| AsserhttpError::JsonRpcMemberAbsent { member: member("jsonrpc") })?;
+        if jsonrpc.as_str() != Some(VERSION) {
+            return Err(AsserhttpError::JsonRpcMemberMismatch {
+                member: member("jsonrpc"),
+                actual: jsonrpc.as_str().map(str::to_string).unwrap_or_else(|| jsonrpc.to_string()),
+                expected: VERSION.to_string(),
+            });
+        }
+        let error = match (raw.result.is_some(), raw.error) {
+            (false, None) => {
+                return Err(AsserhttpError::JsonRpcOutcomeAbsent {
+                    prefix: prefix.to_string(),
+                })
+            },
+            (true, Some(_)) => {
+                return Err(AsserhttpError::JsonRpcOutcomeConflict {
+                    prefix: prefix.to_string(),
+                })
+            },
+            (_, error) => error.map(|e| JsonRpcError::parse(e, &member("error"))).transpose()?,
+        };
+        let id = raw
+            .id
+            .ok_or_else(|| AsserhttpError::JsonRpcMemberAbsent { member: member("id") })?;
+        if !(id.is_string() || id.is_number() || id.is_null()) {
+            return Err(AsserhttpError::JsonRpcMemberInvalid {
+                member: member("id"),
+                expected: "a string, a number or null".to_string(),
+                actual: id.to_string(),
+            });
+        }
+        Ok(JsonRpcResponse {
+            jsonrpc: VERSION.to_string(),
+            result: raw.result,
+            error,
+            id,
+        })
+    }
+}
+
+impl JsonRpcError {
+    fn parse(value: Value, member: &str) -> AsserhttpResult<Self> {
+        let Value::Object(mut error) = value else {
+            return Err(AsserhttpError::JsonRpcMemberInvalid {
+                member: member.to_string(),
+                expected: "an object".to_string(),
+                actual: value.to_string(),
+            });
+        };
+        let code = match error.remove("code") {
+            None => {
+                return Err(AsserhttpError::JsonRpcMemberAbsent {
+                    member: format!("{member}.code"),
+                })
+            },
+            Some(code) => code.as_i64().ok_or_else(|| AsserhttpError::JsonRpcMemberInvalid {
+                member: format!("{member}.code"),
+                expected: "an integer".to_string(),
+                actual: code.to_string(),
+            })?,
+        };
+        let message = match error.remove("message") {
+            None => {
+                return Err(AsserhttpError::JsonRpcMemberAbsent {
+                    member: format!("{member}.message"),
+                })
+            },
+            Some(Value::String(message)) => message,
+            Some(message) => {
+                return Err(AsserhttpError::JsonRpcMemberInvalid {
+                    member: format!("{member}.message"),
+                    expected: "a string".to_string(),
+                    actual: message.to_string(),
+                })
+            },
+        };
+        Ok(Self {
+            code,
+            message,
+            data: error.remove("data"),
+        })
+    }
+}
+
+impl JsonRpcResponse {
+    pub(crate) fn check_result(&self, expected: Value, prefix: &str) -> AsserhttpResult<()> {
+        match self.result.as_ref() {
+            None => Err(AsserhttpError::JsonRpcMemberAbsent {
+                member: format!("{prefix}result"),
+            }),
+            Some(actual) if actual != &expected => Err(AsserhttpError::JsonRpcMemberMismatch {
+                member: format!("{prefix}result"),
+                actual: actual.to_string(),
+                expected: expected.to_string(),
+            }),
+            Some(_) => Ok(()),
+        }
+    }
+
+    pub(crate) fn check_error(&self, code: i64, prefix: &str) -> AsserhttpResult<()> {
+        match self.error.as_ref() {
+            None => Err(AsserhttpError::JsonRpcMemberAbsent {
+                member: format!("{prefix}error"),
+            }),
+            Some(error) if error.code != code => Err(AsserhttpError::JsonRpcMemberMismatch {
+                member: format!("{prefix}error.code"),
+                actual: error.code.to_string(),
+                expected: code.to_string(),
+            }),
+            Some(_) => Ok(()),
+        }
+    }
+
+    pub(crate) fn check_id(&self, expected: Value) -> AsserhttpResult<()> {
+        if self.id != expected {
+            return Err(AsserhttpError::JsonRpcMemberMismatch {
+                member: "id".to_string(),
+                actual: self.id.to_string(),
+                expected: expected.to_string(),
+            });
+        }
+        Ok(())
+    }
+}
+
+/// Name of the json type of a body which is neither a single response nor a batch
+fn kind(value: &Value) -> &'static str {
+    match value {
+        Value::Null => "null",
+        Value::Bool(_) => "boolean",
+        Value::Number(_) => "number",
+        Value::String(_) => "string",
+        Value::Array(_) => "batch",
+        Value::Object(_) => "single",
+    }
+}
+
+fn present<'de, D>(deserializer: D) -> Result<Option<Value>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    Value::deserialize(deserializer).map(Some)
+}
@@ -6,6 +6,14 @@
 //!
 //! It works for blocking or async client methods and for responses wrapped in `Result`.
 //!
+//! Reading the body does not consume it, so body assertions and captures can be chained, with a few client specific
+//! caveats:
+//! * Rocket's local responses do not allow putting a body back once read. Only the first body assertion or capture
+//!   sees the body, the following ones fail with [AsserhttpError::BodyAbsent]
+//! * awc keeps the body read by an assertion in the response extensions: read it again with
+//!   [AsserhttpCapture::extract_json] rather than `ClientResponse::body`, which finds it already consumed
+//! * ureq responses are rebuilt once their body is read, which resets their url and remote address
+//!
 //! # API
 //!
 //! Here's the list of all the provided asserters.
@@ -50,18 +58,119 @@
 //!     .expect_header_present(headers::CONTENT_TYPE)
 //!     .expect_header_absent("x-my-header")
 //!     .expect_header_absent(headers::ACCEPT)
+//!     .expect_header_matches("location", "^/users/[0-9a-f-]{36}$")
+//!     .expect_headers_match("vary", "^(accept|origin)")
+//!     .expect_header_present_matching("^x-ratelimit-")
+//!     .expect_header_absent_matching("^x-internal-")
+//!     .expect_only_headers(["content-type", "x-request-id"])
+//!     .expect_header_count(3)
+//!     .expect_header_values_count("vary", 2)
+//!     .expect_headers_with_prefix("x-amz-", |h| assert!(h.contains_key("x-amz-request-id")))
 //!     .expect_content_type_json()
 //!     .expect_content_type_text()
+//!     .expect_content_type("application/json; charset=utf-8")
+//!     .expect_content_type_any_json()
+//!     .expect_content_type_any_xml()
+//!     .expect_content_type_multipart()
+//!     .expect_content_type_charset("utf-8")
+//!     .expect_header_date("expires", |t| assert!(t > std::time::SystemTime::now()))
+//!     .expect_date_within(std::time::Duration::from_secs(60))
+//!     .expect_retry_after(|r| assert!(r.delay() <= std::time::Duration::from_secs(120)))
+//!     // cache-control
+//!     .expect_cache_control(|cc| assert!(cc.no_cache() && cc.must_revalidate()))
+//!     .expect_no_store()
+//!     .expect_max_age(std::time::Duration::from_secs(3600))
+//!     .expect_cache_private()
+//!     .expect_must_revalidate()
+//!     // cookie
+//!     .expect_cookie("session", |c| assert!(c.http_only && c.same_site.is_some()))
+//!     .expect_cookie_secure("session")
+//!     .expect_cookie_http_only("session")
+//!     .expect_cookie_removed("session")
+//!     // security headers
+//!     .expect_security_headers()
+//!     .expect_security_headers_with(SecurityHeadersPolicy::default().skip_hsts())
+//!     // content-security-policy
+//!     .expect_csp(|csp| assert!(csp.directive("script-src").contains("'self'")))
+//!     .expect_csp_report_only(|csp| assert!(csp.has("report-uri")))
+//!     .expect_csp_no_unsafe_inline()
+//!     // cors
+//!     .expect_cors_allowed_origin("https://app.example")
+//!     .expect_cors_allowed_methods(["GET", "PUT"])
+//!     .expect_cors_allowed_headers(["content-type", "x-request-id"])
+//!     .expect_cors_credentials()
+//!     .expect_cors_max_age(std::time::Duration::from_secs(600))
+//!     .expect_cors_preflight_ok("https://app.example", "PUT")
+//!     // redirect
+//!     .expect_redirect_to("/login")
+//!     .expect_redirect_to_with_base("/login", "https://app.example")
+//!     .expect_redirect(|url| assert!(url.query_pairs().any(|(k, _)| k == "next")))
+//!     .expect_redirect_with_base("https://app.example", |url| assert!(url.query_pairs().any(|(k, _)| k == "next")))
+//!     .expect_permanent_redirect()
+//!     .expect_temporary_redirect()
+//!     // conditional requests
+//!     .expect_etag(|e| assert!(e.is_weak() && e.tag() == "0815"))
+//!     .expect_etag_strong()
+//!     .expect_last_modified(|t| assert!(t < std::time::SystemTime::now()))
+//!     .expect_not_modified([("etag", "\"33a64df5\""), ("cache-control", "max-age=60")])
+//!     // link
+//!     .expect_link("next", |url| assert_eq!(url.query(), Some("page=3")))
+//!     .expect_no_link("next")
+//!     .expect_links(|links| assert!(links.iter().any(|l| l.has_rel("last"))))
+//!     .expect_pagination_consistent()
+//!     // content-disposition
+//!     .expect_content_disposition(|cd| assert!(cd.is_attachment() && cd.filename.unwrap().ends_with(".pdf")))
+//!     .expect_attachment("report.pdf")
+//!     .expect_inline()
+//!     // range requests
+//!     .expect_content_range(0, 499, 1234)
+//!     .expect_accept_ranges_bytes()
+//!     .expect_range_consistent()
+//!     // rate limit
+//!     .expect_rate_limit(|rl| assert!(rl.remaining < rl.limit))
+//!     .expect_rate_limited()
 //!     // body
 //!     .expect_body_json(|b: Value| assert_eq!(b, json!({"a": "b"})))
 //!     .expect_body_json_eq(json!({"name": "jdoe"}))
+//!     .expect_body_json_eq_file("tests/fixtures/user.json")
 //!     .expect_body_text(|b| assert_eq!(b, "abcd"))
 //!     .expect_body_text_eq("abcd")
+//!     .expect_body_text_eq_file("tests/fixtures/user.txt")
 //!     .expect_body_text_matches("[a-z]+")
+//!     .expect_body_text_contains("bc")
+//!     .expect_body_text_not_contains("xyz")
+//!     .expect_body_text_starts_with("ab")
+//!     .expect_body_text_ends_with("cd")
 //!     .expect_body_bytes(|b| assert_eq!(b, b"abcd"))
 //!     .expect_body_bytes_eq(b"abcd")
 //!     .expect_body_present()
-//!     .expect_body_absent();
+//!     .expect_body_absent()
+//!     // problem details
+//!     .expect_problem(|p| assert_eq!(p.status, Some(404)))
+//!     .expect_problem_type("https://example.com/probs/out-of-credit")
+//!     .expect_problem_title("You do not have enough credit.")
+//!     .expect_problem_detail_matches("balance is [0-9]+")
+//!     // graphql
+//!     .expect_graphql(|r| assert!(r.data.is_some()))
+//!     .expect_graphql_data(json!({"user": {"name": "jdoe"}}))
+//!     .expect_graphql_no_errors()
+//!     .expect_graphql_error_code("FORBIDDEN")
+//!     .expect_graphql_error_path(["user", "email"])
+//!     // json-rpc
+//!     .expect_jsonrpc(|r| assert!(r.error.is_none()))
+//!     .expect_jsonrpc_result(json!({"balance": 30}))
+//!     .expect_jsonrpc_error(-32601)
+//!     .expect_jsonrpc_id(1)
+//!     .expect_jsonrpc_batch(|b| assert!(b.entry(1).is_some()))
+//!     .expect_jsonrpc_batch_result(1, json!({"balance": 30}))
+//!     .expect_jsonrpc_batch_error(2, -32601)
+//!     // capture
+//!     .capture_header("x-request-id", &mut String::new())
+//!     .capture_json_path("$.items[0].id", &mut 0u64)
+//!     .extract_json::<Value>()
+//!     // snapshot
+//!     .expect_snapshot("create_user")
+//!     .expect_snapshot_with("create_user", SnapshotSettings::default().redact_json_path("$.id"));
 //! }
 //! ```
 //!
@@ -332,14 +441,40 @@
 pub use {
     accessor::AllAccessors,
     body::infallible::AsserhttpBody,
+    cache_control::{infallible::AsserhttpCacheControl, CacheControl},
+    capture::infallible::AsserhttpCapture,
+    conditional::{infallible::AsserhttpConditional, EntityTag},
+    content_disposition::{infallible::AsserhttpContentDisposition, ContentDisposition},
+    cookie::{infallible::AsserhttpCookie, Cookie},
+    cors::infallible::AsserhttpCors,
+    csp::{infallible::AsserhttpCsp, Csp, CspDirective},
     error::{AsserhttpError, AsserhttpResult},
-    header::{infallible::AsserhttpHeader, key::HeaderKey, value::HeaderValue, values::HeaderValues},
+    graphql::{infallible::AsserhttpGraphql, GraphqlError, GraphqlLocation, GraphqlResponse},
+    header::{date::RetryAfter, infallible::AsserhttpHeader, key::HeaderKey, value::HeaderValue, values::HeaderValues},
     http_types::{headers, StatusCode as Status},
+    jsonrpc::{infallible::AsserhttpJsonRpc, JsonRpcBatch, JsonRpcError, JsonRpcResponse},
+    link::{infallible::AsserhttpLink, Link},
+    problem::{infallible::AsserhttpProblem, ProblemDetails},
+    range::infallible::AsserhttpRange,
+    rate_limit::{infallible::AsserhttpRateLimit, RateLimit},
+    redirect::infallible::AsserhttpRedirect,
+    security::{infallible::AsserhttpSecurityHeaders, SecurityHeadersPolicy},
+    snapshot::{infallible::AsserhttpSnapshot, SnapshotSettings},
     status::infallible::AsserhttpStatus,
+    url::Url,
 };
 
 #[cfg(feature = "fallible")]
-pub use {body::fallible::FallibleAsserhttpBody, header::fallible::FallibleAsserhttpHeader, status::fallible::FallibleAsserhttpStatus};
+pub use {
+    body::fallible::FallibleAsserhttpBody, cache_control::fallible::FallibleAsserhttpCacheControl,
+    capture::fallible::FallibleAsserhttpCapture, conditional::fallible::FallibleAsserhttpConditional,
+    content_disposition::fallible::FallibleAsserhttpContentDisposition, cookie::fallible::FallibleAsserhttpCookie,
+    cors::fallible::FallibleAsserhttpCors, csp::fallible::FallibleAsserhttpCsp, graphql::fallible::FallibleAsserhttpGraphql,
+    header::fallible::FallibleAsserhttpHeader, jsonrpc::fallible::FallibleAsserhttpJsonRpc, link::fallible::FallibleAsserhttpLink,
+    problem::fallible::FallibleAsserhttpProblem, range::fallible::FallibleAsserhttpRange, rate_limit::fallible::FallibleAsserhttpRateLimit,
+    redirect::fallible::FallibleAsserhttpRedirect, security::fallible::FallibleAsserhttpSecurityHeaders,
+    snapshot::fallible::FallibleAsserhttpSnapshot, status::fallible::FallibleAsserhttpStatus,
+};
 
 #[cfg(feature = "actix")]
 mod assert_actix;
@@ -366,12 +501,49 @@ pub mod grpc;
 
 mod accessor;
 mod body;
+mod cache_control;
+mod capture;
+mod conditional;
+mod content_disposition;
+mod cookie;
+mod cors;
+mod csp;
+mod graphql;
 mod header;
+mod jsonrpc;
+mod link;
+mod problem;
+mod range;
+mod rate_limit;
+mod redirect;
+mod security;
+mod snapshot;
 mod status;
 
 /// For assertions on http response
 #[cfg(not(feature = "fallible"))]
-pub trait Asserhttp<T>: AsserhttpStatus<T> + AsserhttpHeader<T> + AsserhttpBody<T> {}
+pub trait Asserhttp<T>:
+    AsserhttpStatus<T>
+    + AsserhttpHeader<T>
+    + AsserhttpBody<T>
+    + AsserhttpProblem<T>
+    + AsserhttpGraphql<T>
+    + AsserhttpJsonRpc<T>
+    + AsserhttpCapture<T>
+    + AsserhttpSnapshot<T>
+    + AsserhttpCacheControl<T>
+    + AsserhttpCookie<T>
+    + AsserhttpSecurityHeaders<T>
+    + AsserhttpCsp<T>
+    + AsserhttpCors<T>
+    + AsserhttpRedirect<T>
+    + AsserhttpConditional<T>
+    + AsserhttpLink<T>
+    + AsserhttpContentDisposition<T>
+    + AsserhttpRange<T>
+    + AsserhttpRateLimit<T>
+{
+}
 
 #[cfg(feature = "fallible")]
 pub trait Asserhttp<T>:
@@ -381,6 +553,38 @@ pub trait Asserhttp<T>:
     + FallibleAsserhttpHeader<T>
     + AsserhttpBody<T>
     + FallibleAsserhttpBody<T>
+    + AsserhttpProblem<T>
+    + FallibleAsserhttpProblem<T>
+    + AsserhttpGraphql<T>
+    + FallibleAsserhttpGraphql<T>
+    + AsserhttpJsonRpc<T>
+    + FallibleAsserhttpJsonRpc<T>
+    + AsserhttpCapture<T>
+    + FallibleAsserhttpCapture<T>
+    + AsserhttpSnapshot<T>
+    + FallibleAsserhttpSnapshot<T>
+    + AsserhttpCacheControl<T>
+    + FallibleAsserhttpCacheControl<T>
+    + AsserhttpCookie<T>
+    + FallibleAsserhttpCookie<T>
+    + AsserhttpSecurityHeaders<T>
+    + FallibleAsserhttpSecurityHeaders<T>
+    + AsserhttpCsp<T>
+    + FallibleAsserhttpCsp<T>
+    + AsserhttpCors<T>
+    + FallibleAsserhttpCors<T>
+    + AsserhttpRedirect<T>
+    + FallibleAsserhttpRedirect<T>
+    + AsserhttpConditional<T>
+    + FallibleAsserhttpConditional<T>
+    + AsserhttpLink<T>
+    + FallibleAsserhttpLink<T>
+    + AsserhttpContentDisposition<T>
+    + FallibleAsserhttpContentDisposition<T>
+    + AsserhttpRange<T>
+    + FallibleAsserhttpRange<T>
+    + AsserhttpRateLimit<T>
+    + FallibleAsserhttpRateLimit<T>
 {
 }
 
@@ -0,0 +1,220 @@
+use url::Url;
+
+use crate::{
+    accessor::HeaderAccessor,
+    error::{AsserhttpError, AsserhttpResult},
+    header::key::HeaderKey,
+    link::{links, pagination_violations, Link, LINK},
+};
+
+/// For assertions on the links of the `Link` header returning an error instead of panicking
+pub trait FallibleAsserhttpLink<T> {
+    /// Allows verifying the target of the first link with the relation type in a closure, resolved against
+    /// `http://localhost/` when relative
+    /// * `rel` - relation type e.g. `next`, compared ignoring case
+    /// * `asserter` - closure to verify the link target
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use ureq::OrAnyStatus;
+    /// use asserhttp::*;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     reqwest::blocking::get("http://localhost").try_expect_link("next", |url| { assert_eq!(url.query(), Some("page=3")); Ok(()) }).unwrap();
+    ///     reqwest::get("http://localhost").await.try_expect_link("next", |url| { assert_eq!(url.query(), Some("page=3")); Ok(()) }).unwrap();
+    ///
+    ///     isahc::get("http://localhost").try_expect_link("next", |url| { assert_eq!(url.query(), Some("page=3")); Ok(()) }).unwrap();
+    ///     isahc::get_async("http://localhost").await.try_expect_link("next", |url| { assert_eq!(url.query(), Some("page=3")); Ok(()) }).unwrap();
+    ///
+    ///     surf::get("http://localhost").await.try_expect_link("next", |url| { assert_eq!(url.query(), Some("page=3")); Ok(()) }).unwrap();
+    ///
+    ///     ureq::get("http://localhost").call().or_any_status().try_expect_link("next", |url| { assert_eq!(url.query(), Some("page=3")); Ok(()) }).unwrap();
+    ///
+    ///     hyper::Client::new().get("http://localhost".parse().unwrap()).await.try_expect_link("next", |url| { assert_eq!(url.query(), Some("page=3")); Ok(()) }).unwrap();
+    ///
+    ///     awc::Client::default().get("http://localhost").send().await.try_expect_link("next", |url| { assert_eq!(url.query(), Some("page=3")); Ok(()) }).unwrap();
+    /// }
+    /// ```
+    /// # Error
+    /// * [AsserhttpError::InvalidLink] when the `Link` header cannot be parsed
+    /// * [AsserhttpError::LinkAbsent] when no link has this relation type
+    fn try_expect_link<F>(&mut self, rel: impl AsRef<str>, asserter: F) -> AsserhttpResult<&mut T>
+    where
+        F: FnOnce(Url) -> AsserhttpResult<()>;
+
+    /// Expects no link with the relation type, as for the `next` link of the last page
+    /// * `rel` - relation type e.g. `next`, compared ignoring case
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use ureq::OrAnyStatus;
+    /// use asserhttp::*;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     reqwest::blocking::get("http://localhost").try_expect_no_link("next").unwrap();
+    ///     reqwest::get("http://localhost").await.try_expect_no_link("next").unwrap();
+    ///
+    ///     isahc::get("http://localhost").try_expect_no_link("next").unwrap();
+    ///     isahc::get_async("http://localhost").await.try_expect_no_link("next").unwrap();
+    ///
+    ///     surf::get("http://localhost").await.try_expect_no_link("next").unwrap();
+    ///
+    ///     ureq::get("http://localhost").call().or_any_status().try_expect_no_link("next").unwrap();
+    ///
+    ///     hyper::Client::new().get("http://localhost".parse().unwrap()).await.try_expect_no_link("next").unwrap();
+    ///
+    ///     awc::Client::default().get("http://localhost").send().await.try_expect_no_link("next").unwrap();
+    /// }
+    /// ```
+    /// # Error
+    /// * [AsserhttpError::InvalidLink] when the `Link` header cannot be parsed
+    /// * [AsserhttpError::LinkPresent] when a link has this relation type
+    fn try_expect_no_link(&mut self, rel: impl AsRef<str>) -> AsserhttpResult<&mut T>;
+
+    /// Allows verifying every link of the `Link` headers in a closure, with their parameters
+    /// * `asserter` - closure to verify the links
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use ureq::OrAnyStatus;
+    /// use asserhttp::*;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     reqwest::blocking::get("http://localhost").try_expect_links(|links| { assert!(links.iter().any(|l| l.has_rel("last"))); Ok(()) }).unwrap();
+    ///     reqwest::get("http://localhost").await.try_expect_links(|links| { assert!(links.iter().any(|l| l.has_rel("last"))); Ok(()) }).unwrap();
+    ///
+    ///     isahc::get("http://localhost").try_expect_links(|links| { assert!(links.iter().any(|l| l.has_rel("last"))); Ok(()) }).unwrap();
+    ///     isahc::get_async("http://localhost").await.try_expect_links(|links| { assert!(links.iter().any(|l| l.has_rel("last"))); Ok(()) }).unwrap();
+    ///
+    ///     surf::get("http://localhost").await.try_expect_links(|links| { assert!(links.iter().any(|l| l.has_rel("last"))); Ok(()) }).unwrap();
+    ///
+    ///     ureq::get("http://localhost").call().or_any_status().try_expect_links(|links| { assert!(links.iter().any(|l| l.has_rel("last"))); Ok(()) }).unwrap();
+    ///
+    ///     hyper::Client::new().get("http://localhost".parse().unwrap()).await.try_expect_links(|links| { assert!(links.iter().any(|l| l.has_rel("last"))); Ok(()) }).unwrap();
+    ///
+    ///     awc::Client::default().get("http://localhost").send().await.try_expect_links(|links| { assert!(links.iter().any(|l| l.has_rel("last"))); Ok(()) }).unwrap();
+    /// }
+    /// ```
+    /// # Error
+    /// * [AsserhttpError::HeaderAbsent] when there is no `Link` header
+    /// * [AsserhttpError::InvalidLink] when the `Link` header cannot be parsed
+    fn try_expect_links<F>(&mut self, asserter: F) -> AsserhttpResult<&mut T>
+    where
+        F: FnOnce(Vec<Link>) -> AsserhttpResult<()>;
+
+    /// Expects the `first`, `prev`, `next` and `last` links to be consistent: each appears at most once, all
+    /// target the same resource and the query parameters numbering pages, like `page` or `offset`, are in page order
+    /// A relative link is assumed to share the origin of the absolute ones, so only its path is compared
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use ureq::OrAnyStatus;
+    /// use asserhttp::*;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     reqwest::blocking::get("http://localhost").try_expect_pagination_consistent().unwrap();
+    ///     reqwest::get("http://localhost").await.try_expect_pagination_consistent().unwrap();
+    ///
+    ///     isahc::get("http://localhost").try_expect_pagination_consistent().unwrap();
+    ///     isahc::get_async("http://localhost").await.try_expect_pagination_consistent().unwrap();
+    ///
+    ///     surf::get("http://localhost").await.try_expect_pagination_consistent().unwrap();
+    ///
+    ///     ureq::get("http://localhost").call().or_any_status().try_expect_pagination_consistent().unwrap();
+    ///
+    ///     hyper::Client::new().get("http://localhost".parse().unwrap()).await.try_expect_pagination_consistent().unwrap();
+    ///
+    ///     awc::Client::default().get("http://localhost").send().await.try_expect_pagination_consistent().unwrap();
+    /// }
+    /// ```
+    /// # Error
+    /// * [AsserhttpError::InvalidLink] when the `Link` header cannot be parsed
+    /// * [AsserhttpError::PaginationInconsistent] listing every inconsistency, or when there is no pagination link at all
+    fn try_expect_pagination_consistent(&mut self) -> AsserhttpResult<&mut T>;
+}
+
+impl<T> FallibleAsserhttpLink<T> for T
+where
+    T: HeaderAccessor,
+{
+    fn try_expect_link<F>(&mut self, rel: impl AsRef<str>, asserter: F) -> AsserhttpResult<&mut T>
+    where
+        F: FnOnce(Url) -> AsserhttpResult<()>,
+    {
+        let rel = rel.as_ref();
+        let link = links(self)?
+            .into_iter()
+            .find(|l| l.has_rel(rel))
+            .ok_or_else(|| AsserhttpError::LinkAbsent { rel: rel.to_string() })?;
+        asserter(link.url)?;
+        Ok(self)
+    }
+
+    fn try_expect_no_link(&mut self, rel: impl AsRef<str>) -> AsserhttpResult<&mut T> {
+        let rel = rel.as_ref();
+        if let Some(link) = links(self)?.into_iter().find(|l| l.has_rel(rel)) {
+            return Err(AsserhttpError::LinkPresent {
+                rel: rel.to_string(),
+                actual: link.url.to_string(),
+            });
+        }
+        Ok(self)
+    }
+
+    fn try_expect_links<F>(&mut self, asserter: F) -> AsserhttpResult<&mut T>
+    where
+        F: FnOnce(Vec<Link>) -> AsserhttpResult<()>,
+    {
+        HeaderKey::from(LINK).try_assert_contained(self.get_keys())?;
+        asserter(links(self)?)?;
+        Ok(self)
+    }
+
+    fn try_expect_pagination_consistent(&mut self) -> AsserhttpResult<&mut T> {
+        let violations = pagination_violations(&links(self)?);
+        if !violations.is_empty() {
+            return Err(AsserhttpError::PaginationInconsistent { violations });
+        }
+        Ok(self)
+    }
+}
+
+impl<T, E> FallibleAsserhttpLink<T> for Result<T, E>
+where
+    T: HeaderAccessor,
+    E: std::fmt::Debug,
+{
+    fn try_expect_link<F>(&mut self, rel: impl AsRef<str>, asserter: F) -> AsserhttpResult<&mut T>
+    where
+        F: FnOnce(Url) -> AsserhttpResult<()>,
+    {
+        self.as_mut()
+            .map_err(|e| AsserhttpError::HttpError(format!("{e:?}")))?
+            .try_expect_link(rel, asserter)
+    }
+
+    fn try_expect_no_link(&mut self, rel: impl AsRef<str>) -> AsserhttpResult<&mut T> {
+        self.as_mut()
+            .map_err(|e| AsserhttpError::HttpError(format!("{e:?}")))?
+            .try_expect_no_link(rel)
+    }
+
+    fn try_expect_links<F>(&mut self, asserter: F) -> AsserhttpResult<&mut T>
+    where
+        F: FnOnce(Vec<Link>) -> AsserhttpResult<()>,
+    {
+        self.as_mut()
+            .map_err(|e| AsserhttpError::HttpError(format!("{e:?}")))?
+            .try_expect_links(asserter)
+    }
+
+    fn try_expect_pagination_consistent(&mut self) -> AsserhttpResult<&mut T> {
+        self.as_mut()
+            .map_err(|e| AsserhttpError::HttpError(format!("{e:?}")))?
+            .try_expect_pagination_consistent()
+    }
+}
@@ -0,0 +1,199 @@
+use url::Url;
+
+use crate::{
+    accessor::HeaderAccessor,
+    link::{fallible::FallibleAsserhttpLink, Link},
+};
+
+/// For assertions on the links of the `Link` header
+pub trait AsserhttpLink<T> {
+    /// Allows verifying the target of the first link with the relation type in a closure, resolved against
+    /// `http://localhost/` when relative
+    /// * `rel` - relation type e.g. `next`, compared ignoring case
+    /// * `asserter` - closure to verify the link target
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use ureq::OrAnyStatus;
+    /// use asserhttp::*;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     reqwest::blocking::get("http://localhost").expect_link("next", |url| assert_eq!(url.query(), Some("page=3")));
+    ///     reqwest::get("http://localhost").await.expect_link("next", |url| assert_eq!(url.query(), Some("page=3")));
+    ///
+    ///     isahc::get("http://localhost").expect_link("next", |url| assert_eq!(url.query(), Some("page=3")));
+    ///     isahc::get_async("http://localhost").await.expect_link("next", |url| assert_eq!(url.query(), Some("page=3")));
+    ///
+    ///     surf::get("http://localhost").await.expect_link("next", |url| assert_eq!(url.query(), Some("page=3")));
+    ///
+    ///     ureq::get("http://localhost").call().or_any_status().expect_link("next", |url| assert_eq!(url.query(), Some("page=3")));
+    ///
+    ///     hyper::Client::new().get("http://localhost".parse().unwrap()).await.expect_link("next", |url| assert_eq!(url.query(), Some("page=3")));
+    ///
+    ///     awc::Client::default().get("http://localhost").send().await.expect_link("next", |url| assert_eq!(url.query(), Some("page=3")));
+    /// }
+    /// ```
+    fn expect_link<F>(&mut self, rel: impl AsRef<str>, asserter: F) -> &mut T
+    where
+        F: FnOnce(Url);
+
+    /// Expects no link with the relation type, as for the `next` link of the last page
+    /// * `rel` - relation type e.g. `next`, compared ignoring case
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use ureq::OrAnyStatus;
+    /// use asserhttp::*;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     reqwest::blocking::get("http://localhost").expect_no_link("next");
+    ///     reqwest::get("http://localhost").await.expect_no_link("next");
+    ///
+    ///     isahc::get("http://localhost").expect_no_link("next");
+    ///     isahc::get_async("http://localhost").await.expect_no_link("next");
+    ///
+    ///     surf::get("http://localhost").await.expect_no_link("next");
+    ///
+    ///     ureq::get("http://localhost").call().or_any_status().expect_no_link("next");
+    ///
+    ///     hyper::Client::new().get("http://localhost".parse().unwrap()).await.expect_no_link("next");
+    ///
+    ///     awc::Client::default().get("http://localhost").send().await.expect_no_link("next");
+    /// }
+    /// ```
+    fn expect_no_link(&mut self, rel: impl AsRef<str>) -> &mut T;
+
+    /// Allows verifying every link of the `Link` headers in a closure, with their parameters
+    /// * `asserter` - closure to verify the links
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use ureq::OrAnyStatus;
+    /// use asserhttp::*;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     reqwest::blocking::get("http://localhost").expect_links(|links| assert!(links.iter().any(|l| l.has_rel("last"))));
+    ///     reqwest::get("http://localhost").await.expect_links(|links| assert!(links.iter().any(|l| l.has_rel("last"))));
+    ///
+    ///     isahc::get("http://localhost").expect_links(|links| assert!(links.iter().any(|l| l.has_rel("last"))));
+    ///     isahc::get_async("http://localhost").await.expect_links(|links| assert!(links.iter().any(|l| l.has_rel("last"))));
+    ///
+    ///     surf::get("http://localhost").await.expect_links(|links| assert!(links.iter().any(|l| l.has_rel("last"))));
+    ///
+    ///     ureq::get("http://localhost").call().or_any_status().expect_links(|links| assert!(links.iter().any(|l| l.has_rel("last"))));
+    ///
+    ///     hyper::Client::new().get("http://localhost".parse().unwrap()).await.expect_links(|links| assert!(links.iter().any(|l| l.has_rel("last"))));
+    ///
+    ///     awc::Client::default().get("http://localhost").send().await.expect_links(|links| assert!(links.iter().any(|l| l.has_rel("last"))));
+    /// }
+    /// ```
+    fn expect_links<F>(&mut self, asserter: F) -> &mut T
+    where
+        F: FnOnce(Vec<Link>);
+
+    /// Expects the `first`, `prev`, `next` and `last` links to be consistent: each appears at most once, all
+    /// target the same resource and the query parameters numbering pages, like `page` or `offset`, are in page order
+    /// A relative link is assumed to share the origin of the absolute ones, so only its path is compared
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use ureq::OrAnyStatus;
+    /// use asserhttp::*;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     reqwest::blocking::get("http://localhost").expect_pagination_consistent();
+    ///     reqwest::get("http://localhost").await.expect_pagination_consistent();
+    ///
+    ///     isahc::get("http://localhost").expect_pagination_consistent();
+    ///     isahc::get_async("http://localhost").await.expect_pagination_consistent();
+    ///
+    ///     surf::get("http://localhost").await.expect_pagination_consistent();
+    ///
+    ///     ureq::get("http://localhost").call().or_any_status().expect_pagination_consistent();
+    ///
+    ///     hyper::Client::new().get("http://localhost".parse().unwrap()).await.expect_pagination_consistent();
+    ///
+    ///     awc::Client::default().get("http://localhost").send().await.expect_pagination_consistent();
+    /// }
+    /// ```
+    fn expect_pagination_consistent(&mut self) -> &mut T;
+}
+
+impl<T> AsserhttpLink<T> for T
+where
+    T: HeaderAccessor,
+{
+    fn expect_link<F>(&mut self, rel: impl AsRef<str>, asserter: F) -> &mut T
+    where
+        F: FnOnce(Url),
+    {
+        #[allow(clippy::blocks_in_conditions)]
+        match self.try_expect_link(rel, |v| {
+            asserter(v);
+            Ok(())
+        }) {
+            Err(e) => panic!("{e}"),
+            Ok(r) => r,
+        }
+    }
+
+    fn expect_no_link(&mut self, rel: impl AsRef<str>) -> &mut T {
+        match self.try_expect_no_link(rel) {
+            Err(e) => panic!("{e}"),
+            Ok(r) => r,
+        }
+    }
+
+    fn expect_links<F>(&mut self, asserter: F) -> &mut T
+    where
+        F: FnOnce(Vec<Link>),
+    {
+        #[allow(clippy::blocks_in_conditions)]
+        match self.try_expect_links(|v| {
+            asserter(v);
+            Ok(())
+        }) {
+            Err(e) => panic!("{e}"),
+            Ok(r) => r,
+        }
+    }
+
+    fn expect_pagination_consistent(&mut self) -> &mut T {
+        match self.try_expect_pagination_consistent() {
+            Err(e) => panic!("{e}"),
+            Ok(r) => r,
+        }
+    }
+}
+
+impl<T, E> AsserhttpLink<T> for Result<T, E>
+where
+    T: HeaderAccessor,
+    E: std::fmt::Debug,
+{
+    fn expect_link<F>(&mut self, rel: impl AsRef<str>, asserter: F) -> &mut T
+    where
+        F: FnOnce(Url),
+    {
+        self.as_mut().unwrap().expect_link(rel, asserter)
+    }
+
+    fn expect_no_link(&mut self, rel: impl AsRef<str>) -> &mut T {
+        self.as_mut().unwrap().expect_no_link(rel)
+    }
+
+    fn expect_links<F>(&mut self, asserter: F) -> &mut T
+    where
+        F: FnOnce(Vec<Link>),
+    {
+        self.as_mut().unwrap().expect_links(asserter)
+    }
+
+    fn expect_pagination_consistent(&mut self) -> &mut T {
+        self.as_mut().unwrap().expect_pagination_consistent()
+    }
+}
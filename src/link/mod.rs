@@ -0,0 +1,176 @@
+use std::collections::BTreeMap;
+
+use url::Url;
+
+use crate::{
+    accessor::HeaderAccessor,
+    error::{AsserhttpError, AsserhttpResult},
+    header::{
+        key::HeaderKey,
+        quoted::{split_unquoted, unquote},
+    },
+    redirect,
+};
+
+pub(crate) mod fallible;
+pub(crate) mod infallible;
+
+/// Not among the `http_types` header constants
+pub(crate) const LINK: &str = "link";
+
+/// Relations of the links of a paginated collection, in page order
+const PAGINATION_RELS: [&str; 4] = ["first", "prev", "next", "last"];
+
+/// A link of a [Link](https://www.rfc-editor.org/rfc/rfc8288#section-3) header, with its target resolved against
+/// `http://localhost/` when relative
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Link {
+    pub url: Url,
+    /// Target as sent, which `url` resolves when relative
+    pub target: String,
+    /// Relation types, lowercased since they are compared ignoring case
+    pub rel: Vec<String>,
+    /// `type` parameter, hinting the media type of the target
+    pub media_type: Option<String>,
+    pub title: Option<String>,
+    /// Every parameter, keyed by lowercased name, including `rel`, `type` and `title`
+    pub params: BTreeMap<String, String>,
+}
+
+impl Link {
+    /// Parses a single `<uri-reference>; param=value; ...` link-value
+    fn parse(value: &str) -> AsserhttpResult<Self> {
+        let invalid = || AsserhttpError::InvalidLink {
+            value: value.trim().to_string(),
+        };
+        let (target, params) = value
+            .trim()
+            .strip_prefix('<')
+            .and_then(|v| v.split_once('>'))
+            .ok_or_else(invalid)?;
+        let mut link = Self {
+            url: redirect::parse(target.trim())?,
+            target: target.trim().to_string(),
+            rel: vec![],
+            media_type: None,
+            title: None,
+            params: BTreeMap::new(),
+        };
+        for param in split_unquoted(params, ';').into_iter().filter(|p| !p.trim().is_empty()) {
+            let (name, value) = param.split_once('=').unwrap_or((param, ""));
+            let name = name.trim().to_lowercase();
+            if name.is_empty() {
+                return Err(invalid());
+            }
+            // occurrences after the first one must be ignored
+            link.params.entry(name).or_insert_with(|| unquote(value.trim()));
+        }
+        link.rel = link
+            .params
+            .get("rel")
+            .map(|rel| rel.split_whitespace().map(str::to_lowercase).collect())
+            .unwrap_or_default();
+        link.media_type = link.params.get("type").cloned();
+        link.title = link.params.get("title").cloned();
+        Ok(link)
+    }
+
+    /// Whether this link has the relation type, ignoring case
+    pub fn has_rel(&self, rel: &str) -> bool {
+        self.rel.iter().any(|r| r.eq_ignore_ascii_case(rel))
+    }
+
+    /// Whether the target is a relative reference, in which case the origin of `url` is made up
+    pub fn is_relative(&self) -> bool {
+        Url::parse(&self.target).is_err()
+    }
+}
+
+/// Every link of every `Link` header, in order
+pub(crate) fn links(accessor: &impl HeaderAccessor) -> AsserhttpResult<Vec<Link>> {
+    accessor
+        .get_raw_values(&HeaderKey::from(LINK))
+        .iter()
+        .flat_map(|value| split_unquoted(value, ','))
+        .filter(|link| !link.trim().is_empty())
+        .map(Link::parse)
+        .collect()
+}
+
+/// Every inconsistency between the `first`, `prev`, `next` and `last` links, in a stable order
+pub(crate) fn pagination_violations(links: &[Link]) -> Vec<String> {
+    let mut violations = vec![];
+    let mut pages = vec![];
+    for rel in PAGINATION_RELS {
+        let matching = links.iter().filter(|l| l.has_rel(rel)).collect::<Vec<_>>();
+        if matching.len() > 1 {
+            violations.push(format!("'{rel}' link appears {} times", matching.len()));
+        }
+        if let Some(link) = matching.first() {
+            pages.push((rel, *link));
+        }
+    }
+    let Some((reference_rel, reference)) = pages.first().copied() else {
+        return vec!["no 'first', 'prev', 'next' nor 'last' link".to_string()];
+    };
+    // a relative link is compared by path only, as it shares the origin of the absolute ones
+    let origin = pages.iter().find(|(_, l)| !l.is_relative()).map(|(_, l)| l.url.origin());
+    for (rel, link) in &pages[1..] {
+        let other_origin = !link.is_relative() && origin.as_ref().is_some_and(|o| *o != link.url.origin());
+        if other_origin || link.url.path() != reference.url.path() {
+            violations.push(format!(
+                "'{rel}' link '{}' targets another resource than '{reference_rel}' link '{}'",
+                link.target, reference.target
+            ));
+        }
+    }
+    for param in page_params(&pages) {
+        let position = |link: &Link| {
+            link.url
+                .query_pairs()
+                .find(|(k, _)| *k == param)
+                .and_then(|(_, v)| v.parse::<u64>().ok())
+        };
+        for (i, (before_rel, before)) in pages.iter().enumerate() {
+            for (after_rel, after) in &pages[i + 1..] {
+                let (Some(b), Some(a)) = (position(before), position(after)) else {
+                    continue;
+                };
+                // the previous and next pages surround the current one, any other pair may be the same page
+                let strict = *before_rel == "prev" && *after_rel == "next";
+                if b > a || (strict && b == a) {
+                    violations.push(format!(
+                        "'{before_rel}' link has {param}={b} which does not come before {param}={a} of '{after_rel}' link"
+                    ));
+                }
+            }
+        }
+    }
+    violations
+}
+
+/// Query parameters holding an integer in every pagination link, like `page` or `offset`, and varying between them
+fn page_params(pages: &[(&str, &Link)]) -> Vec<String> {
+    let numeric = |link: &Link| {
+        link.url
+            .query_pairs()
+            .filter_map(|(k, v)| v.parse::<u64>().ok().map(|v| (k.to_string(), v)))
+            .collect::<BTreeMap<_, _>>()
+    };
+    let mut candidates = pages.first().map(|(_, link)| numeric(link)).unwrap_or_default();
+    let mut varying = vec![];
+    for (_, link) in &pages[1..] {
+        let params = numeric(link);
+        candidates.retain(|k, v| match params.get(k) {
+            Some(other) => {
+                if other != v && !varying.contains(k) {
+                    varying.push(k.clone());
+                }
+                true
+            },
+            None => false,
+        });
+    }
+    varying.retain(|k| candidates.contains_key(k));
+    varying
+}
@@ -0,0 +1,255 @@
+use crate::{
+    accessor::{BodyAccessor, StatusAccessor},
+    error::{AsserhttpError, AsserhttpResult},
+    problem::ProblemDetails,
+};
+
+/// For assertions on [RFC 9457](https://www.rfc-editor.org/rfc/rfc9457) problem details returning an error instead of panicking
+pub trait FallibleAsserhttpProblem<T> {
+    /// Allows verifying a problem details body in a closure
+    /// * `asserter` - closure to verify problem details
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use ureq::OrAnyStatus;
+    /// use asserhttp::*;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     reqwest::blocking::get("http://localhost").try_expect_problem(|p| { assert_eq!(p.status, Some(404)); Ok(()) }).unwrap();
+    ///     reqwest::get("http://localhost").await.try_expect_problem(|p| { assert_eq!(p.status, Some(404)); Ok(()) }).unwrap();
+    ///
+    ///     isahc::get("http://localhost").try_expect_problem(|p| { assert_eq!(p.status, Some(404)); Ok(()) }).unwrap();
+    ///     isahc::get_async("http://localhost").await.try_expect_problem(|p| { assert_eq!(p.status, Some(404)); Ok(()) }).unwrap();
+    ///
+    ///     surf::get("http://localhost").await.try_expect_problem(|p| { assert_eq!(p.status, Some(404)); Ok(()) }).unwrap();
+    ///
+    ///     ureq::get("http://localhost").call().or_any_status().try_expect_problem(|p| { assert_eq!(p.status, Some(404)); Ok(()) }).unwrap();
+    ///
+    ///     hyper::Client::new().get("http://localhost".parse().unwrap()).await.try_expect_problem(|p| { assert_eq!(p.status, Some(404)); Ok(()) }).unwrap();
+    ///
+    ///     awc::Client::default().get("http://localhost").send().await.try_expect_problem(|p| { assert_eq!(p.status, Some(404)); Ok(()) }).unwrap();
+    /// }
+    /// ```
+    /// # Error
+    /// * [AsserhttpError::BodyAbsent] when the response body is empty
+    fn try_expect_problem<F>(&mut self, asserter: F) -> AsserhttpResult<&mut T>
+    where
+        F: FnOnce(ProblemDetails) -> AsserhttpResult<()>;
+
+    /// Expects response body to be a problem details with the given type
+    /// * `problem_type` - expected 'type' member
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use ureq::OrAnyStatus;
+    /// use asserhttp::*;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     reqwest::blocking::get("http://localhost").try_expect_problem_type("https://example.com/probs/out-of-credit").unwrap();
+    ///     reqwest::get("http://localhost").await.try_expect_problem_type("https://example.com/probs/out-of-credit").unwrap();
+    ///
+    ///     isahc::get("http://localhost").try_expect_problem_type("https://example.com/probs/out-of-credit").unwrap();
+    ///     isahc::get_async("http://localhost").await.try_expect_problem_type("https://example.com/probs/out-of-credit").unwrap();
+    ///
+    ///     surf::get("http://localhost").await.try_expect_problem_type("https://example.com/probs/out-of-credit").unwrap();
+    ///
+    ///     ureq::get("http://localhost").call().or_any_status().try_expect_problem_type("https://example.com/probs/out-of-credit").unwrap();
+    ///
+    ///     hyper::Client::new().get("http://localhost".parse().unwrap()).await.try_expect_problem_type("https://example.com/probs/out-of-credit").unwrap();
+    ///
+    ///     awc::Client::default().get("http://localhost").send().await.try_expect_problem_type("https://example.com/probs/out-of-credit").unwrap();
+    /// }
+    /// ```
+    /// # Error
+    /// * [AsserhttpError::BodyAbsent] when the response body is empty
+    /// * [AsserhttpError::ProblemStatusMismatch] when the 'status' member differs from the response status
+    /// * [AsserhttpError::ProblemTypeMismatch] when the 'type' member is different from the expected one
+    fn try_expect_problem_type<P>(&mut self, problem_type: P) -> AsserhttpResult<&mut T>
+    where
+        P: Into<String>;
+
+    /// Expects response body to be a problem details with the given title
+    /// * `title` - expected 'title' member
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use ureq::OrAnyStatus;
+    /// use asserhttp::*;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     reqwest::blocking::get("http://localhost").try_expect_problem_title("You do not have enough credit.").unwrap();
+    ///     reqwest::get("http://localhost").await.try_expect_problem_title("You do not have enough credit.").unwrap();
+    ///
+    ///     isahc::get("http://localhost").try_expect_problem_title("You do not have enough credit.").unwrap();
+    ///     isahc::get_async("http://localhost").await.try_expect_problem_title("You do not have enough credit.").unwrap();
+    ///
+    ///     surf::get("http://localhost").await.try_expect_problem_title("You do not have enough credit.").unwrap();
+    ///
+    ///     ureq::get("http://localhost").call().or_any_status().try_expect_problem_title("You do not have enough credit.").unwrap();
+    ///
+    ///     hyper::Client::new().get("http://localhost".parse().unwrap()).await.try_expect_problem_title("You do not have enough credit.").unwrap();
+    ///
+    ///     awc::Client::default().get("http://localhost").send().await.try_expect_problem_title("You do not have enough credit.").unwrap();
+    /// }
+    /// ```
+    /// # Error
+    /// * [AsserhttpError::BodyAbsent] when the response body is empty
+    /// * [AsserhttpError::ProblemStatusMismatch] when the 'status' member differs from the response status
+    /// * [AsserhttpError::ProblemMemberAbsent] when there is no 'title' member
+    /// * [AsserhttpError::ProblemTitleMismatch] when the 'title' member is different from the expected one
+    fn try_expect_problem_title<P>(&mut self, title: P) -> AsserhttpResult<&mut T>
+    where
+        P: Into<String>;
+
+    /// Expects response body to be a problem details whose detail matches the provided regex
+    /// * `regex` - must match the 'detail' member
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use ureq::OrAnyStatus;
+    /// use asserhttp::*;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     reqwest::blocking::get("http://localhost").try_expect_problem_detail_matches("balance is [0-9]+").unwrap();
+    ///     reqwest::get("http://localhost").await.try_expect_problem_detail_matches("balance is [0-9]+").unwrap();
+    ///
+    ///     isahc::get("http://localhost").try_expect_problem_detail_matches("balance is [0-9]+").unwrap();
+    ///     isahc::get_async("http://localhost").await.try_expect_problem_detail_matches("balance is [0-9]+").unwrap();
+    ///
+    ///     surf::get("http://localhost").await.try_expect_problem_detail_matches("balance is [0-9]+").unwrap();
+    ///
+    ///     ureq::get("http://localhost").call().or_any_status().try_expect_problem_detail_matches("balance is [0-9]+").unwrap();
+    ///
+    ///     hyper::Client::new().get("http://localhost".parse().unwrap()).await.try_expect_problem_detail_matches("balance is [0-9]+").unwrap();
+    ///
+    ///     awc::Client::default().get("http://localhost").send().await.try_expect_problem_detail_matches("balance is [0-9]+").unwrap();
+    /// }
+    /// ```
+    /// # Error
+    /// * [AsserhttpError::BodyAbsent] when the response body is empty
+    /// * [AsserhttpError::ProblemStatusMismatch] when the 'status' member differs from the response status
+    /// * [AsserhttpError::ProblemMemberAbsent] when there is no 'detail' member
+    /// * [AsserhttpError::ProblemDetailMismatch] when the 'detail' member does not match the regex
+    /// * [AsserhttpError::RegexError] when the supplied Regex is invalid
+    fn try_expect_problem_detail_matches<R>(&mut self, regex: R) -> AsserhttpResult<&mut T>
+    where
+        R: TryInto<regex::Regex, Error = regex::Error>;
+}
+
+impl<T> FallibleAsserhttpProblem<T> for T
+where
+    T: StatusAccessor + BodyAccessor,
+{
+    fn try_expect_problem<F>(&mut self, asserter: F) -> AsserhttpResult<&mut T>
+    where
+        F: FnOnce(ProblemDetails) -> AsserhttpResult<()>,
+    {
+        asserter(self.get_json()?)?;
+        Ok(self)
+    }
+
+    fn try_expect_problem_type<P>(&mut self, problem_type: P) -> AsserhttpResult<&mut T>
+    where
+        P: Into<String>,
+    {
+        let problem = consistent_problem(self)?;
+        let expected = problem_type.into();
+        if problem.problem_type != expected {
+            return Err(AsserhttpError::ProblemTypeMismatch {
+                actual: problem.problem_type,
+                expected,
+            });
+        }
+        Ok(self)
+    }
+
+    fn try_expect_problem_title<P>(&mut self, title: P) -> AsserhttpResult<&mut T>
+    where
+        P: Into<String>,
+    {
+        let actual = consistent_problem(self)?.title.ok_or(AsserhttpError::ProblemMemberAbsent {
+            member: "title".to_string(),
+        })?;
+        let expected = title.into();
+        if actual != expected {
+            return Err(AsserhttpError::ProblemTitleMismatch { actual, expected });
+        }
+        Ok(self)
+    }
+
+    fn try_expect_problem_detail_matches<R>(&mut self, regex: R) -> AsserhttpResult<&mut T>
+    where
+        R: TryInto<regex::Regex, Error = regex::Error>,
+    {
+        let regex = regex.try_into()?;
+        let actual = consistent_problem(self)?.detail.ok_or(AsserhttpError::ProblemMemberAbsent {
+            member: "detail".to_string(),
+        })?;
+        if !regex.is_match(&actual) {
+            return Err(AsserhttpError::ProblemDetailMismatch {
+                actual,
+                regex: regex.to_string(),
+            });
+        }
+        Ok(self)
+    }
+}
+
+impl<T, E> FallibleAsserhttpProblem<T> for Result<T, E>
+where
+    T: StatusAccessor + BodyAccessor,
+    E: std::fmt::Debug,
+{
+    fn try_expect_problem<F>(&mut self, asserter: F) -> AsserhttpResult<&mut T>
+    where
+        F: FnOnce(ProblemDetails) -> AsserhttpResult<()>,
+    {
+        self.as_mut()
+            .map_err(|e| AsserhttpError::HttpError(format!("{e:?}")))?
+            .try_expect_problem(asserter)
+    }
+
+    fn try_expect_problem_type<P>(&mut self, problem_type: P) -> AsserhttpResult<&mut T>
+    where
+        P: Into<String>,
+    {
+        self.as_mut()
+            .map_err(|e| AsserhttpError::HttpError(format!("{e:?}")))?
+            .try_expect_problem_type(problem_type)
+    }
+
+    fn try_expect_problem_title<P>(&mut self, title: P) -> AsserhttpResult<&mut T>
+    where
+        P: Into<String>,
+    {
+        self.as_mut()
+            .map_err(|e| AsserhttpError::HttpError(format!("{e:?}")))?
+            .try_expect_problem_title(title)
+    }
+
+    fn try_expect_problem_detail_matches<R>(&mut self, regex: R) -> AsserhttpResult<&mut T>
+    where
+        R: TryInto<regex::Regex, Error = regex::Error>,
+    {
+        self.as_mut()
+            .map_err(|e| AsserhttpError::HttpError(format!("{e:?}")))?
+            .try_expect_problem_detail_matches(regex)
+    }
+}
+
+/// Reads the problem details and verifies its (optional) 'status' member agrees with the response status
+fn consistent_problem<T>(response: &mut T) -> AsserhttpResult<ProblemDetails>
+where
+    T: StatusAccessor + BodyAccessor,
+{
+    let problem = response.get_json::<ProblemDetails>()?;
+    let status = response.get_status();
+    match problem.status {
+        Some(member) if member != status => Err(AsserhttpError::ProblemStatusMismatch { status, member }),
+        _ => Ok(problem),
+    }
+}
@@ -0,0 +1,209 @@
+use crate::{
+    accessor::{BodyAccessor, StatusAccessor},
+    problem::{fallible::FallibleAsserhttpProblem, ProblemDetails},
+};
+
+/// For assertions on [RFC 9457](https://www.rfc-editor.org/rfc/rfc9457) problem details
+pub trait AsserhttpProblem<T> {
+    /// Allows verifying a problem details body in a closure
+    /// * `asserter` - closure to verify problem details
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use ureq::OrAnyStatus;
+    /// use asserhttp::*;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     reqwest::blocking::get("http://localhost").expect_problem(|p| assert_eq!(p.status, Some(404)));
+    ///     reqwest::get("http://localhost").await.expect_problem(|p| assert_eq!(p.status, Some(404)));
+    ///
+    ///     isahc::get("http://localhost").expect_problem(|p| assert_eq!(p.status, Some(404)));
+    ///     isahc::get_async("http://localhost").await.expect_problem(|p| assert_eq!(p.status, Some(404)));
+    ///
+    ///     surf::get("http://localhost").await.expect_problem(|p| assert_eq!(p.status, Some(404)));
+    ///
+    ///     ureq::get("http://localhost").call().or_any_status().expect_problem(|p| assert_eq!(p.status, Some(404)));
+    ///
+    ///     hyper::Client::new().get("http://localhost".parse().unwrap()).await.expect_problem(|p| assert_eq!(p.status, Some(404)));
+    ///
+    ///     awc::Client::default().get("http://localhost").send().await.expect_problem(|p| assert_eq!(p.status, Some(404)));
+    /// }
+    /// ```
+    fn expect_problem<F>(&mut self, asserter: F) -> &mut T
+    where
+        F: FnOnce(ProblemDetails);
+
+    /// Expects response body to be a problem details with the given type.
+    /// Also verifies the 'status' member, when present, is the response status
+    /// * `problem_type` - expected 'type' member
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use ureq::OrAnyStatus;
+    /// use asserhttp::*;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     reqwest::blocking::get("http://localhost").expect_problem_type("https://example.com/probs/out-of-credit");
+    ///     reqwest::get("http://localhost").await.expect_problem_type("https://example.com/probs/out-of-credit");
+    ///
+    ///     isahc::get("http://localhost").expect_problem_type("https://example.com/probs/out-of-credit");
+    ///     isahc::get_async("http://localhost").await.expect_problem_type("https://example.com/probs/out-of-credit");
+    ///
+    ///     surf::get("http://localhost").await.expect_problem_type("https://example.com/probs/out-of-credit");
+    ///
+    ///     ureq::get("http://localhost").call().or_any_status().expect_problem_type("https://example.com/probs/out-of-credit");
+    ///
+    ///     hyper::Client::new().get("http://localhost".parse().unwrap()).await.expect_problem_type("https://example.com/probs/out-of-credit");
+    ///
+    ///     awc::Client::default().get("http://localhost").send().await.expect_problem_type("https://example.com/probs/out-of-credit");
+    /// }
+    /// ```
+    fn expect_problem_type<P>(&mut self, problem_type: P) -> &mut T
+    where
+        P: Into<String>;
+
+    /// Expects response body to be a problem details with the given title.
+    /// Also verifies the 'status' member, when present, is the response status
+    /// * `title` - expected 'title' member
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use ureq::OrAnyStatus;
+    /// use asserhttp::*;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     reqwest::blocking::get("http://localhost").expect_problem_title("You do not have enough credit.");
+    ///     reqwest::get("http://localhost").await.expect_problem_title("You do not have enough credit.");
+    ///
+    ///     isahc::get("http://localhost").expect_problem_title("You do not have enough credit.");
+    ///     isahc::get_async("http://localhost").await.expect_problem_title("You do not have enough credit.");
+    ///
+    ///     surf::get("http://localhost").await.expect_problem_title("You do not have enough credit.");
+    ///
+    ///     ureq::get("http://localhost").call().or_any_status().expect_problem_title("You do not have enough credit.");
+    ///
+    ///     hyper::Client::new().get("http://localhost".parse().unwrap()).await.expect_problem_title("You do not have enough credit.");
+    ///
+    ///     awc::Client::default().get("http://localhost").send().await.expect_problem_title("You do not have enough credit.");
+    /// }
+    /// ```
+    fn expect_problem_title<P>(&mut self, title: P) -> &mut T
+    where
+        P: Into<String>;
+
+    /// Expects response body to be a problem details whose detail matches the provided regex.
+    /// Also verifies the 'status' member, when present, is the response status
+    /// * `regex` - must match the 'detail' member
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use ureq::OrAnyStatus;
+    /// use asserhttp::*;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     reqwest::blocking::get("http://localhost").expect_problem_detail_matches("balance is [0-9]+");
+    ///     reqwest::get("http://localhost").await.expect_problem_detail_matches("balance is [0-9]+");
+    ///
+    ///     isahc::get("http://localhost").expect_problem_detail_matches("balance is [0-9]+");
+    ///     isahc::get_async("http://localhost").await.expect_problem_detail_matches("balance is [0-9]+");
+    ///
+    ///     surf::get("http://localhost").await.expect_problem_detail_matches("balance is [0-9]+");
+    ///
+    ///     ureq::get("http://localhost").call().or_any_status().expect_problem_detail_matches("balance is [0-9]+");
+    ///
+    ///     hyper::Client::new().get("http://localhost".parse().unwrap()).await.expect_problem_detail_matches("balance is [0-9]+");
+    ///
+    ///     awc::Client::default().get("http://localhost").send().await.expect_problem_detail_matches("balance is [0-9]+");
+    /// }
+    /// ```
+    fn expect_problem_detail_matches<R>(&mut self, regex: R) -> &mut T
+    where
+        R: TryInto<regex::Regex, Error = regex::Error>;
+}
+
+impl<T> AsserhttpProblem<T> for T
+where
+    T: StatusAccessor + BodyAccessor,
+{
+    fn expect_problem<F>(&mut self, asserter: F) -> &mut T
+    where
+        F: FnOnce(ProblemDetails),
+    {
+        #[allow(clippy::blocks_in_conditions)]
+        match self.try_expect_problem(|p| {
+            asserter(p);
+            Ok(())
+        }) {
+            Err(e) => panic!("{e}"),
+            Ok(r) => r,
+        }
+    }
+
+    fn expect_problem_type<P>(&mut self, problem_type: P) -> &mut T
+    where
+        P: Into<String>,
+    {
+        match self.try_expect_problem_type(problem_type) {
+            Err(e) => panic!("{e}"),
+            Ok(r) => r,
+        }
+    }
+
+    fn expect_problem_title<P>(&mut self, title: P) -> &mut T
+    where
+        P: Into<String>,
+    {
+        match self.try_expect_problem_title(title) {
+            Err(e) => panic!("{e}"),
+            Ok(r) => r,
+        }
+    }
+
+    fn expect_problem_detail_matches<R>(&mut self, regex: R) -> &mut T
+    where
+        R: TryInto<regex::Regex, Error = regex::Error>,
+    {
+        match self.try_expect_problem_detail_matches(regex) {
+            Err(e) => panic!("{e}"),
+            Ok(r) => r,
+        }
+    }
+}
+
+impl<T, E> AsserhttpProblem<T> for Result<T, E>
+where
+    T: StatusAccessor + BodyAccessor,
+    E: std::fmt::Debug,
+{
+    fn expect_problem<F>(&mut self, asserter: F) -> &mut T
+    where
+        F: FnOnce(ProblemDetails),
+    {
+        self.as_mut().unwrap().expect_problem(asserter)
+    }
+
+    fn expect_problem_type<P>(&mut self, problem_type: P) -> &mut T
+    where
+        P: Into<String>,
+    {
+        self.as_mut().unwrap().expect_problem_type(problem_type)
+    }
+
+    fn expect_problem_title<P>(&mut self, title: P) -> &mut T
+    where
+        P: Into<String>,
+    {
+        self.as_mut().unwrap().expect_problem_title(title)
+    }
+
+    fn expect_problem_detail_matches<R>(&mut self, regex: R) -> &mut T
+    where
+        R: TryInto<regex::Regex, Error = regex::Error>,
+    {
+        self.as_mut().unwrap().expect_problem_detail_matches(regex)
+    }
+}
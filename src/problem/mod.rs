@@ -0,0 +1,34 @@
+pub(crate) mod fallible;
+pub(crate) mod infallible;
+
+/// Error payload as defined by [RFC 9457](https://www.rfc-editor.org/rfc/rfc9457)
+/// (Problem Details for HTTP APIs) i.e. `application/problem+json`
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct ProblemDetails {
+    /// URI reference identifying the problem type. Defaults to `about:blank` when absent
+    #[serde(rename = "type", default = "ProblemDetails::about_blank")]
+    pub problem_type: String,
+    /// Short, human-readable summary of the problem type
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    /// Advisory copy of the http status code
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<u16>,
+    /// Human-readable explanation specific to this occurrence of the problem
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub detail: Option<String>,
+    /// URI reference identifying this occurrence of the problem
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub instance: Option<String>,
+    /// Any additional member
+    #[serde(flatten)]
+    pub extensions: serde_json::Map<String, serde_json::Value>,
+}
+
+impl ProblemDetails {
+    pub const ABOUT_BLANK: &'static str = "about:blank";
+
+    fn about_blank() -> String {
+        Self::ABOUT_BLANK.to_string()
+    }
+}
@@ -0,0 +1,174 @@
+use crate::{
+    accessor::{BodyAccessor, HeaderAccessor, StatusAccessor},
+    error::{AsserhttpError, AsserhttpResult},
+    header::key::HeaderKey,
+    headers,
+    range::{range_violations, ContentRange},
+};
+
+/// For assertions on range requests and partial responses returning an error instead of panicking
+pub trait FallibleAsserhttpRange<T> {
+    /// Expects the `Content-Range` header to select this byte range
+    /// * `first` - position of the first byte, inclusive
+    /// * `last` - position of the last byte, inclusive
+    /// * `complete_length` - length of the whole representation, `None` when the server does not know it
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use ureq::OrAnyStatus;
+    /// use asserhttp::*;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     reqwest::blocking::get("http://localhost").try_expect_content_range(0, 499, 1234).unwrap();
+    ///     reqwest::get("http://localhost").await.try_expect_content_range(0, 499, 1234).unwrap();
+    ///
+    ///     isahc::get("http://localhost").try_expect_content_range(0, 499, 1234).unwrap();
+    ///     isahc::get_async("http://localhost").await.try_expect_content_range(0, 499, 1234).unwrap();
+    ///
+    ///     surf::get("http://localhost").await.try_expect_content_range(0, 499, 1234).unwrap();
+    ///
+    ///     ureq::get("http://localhost").call().or_any_status().try_expect_content_range(0, 499, 1234).unwrap();
+    ///
+    ///     hyper::Client::new().get("http://localhost".parse().unwrap()).await.try_expect_content_range(0, 499, 1234).unwrap();
+    ///
+    ///     awc::Client::default().get("http://localhost").send().await.try_expect_content_range(0, 499, 1234).unwrap();
+    /// }
+    /// ```
+    /// # Error
+    /// * [AsserhttpError::HeaderAbsent] when there is no `Content-Range` header
+    /// * [AsserhttpError::InvalidContentRange] when the header is not a valid byte range
+    /// * [AsserhttpError::ContentRangeMismatch] when the range or the complete length differ
+    fn try_expect_content_range(&mut self, first: u64, last: u64, complete_length: impl Into<Option<u64>>) -> AsserhttpResult<&mut T>;
+
+    /// Expects the server to advertise support of byte range requests with `Accept-Ranges: bytes`
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use ureq::OrAnyStatus;
+    /// use asserhttp::*;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     reqwest::blocking::get("http://localhost").try_expect_accept_ranges_bytes().unwrap();
+    ///     reqwest::get("http://localhost").await.try_expect_accept_ranges_bytes().unwrap();
+    ///
+    ///     isahc::get("http://localhost").try_expect_accept_ranges_bytes().unwrap();
+    ///     isahc::get_async("http://localhost").await.try_expect_accept_ranges_bytes().unwrap();
+    ///
+    ///     surf::get("http://localhost").await.try_expect_accept_ranges_bytes().unwrap();
+    ///
+    ///     ureq::get("http://localhost").call().or_any_status().try_expect_accept_ranges_bytes().unwrap();
+    ///
+    ///     hyper::Client::new().get("http://localhost".parse().unwrap()).await.try_expect_accept_ranges_bytes().unwrap();
+    ///
+    ///     awc::Client::default().get("http://localhost").send().await.try_expect_accept_ranges_bytes().unwrap();
+    /// }
+    /// ```
+    /// # Error
+    /// * [AsserhttpError::HeaderAbsent] when there is no `Accept-Ranges` header
+    /// * [AsserhttpError::HeaderValueMismatch] when `bytes` is not among the accepted units
+    fn try_expect_accept_ranges_bytes(&mut self) -> AsserhttpResult<&mut T>;
+
+    /// Expects a partial response to be consistent: the `Content-Range` is well formed and spans as many bytes
+    /// as the body and the `Content-Length`. For `multipart/byteranges` responses every part is verified against its own
+    /// `Content-Range` instead
+    ///
+    /// An unsatisfied range e.g. `bytes */26` is only an inconsistency in a `206 Partial Content` response, since a
+    /// `416 Range Not Satisfiable` one is expected to send it. Parts are delimited by their boundary at the start of a line
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use ureq::OrAnyStatus;
+    /// use asserhttp::*;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     reqwest::blocking::get("http://localhost").try_expect_range_consistent().unwrap();
+    ///     reqwest::get("http://localhost").await.try_expect_range_consistent().unwrap();
+    ///
+    ///     isahc::get("http://localhost").try_expect_range_consistent().unwrap();
+    ///     isahc::get_async("http://localhost").await.try_expect_range_consistent().unwrap();
+    ///
+    ///     surf::get("http://localhost").await.try_expect_range_consistent().unwrap();
+    ///
+    ///     ureq::get("http://localhost").call().or_any_status().try_expect_range_consistent().unwrap();
+    ///
+    ///     hyper::Client::new().get("http://localhost".parse().unwrap()).await.try_expect_range_consistent().unwrap();
+    ///
+    ///     awc::Client::default().get("http://localhost").send().await.try_expect_range_consistent().unwrap();
+    /// }
+    /// ```
+    /// # Error
+    /// * [AsserhttpError::HeaderAbsent] when there is neither a `Content-Range` header nor a `multipart/byteranges` body
+    /// * [AsserhttpError::InvalidContentRange] when the `Content-Range` header is not a valid byte range
+    /// * [AsserhttpError::RangeInconsistent] listing every inconsistency
+    fn try_expect_range_consistent(&mut self) -> AsserhttpResult<&mut T>;
+}
+
+impl<T> FallibleAsserhttpRange<T> for T
+where
+    T: StatusAccessor + HeaderAccessor + BodyAccessor,
+{
+    fn try_expect_content_range(&mut self, first: u64, last: u64, complete_length: impl Into<Option<u64>>) -> AsserhttpResult<&mut T> {
+        let key = HeaderKey::from(headers::CONTENT_RANGE);
+        let actual = self.first_raw_value(&key)?;
+        let expected = ContentRange {
+            range: Some((first, last)),
+            complete_length: complete_length.into(),
+        };
+        if ContentRange::parse(&actual)? != expected {
+            return Err(AsserhttpError::ContentRangeMismatch {
+                expected: expected.to_string(),
+                actual,
+            });
+        }
+        Ok(self)
+    }
+
+    fn try_expect_accept_ranges_bytes(&mut self) -> AsserhttpResult<&mut T> {
+        let key = HeaderKey::from(headers::ACCEPT_RANGES);
+        key.try_assert_contained(self.get_keys())?;
+        let units = self.get_values(&key);
+        if !units.iter().any(|unit| unit.eq_ignore_ascii_case("bytes")) {
+            return Err(AsserhttpError::HeaderValueMismatch {
+                actual: self.get_raw_values(&key).join(", ").into(),
+                expected: "bytes".to_string().into(),
+                key,
+            });
+        }
+        Ok(self)
+    }
+
+    fn try_expect_range_consistent(&mut self) -> AsserhttpResult<&mut T> {
+        let violations = range_violations(self)?;
+        if !violations.is_empty() {
+            return Err(AsserhttpError::RangeInconsistent { violations });
+        }
+        Ok(self)
+    }
+}
+
+impl<T, E> FallibleAsserhttpRange<T> for Result<T, E>
+where
+    T: StatusAccessor + HeaderAccessor + BodyAccessor,
+    E: std::fmt::Debug,
+{
+    fn try_expect_content_range(&mut self, first: u64, last: u64, complete_length: impl Into<Option<u64>>) -> AsserhttpResult<&mut T> {
+        self.as_mut()
+            .map_err(|e| AsserhttpError::HttpError(format!("{e:?}")))?
+            .try_expect_content_range(first, last, complete_length)
+    }
+
+    fn try_expect_accept_ranges_bytes(&mut self) -> AsserhttpResult<&mut T> {
+        self.as_mut()
+            .map_err(|e| AsserhttpError::HttpError(format!("{e:?}")))?
+            .try_expect_accept_ranges_bytes()
+    }
+
+    fn try_expect_range_consistent(&mut self) -> AsserhttpResult<&mut T> {
+        self.as_mut()
+            .map_err(|e| AsserhttpError::HttpError(format!("{e:?}")))?
+            .try_expect_range_consistent()
+    }
+}
@@ -0,0 +1,137 @@
+use crate::{
+    accessor::{BodyAccessor, HeaderAccessor, StatusAccessor},
+    range::fallible::FallibleAsserhttpRange,
+};
+
+/// For assertions on range requests and partial responses
+pub trait AsserhttpRange<T> {
+    /// Expects the `Content-Range` header to select this byte range
+    /// * `first` - position of the first byte, inclusive
+    /// * `last` - position of the last byte, inclusive
+    /// * `complete_length` - length of the whole representation, `None` when the server does not know it
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use ureq::OrAnyStatus;
+    /// use asserhttp::*;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     reqwest::blocking::get("http://localhost").expect_content_range(0, 499, 1234);
+    ///     reqwest::get("http://localhost").await.expect_content_range(0, 499, 1234);
+    ///
+    ///     isahc::get("http://localhost").expect_content_range(0, 499, 1234);
+    ///     isahc::get_async("http://localhost").await.expect_content_range(0, 499, 1234);
+    ///
+    ///     surf::get("http://localhost").await.expect_content_range(0, 499, 1234);
+    ///
+    ///     ureq::get("http://localhost").call().or_any_status().expect_content_range(0, 499, 1234);
+    ///
+    ///     hyper::Client::new().get("http://localhost".parse().unwrap()).await.expect_content_range(0, 499, 1234);
+    ///
+    ///     awc::Client::default().get("http://localhost").send().await.expect_content_range(0, 499, 1234);
+    /// }
+    /// ```
+    fn expect_content_range(&mut self, first: u64, last: u64, complete_length: impl Into<Option<u64>>) -> &mut T;
+
+    /// Expects the server to advertise support of byte range requests with `Accept-Ranges: bytes`
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use ureq::OrAnyStatus;
+    /// use asserhttp::*;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     reqwest::blocking::get("http://localhost").expect_accept_ranges_bytes();
+    ///     reqwest::get("http://localhost").await.expect_accept_ranges_bytes();
+    ///
+    ///     isahc::get("http://localhost").expect_accept_ranges_bytes();
+    ///     isahc::get_async("http://localhost").await.expect_accept_ranges_bytes();
+    ///
+    ///     surf::get("http://localhost").await.expect_accept_ranges_bytes();
+    ///
+    ///     ureq::get("http://localhost").call().or_any_status().expect_accept_ranges_bytes();
+    ///
+    ///     hyper::Client::new().get("http://localhost".parse().unwrap()).await.expect_accept_ranges_bytes();
+    ///
+    ///     awc::Client::default().get("http://localhost").send().await.expect_accept_ranges_bytes();
+    /// }
+    /// ```
+    fn expect_accept_ranges_bytes(&mut self) -> &mut T;
+
+    /// Expects a partial response to be consistent: the `Content-Range` is well formed and spans as many bytes
+    /// as the body and the `Content-Length`. For `multipart/byteranges` responses every part is verified against its own
+    /// `Content-Range` instead
+    ///
+    /// An unsatisfied range e.g. `bytes */26` is only an inconsistency in a `206 Partial Content` response, since a
+    /// `416 Range Not Satisfiable` one is expected to send it. Parts are delimited by their boundary at the start of a line
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use ureq::OrAnyStatus;
+    /// use asserhttp::*;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     reqwest::blocking::get("http://localhost").expect_range_consistent();
+    ///     reqwest::get("http://localhost").await.expect_range_consistent();
+    ///
+    ///     isahc::get("http://localhost").expect_range_consistent();
+    ///     isahc::get_async("http://localhost").await.expect_range_consistent();
+    ///
+    ///     surf::get("http://localhost").await.expect_range_consistent();
+    ///
+    ///     ureq::get("http://localhost").call().or_any_status().expect_range_consistent();
+    ///
+    ///     hyper::Client::new().get("http://localhost".parse().unwrap()).await.expect_range_consistent();
+    ///
+    ///     awc::Client::default().get("http://localhost").send().await.expect_range_consistent();
+    /// }
+    /// ```
+    fn expect_range_consistent(&mut self) -> &mut T;
+}
+
+impl<T> AsserhttpRange<T> for T
+where
+    T: StatusAccessor + HeaderAccessor + BodyAccessor,
+{
+    fn expect_content_range(&mut self, first: u64, last: u64, complete_length: impl Into<Option<u64>>) -> &mut T {
+        match self.try_expect_content_range(first, last, complete_length) {
+            Err(e) => panic!("{e}"),
+            Ok(r) => r,
+        }
+    }
+
+    fn expect_accept_ranges_bytes(&mut self) -> &mut T {
+        match self.try_expect_accept_ranges_bytes() {
+            Err(e) => panic!("{e}"),
+            Ok(r) => r,
+        }
+    }
+
+    fn expect_range_consistent(&mut self) -> &mut T {
+        match self.try_expect_range_consistent() {
+            Err(e) => panic!("{e}"),
+            Ok(r) => r,
+        }
+    }
+}
+
+impl<T, E> AsserhttpRange<T> for Result<T, E>
+where
+    T: StatusAccessor + HeaderAccessor + BodyAccessor,
+    E: std::fmt::Debug,
+{
+    fn expect_content_range(&mut self, first: u64, last: u64, complete_length: impl Into<Option<u64>>) -> &mut T {
+        self.as_mut().unwrap().expect_content_range(first, last, complete_length)
+    }
+
+    fn expect_accept_ranges_bytes(&mut self) -> &mut T {
+        self.as_mut().unwrap().expect_accept_ranges_bytes()
+    }
+
+    fn expect_range_consistent(&mut self) -> &mut T {
+        self.as_mut().unwrap().expect_range_consistent()
+    }
+}
@@ -0,0 +1,206 @@
+use std::fmt::{Display, Formatter};
+
+use crate::{
+    accessor::{BodyAccessor, HeaderAccessor, StatusAccessor},
+    error::{AsserhttpError, AsserhttpResult},
+    header::{
+        key::HeaderKey,
+        quoted::{split_unquoted, unquote},
+    },
+    headers,
+};
+
+pub(crate) mod fallible;
+pub(crate) mod infallible;
+
+const BYTES: &str = "bytes";
+const MULTIPART_BYTERANGES: &str = "multipart/byteranges";
+
+/// A parsed [Content-Range](https://www.rfc-editor.org/rfc/rfc9110#name-content-range) header in bytes
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub(crate) struct ContentRange {
+    /// First and last byte positions, both inclusive. `None` for an unsatisfied range e.g. `bytes */1234`
+    pub(crate) range: Option<(u64, u64)>,
+    /// Length of the whole representation. `None` when unknown e.g. `bytes 0-499/*`
+    pub(crate) complete_length: Option<u64>,
+}
+
+impl ContentRange {
+    pub(crate) fn parse(value: &str) -> AsserhttpResult<Self> {
+        let invalid = || AsserhttpError::InvalidContentRange { value: value.to_string() };
+        let (unit, rest) = value.trim().split_once(' ').ok_or_else(invalid)?;
+        let (range, complete_length) = rest.trim().split_once('/').ok_or_else(invalid)?;
+        if !unit.eq_ignore_ascii_case(BYTES) {
+            return Err(invalid());
+        }
+        let complete_length = match complete_length {
+            "*" => None,
+            length => Some(length.parse().map_err(|_| invalid())?),
+        };
+        let range = match range {
+            "*" if complete_length.is_some() => None,
+            range => {
+                let (first, last) = range.split_once('-').ok_or_else(invalid)?;
+                Some((first.parse().map_err(|_| invalid())?, last.parse().map_err(|_| invalid())?))
+            },
+        };
+        Ok(Self { range, complete_length })
+    }
+
+    /// Every inconsistency of the range with itself and with the length of its content. An unsatisfied range is
+    /// only one when `partial` i.e. in a `206 Partial Content` response, as a `416 Range Not Satisfiable` one sends it
+    fn violations(&self, content_length: u64, partial: bool) -> Vec<String> {
+        let Some((first, last)) = self.range else {
+            return if partial {
+                vec![format!("'{self}' has no satisfied range")]
+            } else {
+                vec![]
+            };
+        };
+        let mut violations = vec![];
+        if first > last {
+            violations.push(format!("'{self}' starts after its last byte"));
+        }
+        if let Some(complete_length) = self.complete_length.filter(|length| last >= *length) {
+            violations.push(format!("'{self}' ends beyond the complete length {complete_length}"));
+        }
+        let length = (last + 1).saturating_sub(first);
+        if first <= last && content_length != length {
+            violations.push(format!("'{self}' spans {length} bytes but the content has {content_length}"));
+        }
+        violations
+    }
+}
+
+impl Display for ContentRange {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let complete_length = self.complete_length.map(|l| l.to_string()).unwrap_or_else(|| "*".to_string());
+        match self.range {
+            Some((first, last)) => write!(f, "{BYTES} {first}-{last}/{complete_length}"),
+            None => write!(f, "{BYTES} */{complete_length}"),
+        }
+    }
+}
+
+/// Every inconsistency between the `Content-Range`, the `Content-Length` and the body, either of a single part
+/// response or of each part of a `multipart/byteranges` one
+pub(crate) fn range_violations<T>(response: &mut T) -> AsserhttpResult<Vec<String>>
+where
+    T: StatusAccessor + HeaderAccessor + BodyAccessor,
+{
+    let partial = response.get_status() == 206;
+    let first = |key: &str| response.get_raw_values(&HeaderKey::from(key)).into_iter().next();
+    let content_range = first(headers::CONTENT_RANGE.as_str());
+    let content_length = first(headers::CONTENT_LENGTH.as_str()).and_then(|l| l.trim().parse::<u64>().ok());
+    let boundary = first(headers::CONTENT_TYPE.as_str()).and_then(|content_type| byteranges_boundary(&content_type));
+    let body = match response.get_bytes() {
+        Ok(body) => body,
+        Err(AsserhttpError::BodyAbsent) => vec![],
+        Err(e) => return Err(e),
+    };
+    let mut violations = vec![];
+    if let Some(content_length) = content_length.filter(|l| *l != body.len() as u64) {
+        violations.push(format!(
+            "'content-length' is {content_length} but the body has {} bytes",
+            body.len()
+        ));
+    }
+    match (boundary, content_range) {
+        (Some(_), Some(_)) => violations.push(format!("'content-range' should not be sent with '{MULTIPART_BYTERANGES}'")),
+        (Some(boundary), None) => violations.extend(multipart_violations(&body, &boundary)),
+        (None, Some(content_range)) => violations.extend(ContentRange::parse(&content_range)?.violations(body.len() as u64, partial)),
+        (None, None) => {
+            HeaderKey::from(headers::CONTENT_RANGE).try_assert_contained(response.get_keys())?;
+        },
+    }
+    Ok(violations)
+}
+
+/// The `boundary` parameter of a `multipart/byteranges` content type
+fn byteranges_boundary(content_type: &str) -> Option<String> {
+    let mut parts = split_unquoted(content_type, ';').into_iter();
+    if !parts.next()?.trim().eq_ignore_ascii_case(MULTIPART_BYTERANGES) {
+        return None;
+    }
+    parts
+        .filter_map(|p| p.split_once('='))
+        .find(|(name, _)| name.trim().eq_ignore_ascii_case("boundary"))
+        .map(|(_, value)| unquote(value.trim()))
+}
+
+fn multipart_violations(body: &[u8], boundary: &str) -> Vec<String> {
+    let delimiter = format!("--{boundary}");
+    let mut violations = vec![];
+    let mut complete_lengths = vec![];
+    // the preamble before the first delimiter is ignored, as well as the epilogue after the closing one
+    let parts = split_delimited(body, delimiter.as_bytes())
+        .into_iter()
+        .skip(1)
+        .take_while(|part| !part.starts_with(b"--"))
+        .collect::<Vec<_>>();
+    if parts.is_empty() {
+        return vec![format!("'{MULTIPART_BYTERANGES}' body has no part delimited by '{delimiter}'")];
+    }
+    for (i, part) in parts.into_iter().enumerate() {
+        let part = strip_newline_suffix(strip_newline_prefix(part));
+        let (head, content) = split_once(part, b"\r\n\r\n")
+            .or_else(|| split_once(part, b"\n\n"))
+            .unwrap_or((part, &[]));
+        let content_range = String::from_utf8_lossy(head)
+            .lines()
+            .filter_map(|line| line.split_once(':'))
+            .find(|(name, _)| name.trim().eq_ignore_ascii_case(headers::CONTENT_RANGE.as_str()))
+            .map(|(_, value)| value.trim().to_string());
+        match content_range.as_deref().map(ContentRange::parse) {
+            None => violations.push(format!("part {i} has no 'content-range'")),
+            Some(Err(_)) => violations.push(format!("part {i} has an invalid 'content-range'")),
+            Some(Ok(range)) => {
+                violations.extend(
+                    range
+                        .violations(content.len() as u64, true)
+                        .into_iter()
+                        .map(|v| format!("part {i}: {v}")),
+                );
+                complete_lengths.push(range.complete_length);
+            },
+        }
+    }
+    complete_lengths.dedup();
+    if complete_lengths.len() > 1 {
+        violations.push("parts disagree on the complete length".to_string());
+    }
+    violations
+}
+
+/// Splits on every delimiter starting a line, so that the boundary appearing within a part content is kept
+fn split_delimited<'a>(body: &'a [u8], delimiter: &[u8]) -> Vec<&'a [u8]> {
+    let mut parts = vec![];
+    let (mut start, mut i) = (0, 0);
+    while i + delimiter.len() <= body.len() {
+        if body[i..].starts_with(delimiter) && (i == 0 || body[i - 1] == b'\n') {
+            parts.push(&body[start..i]);
+            i += delimiter.len();
+            start = i;
+        } else {
+            i += 1;
+        }
+    }
+    parts.push(&body[start..]);
+    parts
+}
+
+fn split_once<'a>(haystack: &'a [u8], needle: &[u8]) -> Option<(&'a [u8], &'a [u8])> {
+    haystack
+        .windows(needle.len())
+        .position(|window| window == needle)
+        .map(|i| (&haystack[..i], &haystack[i + needle.len()..]))
+}
+
+fn strip_newline_prefix(part: &[u8]) -> &[u8] {
+    part.strip_prefix(b"\r\n").or_else(|| part.strip_prefix(b"\n")).unwrap_or(part)
+}
+
+/// The line break before a delimiter belongs to it rather than to the part content
+fn strip_newline_suffix(part: &[u8]) -> &[u8] {
+    part.strip_suffix(b"\r\n").or_else(|| part.strip_suffix(b"\n")).unwrap_or(part)
+}
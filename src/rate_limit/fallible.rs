@@ -0,0 +1,113 @@
+use crate::{
+    accessor::{HeaderAccessor, StatusAccessor},
+    error::{AsserhttpError, AsserhttpResult},
+    header::fallible::FallibleAsserhttpHeader,
+    rate_limit::RateLimit,
+    status::fallible::FallibleAsserhttpStatus,
+};
+
+/// For assertions on rate limiting returning an error instead of panicking
+pub trait FallibleAsserhttpRateLimit<T> {
+    /// Allows verifying the advertised rate limit in a closure, read from the combined `RateLimit` and
+    /// `RateLimit-Policy` headers, the `RateLimit-Limit`, `RateLimit-Remaining` and `RateLimit-Reset` headers or their
+    /// legacy `X-RateLimit-*` forms, in this order of precedence
+    /// * `asserter` - closure to verify the rate limit
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use ureq::OrAnyStatus;
+    /// use asserhttp::*;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     reqwest::blocking::get("http://localhost").try_expect_rate_limit(|rl| { assert!(rl.remaining < rl.limit); Ok(()) }).unwrap();
+    ///     reqwest::get("http://localhost").await.try_expect_rate_limit(|rl| { assert!(rl.remaining < rl.limit); Ok(()) }).unwrap();
+    ///
+    ///     isahc::get("http://localhost").try_expect_rate_limit(|rl| { assert!(rl.remaining < rl.limit); Ok(()) }).unwrap();
+    ///     isahc::get_async("http://localhost").await.try_expect_rate_limit(|rl| { assert!(rl.remaining < rl.limit); Ok(()) }).unwrap();
+    ///
+    ///     surf::get("http://localhost").await.try_expect_rate_limit(|rl| { assert!(rl.remaining < rl.limit); Ok(()) }).unwrap();
+    ///
+    ///     ureq::get("http://localhost").call().or_any_status().try_expect_rate_limit(|rl| { assert!(rl.remaining < rl.limit); Ok(()) }).unwrap();
+    ///
+    ///     hyper::Client::new().get("http://localhost".parse().unwrap()).await.try_expect_rate_limit(|rl| { assert!(rl.remaining < rl.limit); Ok(()) }).unwrap();
+    ///
+    ///     awc::Client::default().get("http://localhost").send().await.try_expect_rate_limit(|rl| { assert!(rl.remaining < rl.limit); Ok(()) }).unwrap();
+    /// }
+    /// ```
+    /// # Error
+    /// * [AsserhttpError::RateLimitAbsent] when either the limit or the remaining requests are not advertised
+    /// * [AsserhttpError::InvalidRateLimit] when a rate limit value is not a number
+    /// * [AsserhttpError::RateLimitResetElapsed] when the reset is a Unix timestamp in the past
+    fn try_expect_rate_limit<F>(&mut self, asserter: F) -> AsserhttpResult<&mut T>
+    where
+        F: FnOnce(RateLimit) -> AsserhttpResult<()>;
+
+    /// Expects a `429 Too Many Requests` response telling the client when to retry with a `Retry-After` header
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use ureq::OrAnyStatus;
+    /// use asserhttp::*;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     reqwest::blocking::get("http://localhost").try_expect_rate_limited().unwrap();
+    ///     reqwest::get("http://localhost").await.try_expect_rate_limited().unwrap();
+    ///
+    ///     isahc::get("http://localhost").try_expect_rate_limited().unwrap();
+    ///     isahc::get_async("http://localhost").await.try_expect_rate_limited().unwrap();
+    ///
+    ///     surf::get("http://localhost").await.try_expect_rate_limited().unwrap();
+    ///
+    ///     ureq::get("http://localhost").call().or_any_status().try_expect_rate_limited().unwrap();
+    ///
+    ///     hyper::Client::new().get("http://localhost".parse().unwrap()).await.try_expect_rate_limited().unwrap();
+    ///
+    ///     awc::Client::default().get("http://localhost").send().await.try_expect_rate_limited().unwrap();
+    /// }
+    /// ```
+    /// # Error
+    /// * [AsserhttpError::StatusMismatch] when the status is not `429`
+    /// * [AsserhttpError::HeaderAbsent] when there is no `Retry-After` header
+    /// * [AsserhttpError::InvalidRetryAfter] when the `Retry-After` header is neither a delay nor an HTTP-date
+    fn try_expect_rate_limited(&mut self) -> AsserhttpResult<&mut T>;
+}
+
+impl<T> FallibleAsserhttpRateLimit<T> for T
+where
+    T: HeaderAccessor + StatusAccessor,
+{
+    fn try_expect_rate_limit<F>(&mut self, asserter: F) -> AsserhttpResult<&mut T>
+    where
+        F: FnOnce(RateLimit) -> AsserhttpResult<()>,
+    {
+        asserter(RateLimit::parse(self)?)?;
+        Ok(self)
+    }
+
+    fn try_expect_rate_limited(&mut self) -> AsserhttpResult<&mut T> {
+        self.try_expect_status(429)?.try_expect_retry_after(|_| Ok(()))
+    }
+}
+
+impl<T, E> FallibleAsserhttpRateLimit<T> for Result<T, E>
+where
+    T: HeaderAccessor + StatusAccessor,
+    E: std::fmt::Debug,
+{
+    fn try_expect_rate_limit<F>(&mut self, asserter: F) -> AsserhttpResult<&mut T>
+    where
+        F: FnOnce(RateLimit) -> AsserhttpResult<()>,
+    {
+        self.as_mut()
+            .map_err(|e| AsserhttpError::HttpError(format!("{e:?}")))?
+            .try_expect_rate_limit(asserter)
+    }
+
+    fn try_expect_rate_limited(&mut self) -> AsserhttpResult<&mut T> {
+        self.as_mut()
+            .map_err(|e| AsserhttpError::HttpError(format!("{e:?}")))?
+            .try_expect_rate_limited()
+    }
+}
@@ -0,0 +1,107 @@
+use crate::{
+    accessor::{HeaderAccessor, StatusAccessor},
+    rate_limit::{fallible::FallibleAsserhttpRateLimit, RateLimit},
+};
+
+/// For assertions on rate limiting
+pub trait AsserhttpRateLimit<T> {
+    /// Allows verifying the advertised rate limit in a closure, read from the combined `RateLimit` and
+    /// `RateLimit-Policy` headers, the `RateLimit-Limit`, `RateLimit-Remaining` and `RateLimit-Reset` headers or their
+    /// legacy `X-RateLimit-*` forms, in this order of precedence
+    /// * `asserter` - closure to verify the rate limit
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use ureq::OrAnyStatus;
+    /// use asserhttp::*;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     reqwest::blocking::get("http://localhost").expect_rate_limit(|rl| assert!(rl.remaining < rl.limit));
+    ///     reqwest::get("http://localhost").await.expect_rate_limit(|rl| assert!(rl.remaining < rl.limit));
+    ///
+    ///     isahc::get("http://localhost").expect_rate_limit(|rl| assert!(rl.remaining < rl.limit));
+    ///     isahc::get_async("http://localhost").await.expect_rate_limit(|rl| assert!(rl.remaining < rl.limit));
+    ///
+    ///     surf::get("http://localhost").await.expect_rate_limit(|rl| assert!(rl.remaining < rl.limit));
+    ///
+    ///     ureq::get("http://localhost").call().or_any_status().expect_rate_limit(|rl| assert!(rl.remaining < rl.limit));
+    ///
+    ///     hyper::Client::new().get("http://localhost".parse().unwrap()).await.expect_rate_limit(|rl| assert!(rl.remaining < rl.limit));
+    ///
+    ///     awc::Client::default().get("http://localhost").send().await.expect_rate_limit(|rl| assert!(rl.remaining < rl.limit));
+    /// }
+    /// ```
+    fn expect_rate_limit<F>(&mut self, asserter: F) -> &mut T
+    where
+        F: FnOnce(RateLimit);
+
+    /// Expects a `429 Too Many Requests` response telling the client when to retry with a `Retry-After` header
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use ureq::OrAnyStatus;
+    /// use asserhttp::*;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     reqwest::blocking::get("http://localhost").expect_rate_limited();
+    ///     reqwest::get("http://localhost").await.expect_rate_limited();
+    ///
+    ///     isahc::get("http://localhost").expect_rate_limited();
+    ///     isahc::get_async("http://localhost").await.expect_rate_limited();
+    ///
+    ///     surf::get("http://localhost").await.expect_rate_limited();
+    ///
+    ///     ureq::get("http://localhost").call().or_any_status().expect_rate_limited();
+    ///
+    ///     hyper::Client::new().get("http://localhost".parse().unwrap()).await.expect_rate_limited();
+    ///
+    ///     awc::Client::default().get("http://localhost").send().await.expect_rate_limited();
+    /// }
+    /// ```
+    fn expect_rate_limited(&mut self) -> &mut T;
+}
+
+impl<T> AsserhttpRateLimit<T> for T
+where
+    T: HeaderAccessor + StatusAccessor,
+{
+    fn expect_rate_limit<F>(&mut self, asserter: F) -> &mut T
+    where
+        F: FnOnce(RateLimit),
+    {
+        #[allow(clippy::blocks_in_conditions)]
+        match self.try_expect_rate_limit(|v| {
+            asserter(v);
+            Ok(())
+        }) {
+            Err(e) => panic!("{e}"),
+            Ok(r) => r,
+        }
+    }
+
+    fn expect_rate_limited(&mut self) -> &mut T {
+        match self.try_expect_rate_limited() {
+            Err(e) => panic!("{e}"),
+            Ok(r) => r,
+        }
+    }
+}
+
+impl<T, E> AsserhttpRateLimit<T> for Result<T, E>
+where
+    T: HeaderAccessor + StatusAccessor,
+    E: std::fmt::Debug,
+{
+    fn expect_rate_limit<F>(&mut self, asserter: F) -> &mut T
+    where
+        F: FnOnce(RateLimit),
+    {
+        self.as_mut().unwrap().expect_rate_limit(asserter)
+    }
+
+    fn expect_rate_limited(&mut self) -> &mut T {
+        self.as_mut().unwrap().expect_rate_limited()
+    }
+}
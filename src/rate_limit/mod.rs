@@ -0,0 +1,144 @@
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use crate::{
+    accessor::HeaderAccessor,
+    error::{AsserhttpError, AsserhttpResult},
+    header::{
+        key::HeaderKey,
+        quoted::{split_unquoted, unquote},
+    },
+};
+
+pub(crate) mod fallible;
+pub(crate) mod infallible;
+
+/// Combined header of the [IETF draft](https://datatracker.ietf.org/doc/draft-ietf-httpapi-ratelimit-headers/) e.g.
+/// `limit=100, remaining=50, reset=30` or, in later revisions, `"default";r=50;t=30`
+const RATELIMIT: &str = "ratelimit";
+/// Quota policies of the IETF draft e.g. `100;w=60` or, in later revisions, `"default";q=100;w=60`
+const RATELIMIT_POLICY: &str = "ratelimit-policy";
+/// Prefixes of the separate headers, the ones of earlier revisions of the IETF draft first then the legacy ones
+const PREFIXES: [&str; 2] = ["ratelimit-", "x-ratelimit-"];
+
+/// Resets later than this many seconds are Unix timestamps, as some legacy `X-RateLimit-Reset` headers are, rather
+/// than delays
+const TIMESTAMP_THRESHOLD: u64 = 1_000_000_000;
+
+/// Rate limit advertised by a server, gathered from either the combined `RateLimit` and `RateLimit-Policy` headers,
+/// the `RateLimit-Limit`, `RateLimit-Remaining` and `RateLimit-Reset` headers or their legacy `X-RateLimit-*` forms.
+/// When several policies are advertised, the first one of `RateLimit` is picked along with the `RateLimit-Policy` one
+/// of the same name, none when no policy has it. The first `RateLimit-Policy` one is picked when `RateLimit` names none
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct RateLimit {
+    /// Requests allowed in the time window, from the quota of the policy when not sent on its own
+    pub limit: u64,
+    /// Requests left in the time window
+    pub remaining: u64,
+    /// Time left until the quota resets
+    pub reset: Option<Duration>,
+    /// Time window of the quota policy
+    pub window: Option<Duration>,
+}
+
+impl RateLimit {
+    pub(crate) fn parse(accessor: &impl HeaderAccessor) -> AsserhttpResult<Self> {
+        let combined_key = HeaderKey::from(RATELIMIT);
+        let combined = policies(accessor, &combined_key)?.into_iter().next();
+        let policies = policies(accessor, &HeaderKey::from(RATELIMIT_POLICY))?;
+        let name = combined.as_ref().and_then(|c| c.name.as_ref());
+        let policy = match name {
+            Some(name) => policies.iter().find(|p| p.name.as_ref() == Some(name)),
+            None => policies.first(),
+        };
+        let field = |names: &[&str], suffix: &str| -> AsserhttpResult<Option<(HeaderKey, u64)>> {
+            if let Some(value) = combined.as_ref().and_then(|c| c.get(names)) {
+                return Ok(Some((combined_key.clone(), value)));
+            }
+            for prefix in PREFIXES {
+                let key = HeaderKey::from(format!("{prefix}{suffix}").as_str());
+                if let Some(value) = accessor.get_raw_values(&key).into_iter().next() {
+                    let value = number(&key, &value)?;
+                    return Ok(Some((key, value)));
+                }
+            }
+            Ok(None)
+        };
+        let quota = policy.and_then(|p| p.get(&["q", ""]));
+        let limit = field(&["limit"], "limit")?.map(|(_, limit)| limit).or(quota);
+        let remaining = field(&["remaining", "r"], "remaining")?.map(|(_, remaining)| remaining);
+        let reset = field(&["reset", "t"], "reset")?
+            .map(|(key, reset)| delay(key, reset))
+            .transpose()?;
+        let window = policy.and_then(|p| p.get(&["w"])).map(Duration::from_secs);
+        Ok(Self {
+            limit: limit.ok_or_else(|| AsserhttpError::RateLimitAbsent {
+                field: "limit".to_string(),
+            })?,
+            remaining: remaining.ok_or_else(|| AsserhttpError::RateLimitAbsent {
+                field: "remaining".to_string(),
+            })?,
+            reset,
+            window,
+        })
+    }
+}
+
+/// One policy of a structured header: the quoted name of later revisions and the numeric parameters keyed by
+/// lowercased name. A bare number like the quota of `100;w=60` is keyed by an empty name
+#[derive(Debug)]
+struct Policy {
+    name: Option<String>,
+    params: Vec<(String, u64)>,
+}
+
+impl Policy {
+    fn get(&self, names: &[&str]) -> Option<u64> {
+        self.params.iter().find(|(k, _)| names.contains(&k.as_str())).map(|(_, v)| *v)
+    }
+}
+
+/// Every policy of a structured header, in order. A list member starting with a quoted name or a bare number begins
+/// a new policy whereas a `name=value` one belongs to the current policy, as in `limit=100, remaining=50, reset=30`
+fn policies(accessor: &impl HeaderAccessor, key: &HeaderKey) -> AsserhttpResult<Vec<Policy>> {
+    let Some(value) = accessor.get_raw_values(key).into_iter().next() else {
+        return Ok(vec![]);
+    };
+    let mut policies: Vec<Policy> = vec![];
+    for member in split_unquoted(&value, ',').into_iter().filter(|m| !m.trim().is_empty()) {
+        let mut members = split_unquoted(member, ';')
+            .into_iter()
+            .map(str::trim)
+            .filter(|p| !p.is_empty())
+            .peekable();
+        let name = members.next_if(|p| p.starts_with('"')).map(unquote);
+        let starts_policy = name.is_some() || members.peek().is_some_and(|p| !p.contains('='));
+        let params = members
+            .map(|p| {
+                let (name, n) = p.split_once('=').unwrap_or(("", p));
+                Ok((name.trim().to_lowercase(), number(key, n)?))
+            })
+            .collect::<AsserhttpResult<Vec<_>>>()?;
+        match policies.last_mut() {
+            Some(policy) if !starts_policy => policy.params.extend(params),
+            _ => policies.push(Policy { name, params }),
+        }
+    }
+    Ok(policies)
+}
+
+/// Time left until a reset, which may be a Unix timestamp rather than a delay
+fn delay(key: HeaderKey, reset: u64) -> AsserhttpResult<Duration> {
+    if reset <= TIMESTAMP_THRESHOLD {
+        return Ok(Duration::from_secs(reset));
+    }
+    (UNIX_EPOCH + Duration::from_secs(reset))
+        .duration_since(SystemTime::now())
+        .map_err(|_| AsserhttpError::RateLimitResetElapsed { key, timestamp: reset })
+}
+
+fn number(key: &HeaderKey, value: &str) -> AsserhttpResult<u64> {
+    value.trim().parse().map_err(|_| AsserhttpError::InvalidRateLimit {
+        key: key.clone(),
+        value: value.trim().to_string(),
+    })
+}
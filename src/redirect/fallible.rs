@@ -0,0 +1,312 @@
+use url::Url;
+
+use crate::{
+    accessor::{HeaderAccessor, StatusAccessor},
+    error::{AsserhttpError, AsserhttpResult},
+    redirect::{self, any_redirection, one_of, parse, resolve, same_location, PERMANENT, TEMPORARY},
+};
+
+/// For assertions on redirections, combining the status and the `Location` header returning an error instead of panicking
+pub trait FallibleAsserhttpRedirect<T> {
+    /// Allows verifying in a closure the `Location` of a 3xx response, parsed as an url e.g. to verify query parameters.
+    /// The location has to be absolute, use [FallibleAsserhttpRedirect::try_expect_redirect_with_base] otherwise
+    /// * `asserter` - closure to verify the location
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use ureq::OrAnyStatus;
+    /// use asserhttp::*;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     reqwest::blocking::get("http://localhost").try_expect_redirect(|url| { assert!(url.query_pairs().any(|(k, _)| k == "next")); Ok(()) }).unwrap();
+    ///     reqwest::get("http://localhost").await.try_expect_redirect(|url| { assert!(url.query_pairs().any(|(k, _)| k == "next")); Ok(()) }).unwrap();
+    ///
+    ///     isahc::get("http://localhost").try_expect_redirect(|url| { assert!(url.query_pairs().any(|(k, _)| k == "next")); Ok(()) }).unwrap();
+    ///     isahc::get_async("http://localhost").await.try_expect_redirect(|url| { assert!(url.query_pairs().any(|(k, _)| k == "next")); Ok(()) }).unwrap();
+    ///
+    ///     surf::get("http://localhost").await.try_expect_redirect(|url| { assert!(url.query_pairs().any(|(k, _)| k == "next")); Ok(()) }).unwrap();
+    ///
+    ///     ureq::get("http://localhost").call().or_any_status().try_expect_redirect(|url| { assert!(url.query_pairs().any(|(k, _)| k == "next")); Ok(()) }).unwrap();
+    ///
+    ///     hyper::Client::new().get("http://localhost".parse().unwrap()).await.try_expect_redirect(|url| { assert!(url.query_pairs().any(|(k, _)| k == "next")); Ok(()) }).unwrap();
+    ///
+    ///     awc::Client::default().get("http://localhost").send().await.try_expect_redirect(|url| { assert!(url.query_pairs().any(|(k, _)| k == "next")); Ok(()) }).unwrap();
+    /// }
+    /// ```
+    /// # Error
+    /// * [AsserhttpError::StatusRangeMismatch] when the status is not 3xx
+    /// * [AsserhttpError::HeaderAbsent] when there is no `Location` header
+    /// * [AsserhttpError::InvalidUrl] when the location is relative or not an url
+    fn try_expect_redirect<F>(&mut self, asserter: F) -> AsserhttpResult<&mut T>
+    where
+        F: FnOnce(Url) -> AsserhttpResult<()>;
+
+    /// Allows verifying in a closure the `Location` of a 3xx response, resolved against the base url when relative
+    /// * `base` - url against which a relative location is resolved e.g. `https://app.example`
+    /// * `asserter` - closure to verify the location
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use ureq::OrAnyStatus;
+    /// use asserhttp::*;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     reqwest::blocking::get("http://localhost").try_expect_redirect_with_base("https://app.example", |url| { assert!(url.query_pairs().any(|(k, _)| k == "next")); Ok(()) }).unwrap();
+    ///     reqwest::get("http://localhost").await.try_expect_redirect_with_base("https://app.example", |url| { assert!(url.query_pairs().any(|(k, _)| k == "next")); Ok(()) }).unwrap();
+    ///
+    ///     isahc::get("http://localhost").try_expect_redirect_with_base("https://app.example", |url| { assert!(url.query_pairs().any(|(k, _)| k == "next")); Ok(()) }).unwrap();
+    ///     isahc::get_async("http://localhost").await.try_expect_redirect_with_base("https://app.example", |url| { assert!(url.query_pairs().any(|(k, _)| k == "next")); Ok(()) }).unwrap();
+    ///
+    ///     surf::get("http://localhost").await.try_expect_redirect_with_base("https://app.example", |url| { assert!(url.query_pairs().any(|(k, _)| k == "next")); Ok(()) }).unwrap();
+    ///
+    ///     ureq::get("http://localhost").call().or_any_status().try_expect_redirect_with_base("https://app.example", |url| { assert!(url.query_pairs().any(|(k, _)| k == "next")); Ok(()) }).unwrap();
+    ///
+    ///     hyper::Client::new().get("http://localhost".parse().unwrap()).await.try_expect_redirect_with_base("https://app.example", |url| { assert!(url.query_pairs().any(|(k, _)| k == "next")); Ok(()) }).unwrap();
+    ///
+    ///     awc::Client::default().get("http://localhost").send().await.try_expect_redirect_with_base("https://app.example", |url| { assert!(url.query_pairs().any(|(k, _)| k == "next")); Ok(()) }).unwrap();
+    /// }
+    /// ```
+    /// # Error
+    /// * [AsserhttpError::StatusRangeMismatch] when the status is not 3xx
+    /// * [AsserhttpError::HeaderAbsent] when there is no `Location` header
+    /// * [AsserhttpError::InvalidUrl] when the location cannot be resolved to an url
+    fn try_expect_redirect_with_base<F>(&mut self, base: impl AsRef<str>, asserter: F) -> AsserhttpResult<&mut T>
+    where
+        F: FnOnce(Url) -> AsserhttpResult<()>;
+
+    /// Expects a 3xx response redirecting to the location. A relative expected location e.g. `/login?next=%2F` only
+    /// matches a relative `Location`, use [FallibleAsserhttpRedirect::try_expect_redirect_to_with_base] to also
+    /// accept an absolute one of the same origin. An absolute expected location compares the origin too
+    /// * `location` - expected location
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use ureq::OrAnyStatus;
+    /// use asserhttp::*;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     reqwest::blocking::get("http://localhost").try_expect_redirect_to("/login").unwrap();
+    ///     reqwest::get("http://localhost").await.try_expect_redirect_to("/login").unwrap();
+    ///
+    ///     isahc::get("http://localhost").try_expect_redirect_to("/login").unwrap();
+    ///     isahc::get_async("http://localhost").await.try_expect_redirect_to("/login").unwrap();
+    ///
+    ///     surf::get("http://localhost").await.try_expect_redirect_to("/login").unwrap();
+    ///
+    ///     ureq::get("http://localhost").call().or_any_status().try_expect_redirect_to("/login").unwrap();
+    ///
+    ///     hyper::Client::new().get("http://localhost".parse().unwrap()).await.try_expect_redirect_to("/login").unwrap();
+    ///
+    ///     awc::Client::default().get("http://localhost").send().await.try_expect_redirect_to("/login").unwrap();
+    /// }
+    /// ```
+    /// # Error
+    /// * [AsserhttpError::StatusRangeMismatch] when the status is not 3xx
+    /// * [AsserhttpError::HeaderAbsent] when there is no `Location` header
+    /// * [AsserhttpError::InvalidUrl] when the location cannot be resolved to an url
+    /// * [AsserhttpError::RedirectMismatch] when the response redirects elsewhere
+    fn try_expect_redirect_to(&mut self, location: impl AsRef<str>) -> AsserhttpResult<&mut T>;
+
+    /// Expects a 3xx response redirecting to the location, both resolved against the base url
+    /// * `location` - expected location
+    /// * `base` - url against which relative locations are resolved e.g. `https://app.example`
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use ureq::OrAnyStatus;
+    /// use asserhttp::*;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     reqwest::blocking::get("http://localhost").try_expect_redirect_to_with_base("/login", "https://app.example").unwrap();
+    ///     reqwest::get("http://localhost").await.try_expect_redirect_to_with_base("/login", "https://app.example").unwrap();
+    ///
+    ///     isahc::get("http://localhost").try_expect_redirect_to_with_base("/login", "https://app.example").unwrap();
+    ///     isahc::get_async("http://localhost").await.try_expect_redirect_to_with_base("/login", "https://app.example").unwrap();
+    ///
+    ///     surf::get("http://localhost").await.try_expect_redirect_to_with_base("/login", "https://app.example").unwrap();
+    ///
+    ///     ureq::get("http://localhost").call().or_any_status().try_expect_redirect_to_with_base("/login", "https://app.example").unwrap();
+    ///
+    ///     hyper::Client::new().get("http://localhost".parse().unwrap()).await.try_expect_redirect_to_with_base("/login", "https://app.example").unwrap();
+    ///
+    ///     awc::Client::default().get("http://localhost").send().await.try_expect_redirect_to_with_base("/login", "https://app.example").unwrap();
+    /// }
+    /// ```
+    /// # Error
+    /// * [AsserhttpError::StatusRangeMismatch] when the status is not 3xx
+    /// * [AsserhttpError::HeaderAbsent] when there is no `Location` header
+    /// * [AsserhttpError::InvalidUrl] when the location cannot be resolved to an url
+    /// * [AsserhttpError::RedirectMismatch] when the response redirects elsewhere
+    fn try_expect_redirect_to_with_base(&mut self, location: impl AsRef<str>, base: impl AsRef<str>) -> AsserhttpResult<&mut T>;
+
+    /// Expects a permanent redirect: a `301` or `308` status with a `Location` header
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use ureq::OrAnyStatus;
+    /// use asserhttp::*;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     reqwest::blocking::get("http://localhost").try_expect_permanent_redirect().unwrap();
+    ///     reqwest::get("http://localhost").await.try_expect_permanent_redirect().unwrap();
+    ///
+    ///     isahc::get("http://localhost").try_expect_permanent_redirect().unwrap();
+    ///     isahc::get_async("http://localhost").await.try_expect_permanent_redirect().unwrap();
+    ///
+    ///     surf::get("http://localhost").await.try_expect_permanent_redirect().unwrap();
+    ///
+    ///     ureq::get("http://localhost").call().or_any_status().try_expect_permanent_redirect().unwrap();
+    ///
+    ///     hyper::Client::new().get("http://localhost".parse().unwrap()).await.try_expect_permanent_redirect().unwrap();
+    ///
+    ///     awc::Client::default().get("http://localhost").send().await.try_expect_permanent_redirect().unwrap();
+    /// }
+    /// ```
+    /// # Error
+    /// * [AsserhttpError::StatusNotOneOf] when the status is neither `301` nor `308`
+    /// * [AsserhttpError::HeaderAbsent] when there is no `Location` header
+    fn try_expect_permanent_redirect(&mut self) -> AsserhttpResult<&mut T>;
+
+    /// Expects a temporary redirect: a `302`, `303` or `307` status with a `Location` header
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use ureq::OrAnyStatus;
+    /// use asserhttp::*;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     reqwest::blocking::get("http://localhost").try_expect_temporary_redirect().unwrap();
+    ///     reqwest::get("http://localhost").await.try_expect_temporary_redirect().unwrap();
+    ///
+    ///     isahc::get("http://localhost").try_expect_temporary_redirect().unwrap();
+    ///     isahc::get_async("http://localhost").await.try_expect_temporary_redirect().unwrap();
+    ///
+    ///     surf::get("http://localhost").await.try_expect_temporary_redirect().unwrap();
+    ///
+    ///     ureq::get("http://localhost").call().or_any_status().try_expect_temporary_redirect().unwrap();
+    ///
+    ///     hyper::Client::new().get("http://localhost".parse().unwrap()).await.try_expect_temporary_redirect().unwrap();
+    ///
+    ///     awc::Client::default().get("http://localhost").send().await.try_expect_temporary_redirect().unwrap();
+    /// }
+    /// ```
+    /// # Error
+    /// * [AsserhttpError::StatusNotOneOf] when the status is not one of `302`, `303` or `307`
+    /// * [AsserhttpError::HeaderAbsent] when there is no `Location` header
+    fn try_expect_temporary_redirect(&mut self) -> AsserhttpResult<&mut T>;
+}
+
+impl<T> FallibleAsserhttpRedirect<T> for T
+where
+    T: HeaderAccessor + StatusAccessor,
+{
+    fn try_expect_redirect<F>(&mut self, asserter: F) -> AsserhttpResult<&mut T>
+    where
+        F: FnOnce(Url) -> AsserhttpResult<()>,
+    {
+        let location = redirect::location(self, any_redirection())?;
+        let url = Url::parse(&location).map_err(|e| AsserhttpError::InvalidUrl {
+            value: location.clone(),
+            message: e.to_string(),
+        })?;
+        asserter(url)?;
+        Ok(self)
+    }
+
+    fn try_expect_redirect_with_base<F>(&mut self, base: impl AsRef<str>, asserter: F) -> AsserhttpResult<&mut T>
+    where
+        F: FnOnce(Url) -> AsserhttpResult<()>,
+    {
+        let location = redirect::location(self, any_redirection())?;
+        asserter(resolve(&location, &parse(base.as_ref())?)?)?;
+        Ok(self)
+    }
+
+    fn try_expect_redirect_to(&mut self, location: impl AsRef<str>) -> AsserhttpResult<&mut T> {
+        let expected = location.as_ref();
+        let actual = redirect::location(self, any_redirection())?;
+        if same_location(&actual, expected, None)? {
+            return Ok(self);
+        }
+        Err(AsserhttpError::RedirectMismatch {
+            expected: expected.to_string(),
+            actual,
+        })
+    }
+
+    fn try_expect_redirect_to_with_base(&mut self, location: impl AsRef<str>, base: impl AsRef<str>) -> AsserhttpResult<&mut T> {
+        let expected = location.as_ref();
+        let actual = redirect::location(self, any_redirection())?;
+        if same_location(&actual, expected, Some(base.as_ref()))? {
+            return Ok(self);
+        }
+        Err(AsserhttpError::RedirectMismatch {
+            expected: expected.to_string(),
+            actual,
+        })
+    }
+
+    fn try_expect_permanent_redirect(&mut self) -> AsserhttpResult<&mut T> {
+        redirect::location(self, one_of(&PERMANENT))?;
+        Ok(self)
+    }
+
+    fn try_expect_temporary_redirect(&mut self) -> AsserhttpResult<&mut T> {
+        redirect::location(self, one_of(&TEMPORARY))?;
+        Ok(self)
+    }
+}
+
+impl<T, E> FallibleAsserhttpRedirect<T> for Result<T, E>
+where
+    T: HeaderAccessor + StatusAccessor,
+    E: std::fmt::Debug,
+{
+    fn try_expect_redirect<F>(&mut self, asserter: F) -> AsserhttpResult<&mut T>
+    where
+        F: FnOnce(Url) -> AsserhttpResult<()>,
+    {
+        self.as_mut()
+            .map_err(|e| AsserhttpError::HttpError(format!("{e:?}")))?
+            .try_expect_redirect(asserter)
+    }
+
+    fn try_expect_redirect_with_base<F>(&mut self, base: impl AsRef<str>, asserter: F) -> AsserhttpResult<&mut T>
+    where
+        F: FnOnce(Url) -> AsserhttpResult<()>,
+    {
+        self.as_mut()
+            .map_err(|e| AsserhttpError::HttpError(format!("{e:?}")))?
+            .try_expect_redirect_with_base(base, asserter)
+    }
+
+    fn try_expect_redirect_to(&mut self, location: impl AsRef<str>) -> AsserhttpResult<&mut T> {
+        self.as_mut()
+            .map_err(|e| AsserhttpError::HttpError(format!("{e:?}")))?
+            .try_expect_redirect_to(location)
+    }
+
+    fn try_expect_redirect_to_with_base(&mut self, location: impl AsRef<str>, base: impl AsRef<str>) -> AsserhttpResult<&mut T> {
+        self.as_mut()
+            .map_err(|e| AsserhttpError::HttpError(format!("{e:?}")))?
+            .try_expect_redirect_to_with_base(location, base)
+    }
+
+    fn try_expect_permanent_redirect(&mut self) -> AsserhttpResult<&mut T> {
+        self.as_mut()
+            .map_err(|e| AsserhttpError::HttpError(format!("{e:?}")))?
+            .try_expect_permanent_redirect()
+    }
+
+    fn try_expect_temporary_redirect(&mut self) -> AsserhttpResult<&mut T> {
+        self.as_mut()
+            .map_err(|e| AsserhttpError::HttpError(format!("{e:?}")))?
+            .try_expect_temporary_redirect()
+    }
+}
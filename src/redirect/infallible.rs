@@ -0,0 +1,275 @@
+use url::Url;
+
+use crate::{
+    accessor::{HeaderAccessor, StatusAccessor},
+    redirect::fallible::FallibleAsserhttpRedirect,
+};
+
+/// For assertions on redirections, combining the status and the `Location` header
+pub trait AsserhttpRedirect<T> {
+    /// Allows verifying in a closure the `Location` of a 3xx response, parsed as an url e.g. to verify query parameters.
+    /// The location has to be absolute, use [AsserhttpRedirect::expect_redirect_with_base] otherwise
+    /// * `asserter` - closure to verify the location
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use ureq::OrAnyStatus;
+    /// use asserhttp::*;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     reqwest::blocking::get("http://localhost").expect_redirect(|url| assert!(url.query_pairs().any(|(k, _)| k == "next")));
+    ///     reqwest::get("http://localhost").await.expect_redirect(|url| assert!(url.query_pairs().any(|(k, _)| k == "next")));
+    ///
+    ///     isahc::get("http://localhost").expect_redirect(|url| assert!(url.query_pairs().any(|(k, _)| k == "next")));
+    ///     isahc::get_async("http://localhost").await.expect_redirect(|url| assert!(url.query_pairs().any(|(k, _)| k == "next")));
+    ///
+    ///     surf::get("http://localhost").await.expect_redirect(|url| assert!(url.query_pairs().any(|(k, _)| k == "next")));
+    ///
+    ///     ureq::get("http://localhost").call().or_any_status().expect_redirect(|url| assert!(url.query_pairs().any(|(k, _)| k == "next")));
+    ///
+    ///     hyper::Client::new().get("http://localhost".parse().unwrap()).await.expect_redirect(|url| assert!(url.query_pairs().any(|(k, _)| k == "next")));
+    ///
+    ///     awc::Client::default().get("http://localhost").send().await.expect_redirect(|url| assert!(url.query_pairs().any(|(k, _)| k == "next")));
+    /// }
+    /// ```
+    fn expect_redirect<F>(&mut self, asserter: F) -> &mut T
+    where
+        F: FnOnce(Url);
+
+    /// Allows verifying in a closure the `Location` of a 3xx response, resolved against the base url when relative
+    /// * `base` - url against which a relative location is resolved e.g. `https://app.example`
+    /// * `asserter` - closure to verify the location
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use ureq::OrAnyStatus;
+    /// use asserhttp::*;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     reqwest::blocking::get("http://localhost").expect_redirect_with_base("https://app.example", |url| assert!(url.query_pairs().any(|(k, _)| k == "next")));
+    ///     reqwest::get("http://localhost").await.expect_redirect_with_base("https://app.example", |url| assert!(url.query_pairs().any(|(k, _)| k == "next")));
+    ///
+    ///     isahc::get("http://localhost").expect_redirect_with_base("https://app.example", |url| assert!(url.query_pairs().any(|(k, _)| k == "next")));
+    ///     isahc::get_async("http://localhost").await.expect_redirect_with_base("https://app.example", |url| assert!(url.query_pairs().any(|(k, _)| k == "next")));
+    ///
+    ///     surf::get("http://localhost").await.expect_redirect_with_base("https://app.example", |url| assert!(url.query_pairs().any(|(k, _)| k == "next")));
+    ///
+    ///     ureq::get("http://localhost").call().or_any_status().expect_redirect_with_base("https://app.example", |url| assert!(url.query_pairs().any(|(k, _)| k == "next")));
+    ///
+    ///     hyper::Client::new().get("http://localhost".parse().unwrap()).await.expect_redirect_with_base("https://app.example", |url| assert!(url.query_pairs().any(|(k, _)| k == "next")));
+    ///
+    ///     awc::Client::default().get("http://localhost").send().await.expect_redirect_with_base("https://app.example", |url| assert!(url.query_pairs().any(|(k, _)| k == "next")));
+    /// }
+    /// ```
+    fn expect_redirect_with_base<F>(&mut self, base: impl AsRef<str>, asserter: F) -> &mut T
+    where
+        F: FnOnce(Url);
+
+    /// Expects a 3xx response redirecting to the location. A relative expected location e.g. `/login?next=%2F` only
+    /// matches a relative `Location`, use [AsserhttpRedirect::expect_redirect_to_with_base] to also accept an
+    /// absolute one of the same origin. An absolute expected location compares the origin too
+    /// * `location` - expected location
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use ureq::OrAnyStatus;
+    /// use asserhttp::*;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     reqwest::blocking::get("http://localhost").expect_redirect_to("/login");
+    ///     reqwest::get("http://localhost").await.expect_redirect_to("/login");
+    ///
+    ///     isahc::get("http://localhost").expect_redirect_to("/login");
+    ///     isahc::get_async("http://localhost").await.expect_redirect_to("/login");
+    ///
+    ///     surf::get("http://localhost").await.expect_redirect_to("/login");
+    ///
+    ///     ureq::get("http://localhost").call().or_any_status().expect_redirect_to("/login");
+    ///
+    ///     hyper::Client::new().get("http://localhost".parse().unwrap()).await.expect_redirect_to("/login");
+    ///
+    ///     awc::Client::default().get("http://localhost").send().await.expect_redirect_to("/login");
+    /// }
+    /// ```
+    fn expect_redirect_to(&mut self, location: impl AsRef<str>) -> &mut T;
+
+    /// Expects a 3xx response redirecting to the location, both resolved against the base url
+    /// * `location` - expected location
+    /// * `base` - url against which relative locations are resolved e.g. `https://app.example`
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use ureq::OrAnyStatus;
+    /// use asserhttp::*;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     reqwest::blocking::get("http://localhost").expect_redirect_to_with_base("/login", "https://app.example");
+    ///     reqwest::get("http://localhost").await.expect_redirect_to_with_base("/login", "https://app.example");
+    ///
+    ///     isahc::get("http://localhost").expect_redirect_to_with_base("/login", "https://app.example");
+    ///     isahc::get_async("http://localhost").await.expect_redirect_to_with_base("/login", "https://app.example");
+    ///
+    ///     surf::get("http://localhost").await.expect_redirect_to_with_base("/login", "https://app.example");
+    ///
+    ///     ureq::get("http://localhost").call().or_any_status().expect_redirect_to_with_base("/login", "https://app.example");
+    ///
+    ///     hyper::Client::new().get("http://localhost".parse().unwrap()).await.expect_redirect_to_with_base("/login", "https://app.example");
+    ///
+    ///     awc::Client::default().get("http://localhost").send().await.expect_redirect_to_with_base("/login", "https://app.example");
+    /// }
+    /// ```
+    fn expect_redirect_to_with_base(&mut self, location: impl AsRef<str>, base: impl AsRef<str>) -> &mut T;
+
+    /// Expects a permanent redirect: a `301` or `308` status with a `Location` header
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use ureq::OrAnyStatus;
+    /// use asserhttp::*;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     reqwest::blocking::get("http://localhost").expect_permanent_redirect();
+    ///     reqwest::get("http://localhost").await.expect_permanent_redirect();
+    ///
+    ///     isahc::get("http://localhost").expect_permanent_redirect();
+    ///     isahc::get_async("http://localhost").await.expect_permanent_redirect();
+    ///
+    ///     surf::get("http://localhost").await.expect_permanent_redirect();
+    ///
+    ///     ureq::get("http://localhost").call().or_any_status().expect_permanent_redirect();
+    ///
+    ///     hyper::Client::new().get("http://localhost".parse().unwrap()).await.expect_permanent_redirect();
+    ///
+    ///     awc::Client::default().get("http://localhost").send().await.expect_permanent_redirect();
+    /// }
+    /// ```
+    fn expect_permanent_redirect(&mut self) -> &mut T;
+
+    /// Expects a temporary redirect: a `302`, `303` or `307` status with a `Location` header
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use ureq::OrAnyStatus;
+    /// use asserhttp::*;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     reqwest::blocking::get("http://localhost").expect_temporary_redirect();
+    ///     reqwest::get("http://localhost").await.expect_temporary_redirect();
+    ///
+    ///     isahc::get("http://localhost").expect_temporary_redirect();
+    ///     isahc::get_async("http://localhost").await.expect_temporary_redirect();
+    ///
+    ///     surf::get("http://localhost").await.expect_temporary_redirect();
+    ///
+    ///     ureq::get("http://localhost").call().or_any_status().expect_temporary_redirect();
+    ///
+    ///     hyper::Client::new().get("http://localhost".parse().unwrap()).await.expect_temporary_redirect();
+    ///
+    ///     awc::Client::default().get("http://localhost").send().await.expect_temporary_redirect();
+    /// }
+    /// ```
+    fn expect_temporary_redirect(&mut self) -> &mut T;
+}
+
+impl<T> AsserhttpRedirect<T> for T
+where
+    T: HeaderAccessor + StatusAccessor,
+{
+    fn expect_redirect<F>(&mut self, asserter: F) -> &mut T
+    where
+        F: FnOnce(Url),
+    {
+        #[allow(clippy::blocks_in_conditions)]
+        match self.try_expect_redirect(|v| {
+            asserter(v);
+            Ok(())
+        }) {
+            Err(e) => panic!("{e}"),
+            Ok(r) => r,
+        }
+    }
+
+    fn expect_redirect_with_base<F>(&mut self, base: impl AsRef<str>, asserter: F) -> &mut T
+    where
+        F: FnOnce(Url),
+    {
+        #[allow(clippy::blocks_in_conditions)]
+        match self.try_expect_redirect_with_base(base, |v| {
+            asserter(v);
+            Ok(())
+        }) {
+            Err(e) => panic!("{e}"),
+            Ok(r) => r,
+        }
+    }
+
+    fn expect_redirect_to(&mut self, location: impl AsRef<str>) -> &mut T {
+        match self.try_expect_redirect_to(location) {
+            Err(e) => panic!("{e}"),
+            Ok(r) => r,
+        }
+    }
+
+    fn expect_redirect_to_with_base(&mut self, location: impl AsRef<str>, base: impl AsRef<str>) -> &mut T {
+        match self.try_expect_redirect_to_with_base(location, base) {
+            Err(e) => panic!("{e}"),
+            Ok(r) => r,
+        }
+    }
+
+    fn expect_permanent_redirect(&mut self) -> &mut T {
+        match self.try_expect_permanent_redirect() {
+            Err(e) => panic!("{e}"),
+            Ok(r) => r,
+        }
+    }
+
+    fn expect_temporary_redirect(&mut self) -> &mut T {
+        match self.try_expect_temporary_redirect() {
+            Err(e) => panic!("{e}"),
+            Ok(r) => r,
+        }
+    }
+}
+
+impl<T, E> AsserhttpRedirect<T> for Result<T, E>
+where
+    T: HeaderAccessor + StatusAccessor,
+    E: std::fmt::Debug,
+{
+    fn expect_redirect<F>(&mut self, asserter: F) -> &mut T
+    where
+        F: FnOnce(Url),
+    {
+        self.as_mut().unwrap().expect_redirect(asserter)
+    }
+
+    fn expect_redirect_with_base<F>(&mut self, base: impl AsRef<str>, asserter: F) -> &mut T
+    where
+        F: FnOnce(Url),
+    {
+        self.as_mut().unwrap().expect_redirect_with_base(base, asserter)
+    }
+
+    fn expect_redirect_to(&mut self, location: impl AsRef<str>) -> &mut T {
+        self.as_mut().unwrap().expect_redirect_to(location)
+    }
+
+    fn expect_redirect_to_with_base(&mut self, location: impl AsRef<str>, base: impl AsRef<str>) -> &mut T {
+        self.as_mut().unwrap().expect_redirect_to_with_base(location, base)
+    }
+
+    fn expect_permanent_redirect(&mut self) -> &mut T {
+        self.as_mut().unwrap().expect_permanent_redirect()
+    }
+
+    fn expect_temporary_redirect(&mut self) -> &mut T {
+        self.as_mut().unwrap().expect_temporary_redirect()
+    }
+}
@@ -0,0 +1,83 @@
+use url::Url;
+
+use crate::{
+    accessor::{HeaderAccessor, StatusAccessor},
+    error::{AsserhttpError, AsserhttpResult},
+    header::key::HeaderKey,
+    headers,
+    status::fallible::{FallibleAnyStatus, FallibleAsserhttpStatus},
+};
+
+pub(crate) mod fallible;
+pub(crate) mod infallible;
+
+/// Base against which relative locations are resolved when none is given
+const DEFAULT_BASE: &str = "http://localhost/";
+
+const PERMANENT: [u16; 2] = [301, 308];
+const TEMPORARY: [u16; 3] = [302, 303, 307];
+
+/// Expects the status to be accepted and a `Location` header to be present, then returns it
+fn location<T>(response: &mut T, status: impl Into<FallibleAnyStatus>) -> AsserhttpResult<String>
+where
+    T: HeaderAccessor + StatusAccessor,
+{
+    let location = response
+        .try_expect_status(status)?
+        .first_raw_value(&HeaderKey::from(headers::LOCATION))?;
+    Ok(location.trim().to_string())
+}
+
+fn any_redirection() -> FallibleAnyStatus {
+    FallibleAnyStatus::from(|status: u16| {
+        if (300..400).contains(&status) {
+            return Ok(());
+        }
+        Err(AsserhttpError::StatusRangeMismatch {
+            lower: 300,
+            upper: 400,
+            actual: status,
+        })
+    })
+}
+
+fn one_of(expected: &'static [u16]) -> FallibleAnyStatus {
+    FallibleAnyStatus::from(move |status: u16| {
+        if expected.contains(&status) {
+            return Ok(());
+        }
+        Err(AsserhttpError::StatusNotOneOf {
+            expected: expected.to_vec(),
+            actual: status,
+        })
+    })
+}
+
+/// Resolves a possibly relative location against the base
+fn resolve(location: &str, base: &Url) -> AsserhttpResult<Url> {
+    base.join(location).map_err(|e| AsserhttpError::InvalidUrl {
+        value: location.to_string(),
+        message: e.to_string(),
+    })
+}
+
+pub(crate) fn parse(url: &str) -> AsserhttpResult<Url> {
+    resolve(url, &Url::parse(DEFAULT_BASE).map_err(|_| AsserhttpError::InternalError)?)
+}
+
+/// Whether the actual location points to the expected one. Both are resolved against the base when there is one.
+/// Otherwise a relative expectation only matches a relative location, and a relative location is assumed to share
+/// the origin of an absolute expectation
+fn same_location(actual: &str, expected: &str, base: Option<&str>) -> AsserhttpResult<bool> {
+    Ok(match base {
+        Some(base) => {
+            let base = parse(base)?;
+            resolve(actual, &base)? == resolve(expected, &base)?
+        },
+        None => match Url::parse(expected) {
+            Ok(expected) => resolve(actual, &expected)? == expected,
+            // resolved against the same base only so that both relative references are normalized the same way
+            Err(_) => Url::parse(actual).is_err() && parse(actual)? == parse(expected)?,
+        },
+    })
+}
@@ -0,0 +1,95 @@
+use crate::{
+    accessor::HeaderAccessor,
+    error::{AsserhttpError, AsserhttpResult},
+    security::SecurityHeadersPolicy,
+};
+
+/// For assertions on the security headers of a response returning an error instead of panicking
+pub trait FallibleAsserhttpSecurityHeaders<T> {
+    /// Expects the response to carry the usual security headers: `Strict-Transport-Security` with a `max-age`
+    /// of at least one year, `X-Content-Type-Options: nosniff`, `X-Frame-Options` or a CSP `frame-ancestors` directive,
+    /// a `Referrer-Policy` and no version disclosed by `Server` or `X-Powered-By`. Every violation is reported at once
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use ureq::OrAnyStatus;
+    /// use asserhttp::*;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     reqwest::blocking::get("http://localhost").try_expect_security_headers().unwrap();
+    ///     reqwest::get("http://localhost").await.try_expect_security_headers().unwrap();
+    ///
+    ///     isahc::get("http://localhost").try_expect_security_headers().unwrap();
+    ///     isahc::get_async("http://localhost").await.try_expect_security_headers().unwrap();
+    ///
+    ///     surf::get("http://localhost").await.try_expect_security_headers().unwrap();
+    ///
+    ///     ureq::get("http://localhost").call().or_any_status().try_expect_security_headers().unwrap();
+    ///
+    ///     hyper::Client::new().get("http://localhost".parse().unwrap()).await.try_expect_security_headers().unwrap();
+    ///
+    ///     awc::Client::default().get("http://localhost").send().await.try_expect_security_headers().unwrap();
+    /// }
+    /// ```
+    /// # Error
+    /// * [AsserhttpError::SecurityHeadersViolations] listing every violation of the policy
+    fn try_expect_security_headers(&mut self) -> AsserhttpResult<&mut T> {
+        self.try_expect_security_headers_with(SecurityHeadersPolicy::default())
+    }
+
+    /// Expects the response to carry the security headers required by the given policy.
+    /// Every violation is reported at once
+    /// * `policy` - which checks are enabled, and the minimum HSTS `max-age`
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use ureq::OrAnyStatus;
+    /// use asserhttp::*;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     reqwest::blocking::get("http://localhost").try_expect_security_headers_with(SecurityHeadersPolicy::default().skip_hsts()).unwrap();
+    ///     reqwest::get("http://localhost").await.try_expect_security_headers_with(SecurityHeadersPolicy::default().skip_hsts()).unwrap();
+    ///
+    ///     isahc::get("http://localhost").try_expect_security_headers_with(SecurityHeadersPolicy::default().skip_hsts()).unwrap();
+    ///     isahc::get_async("http://localhost").await.try_expect_security_headers_with(SecurityHeadersPolicy::default().skip_hsts()).unwrap();
+    ///
+    ///     surf::get("http://localhost").await.try_expect_security_headers_with(SecurityHeadersPolicy::default().skip_hsts()).unwrap();
+    ///
+    ///     ureq::get("http://localhost").call().or_any_status().try_expect_security_headers_with(SecurityHeadersPolicy::default().skip_hsts()).unwrap();
+    ///
+    ///     hyper::Client::new().get("http://localhost".parse().unwrap()).await.try_expect_security_headers_with(SecurityHeadersPolicy::default().skip_hsts()).unwrap();
+    ///
+    ///     awc::Client::default().get("http://localhost").send().await.try_expect_security_headers_with(SecurityHeadersPolicy::default().skip_hsts()).unwrap();
+    /// }
+    /// ```
+    /// # Error
+    /// * [AsserhttpError::SecurityHeadersViolations] listing every violation of the policy
+    fn try_expect_security_headers_with(&mut self, policy: SecurityHeadersPolicy) -> AsserhttpResult<&mut T>;
+}
+
+impl<T> FallibleAsserhttpSecurityHeaders<T> for T
+where
+    T: HeaderAccessor,
+{
+    fn try_expect_security_headers_with(&mut self, policy: SecurityHeadersPolicy) -> AsserhttpResult<&mut T> {
+        let violations = policy.violations(self);
+        if !violations.is_empty() {
+            return Err(AsserhttpError::SecurityHeadersViolations { violations });
+        }
+        Ok(self)
+    }
+}
+
+impl<T, E> FallibleAsserhttpSecurityHeaders<T> for Result<T, E>
+where
+    T: HeaderAccessor,
+    E: std::fmt::Debug,
+{
+    fn try_expect_security_headers_with(&mut self, policy: SecurityHeadersPolicy) -> AsserhttpResult<&mut T> {
+        self.as_mut()
+            .map_err(|e| AsserhttpError::HttpError(format!("{e:?}")))?
+            .try_expect_security_headers_with(policy)
+    }
+}
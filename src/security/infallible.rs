@@ -0,0 +1,96 @@
+use crate::{
+    accessor::HeaderAccessor,
+    security::{fallible::FallibleAsserhttpSecurityHeaders, SecurityHeadersPolicy},
+};
+
+/// For assertions on the security headers of a response
+pub trait AsserhttpSecurityHeaders<T> {
+    /// Expects the response to carry the usual security headers: `Strict-Transport-Security` with a `max-age`
+    /// of at least one year, `X-Content-Type-Options: nosniff`, `X-Frame-Options` or a CSP `frame-ancestors` directive,
+    /// a `Referrer-Policy` and no version disclosed by `Server` or `X-Powered-By`. Every violation is reported at once
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use ureq::OrAnyStatus;
+    /// use asserhttp::*;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     reqwest::blocking::get("http://localhost").expect_security_headers();
+    ///     reqwest::get("http://localhost").await.expect_security_headers();
+    ///
+    ///     isahc::get("http://localhost").expect_security_headers();
+    ///     isahc::get_async("http://localhost").await.expect_security_headers();
+    ///
+    ///     surf::get("http://localhost").await.expect_security_headers();
+    ///
+    ///     ureq::get("http://localhost").call().or_any_status().expect_security_headers();
+    ///
+    ///     hyper::Client::new().get("http://localhost".parse().unwrap()).await.expect_security_headers();
+    ///
+    ///     awc::Client::default().get("http://localhost").send().await.expect_security_headers();
+    /// }
+    /// ```
+    fn expect_security_headers(&mut self) -> &mut T;
+
+    /// Expects the response to carry the security headers required by the given policy.
+    /// Every violation is reported at once
+    /// * `policy` - which checks are enabled, and the minimum HSTS `max-age`
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use ureq::OrAnyStatus;
+    /// use asserhttp::*;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     reqwest::blocking::get("http://localhost").expect_security_headers_with(SecurityHeadersPolicy::default().skip_hsts());
+    ///     reqwest::get("http://localhost").await.expect_security_headers_with(SecurityHeadersPolicy::default().skip_hsts());
+    ///
+    ///     isahc::get("http://localhost").expect_security_headers_with(SecurityHeadersPolicy::default().skip_hsts());
+    ///     isahc::get_async("http://localhost").await.expect_security_headers_with(SecurityHeadersPolicy::default().skip_hsts());
+    ///
+    ///     surf::get("http://localhost").await.expect_security_headers_with(SecurityHeadersPolicy::default().skip_hsts());
+    ///
+    ///     ureq::get("http://localhost").call().or_any_status().expect_security_headers_with(SecurityHeadersPolicy::default().skip_hsts());
+    ///
+    ///     hyper::Client::new().get("http://localhost".parse().unwrap()).await.expect_security_headers_with(SecurityHeadersPolicy::default().skip_hsts());
+    ///
+    ///     awc::Client::default().get("http://localhost").send().await.expect_security_headers_with(SecurityHeadersPolicy::default().skip_hsts());
+    /// }
+    /// ```
+    fn expect_security_headers_with(&mut self, policy: SecurityHeadersPolicy) -> &mut T;
+}
+
+impl<T> AsserhttpSecurityHeaders<T> for T
+where
+    T: HeaderAccessor,
+{
+    fn expect_security_headers(&mut self) -> &mut T {
+        match self.try_expect_security_headers() {
+            Err(e) => panic!("{e}"),
+            Ok(r) => r,
+        }
+    }
+
+    fn expect_security_headers_with(&mut self, policy: SecurityHeadersPolicy) -> &mut T {
+        match self.try_expect_security_headers_with(policy) {
+            Err(e) => panic!("{e}"),
+            Ok(r) => r,
+        }
+    }
+}
+
+impl<T, E> AsserhttpSecurityHeaders<T> for Result<T, E>
+where
+    T: HeaderAccessor,
+    E: std::fmt::Debug,
+{
+    fn expect_security_headers(&mut self) -> &mut T {
+        self.as_mut().unwrap().expect_security_headers()
+    }
+
+    fn expect_security_headers_with(&mut self, policy: SecurityHeadersPolicy) -> &mut T {
+        self.as_mut().unwrap().expect_security_headers_with(policy)
+    }
+}
@@ -0,0 +1,146 @@
+use std::time::Duration;
+
+use crate::{accessor::HeaderAccessor, csp::Csp, header::key::HeaderKey};
+
+pub(crate) mod fallible;
+pub(crate) mod infallible;
+
+/// One year, the minimum accepted for HSTS preloading
+const DEFAULT_HSTS_MIN_MAX_AGE: Duration = Duration::from_secs(31_536_000);
+
+/// Headers which should not disclose the version of the software serving the response
+const VERSION_LEAKING_HEADERS: [&str; 2] = ["server", "x-powered-by"];
+
+/// Configures which security headers a response is expected to carry.
+/// By default every check is enabled and HSTS requires a `max-age` of at least one year
+#[derive(Debug, Clone)]
+pub struct SecurityHeadersPolicy {
+    hsts_min_max_age: Option<Duration>,
+    content_type_options: bool,
+    frame_options: bool,
+    referrer_policy: bool,
+    version_leaks: bool,
+}
+
+impl Default for SecurityHeadersPolicy {
+    fn default() -> Self {
+        Self {
+            hsts_min_max_age: Some(DEFAULT_HSTS_MIN_MAX_AGE),
+            content_type_options: true,
+            frame_options: true,
+            referrer_policy: true,
+            version_leaks: true,
+        }
+    }
+}
+
+impl SecurityHeadersPolicy {
+    /// Minimum `max-age` of the `Strict-Transport-Security` header
+    pub fn hsts_min_max_age(mut self, min: Duration) -> Self {
+        self.hsts_min_max_age = Some(min);
+        self
+    }
+
+    /// Does not require a `Strict-Transport-Security` header e.g. for a service only reachable over plain http
+    pub fn skip_hsts(mut self) -> Self {
+        self.hsts_min_max_age = None;
+        self
+    }
+
+    /// Does not require `X-Content-Type-Options: nosniff`
+    pub fn skip_content_type_options(mut self) -> Self {
+        self.content_type_options = false;
+        self
+    }
+
+    /// Does not require `X-Frame-Options` nor a CSP `frame-ancestors` directive
+    pub fn skip_frame_options(mut self) -> Self {
+        self.frame_options = false;
+        self
+    }
+
+    /// Does not require a `Referrer-Policy` header
+    pub fn skip_referrer_policy(mut self) -> Self {
+        self.referrer_policy = false;
+        self
+    }
+
+    /// Tolerates `Server` or `X-Powered-By` headers disclosing a version
+    pub fn skip_version_leaks(mut self) -> Self {
+        self.version_leaks = false;
+        self
+    }
+
+    /// Every violation of this policy, in a stable order
+    pub(crate) fn violations(&self, accessor: &impl HeaderAccessor) -> Vec<String> {
+        let first = |key: &str| accessor.get_raw_values(&HeaderKey::from(key)).into_iter().next();
+        let mut violations = vec![];
+        if let Some(min) = self.hsts_min_max_age {
+            match first("strict-transport-security") {
+                None => violations.push("missing 'strict-transport-security' header".to_string()),
+                Some(hsts) => match hsts_max_age(&hsts) {
+                    Some(max_age) if max_age >= min.as_secs() => {},
+                    Some(max_age) => violations.push(format!(
+                        "'strict-transport-security' max-age {max_age}s is below the minimum of {}s",
+                        min.as_secs()
+                    )),
+                    None => violations.push(format!("'strict-transport-security' has no max-age in '{hsts}'")),
+                },
+            }
+        }
+        if self.content_type_options {
+            match first("x-content-type-options") {
+                Some(v) if v.trim().eq_ignore_ascii_case("nosniff") => {},
+                Some(v) => violations.push(format!("'x-content-type-options' should be 'nosniff' but was '{v}'")),
+                None => violations.push("missing 'x-content-type-options' header".to_string()),
+            }
+        }
+        if self.frame_options {
+            let csp = accessor.get_raw_values(&HeaderKey::from("content-security-policy"));
+            let frame_ancestors = Csp::parse(&csp, false).iter().any(|policy| policy.has("frame-ancestors"));
+            match first("x-frame-options") {
+                Some(v) if ["deny", "sameorigin"].contains(&v.trim().to_lowercase().as_str()) => {},
+                Some(v) if !frame_ancestors => violations.push(format!("'x-frame-options' should be 'DENY' or 'SAMEORIGIN' but was '{v}'")),
+                None if !frame_ancestors => violations
+                    .push("missing 'x-frame-options' header or 'frame-ancestors' directive in 'content-security-policy'".to_string()),
+                _ => {},
+            }
+        }
+        if self.referrer_policy {
+            match first("referrer-policy") {
+                Some(v) if v.trim().eq_ignore_ascii_case("unsafe-url") => {
+                    violations.push("'referrer-policy' should not be 'unsafe-url'".to_string())
+                },
+                Some(_) => {},
+                None => violations.push("missing 'referrer-policy' header".to_string()),
+            }
+        }
+        if self.version_leaks {
+            for key in VERSION_LEAKING_HEADERS {
+                if let Some(v) = accessor
+                    .get_raw_values(&HeaderKey::from(key))
+                    .into_iter()
+                    .find(|v| leaks_version(v))
+                {
+                    violations.push(format!("'{key}' discloses a version in '{v}'"))
+                }
+            }
+        }
+        violations
+    }
+}
+
+fn hsts_max_age(hsts: &str) -> Option<u64> {
+    hsts.split(';')
+        .filter_map(|d| d.split_once('='))
+        .find(|(k, _)| k.trim().eq_ignore_ascii_case("max-age"))
+        .and_then(|(_, v)| v.trim().trim_matches('"').parse().ok())
+}
+
+/// Whether a product token carries a version, like `nginx/1.25.3` or `PHP/8.2`
+fn leaks_version(value: &str) -> bool {
+    value
+        .split_whitespace()
+        .filter_map(|product| product.split_once('/'))
+        .any(|(_, version)| version.starts_with(|c: char| c.is_ascii_digit()))
+}
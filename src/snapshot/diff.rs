@@ -0,0 +1,31 @@
+/// Line by line diff between the stored and the actual snapshot. Removed lines are prefixed with `-`, added ones
+/// with `+`
+pub fn lines(expected: &str, actual: &str) -> String {
+    let (expected, actual) = (expected.lines().collect::<Vec<_>>(), actual.lines().collect::<Vec<_>>());
+    // lcs[i][j] is the length of the longest common subsequence of expected[i..] and actual[j..]
+    let mut lcs = vec![vec![0usize; actual.len() + 1]; expected.len() + 1];
+    for i in (0..expected.len()).rev() {
+        for j in (0..actual.len()).rev() {
+            lcs[i][j] = if expected[i] == actual[j] {
+                lcs[i + 1][j + 1] + 1
+            } else {
+                lcs[i + 1][j].max(lcs[i][j + 1])
+            };
+        }
+    }
+    let (mut i, mut j) = (0, 0);
+    let mut out = vec![];
+    while i < expected.len() || j < actual.len() {
+        if i < expected.len() && j < actual.len() && expected[i] == actual[j] {
+            out.push(format!("  {}", expected[i]));
+            (i, j) = (i + 1, j + 1);
+        } else if i < expected.len() && (j == actual.len() || lcs[i + 1][j] >= lcs[i][j + 1]) {
+            out.push(format!("- {}", expected[i]));
+            i += 1;
+        } else {
+            out.push(format!("+ {}", actual[j]));
+            j += 1;
+        }
+    }
+    out.join("\n")
+}
@@ -0,0 +1,115 @@
+use crate::{
+    accessor::AllAccessors,
+    error::{AsserhttpError, AsserhttpResult},
+    snapshot::SnapshotSettings,
+};
+
+/// For assertions on a whole response stored in a snapshot file returning an error instead of panicking
+pub trait FallibleAsserhttpSnapshot<T> {
+    /// Expects the response to be equal to the snapshot stored in `tests/snapshots/{name}.snap`.
+    /// The snapshot holds the status, the headers sorted by key and the normalized body. `date` and request id headers
+    /// are redacted while transport headers such as `content-length` are left out.
+    /// When `ASSERHTTP_BLESS` is set, the snapshot is written instead of being compared
+    /// * `name` - name of the snapshot file, without extension
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use ureq::OrAnyStatus;
+    /// use asserhttp::*;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     reqwest::blocking::get("http://localhost").try_expect_snapshot("create_user").unwrap();
+    ///     reqwest::get("http://localhost").await.try_expect_snapshot("create_user").unwrap();
+    ///
+    ///     isahc::get("http://localhost").try_expect_snapshot("create_user").unwrap();
+    ///     isahc::get_async("http://localhost").await.try_expect_snapshot("create_user").unwrap();
+    ///
+    ///     surf::get("http://localhost").await.try_expect_snapshot("create_user").unwrap();
+    ///
+    ///     ureq::get("http://localhost").call().or_any_status().try_expect_snapshot("create_user").unwrap();
+    ///
+    ///     hyper::Client::new().get("http://localhost".parse().unwrap()).await.try_expect_snapshot("create_user").unwrap();
+    ///
+    ///     awc::Client::default().get("http://localhost").send().await.try_expect_snapshot("create_user").unwrap();
+    /// }
+    /// ```
+    /// # Error
+    /// * [AsserhttpError::SnapshotAbsent] when no snapshot is stored yet and `ASSERHTTP_BLESS` is not set
+    /// * [AsserhttpError::SnapshotMismatch] when the response differs from the stored snapshot
+    /// * [AsserhttpError::FixtureRead] when the snapshot file cannot be read
+    /// * [AsserhttpError::FixtureWrite] when the snapshot file cannot be written in bless mode
+    fn try_expect_snapshot<N>(&mut self, name: N) -> AsserhttpResult<&mut T>
+    where
+        N: AsRef<str>,
+    {
+        self.try_expect_snapshot_with(name, SnapshotSettings::default())
+    }
+
+    /// Expects the response to be equal to a stored snapshot, serialized according to the given settings.
+    /// When `ASSERHTTP_BLESS` is set, the snapshot is written instead of being compared
+    /// * `name` - name of the snapshot file, without extension
+    /// * `settings` - snapshot directory, ignored and redacted headers, redacted json paths
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use ureq::OrAnyStatus;
+    /// use asserhttp::*;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     reqwest::blocking::get("http://localhost").try_expect_snapshot_with("create_user", SnapshotSettings::default().redact_json_path("$.id")).unwrap();
+    ///     reqwest::get("http://localhost").await.try_expect_snapshot_with("create_user", SnapshotSettings::default().redact_json_path("$.id")).unwrap();
+    ///
+    ///     isahc::get("http://localhost").try_expect_snapshot_with("create_user", SnapshotSettings::default().redact_json_path("$.id")).unwrap();
+    ///     isahc::get_async("http://localhost").await.try_expect_snapshot_with("create_user", SnapshotSettings::default().redact_json_path("$.id")).unwrap();
+    ///
+    ///     surf::get("http://localhost").await.try_expect_snapshot_with("create_user", SnapshotSettings::default().redact_json_path("$.id")).unwrap();
+    ///
+    ///     ureq::get("http://localhost").call().or_any_status().try_expect_snapshot_with("create_user", SnapshotSettings::default().redact_json_path("$.id")).unwrap();
+    ///
+    ///     hyper::Client::new().get("http://localhost".parse().unwrap()).await.try_expect_snapshot_with("create_user", SnapshotSettings::default().redact_json_path("$.id")).unwrap();
+    ///
+    ///     awc::Client::default().get("http://localhost").send().await.try_expect_snapshot_with("create_user", SnapshotSettings::default().redact_json_path("$.id")).unwrap();
+    /// }
+    /// ```
+    /// # Error
+    /// * [AsserhttpError::SnapshotAbsent] when no snapshot is stored yet and `ASSERHTTP_BLESS` is not set
+    /// * [AsserhttpError::SnapshotMismatch] when the response differs from the stored snapshot
+    /// * [AsserhttpError::FixtureRead] when the snapshot file cannot be read
+    /// * [AsserhttpError::FixtureWrite] when the snapshot file cannot be written in bless mode
+    /// * [AsserhttpError::JsonPathInvalid] when a json path to redact is not supported
+    /// * [AsserhttpError::SnapshotRedactionUnmatched] when a json path to redact selects nothing in the body
+    /// * [AsserhttpError::SnapshotRedactionNotJson] when there are json paths to redact but the body is not json
+    fn try_expect_snapshot_with<N>(&mut self, name: N, settings: SnapshotSettings) -> AsserhttpResult<&mut T>
+    where
+        N: AsRef<str>;
+}
+
+impl<T> FallibleAsserhttpSnapshot<T> for T
+where
+    T: AllAccessors,
+{
+    fn try_expect_snapshot_with<N>(&mut self, name: N, settings: SnapshotSettings) -> AsserhttpResult<&mut T>
+    where
+        N: AsRef<str>,
+    {
+        settings.verify(name.as_ref(), self)?;
+        Ok(self)
+    }
+}
+
+impl<T, E> FallibleAsserhttpSnapshot<T> for Result<T, E>
+where
+    T: AllAccessors,
+    E: std::fmt::Debug,
+{
+    fn try_expect_snapshot_with<N>(&mut self, name: N, settings: SnapshotSettings) -> AsserhttpResult<&mut T>
+    where
+        N: AsRef<str>,
+    {
+        self.as_mut()
+            .map_err(|e| AsserhttpError::HttpError(format!("{e:?}")))?
+            .try_expect_snapshot_with(name, settings)
+    }
+}
@@ -0,0 +1,115 @@
+use crate::{
+    accessor::AllAccessors,
+    snapshot::{fallible::FallibleAsserhttpSnapshot, SnapshotSettings},
+};
+
+/// For assertions on a whole response stored in a snapshot file
+pub trait AsserhttpSnapshot<T> {
+    /// Expects the response to be equal to the snapshot stored in `tests/snapshots/{name}.snap`.
+    /// The snapshot holds the status, the headers sorted by key and the normalized body. `date` and request id headers
+    /// are redacted while transport headers such as `content-length` are left out.
+    /// When `ASSERHTTP_BLESS` is set, the snapshot is written instead of being compared
+    /// * `name` - name of the snapshot file, without extension
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use ureq::OrAnyStatus;
+    /// use asserhttp::*;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     reqwest::blocking::get("http://localhost").expect_snapshot("create_user");
+    ///     reqwest::get("http://localhost").await.expect_snapshot("create_user");
+    ///
+    ///     isahc::get("http://localhost").expect_snapshot("create_user");
+    ///     isahc::get_async("http://localhost").await.expect_snapshot("create_user");
+    ///
+    ///     surf::get("http://localhost").await.expect_snapshot("create_user");
+    ///
+    ///     ureq::get("http://localhost").call().or_any_status().expect_snapshot("create_user");
+    ///
+    ///     hyper::Client::new().get("http://localhost".parse().unwrap()).await.expect_snapshot("create_user");
+    ///
+    ///     awc::Client::default().get("http://localhost").send().await.expect_snapshot("create_user");
+    /// }
+    /// ```
+    fn expect_snapshot<N>(&mut self, name: N) -> &mut T
+    where
+        N: AsRef<str>;
+
+    /// Expects the response to be equal to a stored snapshot, serialized according to the given settings.
+    /// When `ASSERHTTP_BLESS` is set, the snapshot is written instead of being compared
+    /// * `name` - name of the snapshot file, without extension
+    /// * `settings` - snapshot directory, ignored and redacted headers, redacted json paths
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use ureq::OrAnyStatus;
+    /// use asserhttp::*;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     reqwest::blocking::get("http://localhost").expect_snapshot_with("create_user", SnapshotSettings::default().redact_json_path("$.id"));
+    ///     reqwest::get("http://localhost").await.expect_snapshot_with("create_user", SnapshotSettings::default().redact_json_path("$.id"));
+    ///
+    ///     isahc::get("http://localhost").expect_snapshot_with("create_user", SnapshotSettings::default().redact_json_path("$.id"));
+    ///     isahc::get_async("http://localhost").await.expect_snapshot_with("create_user", SnapshotSettings::default().redact_json_path("$.id"));
+    ///
+    ///     surf::get("http://localhost").await.expect_snapshot_with("create_user", SnapshotSettings::default().redact_json_path("$.id"));
+    ///
+    ///     ureq::get("http://localhost").call().or_any_status().expect_snapshot_with("create_user", SnapshotSettings::default().redact_json_path("$.id"));
+    ///
+    ///     hyper::Client::new().get("http://localhost".parse().unwrap()).await.expect_snapshot_with("create_user", SnapshotSettings::default().redact_json_path("$.id"));
+    ///
+    ///     awc::Client::default().get("http://localhost").send().await.expect_snapshot_with("create_user", SnapshotSettings::default().redact_json_path("$.id"));
+    /// }
+    /// ```
+    fn expect_snapshot_with<N>(&mut self, name: N, settings: SnapshotSettings) -> &mut T
+    where
+        N: AsRef<str>;
+}
+
+impl<T> AsserhttpSnapshot<T> for T
+where
+    T: AllAccessors,
+{
+    fn expect_snapshot<N>(&mut self, name: N) -> &mut T
+    where
+        N: AsRef<str>,
+    {
+        match self.try_expect_snapshot(name) {
+            Err(e) => panic!("{e}"),
+            Ok(r) => r,
+        }
+    }
+
+    fn expect_snapshot_with<N>(&mut self, name: N, settings: SnapshotSettings) -> &mut T
+    where
+        N: AsRef<str>,
+    {
+        match self.try_expect_snapshot_with(name, settings) {
+            Err(e) => panic!("{e}"),
+            Ok(r) => r,
+        }
+    }
+}
+
+impl<T, E> AsserhttpSnapshot<T> for Result<T, E>
+where
+    T: AllAccessors,
+    E: std::fmt::Debug,
+{
+    fn expect_snapshot<N>(&mut self, name: N) -> &mut T
+    where
+        N: AsRef<str>,
+    {
+        self.as_mut().unwrap().expect_snapshot(name)
+    }
+
+    fn expect_snapshot_with<N>(&mut self, name: N, settings: SnapshotSettings) -> &mut T
+    where
+        N: AsRef<str>,
+    {
+        self.as_mut().unwrap().expect_snapshot_with(name, settings)
+    }
+}
@@ -0,0 +1,173 @@
+use std::path::{Path, PathBuf};
+
+use serde_json::Value;
+
+use crate::{
+    accessor::AllAccessors,
+    body::fixture,
+    capture::JsonPath,
+    error::{AsserhttpError, AsserhttpResult},
+};
+
+pub(crate) mod diff;
+pub(crate) mod fallible;
+pub(crate) mod infallible;
+
+const DEFAULT_DIR: &str = "tests/snapshots";
+const EXTENSION: &str = "snap";
+const REDACTED: &str = "[redacted]";
+
+/// Headers depending on the transport rather than on the application
+const IGNORED_HEADERS: [&str; 4] = ["connection", "content-length", "keep-alive", "transfer-encoding"];
+
+/// Headers changing on every call
+const REDACTED_HEADERS: [&str; 4] = ["date", "x-request-id", "x-correlation-id", "traceparent"];
+
+/// Configures how a response is serialized into a snapshot
+#[derive(Debug, Clone)]
+pub struct SnapshotSettings {
+    dir: PathBuf,
+    kept_headers: Option<Vec<String>>,
+    ignored_headers: Vec<String>,
+    redacted_headers: Vec<String>,
+    redacted_json_paths: Vec<String>,
+}
+
+impl Default for SnapshotSettings {
+    fn default() -> Self {
+        Self {
+            dir: PathBuf::from(DEFAULT_DIR),
+            kept_headers: None,
+            ignored_headers: IGNORED_HEADERS.map(str::to_string).to_vec(),
+            redacted_headers: REDACTED_HEADERS.map(str::to_string).to_vec(),
+            redacted_json_paths: vec![],
+        }
+    }
+}
+
+impl SnapshotSettings {
+    /// Directory holding the snapshots, `tests/snapshots` by default
+    pub fn dir(mut self, dir: impl AsRef<Path>) -> Self {
+        self.dir = dir.as_ref().to_path_buf();
+        self
+    }
+
+    /// Only keeps the given headers in the snapshot, useful when the server adds headers of its own
+    pub fn only_headers<I, K>(mut self, keys: I) -> Self
+    where
+        I: IntoIterator<Item = K>,
+        K: AsRef<str>,
+    {
+        self.kept_headers = Some(keys.into_iter().map(|k| k.as_ref().to_lowercase()).collect());
+        self
+    }
+
+    /// Leaves a header out of the snapshot
+    pub fn ignore_header(mut self, key: impl AsRef<str>) -> Self {
+        self.ignored_headers.push(key.as_ref().to_lowercase());
+        self
+    }
+
+    /// Keeps a header in the snapshot but replaces its value with `[redacted]`
+    pub fn redact_header(mut self, key: impl AsRef<str>) -> Self {
+        self.redacted_headers.push(key.as_ref().to_lowercase());
+        self
+    }
+
+    /// Replaces the value selected by a JSONPath in a json body with `[redacted]` e.g. `$.id`.
+    /// The snapshot assertion fails when the path selects nothing or when the body is not json, so that a typo cannot
+    /// leave a secret in the snapshot
+    pub fn redact_json_path(mut self, path: impl AsRef<str>) -> Self {
+        self.redacted_json_paths.push(path.as_ref().to_string());
+        self
+    }
+
+    pub(crate) fn path(&self, name: &str) -> PathBuf {
+        self.dir.join(format!("{name}.{EXTENSION}"))
+    }
+
+    /// Serializes the status, the headers sorted by key and the normalized body
+    pub(crate) fn render<T: AllAccessors>(&self, response: &mut T) -> AsserhttpResult<String> {
+        let mut out = format!("status: {}\nheaders:\n", response.get_status());
+        let mut keys = response.get_keys();
+        keys.sort_by_key(|k| k.to_lowercase());
+        keys.dedup_by_key(|k| k.to_lowercase());
+        for key in keys {
+            let name = key.to_lowercase();
+            let kept = match self.kept_headers.as_ref() {
+                Some(kept) => kept.contains(&name),
+                None => true,
+            };
+            if !kept || self.ignored_headers.contains(&name) {
+                continue;
+            }
+            for value in response.get_raw_values(&key) {
+                let value = if self.redacted_headers.contains(&name) { REDACTED } else { &value };
+                out.push_str(&format!("  {name}: {value}\n"));
+            }
+        }
+        out.push_str("body:");
+        match response.get_bytes() {
+            Ok(bytes) => out.push_str(&format!("\n{}\n", self.normalize(&bytes)?)),
+            Err(AsserhttpError::BodyAbsent) => {
+                self.check_no_redaction()?;
+                out.push_str(" <absent>\n")
+            },
+            Err(e) => return Err(e),
+        }
+        Ok(out)
+    }
+
+    /// Json bodies are pretty-printed and redacted, other text bodies kept as is and binary ones hex encoded
+    fn normalize(&self, bytes: &[u8]) -> AsserhttpResult<String> {
+        if let Ok(mut json) = serde_json::from_slice::<Value>(bytes) {
+            for path in &self.redacted_json_paths {
+                let value = JsonPath::parse(path)?
+                    .select_mut(&mut json)
+                    .ok_or_else(|| AsserhttpError::SnapshotRedactionUnmatched { path: path.to_string() })?;
+                *value = Value::String(REDACTED.to_string());
+            }
+            return Ok(format!("{json:#}"));
+        }
+        self.check_no_redaction()?;
+        Ok(match std::str::from_utf8(bytes) {
+            Ok(text) => text.to_string(),
+            Err(_) => bytes
+                .chunks(32)
+                .map(|chunk| chunk.iter().map(|b| format!("{b:02x}")).collect::<String>())
+                .collect::<Vec<_>>()
+                .join("\n"),
+        })
+    }
+
+    /// Json paths to redact cannot be silently ignored when there is no json body to redact them from
+    fn check_no_redaction(&self) -> AsserhttpResult<()> {
+        match self.redacted_json_paths.first() {
+            Some(path) => Err(AsserhttpError::SnapshotRedactionNotJson { path: path.to_string() }),
+            None => Ok(()),
+        }
+    }
+
+    /// Compares the response to the stored snapshot or stores it when blessing
+    pub(crate) fn verify<T: AllAccessors>(&self, name: &str, response: &mut T) -> AsserhttpResult<()> {
+        let path = self.path(name);
+        let actual = self.render(response)?;
+        if fixture::bless() {
+            return fixture::write(&path, &actual);
+        }
+        if !path.exists() {
+            return Err(AsserhttpError::SnapshotAbsent {
+                path: path.display().to_string(),
+                actual,
+            });
+        }
+        let expected = fixture::read(&path)?;
+        if expected != actual {
+            return Err(AsserhttpError::SnapshotMismatch {
+                path: path.display().to_string(),
+                diff: diff::lines(&expected, &actual),
+            });
+        }
+        Ok(())
+    }
+}
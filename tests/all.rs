@@ -55,12 +55,95 @@ pub enum Stub {
     HeaderOne,
     HeaderText,
     HeaderXml,
+    HeaderJsonCharset,
+    HeaderProblemJson,
+    HeaderMultipart,
+    HeaderTextQuotedParam,
+    HeaderDates,
+    HeaderRetryAfterDate,
+    HeaderDatesInvalid,
+    CacheControlPrivate,
+    CacheControlNoCacheFields,
+    CookieSession,
+    CookieRemoved,
+    CookieInsecure,
+    SecuritySecure,
+    SecurityInsecure,
+    CspStrict,
+    CspUnsafe,
+    CspMultiple,
+    CspNonceFallback,
+    CspMultipleUnsafe,
+    CorsPreflight,
+    CorsWildcard,
+    CorsCredentialsWildcard,
+    CorsNoVary,
+    RedirectFound,
+    RedirectMoved,
+    RedirectCrossHost,
+    ConditionalWeak,
+    ConditionalStrong,
+    ConditionalNotModified,
+    ConditionalNotModifiedLastModified,
+    ConditionalInvalid,
+    LinkPaginated,
+    LinkLastPage,
+    LinkInconsistent,
+    LinkMixed,
+    LinkCrossOrigin,
+    LinkInvalid,
+    ContentDispositionAttachment,
+    ContentDispositionEncoded,
+    ContentDispositionToken,
+    ContentDispositionInline,
+    ContentDispositionInvalid,
+    ContentDispositionUnknownCharset,
+    ContentDispositionSignedEscape,
+    RangePartial,
+    RangeUnknownLength,
+    RangeInconsistent,
+    RangeMultipart,
+    RangeMultipartInconsistent,
+    RangeMultipartBoundaryInContent,
+    RangeNotSatisfiable,
+    RangeUnsatisfied,
+    RateLimitCombined,
+    RateLimitStructured,
+    RateLimitSeparate,
+    RateLimitLegacy,
+    RateLimitInvalid,
+    RateLimitLimited,
+    RateLimitExhausted,
+    RateLimitMultiple,
+    RateLimitPolicies,
+    RateLimitElapsed,
+    RateLimitUnknownPolicy,
     BodyJson,
     BodyJsonAbsent,
+    BodyJsonItems,
     BodyBytes,
+    BodyBytesBinary,
     BodyBytesAbsent,
     BodyText,
     BodyTextAbsent,
+    ProblemOutOfCredit,
+    ProblemInconsistent,
+    ProblemBlank,
+    GraphqlOk,
+    GraphqlErrors,
+    JsonRpcResult,
+    JsonRpcErrorResponse,
+    JsonRpcLegacy,
+    JsonRpcBatchResponses,
+    JsonRpcNoVersion,
+    JsonRpcNoId,
+    JsonRpcInvalidId,
+    JsonRpcNoOutcome,
+    JsonRpcBothOutcomes,
+    JsonRpcNoErrorCode,
+    JsonRpcInvalidErrorCode,
+    JsonRpcBatchNoVersion,
+    SnapshotUser,
 }
 
 impl Stub {
@@ -188,6 +271,537 @@ impl Stub {
                 vec![("content-type", "text/plain")].into(),
                 [("content-type", "text/plain")].into_response(),
             ),
+            Stub::HeaderJsonCharset => Responses(
+                HttpResponse::Ok()
+                    .append_header(("content-type", "application/json; charset=UTF-8"))
+                    .finish(),
+                vec![("content-type", "application/json; charset=UTF-8")].into(),
+                [("content-type", "application/json; charset=UTF-8")].into_response(),
+            ),
+            Stub::HeaderProblemJson => Responses(
+                HttpResponse::Ok()
+                    .append_header(("content-type", "application/problem+json"))
+                    .finish(),
+                vec![("content-type", "application/problem+json")].into(),
+                [("content-type", "application/problem+json")].into_response(),
+            ),
+            Stub::HeaderMultipart => Responses(
+                HttpResponse::Ok()
+                    .append_header(("content-type", "multipart/form-data; boundary=abc"))
+                    .finish(),
+                vec![("content-type", "multipart/form-data; boundary=abc")].into(),
+                [("content-type", "multipart/form-data; boundary=abc")].into_response(),
+            ),
+            Stub::HeaderTextQuotedParam => Responses(
+                HttpResponse::Ok().append_header(("content-type", "text/plain; title=\"a;b\"; charset=utf-8")).finish(),
+                vec![("content-type", "text/plain; title=\"a;b\"; charset=utf-8")].into(),
+                [("content-type", "text/plain; title=\"a;b\"; charset=utf-8")].into_response(),
+            ),
+            Stub::HeaderDates => Responses(
+                HttpResponse::Ok()
+                    .append_header(("date", "Wed, 21 Oct 2015 07:28:00 GMT"))
+                    .append_header(("expires", "Wed, 01 Dec 2094 16:00:00 GMT"))
+                    .append_header(("retry-after", "120"))
+                    .finish(),
+                vec![
+                    ("date", "Wed, 21 Oct 2015 07:28:00 GMT"),
+                    ("expires", "Wed, 01 Dec 2094 16:00:00 GMT"),
+                    ("retry-after", "120"),
+                ]
+                .into(),
+                [
+                    ("date", "Wed, 21 Oct 2015 07:28:00 GMT"),
+                    ("expires", "Wed, 01 Dec 2094 16:00:00 GMT"),
+                    ("retry-after", "120"),
+                ]
+                .into_response(),
+            ),
+            Stub::HeaderRetryAfterDate => Responses(
+                HttpResponse::Ok().append_header(("retry-after", "Wed, 21 Oct 2015 07:28:00 GMT")).finish(),
+                vec![("retry-after", "Wed, 21 Oct 2015 07:28:00 GMT")].into(),
+                [("retry-after", "Wed, 21 Oct 2015 07:28:00 GMT")].into_response(),
+            ),
+            Stub::HeaderDatesInvalid => Responses(
+                HttpResponse::Ok()
+                    .append_header(("expires", "0"))
+                    .append_header(("retry-after", "soon"))
+                    .finish(),
+                vec![
+                    ("expires", "0"),
+                    ("retry-after", "soon"),
+                ]
+                .into(),
+                [
+                    ("expires", "0"),
+                    ("retry-after", "soon"),
+                ]
+                .into_response(),
+            ),
+            Stub::CacheControlPrivate => Responses(
+                HttpResponse::Ok()
+                    .append_header(("cache-control", "private,max-age=3600 ,  Must-Revalidate"))
+                    .finish(),
+                vec![("cache-control", "private,max-age=3600 ,  Must-Revalidate")].into(),
+                [("cache-control", "private,max-age=3600 ,  Must-Revalidate")].into_response(),
+            ),
+            Stub::CacheControlNoCacheFields => Responses(
+                HttpResponse::Ok().append_header(("cache-control", "no-cache=\"set-cookie, x-token\", max-age=0")).finish(),
+                vec![("cache-control", "no-cache=\"set-cookie, x-token\", max-age=0")].into(),
+                [("cache-control", "no-cache=\"set-cookie, x-token\", max-age=0")].into_response(),
+            ),
+            Stub::CookieSession => Responses(
+                HttpResponse::Ok()
+                    .append_header(("set-cookie", "session=stale"))
+                    .append_header(("set-cookie", "theme=dark; Path=/"))
+                    .append_header(("set-cookie", "session=abc123; Path=/; Domain=example.com; Expires=Wed, 21 Oct 2099 07:28:00 GMT; Secure; HttpOnly; SameSite=Strict"))
+                    .finish(),
+                vec![
+                    ("set-cookie", "session=stale"),
+                    ("set-cookie", "theme=dark; Path=/"),
+                    ("set-cookie", "session=abc123; Path=/; Domain=example.com; Expires=Wed, 21 Oct 2099 07:28:00 GMT; Secure; HttpOnly; SameSite=Strict"),
+                ]
+                .into(),
+                [
+                    ("set-cookie", "session=stale"),
+                    ("set-cookie", "theme=dark; Path=/"),
+                    ("set-cookie", "session=abc123; Path=/; Domain=example.com; Expires=Wed, 21 Oct 2099 07:28:00 GMT; Secure; HttpOnly; SameSite=Strict"),
+                ]
+                .into_response(),
+            ),
+            Stub::CookieRemoved => Responses(
+                HttpResponse::Ok().append_header(("set-cookie", "session=; Max-Age=0; Path=/")).finish(),
+                vec![("set-cookie", "session=; Max-Age=0; Path=/")].into(),
+                [("set-cookie", "session=; Max-Age=0; Path=/")].into_response(),
+            ),
+            Stub::CookieInsecure => Responses(
+                HttpResponse::Ok().append_header(("set-cookie", "session=abc123")).finish(),
+                vec![("set-cookie", "session=abc123")].into(),
+                [("set-cookie", "session=abc123")].into_response(),
+            ),
+            Stub::SecuritySecure => Responses(
+                HttpResponse::Ok()
+                    .append_header(("strict-transport-security", "max-age=63072000; includeSubDomains; preload"))
+                    .append_header(("x-content-type-options", "nosniff"))
+                    .append_header(("content-security-policy", "default-src 'self'; frame-ancestors 'none'"))
+                    .append_header(("referrer-policy", "no-referrer"))
+                    .append_header(("x-powered-by", "Express"))
+                    .finish(),
+                vec![
+                    ("strict-transport-security", "max-age=63072000; includeSubDomains; preload"),
+                    ("x-content-type-options", "nosniff"),
+                    ("content-security-policy", "default-src 'self'; frame-ancestors 'none'"),
+                    ("referrer-policy", "no-referrer"),
+                    ("x-powered-by", "Express"),
+                ]
+                .into(),
+                [
+                    ("strict-transport-security", "max-age=63072000; includeSubDomains; preload"),
+                    ("x-content-type-options", "nosniff"),
+                    ("content-security-policy", "default-src 'self'; frame-ancestors 'none'"),
+                    ("referrer-policy", "no-referrer"),
+                    ("x-powered-by", "Express"),
+                ]
+                .into_response(),
+            ),
+            Stub::SecurityInsecure => Responses(
+                HttpResponse::Ok()
+                    .append_header(("strict-transport-security", "max-age=3600"))
+                    .append_header(("x-content-type-options", "sniff"))
+                    .append_header(("x-frame-options", "ALLOW-FROM https://example.com"))
+                    .append_header(("referrer-policy", "unsafe-url"))
+                    .append_header(("server", "nginx/1.25.3"))
+                    .append_header(("x-powered-by", "PHP/8.2.1"))
+                    .finish(),
+                vec![
+                    ("strict-transport-security", "max-age=3600"),
+                    ("x-content-type-options", "sniff"),
+                    ("x-frame-options", "ALLOW-FROM https://example.com"),
+                    ("referrer-policy", "unsafe-url"),
+                    ("server", "nginx/1.25.3"),
+                    ("x-powered-by", "PHP/8.2.1"),
+                ]
+                .into(),
+                [
+                    ("strict-transport-security", "max-age=3600"),
+                    ("x-content-type-options", "sniff"),
+                    ("x-frame-options", "ALLOW-FROM https://example.com"),
+                    ("referrer-policy", "unsafe-url"),
+                    ("server", "nginx/1.25.3"),
+                    ("x-powered-by", "PHP/8.2.1"),
+                ]
+                .into_response(),
+            ),
+            Stub::CspStrict => Responses(
+                HttpResponse::Ok().append_header(("content-security-policy", "default-src 'self'; script-src https://cdn.example 'SELF' 'nonce-abc'; frame-ancestors 'none'; upgrade-insecure-requests")).finish(),
+                vec![("content-security-policy", "default-src 'self'; script-src https://cdn.example 'SELF' 'nonce-abc'; frame-ancestors 'none'; upgrade-insecure-requests")].into(),
+                [("content-security-policy", "default-src 'self'; script-src https://cdn.example 'SELF' 'nonce-abc'; frame-ancestors 'none'; upgrade-insecure-requests")].into_response(),
+            ),
+            Stub::CspUnsafe => Responses(
+                HttpResponse::Ok()
+                    .append_header(("content-security-policy", "default-src 'self' 'unsafe-inline'; script-src 'self'"))
+                    .append_header(("content-security-policy-report-only", "script-src 'self'; report-uri /csp"))
+                    .finish(),
+                vec![
+                    ("content-security-policy", "default-src 'self' 'unsafe-inline'; script-src 'self'"),
+                    ("content-security-policy-report-only", "script-src 'self'; report-uri /csp"),
+                ]
+                .into(),
+                [
+                    ("content-security-policy", "default-src 'self' 'unsafe-inline'; script-src 'self'"),
+                    ("content-security-policy-report-only", "script-src 'self'; report-uri /csp"),
+                ]
+                .into_response(),
+            ),
+            Stub::CspMultiple => Responses(
+                HttpResponse::Ok().append_header(("content-security-policy", "script-src 'self'; style-src 'self', script-src 'self' 'unsafe-inline'")).finish(),
+                vec![("content-security-policy", "script-src 'self'; style-src 'self', script-src 'self' 'unsafe-inline'")].into(),
+                [("content-security-policy", "script-src 'self'; style-src 'self', script-src 'self' 'unsafe-inline'")].into_response(),
+            ),
+            Stub::CspNonceFallback => Responses(
+                HttpResponse::Ok().append_header(("content-security-policy", "script-src 'nonce-r4nd0m' 'strict-dynamic' 'unsafe-inline'; style-src 'self' 'sha256-B2yPHKaXnvFWtRChIbabYmUBFZdVfKKXHbWtWidDVF8=' 'unsafe-inline'")).finish(),
+                vec![("content-security-policy", "script-src 'nonce-r4nd0m' 'strict-dynamic' 'unsafe-inline'; style-src 'self' 'sha256-B2yPHKaXnvFWtRChIbabYmUBFZdVfKKXHbWtWidDVF8=' 'unsafe-inline'")].into(),
+                [("content-security-policy", "script-src 'nonce-r4nd0m' 'strict-dynamic' 'unsafe-inline'; style-src 'self' 'sha256-B2yPHKaXnvFWtRChIbabYmUBFZdVfKKXHbWtWidDVF8=' 'unsafe-inline'")].into_response(),
+            ),
+            Stub::CspMultipleUnsafe => Responses(
+                HttpResponse::Ok().append_header(("content-security-policy", "script-src 'self' 'unsafe-inline', frame-ancestors 'none'")).finish(),
+                vec![("content-security-policy", "script-src 'self' 'unsafe-inline', frame-ancestors 'none'")].into(),
+                [("content-security-policy", "script-src 'self' 'unsafe-inline', frame-ancestors 'none'")].into_response(),
+            ),
+            Stub::CorsPreflight => Responses(
+                HttpResponse::Ok()
+                    .append_header(("access-control-allow-origin", "https://app.example"))
+                    .append_header(("access-control-allow-methods", "GET, PUT, DELETE"))
+                    .append_header(("access-control-allow-headers", "Content-Type, X-Request-Id"))
+                    .append_header(("access-control-allow-credentials", "true"))
+                    .append_header(("access-control-max-age", "600"))
+                    .append_header(("vary", "Accept-Encoding, Origin"))
+                    .finish(),
+                vec![
+                    ("access-control-allow-origin", "https://app.example"),
+                    ("access-control-allow-methods", "GET, PUT, DELETE"),
+                    ("access-control-allow-headers", "Content-Type, X-Request-Id"),
+                    ("access-control-allow-credentials", "true"),
+                    ("access-control-max-age", "600"),
+                    ("vary", "Accept-Encoding, Origin"),
+                ]
+                .into(),
+                [
+                    ("access-control-allow-origin", "https://app.example"),
+                    ("access-control-allow-methods", "GET, PUT, DELETE"),
+                    ("access-control-allow-headers", "Content-Type, X-Request-Id"),
+                    ("access-control-allow-credentials", "true"),
+                    ("access-control-max-age", "600"),
+                    ("vary", "Accept-Encoding, Origin"),
+                ]
+                .into_response(),
+            ),
+            Stub::CorsWildcard => Responses(
+                HttpResponse::Ok()
+                    .append_header(("access-control-allow-origin", "*"))
+                    .append_header(("access-control-allow-methods", "*"))
+                    .append_header(("access-control-allow-headers", "*"))
+                    .finish(),
+                vec![
+                    ("access-control-allow-origin", "*"),
+                    ("access-control-allow-methods", "*"),
+                    ("access-control-allow-headers", "*"),
+                ]
+                .into(),
+                [
+                    ("access-control-allow-origin", "*"),
+                    ("access-control-allow-methods", "*"),
+                    ("access-control-allow-headers", "*"),
+                ]
+                .into_response(),
+            ),
+            Stub::CorsCredentialsWildcard => Responses(
+                HttpResponse::Ok()
+                    .append_header(("access-control-allow-origin", "*"))
+                    .append_header(("access-control-allow-methods", "*"))
+                    .append_header(("access-control-allow-credentials", "true"))
+                    .finish(),
+                vec![
+                    ("access-control-allow-origin", "*"),
+                    ("access-control-allow-methods", "*"),
+                    ("access-control-allow-credentials", "true"),
+                ]
+                .into(),
+                [
+                    ("access-control-allow-origin", "*"),
+                    ("access-control-allow-methods", "*"),
+                    ("access-control-allow-credentials", "true"),
+                ]
+                .into_response(),
+            ),
+            Stub::CorsNoVary => Responses(
+                HttpResponse::Ok()
+                    .append_header(("access-control-allow-origin", "https://app.example"))
+                    .append_header(("access-control-allow-methods", "GET"))
+                    .finish(),
+                vec![
+                    ("access-control-allow-origin", "https://app.example"),
+                    ("access-control-allow-methods", "GET"),
+                ]
+                .into(),
+                [
+                    ("access-control-allow-origin", "https://app.example"),
+                    ("access-control-allow-methods", "GET"),
+                ]
+                .into_response(),
+            ),
+            Stub::RedirectFound => Responses(
+                HttpResponse::Found().append_header(("location", "/login?next=%2Fcart")).finish(),
+                Resp(
+                    rocket::Response::build()
+                        .status(rocket::http::Status::Found)
+                        .raw_header("location", "/login?next=%2Fcart")
+                        .finalize(),
+                ),
+                (axum::http::StatusCode::FOUND, [("location", "/login?next=%2Fcart")]).into_response(),
+            ),
+            Stub::RedirectMoved => Responses(
+                HttpResponse::MovedPermanently().append_header(("location", "https://app.example/home?lang=en")).finish(),
+                Resp(
+                    rocket::Response::build()
+                        .status(rocket::http::Status::MovedPermanently)
+                        .raw_header("location", "https://app.example/home?lang=en")
+                        .finalize(),
+                ),
+                (axum::http::StatusCode::MOVED_PERMANENTLY, [("location", "https://app.example/home?lang=en")]).into_response(),
+            ),
+            Stub::RedirectCrossHost => Responses::with(302, &[("location", "https://evil.example/login")], ""),
+            Stub::ConditionalWeak => Responses(
+                HttpResponse::Ok()
+                    .append_header(("etag", "W/\"0815\""))
+                    .append_header(("last-modified", "Wed, 21 Oct 2015 07:28:00 GMT"))
+                    .finish(),
+                vec![
+                    ("etag", "W/\"0815\""),
+                    ("last-modified", "Wed, 21 Oct 2015 07:28:00 GMT"),
+                ]
+                .into(),
+                [
+                    ("etag", "W/\"0815\""),
+                    ("last-modified", "Wed, 21 Oct 2015 07:28:00 GMT"),
+                ]
+                .into_response(),
+            ),
+            Stub::ConditionalStrong => Responses(
+                HttpResponse::Ok()
+                    .append_header(("etag", "\"33a64df5\""))
+                    .finish(),
+                Resp(
+                    rocket::Response::build()
+                        .status(rocket::http::Status::Ok)
+                        .raw_header("etag", "\"33a64df5\"")
+                        .finalize(),
+                ),
+                (axum::http::StatusCode::OK, [("etag", "\"33a64df5\"")]).into_response(),
+            ),
+            Stub::ConditionalNotModified => Responses(
+                HttpResponse::NotModified()
+                    .append_header(("etag", "W/\"33a64df5\""))
+                    .append_header(("cache-control", "max-age=60"))
+                    .append_header(("vary", "Accept-Encoding"))
+                    .finish(),
+                Resp(
+                    rocket::Response::build()
+                        .status(rocket::http::Status::NotModified)
+                        .raw_header("etag", "W/\"33a64df5\"")
+                        .raw_header("cache-control", "max-age=60")
+                        .raw_header("vary", "Accept-Encoding")
+                        .finalize(),
+                ),
+                (
+                    axum::http::StatusCode::NOT_MODIFIED,
+                    [("etag", "W/\"33a64df5\""), ("cache-control", "max-age=60"), ("vary", "Accept-Encoding")],
+                )
+                    .into_response(),
+            ),
+            Stub::ConditionalNotModifiedLastModified => Responses(
+                HttpResponse::NotModified()
+                    .append_header(("last-modified", "Wed, 21 Oct 2015 07:28:00 GMT"))
+                    .finish(),
+                Resp(
+                    rocket::Response::build()
+                        .status(rocket::http::Status::NotModified)
+                        .raw_header("last-modified", "Wed, 21 Oct 2015 07:28:00 GMT")
+                        .finalize(),
+                ),
+                (axum::http::StatusCode::NOT_MODIFIED, [("last-modified", "Wed, 21 Oct 2015 07:28:00 GMT")]).into_response(),
+            ),
+            Stub::ConditionalInvalid => Responses(
+                HttpResponse::Ok()
+                    .append_header(("etag", "33a64df5"))
+                    .append_header(("last-modified", "yesterday"))
+                    .finish(),
+                vec![
+                    ("etag", "33a64df5"),
+                    ("last-modified", "yesterday"),
+                ]
+                .into(),
+                [
+                    ("etag", "33a64df5"),
+                    ("last-modified", "yesterday"),
+                ]
+                .into_response(),
+            ),
+            Stub::LinkPaginated => Responses(
+                HttpResponse::Ok()
+                    .append_header(("link", "<https://api.example/items?page=1&per_page=10>; rel=\"first\", <https://api.example/items?page=2&per_page=10>; rel=\"prev\""))
+                    .append_header(("link", "<https://api.example/items?page=4&per_page=10>; rel=\"next\""))
+                    .append_header(("link", "<https://api.example/items?page=9&per_page=10>; rel=\"last\", <https://api.example/items.csv>; rel=\"alternate\"; type=\"text/csv\"; title=\"Items, as CSV\""))
+                    .finish(),
+                vec![
+                    ("link", "<https://api.example/items?page=1&per_page=10>; rel=\"first\", <https://api.example/items?page=2&per_page=10>; rel=\"prev\""),
+                    ("link", "<https://api.example/items?page=4&per_page=10>; rel=\"next\""),
+                    ("link", "<https://api.example/items?page=9&per_page=10>; rel=\"last\", <https://api.example/items.csv>; rel=\"alternate\"; type=\"text/csv\"; title=\"Items, as CSV\""),
+                ]
+                .into(),
+                axum::response::AppendHeaders([
+                    ("link", "<https://api.example/items?page=1&per_page=10>; rel=\"first\", <https://api.example/items?page=2&per_page=10>; rel=\"prev\""),
+                    ("link", "<https://api.example/items?page=4&per_page=10>; rel=\"next\""),
+                    ("link", "<https://api.example/items?page=9&per_page=10>; rel=\"last\", <https://api.example/items.csv>; rel=\"alternate\"; type=\"text/csv\"; title=\"Items, as CSV\""),
+                ])
+                .into_response(),
+            ),
+            Stub::LinkLastPage => Responses(
+                HttpResponse::Ok().append_header(("link", "</items?page=1>; rel=\"first\", </items?page=8>; rel=\"prev\"")).finish(),
+                vec![("link", "</items?page=1>; rel=\"first\", </items?page=8>; rel=\"prev\"")].into(),
+                [("link", "</items?page=1>; rel=\"first\", </items?page=8>; rel=\"prev\"")].into_response(),
+            ),
+            Stub::LinkInconsistent => Responses(
+                HttpResponse::Ok().append_header(("link", "</items?page=5>; rel=\"prev\", </items?page=3>; rel=\"next\", </other?page=9>; rel=\"last\", </items?page=9>; rel=\"last\"")).finish(),
+                vec![("link", "</items?page=5>; rel=\"prev\", </items?page=3>; rel=\"next\", </other?page=9>; rel=\"last\", </items?page=9>; rel=\"last\"")].into(),
+                [("link", "</items?page=5>; rel=\"prev\", </items?page=3>; rel=\"next\", </other?page=9>; rel=\"last\", </items?page=9>; rel=\"last\"")].into_response(),
+            ),
+            Stub::LinkInvalid => Responses(
+                HttpResponse::Ok().append_header(("link", "https://api.example/items; rel=\"next\"")).finish(),
+                vec![("link", "https://api.example/items; rel=\"next\"")].into(),
+                [("link", "https://api.example/items; rel=\"next\"")].into_response(),
+            ),
+            Stub::ContentDispositionAttachment => Responses(
+                HttpResponse::Ok().append_header(("content-disposition", "attachment; filename=\"report.pdf\"")).finish(),
+                vec![("content-disposition", "attachment; filename=\"report.pdf\"")].into(),
+                [("content-disposition", "attachment; filename=\"report.pdf\"")].into_response(),
+            ),
+            Stub::ContentDispositionEncoded => Responses(
+                HttpResponse::Ok().append_header(("content-disposition", "attachment; filename=\"naive file.txt\"; filename*=UTF-8''na%C3%AFve%20file.txt")).finish(),
+                vec![("content-disposition", "attachment; filename=\"naive file.txt\"; filename*=UTF-8''na%C3%AFve%20file.txt")].into(),
+                [("content-disposition", "attachment; filename=\"naive file.txt\"; filename*=UTF-8''na%C3%AFve%20file.txt")].into_response(),
+            ),
+            Stub::ContentDispositionToken => Responses(
+                HttpResponse::Ok().append_header(("content-disposition", "Attachment; filename=report.pdf")).finish(),
+                vec![("content-disposition", "Attachment; filename=report.pdf")].into(),
+                [("content-disposition", "Attachment; filename=report.pdf")].into_response(),
+            ),
+            Stub::ContentDispositionInline => Responses(
+                HttpResponse::Ok().append_header(("content-disposition", "inline")).finish(),
+                vec![("content-disposition", "inline")].into(),
+                [("content-disposition", "inline")].into_response(),
+            ),
+            Stub::ContentDispositionInvalid => Responses(
+                HttpResponse::Ok().append_header(("content-disposition", "attachment; filename*=UTF-8''%E2%82")).finish(),
+                vec![("content-disposition", "attachment; filename*=UTF-8''%E2%82")].into(),
+                [("content-disposition", "attachment; filename*=UTF-8''%E2%82")].into_response(),
+            ),
+            Stub::ContentDispositionUnknownCharset => Responses(
+                HttpResponse::Ok().append_header(("content-disposition", "attachment; filename=\"report.pdf\"; filename*=KOI8-R''%F2%C1%D0%CF%D2%D4.pdf")).finish(),
+                vec![("content-disposition", "attachment; filename=\"report.pdf\"; filename*=KOI8-R''%F2%C1%D0%CF%D2%D4.pdf")].into(),
+                [("content-disposition", "attachment; filename=\"report.pdf\"; filename*=KOI8-R''%F2%C1%D0%CF%D2%D4.pdf")].into_response(),
+            ),
+            Stub::LinkMixed => Responses::with(200, &[("link", "<https://api.example/items?page=1>; rel=\"first\", </items?page=3>; rel=\"next\", <https://api.example/items?page=9>; rel=\"last\"")], ""),
+            Stub::LinkCrossOrigin => Responses::with(200, &[("link", "<https://api.example/items?page=1>; rel=\"first\", </items?page=3>; rel=\"next\", <https://evil.example/items?page=9>; rel=\"last\"")], ""),
+            Stub::ContentDispositionSignedEscape => Responses::with(200, &[("content-disposition", "attachment; filename*=UTF-8''report%+f.pdf")], ""),
+            Stub::RangePartial => Responses::with(206, &[("content-range", "bytes 0-4/26"), ("accept-ranges", "bytes")], "abcde"),
+            Stub::RangeUnknownLength => Responses::with(206, &[("content-range", "bytes 5-9/*"), ("accept-ranges", "none")], "fghij"),
+            Stub::RangeInconsistent => Responses::with(206, &[("content-range", "bytes 0-9/5")], "abcde"),
+            Stub::RangeMultipart => Responses::with(206, &[("content-type", "multipart/byteranges; boundary=3d6b6a416f9b5")], "--3d6b6a416f9b5\r\nContent-Type: text/plain\r\nContent-Range: bytes 0-4/26\r\n\r\nabcde\r\n--3d6b6a416f9b5\r\nContent-Type: text/plain\r\nContent-Range: bytes 20-25/26\r\n\r\nuvwxyz\r\n--3d6b6a416f9b5--\r\n"),
+            Stub::RangeMultipartInconsistent => Responses::with(206, &[("content-type", "multipart/byteranges; boundary=3d6b6a416f9b5")], "--3d6b6a416f9b5\r\nContent-Range: bytes 0-4/26\r\n\r\nabc\r\n--3d6b6a416f9b5\r\nContent-Type: text/plain\r\n\r\nuvwxyz\r\n--3d6b6a416f9b5--\r\n"),
+            Stub::RangeMultipartBoundaryInContent => Responses::with(206, &[("content-type", "multipart/byteranges; boundary=3d6b6a416f9b5")], "--3d6b6a416f9b5\r\nContent-Type: text/plain\r\nContent-Range: bytes 0-17/26\r\n\r\nabc--3d6b6a416f9b5\r\n--3d6b6a416f9b5\r\nContent-Type: text/plain\r\nContent-Range: bytes 20-25/26\r\n\r\nuvwxyz\r\n--3d6b6a416f9b5--\r\n"),
+            Stub::RangeNotSatisfiable => Responses::with(416, &[("content-range", "bytes */26")], ""),
+            Stub::RangeUnsatisfied => Responses::with(206, &[("content-range", "bytes */26")], ""),
+            Stub::RateLimitCombined => Responses(
+                HttpResponse::Ok().append_header(("ratelimit", "limit=100, remaining=50, reset=30")).finish(),
+                vec![("ratelimit", "limit=100, remaining=50, reset=30")].into(),
+                [("ratelimit", "limit=100, remaining=50, reset=30")].into_response(),
+            ),
+            Stub::RateLimitStructured => Responses(
+                HttpResponse::Ok()
+                    .append_header(("ratelimit", "\"default\";r=0;t=30"))
+                    .append_header(("ratelimit-policy", "\"default\";q=100;w=60"))
+                    .finish(),
+                vec![
+                    ("ratelimit", "\"default\";r=0;t=30"),
+                    ("ratelimit-policy", "\"default\";q=100;w=60"),
+                ]
+                .into(),
+                [
+                    ("ratelimit", "\"default\";r=0;t=30"),
+                    ("ratelimit-policy", "\"default\";q=100;w=60"),
+                ]
+                .into_response(),
+            ),
+            Stub::RateLimitSeparate => Responses(
+                HttpResponse::Ok()
+                    .append_header(("ratelimit-limit", "100"))
+                    .append_header(("ratelimit-remaining", "99"))
+                    .append_header(("ratelimit-reset", "60"))
+                    .finish(),
+                vec![
+                    ("ratelimit-limit", "100"),
+                    ("ratelimit-remaining", "99"),
+                    ("ratelimit-reset", "60"),
+                ]
+                .into(),
+                [
+                    ("ratelimit-limit", "100"),
+                    ("ratelimit-remaining", "99"),
+                    ("ratelimit-reset", "60"),
+                ]
+                .into_response(),
+            ),
+            Stub::RateLimitLegacy => Responses(
+                HttpResponse::Ok()
+                    .append_header(("x-ratelimit-limit", "5000"))
+                    .append_header(("x-ratelimit-remaining", "4999"))
+                    .append_header(("x-ratelimit-reset", "4102444800"))
+                    .finish(),
+                vec![
+                    ("x-ratelimit-limit", "5000"),
+                    ("x-ratelimit-remaining", "4999"),
+                    ("x-ratelimit-reset", "4102444800"),
+                ]
+                .into(),
+                [
+                    ("x-ratelimit-limit", "5000"),
+                    ("x-ratelimit-remaining", "4999"),
+                    ("x-ratelimit-reset", "4102444800"),
+                ]
+                .into_response(),
+            ),
+            Stub::RateLimitInvalid => Responses(
+                HttpResponse::Ok()
+                    .append_header(("x-ratelimit-limit", "many"))
+                    .append_header(("x-ratelimit-remaining", "1"))
+                    .finish(),
+                vec![
+                    ("x-ratelimit-limit", "many"),
+                    ("x-ratelimit-remaining", "1"),
+                ]
+                .into(),
+                [
+                    ("x-ratelimit-limit", "many"),
+                    ("x-ratelimit-remaining", "1"),
+                ]
+                .into_response(),
+            ),
+            Stub::RateLimitLimited => Responses::with(429, &[("retry-after", "120"), ("ratelimit", "limit=100, remaining=0, reset=120")], ""),
+            Stub::RateLimitExhausted => Responses::with(429, &[("x-ratelimit-limit", "100"), ("x-ratelimit-remaining", "0")], ""),
+            Stub::RateLimitMultiple => Responses::with(200, &[("ratelimit", "\"burst\";r=5;t=1, \"daily\";r=900;t=3600"), ("ratelimit-policy", "\"daily\";q=1000;w=86400, \"burst\";q=10;w=1")], ""),
+            Stub::RateLimitPolicies => Responses::with(200, &[("ratelimit", "remaining=50, reset=30"), ("ratelimit-policy", "100, 1000;w=3600")], ""),
+            Stub::RateLimitUnknownPolicy => Responses::with(200, &[("ratelimit", "\"burst\";r=5;t=1"), ("ratelimit-policy", "\"daily\";q=1000;w=86400")], ""),
+            Stub::RateLimitElapsed => Responses::with(200, &[("x-ratelimit-limit", "100"), ("x-ratelimit-remaining", "1"), ("x-ratelimit-reset", "1500000000")], ""),
             Stub::HeaderXml => Responses(
                 HttpResponse::Ok().append_header(("content-type", "application/xml")).finish(),
                 vec![("content-type", "application/xml")].into(),
@@ -204,6 +818,15 @@ impl Stub {
                 axum::http::StatusCode::OK.into_response(),
             ),
             Stub::BodyBytes => Responses(HttpResponse::Ok().body("abcd"), "abcd".into(), b"abcd".into_response()),
+            Stub::BodyBytesBinary => Responses(
+                HttpResponse::Ok().body(BINARY_BODY),
+                Resp(
+                    rocket::Response::build()
+                        .sized_body(BINARY_BODY.len(), Cursor::new(BINARY_BODY))
+                        .finalize(),
+                ),
+                BINARY_BODY.into_response(),
+            ),
             Stub::BodyBytesAbsent => Responses(
                 HttpResponse::Ok().finish(),
                 rocket::http::Status::Ok.into(),
@@ -215,17 +838,156 @@ impl Stub {
                 rocket::http::Status::Ok.into(),
                 axum::http::StatusCode::OK.into_response(),
             ),
+            Stub::ProblemOutOfCredit => Responses::with(403, &[("content-type", "application/problem+json")],
+                json!({
+                    "type": "https://example.com/probs/out-of-credit",
+                    "title": "You do not have enough credit.",
+                    "status": 403,
+                    "detail": "Your current balance is 30, but that costs 50.",
+                    "instance": "/account/12345/msgs/abc",
+                    "balance": 30
+                }),
+            ),
+            Stub::ProblemInconsistent => Responses::with(400, &[("content-type", "application/problem+json")],
+                json!({
+                    "type": "https://example.com/probs/out-of-credit",
+                    "title": "You do not have enough credit.",
+                    "status": 403,
+                    "detail": "Your current balance is 30, but that costs 50."
+                }),
+            ),
+            Stub::ProblemBlank => Responses::with(404, &[("content-type", "application/problem+json")], json!({"status": 404})),
+            Stub::GraphqlOk => Responses::with(200, &[("content-type", "application/graphql-response+json")], json!({"data": {"user": {"name": "jdoe"}}})),
+            Stub::BodyJsonItems => Responses::with(200, &[("content-type", "application/json")],
+                json!({"items": [{"name": "apple", "price": 10}, {"name": "pear", "price": "12"}]}),
+            ),
+            Stub::JsonRpcResult => Responses::with(200, &[("content-type", "application/json")], json!({"jsonrpc": "2.0", "result": {"balance": 30}, "id": 1})),
+            Stub::JsonRpcErrorResponse => Responses::with(200, &[("content-type", "application/json")], json!({
+                "jsonrpc": "2.0",
+                "error": {"code": -32601, "message": "Method not found"},
+                "id": "abc"
+            })),
+            Stub::JsonRpcLegacy => Responses::with(200, &[("content-type", "application/json")], json!({"jsonrpc": "1.0", "result": {"balance": 30}, "id": 1})),
+            Stub::JsonRpcBatchResponses => Responses::with(200, &[("content-type", "application/json")], json!([
+                {"jsonrpc": "2.0", "error": {"code": -32601, "message": "Method not found"}, "id": 2},
+                {"jsonrpc": "2.0", "result": {"balance": 30}, "id": 1}
+            ])),
+            Stub::JsonRpcNoVersion => Responses::with(200, &[("content-type", "application/json")], json!({"result": {"balance": 30}, "id": 1})),
+            Stub::JsonRpcNoId => Responses::with(200, &[("content-type", "application/json")], json!({"jsonrpc": "2.0", "result": {"balance": 30}})),
+            Stub::JsonRpcInvalidId => Responses::with(200, &[("content-type", "application/json")], json!({"jsonrpc": "2.0", "result": {"balance": 30}, "id": {"a": 1}})),
+            Stub::JsonRpcNoOutcome => Responses::with(200, &[("content-type", "application/json")], json!({"jsonrpc": "2.0", "id": 1})),
+            Stub::JsonRpcBothOutcomes => Responses::with(200, &[("content-type", "application/json")], json!({"jsonrpc": "2.0", "result": {"balance": 30}, "error": {"code": -32601, "message": "Method not found"}, "id": 1})),
+            Stub::JsonRpcNoErrorCode => Responses::with(200, &[("content-type", "application/json")], json!({"jsonrpc": "2.0", "error": {"message": "Method not found"}, "id": 1})),
+            Stub::JsonRpcInvalidErrorCode => Responses::with(200, &[("content-type", "application/json")], json!({"jsonrpc": "2.0", "error": {"code": "-32601", "message": "Method not found"}, "id": 1})),
+            Stub::JsonRpcBatchNoVersion => Responses::with(200, &[("content-type", "application/json")], json!([{"jsonrpc": "2.0", "result": {"balance": 30}, "id": 1}, {"error": {"code": -32601, "message": "Method not found"}, "id": 2}])),
+            Stub::SnapshotUser => Responses::with(201, &[("content-type", "application/json")],
+                json!({"id": "3f1c6d2e-8a4b-4f0e-9c1d-2b7e5a9f0c31", "name": "jdoe", "roles": ["admin"]}),
+            ),
+            Stub::GraphqlErrors => Responses::with(200, &[("content-type", "application/graphql-response+json")], json!({
+                "data": {"user": {"name": "jdoe", "email": null}},
+                "errors": [{
+                    "message": "Not allowed to read email",
+                    "locations": [{"line": 3, "column": 5}],
+                    "path": ["user", "email"],
+                    "extensions": {"code": "FORBIDDEN"}
+                }]
+            })),
         }
     }
 }
 
+const BINARY_BODY: &[u8] = b"GET /index.html\r\n\x00\x01\x02\x7fHost: a\tb\xff\xfe";
+
+/// Fixtures of the tests expecting a mismatch are written to a temporary directory on every run instead of being kept in
+/// `tests/fixtures`, so that running the tests with `ASSERHTTP_BLESS` set cannot rewrite them for good
+fn negative_fixture(name: &str, content: &str) -> std::path::PathBuf {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    static WRITES: AtomicUsize = AtomicUsize::new(0);
+
+    let dir = std::env::temp_dir().join("asserhttp-negative-fixtures");
+    std::fs::create_dir_all(&dir).unwrap();
+    // written aside then renamed so that concurrent tests never read a partially written fixture
+    let tmp = dir.join(format!(
+        ".{name}.{}.{}",
+        std::process::id(),
+        WRITES.fetch_add(1, Ordering::Relaxed)
+    ));
+    std::fs::write(&tmp, content).unwrap();
+    let path = dir.join(name);
+    std::fs::rename(tmp, &path).unwrap();
+    path
+}
+
+/// A fixture path guaranteed not to exist, even after a run with `ASSERHTTP_BLESS` set created it
+fn missing_fixture(name: &str) -> std::path::PathBuf {
+    let path = std::env::temp_dir().join("asserhttp-negative-fixtures").join(name);
+    let _ = std::fs::remove_file(&path);
+    path
+}
+
+/// Serves the raw HTTP response of a `.http` stub, for what stubr cannot send such as a header on several lines
+pub fn raw_stub(name: &str) -> String {
+    use std::io::{Read, Write};
+
+    let path = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/stubs").join(name);
+    let response = std::fs::read_to_string(path).unwrap().replace('\n', "\r\n");
+    let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+    let uri = format!("http://{}", listener.local_addr().unwrap());
+    std::thread::spawn(move || {
+        for mut stream in listener.incoming().flatten() {
+            let (mut request, mut buf) = (vec![], [0; 1024]);
+            while !request.windows(4).any(|w| w == b"\r\n\r\n") {
+                match stream.read(&mut buf) {
+                    Ok(0) | Err(_) => break,
+                    Ok(n) => request.extend_from_slice(&buf[..n]),
+                }
+            }
+            let _ = stream.write_all(response.as_bytes());
+        }
+    });
+    uri
+}
+
 pub struct Responses(pub HttpResponse, pub Resp, pub axum::response::Response);
 
+impl Responses {
+    fn with(status: u16, headers: &[(&'static str, &'static str)], body: impl ToString) -> Self {
+        use axum::response::IntoResponse as _;
+
+        let body = body.to_string();
+        let mut actix = HttpResponse::build(actix_web::http::StatusCode::from_u16(status).unwrap());
+        let mut rocket = rocket::Response::build();
+        rocket.status(rocket::http::Status::new(status));
+        let mut axum = axum::http::HeaderMap::new();
+        for (key, value) in headers {
+            actix.append_header((*key, *value));
+            rocket.raw_header(*key, *value);
+            axum.append(*key, value.parse().unwrap());
+        }
+        Self(
+            actix.body(body.clone()),
+            Resp(rocket.sized_body(body.len(), Cursor::new(body.clone())).finalize()),
+            (axum::http::StatusCode::from_u16(status).unwrap(), axum, body).into_response(),
+        )
+    }
+}
+
 #[derive(Deserialize, Serialize, Debug, Eq, PartialEq)]
 pub struct TestBody {
     pub a: String,
 }
 
+#[derive(Deserialize, Serialize, Debug, Eq, PartialEq)]
+pub struct TestOrder {
+    pub items: Vec<TestItem>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Eq, PartialEq)]
+pub struct TestItem {
+    pub name: String,
+    pub price: u32,
+}
+
 #[macro_export]
 macro_rules! asserhttp_test {
     ($fn_name:ident, $stub:literal, $resp:expr, $($(.$meth:ident($( $arg:expr ),*))+),+) => {
@@ -263,6 +1025,155 @@ macro_rules! asserhttp_test {
     };
 }
 
+// stubr sends every header on a single line, so the clients are tested against a raw HTTP response instead
+#[macro_export]
+macro_rules! asserhttp_raw_test {
+    ($fn_name:ident, $stub:literal, $(.$meth:ident($( $arg:expr ),*))+) => {
+        paste::paste! {
+            #[test]
+            fn [<reqwest_blocking_ $fn_name>]() {
+                use asserhttp::*;
+                reqwest::blocking::get($crate::raw_stub($stub)).unwrap()$( .$meth($($arg),*) )+;
+            }
+            #[tokio::test]
+            async fn [<reqwest_async_ $fn_name>]() {
+                use asserhttp::*;
+                reqwest::get($crate::raw_stub($stub)).await.unwrap()$( .$meth($($arg),*) )+;
+            }
+            #[test]
+            fn [<isahc_blocking_ $fn_name>]() {
+                use asserhttp::*;
+                isahc::get($crate::raw_stub($stub)).unwrap()$( .$meth($($arg),*) )+;
+            }
+            #[tokio::test]
+            async fn [<isahc_async_ $fn_name>]() {
+                use asserhttp::*;
+                isahc::get_async($crate::raw_stub($stub)).await.unwrap()$( .$meth($($arg),*) )+;
+            }
+            #[tokio::test]
+            async fn [<surf_ $fn_name>]() {
+                use asserhttp::*;
+                surf::get($crate::raw_stub($stub)).await.unwrap()$( .$meth($($arg),*) )+;
+            }
+            #[test]
+            fn [<ureq_ $fn_name>]() {
+                use asserhttp::*;
+                use ureq::OrAnyStatus;
+                ureq::get(&$crate::raw_stub($stub)).call().or_any_status().unwrap()$( .$meth($($arg),*) )+;
+            }
+            #[tokio::test]
+            async fn [<hyper_ $fn_name>]() {
+                use asserhttp::*;
+                hyper::Client::new().get($crate::raw_stub($stub).parse().unwrap()).await.unwrap()$( .$meth($($arg),*) )+;
+            }
+            #[actix_web::test]
+            async fn [<awc_ $fn_name>]() {
+                use asserhttp::*;
+                awc::Client::default().get($crate::raw_stub($stub)).send().await.unwrap()$( .$meth($($arg),*) )+;
+            }
+        }
+    };
+}
+
+// reqwest, ureq and awc follow redirects by default so they cannot observe a 3xx response
+#[macro_export]
+macro_rules! asserhttp_redirect_test {
+    ($fn_name:ident, $stub:literal, $resp:expr, $($(.$meth:ident($( $arg:expr ),*))+),+) => {
+        $($crate::surf_test!($fn_name, $stub, $( .$meth($($arg),*) )* );)+
+        $($crate::hyper_test!($fn_name, $stub, $( .$meth($($arg),*) )* );)+
+        $($crate::isahc_test!($fn_name, $stub, $( .$meth($($arg),*) )* );)+
+        $($crate::actix_test!($fn_name, $resp.0, $( .$meth($($arg),*) )* );)+
+        $($crate::rocket_test!($fn_name, $resp.1, $( .$meth($($arg),*) )* );)+
+        $($crate::axum_test!($fn_name, $resp.2, $( .$meth($($arg),*) )* );)+
+    };
+    ($fn_name:ident, $stub:literal, $resp:expr, $panic_msg:literal, $($(.$meth:ident($( $arg:expr ),*))+),+) => {
+        $($crate::surf_test!($fn_name, $stub, $panic_msg, $( .$meth($($arg),*) )* );)+
+        $($crate::hyper_test!($fn_name, $stub, $panic_msg, $( .$meth($($arg),*) )* );)+
+        $($crate::isahc_test!($fn_name, $stub, $panic_msg, $( .$meth($($arg),*) )* );)+
+        $($crate::actix_test!($fn_name, $resp.0, $panic_msg, $( .$meth($($arg),*) )* );)+
+        $($crate::rocket_test!($fn_name, $resp.1, $panic_msg, $( .$meth($($arg),*) )* );)+
+        $($crate::axum_test!($fn_name, $resp.2, $panic_msg, $( .$meth($($arg),*) )* );)+
+    };
+    ($fn_name:ident, $stub:literal, $resp:expr, $error:expr, $($(.$meth:ident($( $arg:expr ),*))+),+) => {
+        $($crate::surf_test!($fn_name, $stub, $error, $( .$meth($($arg),*) )* );)+
+        $($crate::hyper_test!($fn_name, $stub, $error, $( .$meth($($arg),*) )* );)+
+        $($crate::isahc_test!($fn_name, $stub, $error, $( .$meth($($arg),*) )* );)+
+        $($crate::actix_test!($fn_name, $resp.0, $error, $( .$meth($($arg),*) )* );)+
+        $($crate::rocket_test!($fn_name, $resp.1, $error, $( .$meth($($arg),*) )* );)+
+        $($crate::axum_test!($fn_name, $resp.2, $error, $( .$meth($($arg),*) )* );)+
+    };
+}
+
+// awc only receives the day of the week of a stubbed 'date' header
+#[macro_export]
+macro_rules! asserhttp_date_test {
+    ($fn_name:ident, $stub:literal, $resp:expr, $($(.$meth:ident($( $arg:expr ),*))+),+) => {
+        $($crate::reqwest_test!($fn_name, $stub, $( .$meth($($arg),*) )* );)+
+        $($crate::surf_test!($fn_name, $stub, $( .$meth($($arg),*) )* );)+
+        $($crate::ureq_test!($fn_name, $stub, $( .$meth($($arg),*) )* );)+
+        $($crate::hyper_test!($fn_name, $stub, $( .$meth($($arg),*) )* );)+
+        $($crate::isahc_test!($fn_name, $stub, $( .$meth($($arg),*) )* );)+
+        $($crate::actix_test!($fn_name, $resp.0, $( .$meth($($arg),*) )* );)+
+        $($crate::rocket_test!($fn_name, $resp.1, $( .$meth($($arg),*) )* );)+
+        $($crate::axum_test!($fn_name, $resp.2, $( .$meth($($arg),*) )* );)+
+    };
+    ($fn_name:ident, $stub:literal, $resp:expr, $panic_msg:literal, $($(.$meth:ident($( $arg:expr ),*))+),+) => {
+        $($crate::reqwest_test!($fn_name, $stub, $panic_msg, $( .$meth($($arg),*) )* );)+
+        $($crate::surf_test!($fn_name, $stub, $panic_msg, $( .$meth($($arg),*) )* );)+
+        $($crate::ureq_test!($fn_name, $stub, $panic_msg, $( .$meth($($arg),*) )* );)+
+        $($crate::hyper_test!($fn_name, $stub, $panic_msg, $( .$meth($($arg),*) )* );)+
+        $($crate::isahc_test!($fn_name, $stub, $panic_msg, $( .$meth($($arg),*) )* );)+
+        $($crate::actix_test!($fn_name, $resp.0, $panic_msg, $( .$meth($($arg),*) )* );)+
+        $($crate::rocket_test!($fn_name, $resp.1, $panic_msg, $( .$meth($($arg),*) )* );)+
+        $($crate::axum_test!($fn_name, $resp.2, $panic_msg, $( .$meth($($arg),*) )* );)+
+    };
+    ($fn_name:ident, $stub:literal, $resp:expr, $error:expr, $($(.$meth:ident($( $arg:expr ),*))+),+) => {
+        $($crate::reqwest_test!($fn_name, $stub, $error, $( .$meth($($arg),*) )* );)+
+        $($crate::surf_test!($fn_name, $stub, $error, $( .$meth($($arg),*) )* );)+
+        $($crate::ureq_test!($fn_name, $stub, $error, $( .$meth($($arg),*) )* );)+
+        $($crate::hyper_test!($fn_name, $stub, $error, $( .$meth($($arg),*) )* );)+
+        $($crate::isahc_test!($fn_name, $stub, $error, $( .$meth($($arg),*) )* );)+
+        $($crate::actix_test!($fn_name, $resp.0, $error, $( .$meth($($arg),*) )* );)+
+        $($crate::rocket_test!($fn_name, $resp.1, $error, $( .$meth($($arg),*) )* );)+
+        $($crate::axum_test!($fn_name, $resp.2, $error, $( .$meth($($arg),*) )* );)+
+    };
+}
+
+// Rocket's default 'Shield' fairing adds 'permissions-policy', 'x-content-type-options' and 'x-frame-options' to every response
+#[macro_export]
+macro_rules! asserhttp_unshielded_test {
+    ($fn_name:ident, $stub:literal, $resp:expr, $($(.$meth:ident($( $arg:expr ),*))+),+) => {
+        $($crate::reqwest_test!($fn_name, $stub, $( .$meth($($arg),*) )* );)+
+        $($crate::surf_test!($fn_name, $stub, $( .$meth($($arg),*) )* );)+
+        $($crate::ureq_test!($fn_name, $stub, $( .$meth($($arg),*) )* );)+
+        $($crate::hyper_test!($fn_name, $stub, $( .$meth($($arg),*) )* );)+
+        $($crate::awc_test!($fn_name, $stub, $( .$meth($($arg),*) )* );)+
+        $($crate::isahc_test!($fn_name, $stub, $( .$meth($($arg),*) )* );)+
+        $($crate::actix_test!($fn_name, $resp.0, $( .$meth($($arg),*) )* );)+
+        $($crate::axum_test!($fn_name, $resp.2, $( .$meth($($arg),*) )* );)+
+    };
+    ($fn_name:ident, $stub:literal, $resp:expr, $panic_msg:literal, $($(.$meth:ident($( $arg:expr ),*))+),+) => {
+        $($crate::reqwest_test!($fn_name, $stub, $panic_msg, $( .$meth($($arg),*) )* );)+
+        $($crate::surf_test!($fn_name, $stub, $panic_msg, $( .$meth($($arg),*) )* );)+
+        $($crate::ureq_test!($fn_name, $stub, $panic_msg, $( .$meth($($arg),*) )* );)+
+        $($crate::hyper_test!($fn_name, $stub, $panic_msg, $( .$meth($($arg),*) )* );)+
+        $($crate::awc_test!($fn_name, $stub, $panic_msg, $( .$meth($($arg),*) )* );)+
+        $($crate::isahc_test!($fn_name, $stub, $panic_msg, $( .$meth($($arg),*) )* );)+
+        $($crate::actix_test!($fn_name, $resp.0, $panic_msg, $( .$meth($($arg),*) )* );)+
+        $($crate::axum_test!($fn_name, $resp.2, $panic_msg, $( .$meth($($arg),*) )* );)+
+    };
+    ($fn_name:ident, $stub:literal, $resp:expr, $error:expr, $($(.$meth:ident($( $arg:expr ),*))+),+) => {
+        $($crate::reqwest_test!($fn_name, $stub, $error, $( .$meth($($arg),*) )* );)+
+        $($crate::surf_test!($fn_name, $stub, $error, $( .$meth($($arg),*) )* );)+
+        $($crate::ureq_test!($fn_name, $stub, $error, $( .$meth($($arg),*) )* );)+
+        $($crate::hyper_test!($fn_name, $stub, $error, $( .$meth($($arg),*) )* );)+
+        $($crate::awc_test!($fn_name, $stub, $error, $( .$meth($($arg),*) )* );)+
+        $($crate::isahc_test!($fn_name, $stub, $error, $( .$meth($($arg),*) )* );)+
+        $($crate::actix_test!($fn_name, $resp.0, $error, $( .$meth($($arg),*) )* );)+
+        $($crate::axum_test!($fn_name, $resp.2, $error, $( .$meth($($arg),*) )* );)+
+    };
+}
+
 mod status {
     use super::Stub::*;
 
@@ -322,6 +1233,7 @@ mod status {
 mod header {
     use super::Stub::*;
     use serde_json::json;
+    use std::time::{Duration, SystemTime};
 
     asserhttp_test!(header_should_succeed, "header/one.json", HeaderOne.responses(), .expect_header("x-a", "a"));
     asserhttp_test!(header_const_should_succeed, "header/json.json", HeaderJson.responses(), .expect_header(headers::CONTENT_TYPE, "application/json"));
@@ -401,12 +1313,135 @@ mod header {
     asserhttp_test!(fallible_header_absent_should_fail_when_present_ignoring_case, "header/one.json", HeaderOne.responses(), AsserhttpError::HeaderPresent { key: HeaderKey::from("X-A") }, .try_expect_header_absent("X-A"));
     asserhttp_test!(fallible_header_absent_const_should_fail_when_present, "header/json.json", HeaderJson.responses(), AsserhttpError::HeaderPresent { key: HeaderKey::from("content-type") }, .try_expect_header_absent(headers::CONTENT_TYPE));
 
+    asserhttp_test!(header_matches_should_succeed, "header/one.json", HeaderOne.responses(), .expect_header_matches("x-a", "^[a-z]$"));
+    asserhttp_test!(header_matches_should_match_key_ignoring_case, "header/one.json", HeaderOne.responses(), .expect_header_matches("X-A", "^a$"));
+    asserhttp_test!(header_matches_should_fail_when_not_matching, "header/one.json", HeaderOne.responses(), "expected header 'x-a' to match regex '^[0-9]+$' but was 'a'", .expect_header_matches("x-a", "^[0-9]+$"));
+    asserhttp_test!(header_matches_should_fail_when_absent, "header/one.json", HeaderOne.responses(), "expected one header named 'x-b' but none found", .expect_header_matches("x-b", "a"));
+    asserhttp_test!(header_matches_should_fail_when_multivalued, "header/multi.json", HeaderMulti.responses(), "expected header 'x-m' to be single valued", .expect_header_matches("x-m", "[a-b]"));
+    asserhttp_test!(header_matches_should_fail_when_regex_invalid, "header/one.json", HeaderOne.responses(), "regex parse error", .expect_header_matches("x-a", "("));
+
+    asserhttp_test!(fallible_header_matches_should_succeed, "header/one.json", HeaderOne.responses(), .try_expect_header_matches("x-a", "^[a-z]$").unwrap());
+    asserhttp_test!(fallible_header_matches_should_fail_when_not_matching, "header/one.json", HeaderOne.responses(), AsserhttpError::HeaderRegexMismatch { key: HeaderKey::from("x-a"), actual: HeaderValue::from("a"), regex: "^[0-9]+$".to_string() }, .try_expect_header_matches("x-a", "^[0-9]+$"));
+    asserhttp_test!(fallible_header_matches_should_fail_when_absent, "header/one.json", HeaderOne.responses(), AsserhttpError::HeaderAbsent { key: HeaderKey::from("x-b") }, .try_expect_header_matches("x-b", "a"));
+
+    asserhttp_test!(headers_match_should_succeed, "header/multi.json", HeaderMulti.responses(), .expect_headers_match("x-m", "^[a-b]$"));
+    asserhttp_test!(headers_match_should_succeed_when_single_valued, "header/one.json", HeaderOne.responses(), .expect_headers_match("x-a", "^a$"));
+    asserhttp_test!(headers_match_should_fail_when_one_not_matching, "header/multi.json", HeaderMulti.responses(), "expected header 'x-m' to match regex '^a$' but was 'b'", .expect_headers_match("x-m", "^a$"));
+    asserhttp_test!(headers_match_should_fail_when_absent, "header/multi.json", HeaderMulti.responses(), "expected one header named 'x-b' but none found", .expect_headers_match("x-b", "a"));
+
+    asserhttp_test!(fallible_headers_match_should_succeed, "header/multi.json", HeaderMulti.responses(), .try_expect_headers_match("x-m", "^[a-b]$").unwrap());
+    asserhttp_test!(fallible_headers_match_should_fail_when_one_not_matching, "header/multi.json", HeaderMulti.responses(), AsserhttpError::HeaderRegexMismatch { key: HeaderKey::from("x-m"), actual: HeaderValue::from("b"), regex: "^a$".to_string() }, .try_expect_headers_match("x-m", "^a$"));
+
+    asserhttp_test!(header_present_matching_should_succeed, "header/many.json", HeaderMany.responses(), .expect_header_present_matching("^x-[a-z]$"));
+    asserhttp_test!(header_present_matching_should_match_lowercase_keys, "header/one.json", HeaderOne.responses(), .expect_header_present_matching("^x-a$"));
+    asserhttp_test!(header_present_matching_should_fail_when_none_matching, "header/many.json", HeaderMany.responses(), "expected a header matching '^x-z' but none found in", .expect_header_present_matching("^x-z"));
+    asserhttp_test!(header_present_matching_should_fail_when_regex_invalid, "header/many.json", HeaderMany.responses(), "regex parse error", .expect_header_present_matching("("));
+
+    asserhttp_test!(fallible_header_present_matching_should_succeed, "header/many.json", HeaderMany.responses(), .try_expect_header_present_matching("^x-[a-z]$").unwrap());
+    asserhttp_test!(fallible_header_present_matching_should_fail_when_none_matching, "header/many.json", HeaderMany.responses(), "", .try_expect_header_present_matching("^x-z").unwrap());
+
+    asserhttp_test!(header_absent_matching_should_succeed, "header/many.json", HeaderMany.responses(), .expect_header_absent_matching("^x-internal-"));
+    asserhttp_test!(header_absent_matching_should_fail_when_matching, "header/many.json", HeaderMany.responses(), "expected no header matching '^x-[a-z]$' but found [\"x-a\", \"x-b\"]", .expect_header_absent_matching("^x-[a-z]$"));
+
+    asserhttp_test!(fallible_header_absent_matching_should_succeed, "header/many.json", HeaderMany.responses(), .try_expect_header_absent_matching("^x-internal-").unwrap());
+    asserhttp_test!(fallible_header_absent_matching_should_fail_when_matching, "header/many.json", HeaderMany.responses(), AsserhttpError::HeaderPresentMatching { regex: "^x-b$".to_string(), keys: vec!["x-b".to_string()] }, .try_expect_header_absent_matching("^x-b$"));
+
+    asserhttp_test!(headers_with_prefix_should_succeed, "header/many.json", HeaderMany.responses(), .expect_headers_with_prefix("x-", |h| assert!(h["x-a"] == ["a"] && h["x-b"] == ["b"])));
+    asserhttp_test!(headers_with_prefix_should_ignore_case, "header/multi.json", HeaderMulti.responses(), .expect_headers_with_prefix("X-M", |h| assert_eq!(h.get("x-m"), Some(&vec!["a".to_string(), "b".to_string()]))));
+    asserhttp_test!(headers_with_prefix_should_be_empty_when_none, "header/many.json", HeaderMany.responses(), .expect_headers_with_prefix("x-amz-", |h| assert!(h.is_empty())));
+    asserhttp_test!(headers_with_prefix_should_fail, "header/many.json", HeaderMany.responses(), "", .expect_headers_with_prefix("x-", |h| assert!(h.is_empty())));
+
+    asserhttp_test!(fallible_headers_with_prefix_should_succeed, "header/many.json", HeaderMany.responses(), .try_expect_headers_with_prefix("x-", |h| { assert!(h.contains_key("x-a") && h.contains_key("x-b")); Ok(()) }).unwrap());
+    asserhttp_test!(fallible_headers_with_prefix_should_fail, "header/many.json", HeaderMany.responses(), AsserhttpError::HeaderAbsent { key: HeaderKey::from("x-amz-request-id") }, .try_expect_headers_with_prefix("x-", |h| if h.contains_key("x-amz-request-id") { Ok(()) } else { Err(AsserhttpError::HeaderAbsent { key: HeaderKey::from("x-amz-request-id") }) }));
+
+    asserhttp_unshielded_test!(only_headers_should_succeed, "header/json.json", HeaderJson.responses(), .expect_only_headers(["content-type"]));
+    asserhttp_unshielded_test!(only_headers_should_match_key_ignoring_case, "header/json.json", HeaderJson.responses(), .expect_only_headers(["Content-Type"]));
+    asserhttp_unshielded_test!(only_headers_const_should_succeed, "header/json.json", HeaderJson.responses(), .expect_only_headers([headers::CONTENT_TYPE]));
+    asserhttp_unshielded_test!(only_headers_should_fail_when_unexpected, "header/json.json", HeaderJson.responses(), "expected no other headers but found [\"content-type\"]", .expect_only_headers([] as [&str; 0]));
+    asserhttp_test!(only_headers_should_fail_when_absent, "header/json.json", HeaderJson.responses(), "expected one header named 'x-b' but none found", .expect_only_headers(["content-type", "x-b"]));
+
+    asserhttp_unshielded_test!(fallible_only_headers_should_succeed, "header/json.json", HeaderJson.responses(), .try_expect_only_headers([String::from("content-type")]).unwrap());
+    asserhttp_unshielded_test!(fallible_only_headers_should_fail_when_unexpected, "header/json.json", HeaderJson.responses(), AsserhttpError::UnexpectedHeaders { unexpected: vec!["content-type".to_string()] }, .try_expect_only_headers([] as [&str; 0]));
+
+    asserhttp_unshielded_test!(header_count_should_succeed, "header/json.json", HeaderJson.responses(), .expect_header_count(1));
+    asserhttp_unshielded_test!(header_count_should_fail, "header/json.json", HeaderJson.responses(), "expected '2' headers but found '1' [\"content-type\"]", .expect_header_count(2));
+
+    asserhttp_unshielded_test!(fallible_header_count_should_succeed, "header/json.json", HeaderJson.responses(), .try_expect_header_count(1).unwrap());
+    asserhttp_unshielded_test!(fallible_header_count_should_fail, "header/json.json", HeaderJson.responses(), AsserhttpError::HeaderCountMismatch { expected: 0, actual: 1, keys: vec!["content-type".to_string()] }, .try_expect_header_count(0));
+
+    // Rocket's default 'Shield' fairing headers are not tolerated
+    crate::rocket_test!(only_headers_should_expect_shield_headers, HeaderJson.responses().1, .expect_only_headers(["content-type", "permissions-policy", "x-content-type-options", "x-frame-options"]));
+    crate::rocket_test!(only_headers_should_fail_when_shield_headers_unexpected, HeaderJson.responses().1, "expected no other headers but found [\"permissions-policy\", \"x-content-type-options\", \"x-frame-options\"]", .expect_only_headers(["content-type"]));
+    crate::rocket_test!(header_count_should_count_shield_headers, HeaderJson.responses().1, .expect_header_count(4));
+
+    asserhttp_test!(header_values_count_should_succeed, "header/multi.json", HeaderMulti.responses(), .expect_header_values_count("x-m", 2));
+    asserhttp_test!(header_values_count_should_fail, "header/multi.json", HeaderMulti.responses(), "expected header 'x-m' to have '1' values but had '[\"a\", \"b\"]'", .expect_header_values_count("x-m", 1));
+    asserhttp_test!(header_values_count_should_fail_when_absent, "header/multi.json", HeaderMulti.responses(), "expected one header named 'vary' but none found", .expect_header_values_count("vary", 2));
+
+    asserhttp_test!(fallible_header_values_count_should_succeed, "header/one.json", HeaderOne.responses(), .try_expect_header_values_count("x-a", 1).unwrap());
+    asserhttp_test!(fallible_header_values_count_should_fail, "header/multi.json", HeaderMulti.responses(), AsserhttpError::HeaderValuesCountMismatch { key: HeaderKey::from("x-m"), expected: 3, actual_values: HeaderValues::from(["a", "b"]) }, .try_expect_header_values_count("x-m", 3));
+
     asserhttp_test!(header_content_type_json_should_succeed, "header/json.json", HeaderJson.responses(), .expect_content_type_json());
     asserhttp_test!(header_content_type_json_should_fail, "header/xml.json", HeaderXml.responses(), "expected header 'content-type' to be equal to 'application/json' but was 'application/xml'", .expect_content_type_json());
 
     asserhttp_test!(header_content_type_text_should_succeed, "header/text.json", HeaderText.responses(), .expect_content_type_text());
     asserhttp_test!(header_content_type_text_should_fail, "header/xml.json", HeaderXml.responses(), "expected header 'content-type' to be equal to 'text/plain' but was 'application/xml'", .expect_content_type_text());
 
+    asserhttp_test!(content_type_should_succeed, "header/json.json", HeaderJson.responses(), .expect_content_type("application/json"));
+    asserhttp_test!(content_type_should_ignore_case, "header/json.json", HeaderJson.responses(), .expect_content_type("Application/JSON"));
+    asserhttp_test!(content_type_should_ignore_unexpected_params, "header/json-charset.json", HeaderJsonCharset.responses(), .expect_content_type("application/json"));
+    asserhttp_test!(content_type_should_succeed_with_params, "header/json-charset.json", HeaderJsonCharset.responses(), .expect_content_type("application/json;charset=\"utf-8\""));
+    asserhttp_test!(content_type_should_succeed_with_subtype_wildcard, "header/problem-json.json", HeaderProblemJson.responses(), .expect_content_type("application/*"));
+    asserhttp_test!(content_type_should_succeed_with_suffix, "header/problem-json.json", HeaderProblemJson.responses(), .expect_content_type("*/*+json"));
+    asserhttp_test!(content_type_should_fail_when_suffix_differs, "header/problem-json.json", HeaderProblemJson.responses(), "expected content type to be 'application/json' but was 'application/problem+json'", .expect_content_type("application/json"));
+    asserhttp_test!(content_type_should_fail_when_param_missing, "header/json.json", HeaderJson.responses(), "expected content type to be 'application/json; charset=utf-8' but was 'application/json'", .expect_content_type("application/json; charset=utf-8"));
+    asserhttp_test!(content_type_should_fail_when_param_differs, "header/multipart.json", HeaderMultipart.responses(), "expected content type to be 'multipart/form-data; boundary=ABC' but was 'multipart/form-data; boundary=abc'", .expect_content_type("multipart/form-data; boundary=ABC"));
+    asserhttp_test!(content_type_should_succeed_with_quoted_separator, "header/text-quoted-param.json", HeaderTextQuotedParam.responses(), .expect_content_type("text/plain; title=\"a;b\"; charset=utf-8"));
+    asserhttp_test!(content_type_should_succeed_with_escaped_quoted_param, "header/text-quoted-param.json", HeaderTextQuotedParam.responses(), .expect_content_type("text/plain; title=\"a\\;b\""));
+    asserhttp_test!(content_type_should_fail_when_quoted_param_differs, "header/text-quoted-param.json", HeaderTextQuotedParam.responses(), "expected content type to be 'text/plain; title=a' but was 'text/plain; title=\"a;b\"; charset=utf-8'", .expect_content_type("text/plain; title=a"));
+    asserhttp_test!(content_type_should_fail_when_supplied_invalid, "header/json.json", HeaderJson.responses(), "'json' is not a valid media type", .expect_content_type("json"));
+
+    asserhttp_test!(fallible_content_type_should_succeed, "header/json-charset.json", HeaderJsonCharset.responses(), .try_expect_content_type("application/json; charset=utf-8").unwrap());
+    asserhttp_test!(fallible_content_type_should_fail, "header/xml.json", HeaderXml.responses(), AsserhttpError::ContentTypeMismatch { expected: "text/xml".to_string(), actual: "application/xml".to_string() }, .try_expect_content_type("text/xml"));
+    asserhttp_test!(fallible_content_type_should_fail_when_supplied_invalid, "header/json.json", HeaderJson.responses(), AsserhttpError::InvalidMediaType { value: "application/json; charset".to_string() }, .try_expect_content_type("application/json; charset"));
+
+    asserhttp_test!(content_type_any_json_should_succeed, "header/json.json", HeaderJson.responses(), .expect_content_type_any_json());
+    asserhttp_test!(content_type_any_json_should_succeed_with_params, "header/json-charset.json", HeaderJsonCharset.responses(), .expect_content_type_any_json());
+    asserhttp_test!(content_type_any_json_should_succeed_with_suffix, "header/problem-json.json", HeaderProblemJson.responses(), .expect_content_type_any_json());
+    asserhttp_test!(content_type_any_json_should_fail, "header/xml.json", HeaderXml.responses(), "expected content type to be 'application/json or */*+json' but was 'application/xml'", .expect_content_type_any_json());
+    asserhttp_test!(fallible_content_type_any_json_should_succeed, "header/problem-json.json", HeaderProblemJson.responses(), .try_expect_content_type_any_json().unwrap());
+    asserhttp_test!(fallible_content_type_any_json_should_fail, "header/text.json", HeaderText.responses(), AsserhttpError::ContentTypeMismatch { expected: "application/json or */*+json".to_string(), actual: "text/plain".to_string() }, .try_expect_content_type_any_json());
+
+    asserhttp_test!(content_type_any_xml_should_succeed, "header/xml.json", HeaderXml.responses(), .expect_content_type_any_xml());
+    asserhttp_test!(content_type_any_xml_should_fail, "header/problem-json.json", HeaderProblemJson.responses(), "expected content type to be 'application/xml or text/xml or */*+xml' but was 'application/problem+json'", .expect_content_type_any_xml());
+    asserhttp_test!(fallible_content_type_any_xml_should_succeed, "header/xml.json", HeaderXml.responses(), .try_expect_content_type_any_xml().unwrap());
+
+    asserhttp_test!(content_type_multipart_should_succeed, "header/multipart.json", HeaderMultipart.responses(), .expect_content_type_multipart());
+    asserhttp_test!(content_type_multipart_should_fail, "header/json.json", HeaderJson.responses(), "expected content type to be 'multipart/*' but was 'application/json'", .expect_content_type_multipart());
+    asserhttp_test!(fallible_content_type_multipart_should_succeed, "header/multipart.json", HeaderMultipart.responses(), .try_expect_content_type_multipart().unwrap());
+
+    asserhttp_test!(content_type_charset_should_succeed, "header/json-charset.json", HeaderJsonCharset.responses(), .expect_content_type_charset("utf-8"));
+    asserhttp_test!(content_type_charset_should_fail_when_absent, "header/json.json", HeaderJson.responses(), "expected content type to be '*/*; charset=utf-8' but was 'application/json'", .expect_content_type_charset("utf-8"));
+    asserhttp_test!(fallible_content_type_charset_should_succeed, "header/json-charset.json", HeaderJsonCharset.responses(), .try_expect_content_type_charset("UTF-8").unwrap());
+    asserhttp_test!(fallible_content_type_charset_should_fail, "header/json-charset.json", HeaderJsonCharset.responses(), AsserhttpError::ContentTypeMismatch { expected: "*/*; charset=iso-8859-1".to_string(), actual: "application/json; charset=UTF-8".to_string() }, .try_expect_content_type_charset("iso-8859-1"));
+
+    asserhttp_test!(header_date_should_succeed, "header/dates.json", HeaderDates.responses(), .expect_header_date("expires", |t| assert_eq!(t, SystemTime::UNIX_EPOCH + Duration::from_secs(3_942_057_600))));
+    asserhttp_test!(header_date_should_fail_when_invalid, "header/dates-invalid.json", HeaderDatesInvalid.responses(), "'0' is not a valid http date", .expect_header_date("expires", |_| {}));
+    asserhttp_test!(fallible_header_date_should_succeed, "header/dates.json", HeaderDates.responses(), .try_expect_header_date(headers::EXPIRES, |t| { assert!(t > SystemTime::now()); Ok(()) }).unwrap());
+    asserhttp_test!(fallible_header_date_should_fail_when_absent, "header/one.json", HeaderOne.responses(), AsserhttpError::HeaderAbsent { key: HeaderKey::from("expires") }, .try_expect_header_date("expires", |_| Ok(())));
+    asserhttp_test!(fallible_header_date_should_fail_when_invalid, "header/dates-invalid.json", HeaderDatesInvalid.responses(), AsserhttpError::InvalidHttpDate { value: "0".to_string() }, .try_expect_header_date("expires", |_| Ok(())));
+
+    asserhttp_date_test!(date_within_should_succeed, "header/dates.json", HeaderDates.responses(), .expect_date_within(Duration::from_secs(u32::MAX as u64)));
+    asserhttp_date_test!(date_within_should_fail_when_drifting, "header/dates.json", HeaderDates.responses(), "expected 'date' to be within 60s of now but was 'Wed, 21 Oct 2015 07:28:00 GMT'", .expect_date_within(Duration::from_secs(60)));
+    asserhttp_date_test!(fallible_date_within_should_succeed, "header/dates.json", HeaderDates.responses(), .try_expect_date_within(Duration::from_secs(u32::MAX as u64)).unwrap());
+
+    asserhttp_test!(retry_after_should_succeed_with_delay, "header/dates.json", HeaderDates.responses(), .expect_retry_after(|r| assert_eq!(r, RetryAfter::Delay(Duration::from_secs(120)))));
+    asserhttp_test!(retry_after_should_succeed_with_date, "header/retry-after-date.json", HeaderRetryAfterDate.responses(), .expect_retry_after(|r| { assert_eq!(r, RetryAfter::Date(SystemTime::UNIX_EPOCH + Duration::from_secs(1_445_412_480))); assert_eq!(r.delay(), Duration::ZERO); }));
+    asserhttp_test!(retry_after_should_fail_when_invalid, "header/dates-invalid.json", HeaderDatesInvalid.responses(), "'soon' is neither a delay in seconds nor a valid http date", .expect_retry_after(|_| {}));
+    asserhttp_test!(fallible_retry_after_should_succeed, "header/dates.json", HeaderDates.responses(), .try_expect_retry_after(|r| { assert!(r.delay() <= Duration::from_secs(120)); Ok(()) }).unwrap());
+    asserhttp_test!(fallible_retry_after_should_fail_when_absent, "header/one.json", HeaderOne.responses(), AsserhttpError::HeaderAbsent { key: HeaderKey::from("retry-after") }, .try_expect_retry_after(|_| Ok(())));
+    asserhttp_test!(fallible_retry_after_should_fail_when_invalid, "header/dates-invalid.json", HeaderDatesInvalid.responses(), AsserhttpError::InvalidRetryAfter { value: "soon".to_string() }, .try_expect_retry_after(|_| Ok(())));
+
     asserhttp_test!(expect_header_first_should_not_be_destructive, "full.json", Full.responses(), .expect_content_type_json().expect_status_ok().expect_body_json_eq(json!({"a": "b"})));
 
     // can't be tested through macros. It just has to compile
@@ -456,6 +1491,392 @@ mod header {
     }
 }
 
+mod cache_control {
+    use super::Stub::*;
+    use std::time::Duration;
+
+    asserhttp_test!(cache_control_should_succeed, "cache-control/private.json", CacheControlPrivate.responses(), .expect_cache_control(|cc| assert_eq!(cc.directives(), vec!["private", "max-age", "must-revalidate"])));
+    asserhttp_test!(cache_control_should_succeed_when_comma_separated, "header/cache-control.json", HeaderCacheControl.responses(), .expect_cache_control(|cc| assert!(cc.no_cache() && cc.no_store() && !cc.private())));
+    asserhttp_test!(cache_control_should_fail, "cache-control/private.json", CacheControlPrivate.responses(), "", .expect_cache_control(|cc| assert!(cc.public())));
+    asserhttp_test!(cache_control_should_fail_when_absent, "header/one.json", HeaderOne.responses(), "expected one header named 'cache-control' but none found", .expect_cache_control(|_| {}));
+
+    asserhttp_test!(fallible_cache_control_should_succeed, "cache-control/private.json", CacheControlPrivate.responses(), .try_expect_cache_control(|cc| { assert_eq!(cc.value("max-age"), Some("3600")); Ok(()) }).unwrap());
+    asserhttp_test!(fallible_cache_control_should_fail_when_absent, "header/one.json", HeaderOne.responses(), AsserhttpError::HeaderAbsent { key: HeaderKey::from("cache-control") }, .try_expect_cache_control(|_| Ok(())));
+
+    asserhttp_test!(no_store_should_succeed, "header/cache-control.json", HeaderCacheControl.responses(), .expect_no_store());
+    asserhttp_test!(no_store_should_fail, "cache-control/private.json", CacheControlPrivate.responses(), "expected cache-control to contain 'no-store' but was 'private, max-age=3600, must-revalidate'", .expect_no_store());
+    asserhttp_test!(fallible_no_store_should_fail, "cache-control/private.json", CacheControlPrivate.responses(), AsserhttpError::CacheControlMismatch { expected: "no-store".to_string(), actual: "private, max-age=3600, must-revalidate".to_string() }, .try_expect_no_store());
+    asserhttp_test!(no_store_should_fail_keeping_quoted_values, "cache-control/no-cache-fields.json", CacheControlNoCacheFields.responses(), "expected cache-control to contain 'no-store' but was 'no-cache=\"set-cookie, x-token\", max-age=0'", .expect_no_store());
+    asserhttp_test!(fallible_no_store_should_fail_keeping_quoted_values, "cache-control/no-cache-fields.json", CacheControlNoCacheFields.responses(), AsserhttpError::CacheControlMismatch { expected: "no-store".to_string(), actual: "no-cache=\"set-cookie, x-token\", max-age=0".to_string() }, .try_expect_no_store());
+    asserhttp_test!(cache_control_should_unquote_values, "cache-control/no-cache-fields.json", CacheControlNoCacheFields.responses(), .expect_cache_control(|cc| assert_eq!(cc.value("no-cache"), Some("set-cookie, x-token"))));
+
+    asserhttp_test!(max_age_should_succeed, "cache-control/private.json", CacheControlPrivate.responses(), .expect_max_age(Duration::from_secs(3600)));
+    asserhttp_test!(max_age_should_fail_when_different, "cache-control/private.json", CacheControlPrivate.responses(), "expected cache-control to contain 'max-age=60' but was 'private, max-age=3600, must-revalidate'", .expect_max_age(Duration::from_secs(60)));
+    asserhttp_test!(max_age_should_fail_when_absent, "header/cache-control.json", HeaderCacheControl.responses(), "expected cache-control to contain 'max-age=60' but was 'no-cache, no-store'", .expect_max_age(Duration::from_secs(60)));
+    asserhttp_test!(fallible_max_age_should_succeed, "cache-control/private.json", CacheControlPrivate.responses(), .try_expect_max_age(Duration::from_secs(3600)).unwrap());
+
+    asserhttp_test!(cache_private_should_succeed, "cache-control/private.json", CacheControlPrivate.responses(), .expect_cache_private());
+    asserhttp_test!(cache_private_should_fail, "header/cache-control.json", HeaderCacheControl.responses(), "expected cache-control to contain 'private' but was 'no-cache, no-store'", .expect_cache_private());
+    asserhttp_test!(fallible_cache_private_should_succeed, "cache-control/private.json", CacheControlPrivate.responses(), .try_expect_cache_private().unwrap());
+
+    asserhttp_test!(must_revalidate_should_succeed_ignoring_case, "cache-control/private.json", CacheControlPrivate.responses(), .expect_must_revalidate());
+    asserhttp_test!(must_revalidate_should_fail, "header/cache-control.json", HeaderCacheControl.responses(), "expected cache-control to contain 'must-revalidate' but was 'no-cache, no-store'", .expect_must_revalidate());
+    asserhttp_test!(fallible_must_revalidate_should_fail, "header/cache-control.json", HeaderCacheControl.responses(), AsserhttpError::CacheControlMismatch { expected: "must-revalidate".to_string(), actual: "no-cache, no-store".to_string() }, .try_expect_must_revalidate());
+}
+
+mod cookie {
+    use super::Stub::*;
+
+    asserhttp_test!(cookie_should_succeed, "cookie/session.json", CookieSession.responses(), .expect_cookie("session", |c| { assert_eq!(c.value, "abc123"); assert_eq!(c.domain.as_deref(), Some("example.com")); assert_eq!(c.same_site.as_deref(), Some("Strict")); assert!(c.expires.is_some()); }));
+    asserhttp_test!(cookie_should_fail, "cookie/session.json", CookieSession.responses(), "", .expect_cookie("session", |c| assert_eq!(c.value, "stale")));
+    asserhttp_test!(cookie_should_fail_when_absent, "cookie/session.json", CookieSession.responses(), "expected a cookie named 'missing' but found", .expect_cookie("missing", |_| {}));
+    asserhttp_test!(fallible_cookie_should_succeed, "cookie/session.json", CookieSession.responses(), .try_expect_cookie("session", |c| { assert_eq!(c.path.as_deref(), Some("/")); Ok(()) }).unwrap());
+    asserhttp_test!(fallible_cookie_should_fail_when_absent, "header/one.json", HeaderOne.responses(), AsserhttpError::CookieAbsent { name: "session".to_string(), actual: vec![] }, .try_expect_cookie("session", |_| Ok(())));
+
+    asserhttp_raw_test!(cookie_should_succeed_when_several_lines, "cookie/multiple.http", .expect_cookie("theme", |c| assert_eq!((c.value.as_str(), c.path.as_deref()), ("dark", Some("/")))).expect_cookie("session", |c| { assert_eq!(c.value, "abc123"); assert_eq!(c.expires, Some(std::time::UNIX_EPOCH + std::time::Duration::from_secs(4_096_250_880))); }));
+    asserhttp_raw_test!(cookie_secure_should_succeed_when_several_lines, "cookie/multiple.http", .expect_cookie_secure("session").expect_cookie_http_only("session"));
+    asserhttp_test!(cookie_secure_should_succeed, "cookie/session.json", CookieSession.responses(), .expect_cookie_secure("session"));
+    asserhttp_test!(cookie_secure_should_fail, "cookie/insecure.json", CookieInsecure.responses(), "expected cookie 'session' to be Secure", .expect_cookie_secure("session"));
+    asserhttp_test!(fallible_cookie_secure_should_fail, "cookie/insecure.json", CookieInsecure.responses(), AsserhttpError::CookieMismatch { name: "session".to_string(), expected: "Secure".to_string() }, .try_expect_cookie_secure("session"));
+
+    asserhttp_test!(cookie_http_only_should_succeed, "cookie/session.json", CookieSession.responses(), .expect_cookie_http_only("session"));
+    asserhttp_test!(cookie_http_only_should_fail, "cookie/insecure.json", CookieInsecure.responses(), "expected cookie 'session' to be HttpOnly", .expect_cookie_http_only("session"));
+    asserhttp_test!(fallible_cookie_http_only_should_succeed, "cookie/session.json", CookieSession.responses(), .try_expect_cookie_http_only("session").unwrap());
+
+    asserhttp_test!(cookie_removed_should_succeed, "cookie/removed.json", CookieRemoved.responses(), .expect_cookie_removed("session"));
+    asserhttp_test!(cookie_removed_should_fail, "cookie/session.json", CookieSession.responses(), "expected cookie 'session' to be removed", .expect_cookie_removed("session"));
+    asserhttp_test!(fallible_cookie_removed_should_fail, "cookie/insecure.json", CookieInsecure.responses(), AsserhttpError::CookieMismatch { name: "session".to_string(), expected: "removed".to_string() }, .try_expect_cookie_removed("session"));
+}
+
+mod security {
+    use super::Stub::*;
+    use std::time::Duration;
+
+    asserhttp_test!(security_headers_should_succeed, "security/secure.json", SecuritySecure.responses(), .expect_security_headers());
+    asserhttp_test!(security_headers_should_fail_reporting_every_violation, "security/insecure.json", SecurityInsecure.responses(), "expected response to comply with the security headers policy but:\n- 'strict-transport-security' max-age 3600s is below the minimum of 31536000s\n- 'x-content-type-options' should be 'nosniff' but was 'sniff'\n- 'x-frame-options' should be 'DENY' or 'SAMEORIGIN' but was 'ALLOW-FROM https://example.com'\n- 'referrer-policy' should not be 'unsafe-url'\n- 'server' discloses a version in 'nginx/1.25.3'\n- 'x-powered-by' discloses a version in 'PHP/8.2.1'", .expect_security_headers());
+    asserhttp_unshielded_test!(security_headers_should_fail_when_absent, "header/one.json", HeaderOne.responses(), "- missing 'strict-transport-security' header\n- missing 'x-content-type-options' header\n- missing 'x-frame-options' header or 'frame-ancestors' directive in 'content-security-policy'\n- missing 'referrer-policy' header", .expect_security_headers());
+    crate::rocket_test!(security_headers_should_fail_when_absent_despite_shield, HeaderOne.responses().1, "expected response to comply with the security headers policy but:\n- missing 'strict-transport-security' header\n- missing 'referrer-policy' header", .expect_security_headers());
+    asserhttp_test!(fallible_security_headers_should_succeed, "security/secure.json", SecuritySecure.responses(), .try_expect_security_headers().unwrap());
+    asserhttp_test!(fallible_security_headers_should_fail, "security/insecure.json", SecurityInsecure.responses(), AsserhttpError::SecurityHeadersViolations { violations: vec!["'strict-transport-security' max-age 3600s is below the minimum of 31536000s".to_string(), "'x-content-type-options' should be 'nosniff' but was 'sniff'".to_string(), "'x-frame-options' should be 'DENY' or 'SAMEORIGIN' but was 'ALLOW-FROM https://example.com'".to_string(), "'referrer-policy' should not be 'unsafe-url'".to_string(), "'server' discloses a version in 'nginx/1.25.3'".to_string(), "'x-powered-by' discloses a version in 'PHP/8.2.1'".to_string()] }, .try_expect_security_headers());
+
+    asserhttp_test!(security_headers_with_should_succeed_when_skipped, "security/insecure.json", SecurityInsecure.responses(), .expect_security_headers_with(SecurityHeadersPolicy::default().hsts_min_max_age(Duration::from_secs(60)).skip_content_type_options().skip_frame_options().skip_referrer_policy().skip_version_leaks()));
+    asserhttp_test!(security_headers_with_should_fail_when_hsts_too_short, "security/secure.json", SecuritySecure.responses(), "'strict-transport-security' max-age 63072000s is below the minimum of 94608000s", .expect_security_headers_with(SecurityHeadersPolicy::default().hsts_min_max_age(Duration::from_secs(94_608_000))));
+    asserhttp_test!(fallible_security_headers_with_should_succeed, "security/secure.json", SecuritySecure.responses(), .try_expect_security_headers_with(SecurityHeadersPolicy::default().skip_hsts()).unwrap());
+    asserhttp_test!(fallible_security_headers_with_should_fail, "security/insecure.json", SecurityInsecure.responses(), AsserhttpError::SecurityHeadersViolations { violations: vec!["'referrer-policy' should not be 'unsafe-url'".to_string()] }, .try_expect_security_headers_with(SecurityHeadersPolicy::default().skip_hsts().skip_content_type_options().skip_frame_options().skip_version_leaks()));
+}
+
+mod csp {
+    use super::Stub::*;
+
+    asserhttp_test!(csp_should_succeed, "csp/strict.json", CspStrict.responses(), .expect_csp(|csp| { assert!(csp.directive("script-src").contains("'self'") && csp.directive("script-src").contains("https://cdn.example")); assert_eq!(csp.directives(), vec!["default-src", "script-src", "frame-ancestors", "upgrade-insecure-requests"]); assert!(!csp.is_report_only()); }));
+    asserhttp_test!(csp_should_fall_back_to_default_src, "csp/strict.json", CspStrict.responses(), .expect_csp(|csp| { assert!(csp.directive("img-src").is_empty()); assert_eq!(csp.effective_directive("img-src").sources(), vec!["'self'"]); }));
+    asserhttp_test!(csp_should_fail, "csp/strict.json", CspStrict.responses(), "", .expect_csp(|csp| assert!(csp.directive("script-src").contains("'unsafe-eval'"))));
+    asserhttp_test!(csp_should_fail_when_absent, "header/one.json", HeaderOne.responses(), "expected one header named 'content-security-policy' but none found", .expect_csp(|_| {}));
+    asserhttp_test!(fallible_csp_should_succeed, "csp/strict.json", CspStrict.responses(), .try_expect_csp(|csp| { assert!(csp.has("upgrade-insecure-requests")); Ok(()) }).unwrap());
+    asserhttp_test!(fallible_csp_should_fail_when_absent, "header/one.json", HeaderOne.responses(), AsserhttpError::HeaderAbsent { key: HeaderKey::from("content-security-policy") }, .try_expect_csp(|_| Ok(())));
+
+    asserhttp_test!(csp_report_only_should_succeed, "csp/unsafe.json", CspUnsafe.responses(), .expect_csp_report_only(|csp| { assert!(csp.is_report_only()); assert_eq!(csp.directive("report-uri").sources(), vec!["/csp"]); }));
+    asserhttp_test!(csp_report_only_should_fail_when_absent, "csp/strict.json", CspStrict.responses(), "expected one header named 'content-security-policy-report-only' but none found", .expect_csp_report_only(|_| {}));
+    asserhttp_test!(fallible_csp_report_only_should_succeed, "csp/unsafe.json", CspUnsafe.responses(), .try_expect_csp_report_only(|csp| { assert_eq!(csp.to_string(), "script-src 'self'; report-uri /csp"); Ok(()) }).unwrap());
+
+    asserhttp_test!(csp_no_unsafe_inline_should_succeed, "csp/strict.json", CspStrict.responses(), .expect_csp_no_unsafe_inline());
+    asserhttp_test!(csp_no_unsafe_inline_should_fail_through_default_src, "csp/unsafe.json", CspUnsafe.responses(), "expected content-security-policy to have no 'unsafe-inline' in 'style-src' but was 'default-src 'self' 'unsafe-inline'; script-src 'self''", .expect_csp_no_unsafe_inline());
+    asserhttp_test!(fallible_csp_no_unsafe_inline_should_fail, "csp/unsafe.json", CspUnsafe.responses(), AsserhttpError::CspMismatch { expected: "no 'unsafe-inline' in 'style-src'".to_string(), actual: "default-src 'self' 'unsafe-inline'; script-src 'self'".to_string() }, .try_expect_csp_no_unsafe_inline());
+    asserhttp_test!(fallible_csp_no_unsafe_inline_should_fail_when_absent, "header/one.json", HeaderOne.responses(), AsserhttpError::HeaderAbsent { key: HeaderKey::from("content-security-policy") }, .try_expect_csp_no_unsafe_inline());
+
+    asserhttp_test!(csp_should_assert_every_policy, "csp/multiple.json", CspMultiple.responses(), .expect_csp(|csp| assert!(csp.directive("script-src").contains("'self'"))));
+    asserhttp_test!(csp_should_fail_when_one_policy_fails, "csp/multiple.json", CspMultiple.responses(), "assertion failed: csp.has(\"style-src\")", .expect_csp(|csp| assert!(csp.has("style-src"))));
+    asserhttp_test!(csp_no_unsafe_inline_should_succeed_when_another_policy_blocks_it, "csp/multiple.json", CspMultiple.responses(), .expect_csp_no_unsafe_inline());
+    asserhttp_test!(fallible_csp_no_unsafe_inline_should_succeed_when_another_policy_blocks_it, "csp/multiple.json", CspMultiple.responses(), .try_expect_csp_no_unsafe_inline().unwrap());
+    asserhttp_test!(csp_no_unsafe_inline_should_succeed_when_nonce_or_hash, "csp/nonce-fallback.json", CspNonceFallback.responses(), .expect_csp_no_unsafe_inline());
+    asserhttp_test!(fallible_csp_no_unsafe_inline_should_succeed_when_nonce_or_hash, "csp/nonce-fallback.json", CspNonceFallback.responses(), .try_expect_csp_no_unsafe_inline().unwrap());
+    asserhttp_test!(csp_no_unsafe_inline_should_fail_when_no_policy_blocks_it, "csp/multiple-unsafe.json", CspMultipleUnsafe.responses(), "expected content-security-policy to have no 'unsafe-inline' in 'script-src' but was 'script-src 'self' 'unsafe-inline', frame-ancestors 'none''", .expect_csp_no_unsafe_inline());
+    asserhttp_test!(fallible_csp_no_unsafe_inline_should_fail_when_no_policy_blocks_it, "csp/multiple-unsafe.json", CspMultipleUnsafe.responses(), AsserhttpError::CspMismatch { expected: "no 'unsafe-inline' in 'script-src'".to_string(), actual: "script-src 'self' 'unsafe-inline', frame-ancestors 'none'".to_string() }, .try_expect_csp_no_unsafe_inline());
+}
+
+mod cors {
+    use super::Stub::*;
+    use std::time::Duration;
+
+    asserhttp_test!(cors_allowed_origin_should_succeed, "cors/preflight.json", CorsPreflight.responses(), .expect_cors_allowed_origin("https://app.example"));
+    asserhttp_test!(cors_allowed_origin_should_succeed_with_wildcard, "cors/wildcard.json", CorsWildcard.responses(), .expect_cors_allowed_origin("https://app.example"));
+    asserhttp_test!(cors_allowed_origin_should_fail_when_other, "cors/preflight.json", CorsPreflight.responses(), "expected cors to allow origin 'https://evil.example' with credentials but 'access-control-allow-origin' was 'https://app.example'", .expect_cors_allowed_origin("https://evil.example"));
+    asserhttp_test!(cors_allowed_origin_should_fail_when_case_differs, "cors/preflight.json", CorsPreflight.responses(), "expected cors to allow origin 'https://APP.example' with credentials but 'access-control-allow-origin' was 'https://app.example'", .expect_cors_allowed_origin("https://APP.example"));
+    asserhttp_test!(cors_allowed_origin_should_fail_when_wildcard_with_credentials, "cors/credentials-wildcard.json", CorsCredentialsWildcard.responses(), "expected cors to allow origin 'https://app.example' with credentials but 'access-control-allow-origin' was '*'", .expect_cors_allowed_origin("https://app.example"));
+    asserhttp_test!(cors_allowed_origin_should_fail_without_vary, "cors/no-vary.json", CorsNoVary.responses(), "expected one header named 'vary' but none found", .expect_cors_allowed_origin("https://app.example"));
+    asserhttp_test!(fallible_cors_allowed_origin_should_succeed, "cors/wildcard.json", CorsWildcard.responses(), .try_expect_cors_allowed_origin("https://app.example").unwrap());
+    asserhttp_test!(fallible_cors_allowed_origin_should_fail_when_absent, "header/one.json", HeaderOne.responses(), AsserhttpError::HeaderAbsent { key: HeaderKey::from("access-control-allow-origin") }, .try_expect_cors_allowed_origin("https://app.example"));
+
+    asserhttp_test!(cors_allowed_methods_should_succeed, "cors/preflight.json", CorsPreflight.responses(), .expect_cors_allowed_methods(["PUT", "DELETE"]));
+    asserhttp_test!(cors_allowed_methods_should_succeed_with_wildcard, "cors/wildcard.json", CorsWildcard.responses(), .expect_cors_allowed_methods(["PATCH"]));
+    asserhttp_test!(cors_allowed_methods_should_fail, "cors/preflight.json", CorsPreflight.responses(), "expected cors to allow method 'PATCH' but 'access-control-allow-methods' was 'GET, PUT, DELETE'", .expect_cors_allowed_methods(["PUT", "PATCH"]));
+    asserhttp_test!(cors_allowed_methods_should_fail_when_case_differs, "cors/preflight.json", CorsPreflight.responses(), "expected cors to allow method 'delete' but 'access-control-allow-methods' was 'GET, PUT, DELETE'", .expect_cors_allowed_methods(["delete"]));
+    asserhttp_test!(fallible_cors_allowed_methods_should_fail_when_wildcard_with_credentials, "cors/credentials-wildcard.json", CorsCredentialsWildcard.responses(), AsserhttpError::CorsMismatch { expected: "allow method 'PATCH'".to_string(), key: HeaderKey::from("access-control-allow-methods"), actual: "*".to_string() }, .try_expect_cors_allowed_methods(["PATCH"]));
+
+    asserhttp_test!(cors_allowed_headers_should_succeed_ignoring_case, "cors/preflight.json", CorsPreflight.responses(), .expect_cors_allowed_headers(["content-type", "x-request-id"]));
+    asserhttp_test!(cors_allowed_headers_should_succeed_with_wildcard, "cors/wildcard.json", CorsWildcard.responses(), .expect_cors_allowed_headers(["x-anything"]));
+    asserhttp_test!(cors_allowed_headers_should_fail_for_authorization_with_wildcard, "cors/wildcard.json", CorsWildcard.responses(), "expected cors to allow header 'authorization' but 'access-control-allow-headers' was '*'", .expect_cors_allowed_headers(["authorization"]));
+    asserhttp_test!(fallible_cors_allowed_headers_should_fail, "cors/preflight.json", CorsPreflight.responses(), AsserhttpError::CorsMismatch { expected: "allow header 'x-other'".to_string(), key: HeaderKey::from("access-control-allow-headers"), actual: "Content-Type, X-Request-Id".to_string() }, .try_expect_cors_allowed_headers(["x-other"]));
+
+    asserhttp_test!(cors_credentials_should_succeed, "cors/preflight.json", CorsPreflight.responses(), .expect_cors_credentials());
+    asserhttp_test!(cors_credentials_should_fail_when_absent, "cors/wildcard.json", CorsWildcard.responses(), "expected one header named 'access-control-allow-credentials' but none found", .expect_cors_credentials());
+    asserhttp_test!(fallible_cors_credentials_should_fail_when_wildcard, "cors/credentials-wildcard.json", CorsCredentialsWildcard.responses(), AsserhttpError::CorsMismatch { expected: "allow credentials".to_string(), key: HeaderKey::from("access-control-allow-origin"), actual: "*".to_string() }, .try_expect_cors_credentials());
+
+    asserhttp_test!(cors_max_age_should_succeed, "cors/preflight.json", CorsPreflight.responses(), .expect_cors_max_age(Duration::from_secs(600)));
+    asserhttp_test!(cors_max_age_should_fail, "cors/preflight.json", CorsPreflight.responses(), "expected header 'access-control-max-age' to be equal to '60' but was '600'", .expect_cors_max_age(Duration::from_secs(60)));
+
+    asserhttp_test!(cors_preflight_ok_should_succeed, "cors/preflight.json", CorsPreflight.responses(), .expect_cors_preflight_ok("https://app.example", "DELETE"));
+    asserhttp_test!(cors_preflight_ok_should_succeed_for_safelisted_method, "cors/preflight.json", CorsPreflight.responses(), .expect_cors_preflight_ok("https://app.example", "POST"));
+    asserhttp_test!(cors_preflight_ok_should_fail_without_vary, "cors/no-vary.json", CorsNoVary.responses(), "expected one header named 'vary' but none found", .expect_cors_preflight_ok("https://app.example", "POST"));
+    asserhttp_test!(cors_preflight_ok_should_fail_when_method_not_allowed, "cors/preflight.json", CorsPreflight.responses(), "expected cors to allow method 'PATCH' but 'access-control-allow-methods' was 'GET, PUT, DELETE'", .expect_cors_preflight_ok("https://app.example", "PATCH"));
+    asserhttp_test!(cors_preflight_ok_should_fail_when_not_success, "status/bad-request.json", StatusBadRequest.responses(), "expected status to be in [200;300[ but was '400'", .expect_cors_preflight_ok("https://app.example", "PUT"));
+    asserhttp_test!(fallible_cors_preflight_ok_should_succeed, "cors/wildcard.json", CorsWildcard.responses(), .try_expect_cors_preflight_ok("https://app.example", "POST").unwrap());
+    asserhttp_test!(fallible_cors_preflight_ok_should_fail_when_not_success, "status/bad-request.json", StatusBadRequest.responses(), AsserhttpError::StatusRangeMismatch { lower: 200, upper: 300, actual: 400 }, .try_expect_cors_preflight_ok("https://app.example", "PUT"));
+}
+
+mod redirect {
+    use super::Stub::*;
+
+    asserhttp_redirect_test!(redirect_should_succeed, "redirect/moved.json", RedirectMoved.responses(), .expect_redirect(|url| { assert_eq!(url.path(), "/home"); assert!(url.query_pairs().any(|(k, v)| k == "lang" && v == "en")); }));
+    asserhttp_redirect_test!(redirect_should_fail_when_relative, "redirect/found.json", RedirectFound.responses(), "'/login?next=%2Fcart' is not a valid url: relative URL without a base", .expect_redirect(|_| {}));
+    asserhttp_redirect_test!(redirect_should_fail_when_not_redirection, "status/ok.json", StatusOk.responses(), "expected status to be in [300;400[ but was '200'", .expect_redirect(|_| {}));
+    asserhttp_redirect_test!(redirect_should_fail_without_location, "status/moved-permanently.json", StatusMovedPermanently.responses(), "expected one header named 'location' but none found", .expect_redirect(|_| {}));
+    asserhttp_redirect_test!(fallible_redirect_should_succeed, "redirect/moved.json", RedirectMoved.responses(), .try_expect_redirect(|url| { assert_eq!(url.host_str(), Some("app.example")); Ok(()) }).unwrap());
+
+    asserhttp_redirect_test!(redirect_with_base_should_resolve_relative_location, "redirect/found.json", RedirectFound.responses(), .expect_redirect_with_base("https://app.example", |url| { assert_eq!(url.as_str(), "https://app.example/login?next=%2Fcart"); assert!(url.query_pairs().any(|(k, v)| k == "next" && v == "/cart")); }));
+    asserhttp_redirect_test!(fallible_redirect_with_base_should_keep_absolute_location, "redirect/moved.json", RedirectMoved.responses(), .try_expect_redirect_with_base("https://other.example", |url| { assert_eq!(url.host_str(), Some("app.example")); Ok(()) }).unwrap());
+
+    asserhttp_redirect_test!(redirect_to_should_succeed, "redirect/found.json", RedirectFound.responses(), .expect_redirect_to("/login?next=%2Fcart"));
+    asserhttp_redirect_test!(redirect_to_should_fail_when_relative_expected_but_absolute, "redirect/moved.json", RedirectMoved.responses(), "expected redirect to '/home?lang=en' but was 'https://app.example/home?lang=en'", .expect_redirect_to("/home?lang=en"));
+    asserhttp_redirect_test!(redirect_to_should_fail_when_cross_host, "redirect/cross-host.json", RedirectCrossHost.responses(), "expected redirect to '/login' but was 'https://evil.example/login'", .expect_redirect_to("/login"));
+    asserhttp_redirect_test!(fallible_redirect_to_should_fail_when_cross_host, "redirect/cross-host.json", RedirectCrossHost.responses(), AsserhttpError::RedirectMismatch { expected: "/login".to_string(), actual: "https://evil.example/login".to_string() }, .try_expect_redirect_to("/login"));
+    asserhttp_redirect_test!(redirect_to_should_succeed_when_relative_to_absolute, "redirect/found.json", RedirectFound.responses(), .expect_redirect_to("https://app.example/login?next=%2Fcart"));
+    asserhttp_redirect_test!(redirect_to_should_fail, "redirect/found.json", RedirectFound.responses(), "expected redirect to '/logout' but was '/login?next=%2Fcart'", .expect_redirect_to("/logout"));
+    asserhttp_redirect_test!(fallible_redirect_to_should_fail_when_other_origin, "redirect/moved.json", RedirectMoved.responses(), AsserhttpError::RedirectMismatch { expected: "https://other.example/home?lang=en".to_string(), actual: "https://app.example/home?lang=en".to_string() }, .try_expect_redirect_to("https://other.example/home?lang=en"));
+
+    asserhttp_redirect_test!(redirect_to_with_base_should_succeed, "redirect/found.json", RedirectFound.responses(), .expect_redirect_to_with_base("https://app.example/login?next=%2Fcart", "https://app.example"));
+    asserhttp_redirect_test!(redirect_to_with_base_should_fail_when_other_origin, "redirect/moved.json", RedirectMoved.responses(), "expected redirect to '/home?lang=en' but was 'https://app.example/home?lang=en'", .expect_redirect_to_with_base("/home?lang=en", "https://other.example"));
+    asserhttp_redirect_test!(fallible_redirect_to_with_base_should_succeed, "redirect/moved.json", RedirectMoved.responses(), .try_expect_redirect_to_with_base("home?lang=en", "https://app.example").unwrap());
+    asserhttp_redirect_test!(redirect_to_with_base_should_fail_when_cross_host, "redirect/cross-host.json", RedirectCrossHost.responses(), "expected redirect to '/login' but was 'https://evil.example/login'", .expect_redirect_to_with_base("/login", "https://app.example"));
+
+    asserhttp_redirect_test!(permanent_redirect_should_succeed, "redirect/moved.json", RedirectMoved.responses(), .expect_permanent_redirect());
+    asserhttp_redirect_test!(permanent_redirect_should_fail, "redirect/found.json", RedirectFound.responses(), "expected status to be one of [301, 308] but was '302'", .expect_permanent_redirect());
+    asserhttp_redirect_test!(fallible_permanent_redirect_should_fail_without_location, "status/moved-permanently.json", StatusMovedPermanently.responses(), AsserhttpError::HeaderAbsent { key: HeaderKey::from("location") }, .try_expect_permanent_redirect());
+
+    asserhttp_redirect_test!(temporary_redirect_should_succeed, "redirect/found.json", RedirectFound.responses(), .expect_temporary_redirect());
+    asserhttp_redirect_test!(temporary_redirect_should_fail, "redirect/moved.json", RedirectMoved.responses(), "expected status to be one of [302, 303, 307] but was '301'", .expect_temporary_redirect());
+    asserhttp_redirect_test!(fallible_temporary_redirect_should_fail, "redirect/moved.json", RedirectMoved.responses(), AsserhttpError::StatusNotOneOf { expected: vec![302, 303, 307], actual: 301 }, .try_expect_temporary_redirect());
+}
+
+mod conditional {
+    use super::Stub::*;
+    use std::time::{Duration, SystemTime};
+
+    asserhttp_test!(etag_should_succeed, "conditional/weak.json", ConditionalWeak.responses(), .expect_etag(|e| { assert!(e.is_weak()); assert_eq!(e.tag(), "0815"); assert_eq!(e.to_string(), "W/\"0815\""); }));
+    asserhttp_test!(etag_should_fail, "conditional/strong.json", ConditionalStrong.responses(), "", .expect_etag(|e| assert!(e.is_weak())));
+    asserhttp_test!(etag_should_fail_when_invalid, "conditional/invalid.json", ConditionalInvalid.responses(), "'33a64df5' is not a valid entity tag", .expect_etag(|_| {}));
+    asserhttp_test!(fallible_etag_should_succeed, "conditional/strong.json", ConditionalStrong.responses(), .try_expect_etag(|e| { assert_eq!(e.tag(), "33a64df5"); Ok(()) }).unwrap());
+    asserhttp_test!(fallible_etag_should_fail_when_absent, "header/one.json", HeaderOne.responses(), AsserhttpError::HeaderAbsent { key: HeaderKey::from("etag") }, .try_expect_etag(|_| Ok(())));
+
+    asserhttp_test!(etag_strong_should_succeed, "conditional/strong.json", ConditionalStrong.responses(), .expect_etag_strong());
+    asserhttp_test!(etag_strong_should_fail, "conditional/weak.json", ConditionalWeak.responses(), "expected a strong entity tag but was 'W/\"0815\"'", .expect_etag_strong());
+    asserhttp_test!(fallible_etag_strong_should_fail, "conditional/weak.json", ConditionalWeak.responses(), AsserhttpError::EntityTagWeak { actual: "W/\"0815\"".to_string() }, .try_expect_etag_strong());
+
+    asserhttp_test!(last_modified_should_succeed, "conditional/weak.json", ConditionalWeak.responses(), .expect_last_modified(|t| assert_eq!(t, SystemTime::UNIX_EPOCH + Duration::from_secs(1_445_412_480))));
+    asserhttp_test!(last_modified_should_fail_when_invalid, "conditional/invalid.json", ConditionalInvalid.responses(), "'yesterday' is not a valid http date", .expect_last_modified(|_| {}));
+    asserhttp_test!(fallible_last_modified_should_succeed, "conditional/weak.json", ConditionalWeak.responses(), .try_expect_last_modified(|t| { assert!(t < SystemTime::now()); Ok(()) }).unwrap());
+    asserhttp_test!(fallible_last_modified_should_fail_when_absent, "conditional/strong.json", ConditionalStrong.responses(), AsserhttpError::HeaderAbsent { key: HeaderKey::from("last-modified") }, .try_expect_last_modified(|_| Ok(())));
+
+    asserhttp_test!(not_modified_should_succeed_comparing_weakly, "conditional/not-modified.json", ConditionalNotModified.responses(), .expect_not_modified([("etag", "\"33a64df5\""), ("Cache-Control", "max-age=60"), ("vary", "Accept-Encoding")]));
+    asserhttp_test!(not_modified_should_succeed_with_last_modified_only, "conditional/not-modified-last-modified.json", ConditionalNotModifiedLastModified.responses(), .expect_not_modified([("last-modified", "Wed, 21 Oct 2015 07:28:00 GMT")]));
+    asserhttp_test!(not_modified_should_fail_when_other_etag, "conditional/not-modified.json", ConditionalNotModified.responses(), "expected entity tag to match '\"0815\"' but was 'W/\"33a64df5\"'", .expect_not_modified([("etag", "\"0815\"")]));
+    asserhttp_test!(not_modified_should_fail_when_caching_header_differs, "conditional/not-modified.json", ConditionalNotModified.responses(), "expected header 'cache-control' to be equal to 'max-age=3600' but was 'max-age=60'", .expect_not_modified([("cache-control", "max-age=3600")]));
+    asserhttp_test!(not_modified_should_fail_when_validator_not_repeated, "conditional/not-modified.json", ConditionalNotModified.responses(), "expected one header named 'last-modified' but none found", .expect_not_modified([("etag", "\"33a64df5\""), ("last-modified", "Wed, 21 Oct 2015 07:28:00 GMT")]));
+    asserhttp_test!(not_modified_should_fail_when_not_304, "conditional/strong.json", ConditionalStrong.responses(), "expected status to be '304' but was '200'", .expect_not_modified([("etag", "\"33a64df5\"")]));
+    asserhttp_test!(fallible_not_modified_should_succeed, "conditional/not-modified.json", ConditionalNotModified.responses(), .try_expect_not_modified([("etag", "W/\"33a64df5\"")]).unwrap());
+    asserhttp_test!(fallible_not_modified_should_fail_when_caching_header_absent, "conditional/not-modified-last-modified.json", ConditionalNotModifiedLastModified.responses(), AsserhttpError::HeaderAbsent { key: HeaderKey::from("expires") }, .try_expect_not_modified([("expires", "Thu, 22 Oct 2015 07:28:00 GMT")]));
+    asserhttp_test!(not_modified_should_fail_when_header_not_repeated, "conditional/not-modified.json", ConditionalNotModified.responses(), "'content-type' is not repeated by a 304 response, only cache-control, content-location, etag, expires, last-modified and vary are", .expect_not_modified([("etag", "\"33a64df5\""), ("content-type", "application/json")]));
+    asserhttp_test!(fallible_not_modified_should_fail_when_header_misspelled, "conditional/not-modified.json", ConditionalNotModified.responses(), AsserhttpError::NotModifiedHeaderUnrepeated { key: HeaderKey::from("e-tag") }, .try_expect_not_modified([("e-tag", "\"33a64df5\"")]));
+    asserhttp_test!(fallible_not_modified_should_fail_when_invalid, "conditional/not-modified.json", ConditionalNotModified.responses(), AsserhttpError::InvalidEntityTag { value: "33a64df5".to_string() }, .try_expect_not_modified([("etag", "33a64df5")]));
+}
+
+mod link {
+    use super::Stub::*;
+
+    asserhttp_test!(link_should_succeed, "link/paginated.json", LinkPaginated.responses(), .expect_link("next", |url| assert_eq!(url.as_str(), "https://api.example/items?page=4&per_page=10")));
+    asserhttp_test!(link_should_succeed_ignoring_case, "link/paginated.json", LinkPaginated.responses(), .expect_link("Last", |url| assert_eq!(url.query(), Some("page=9&per_page=10"))));
+    asserhttp_test!(link_should_succeed_when_relative, "link/last-page.json", LinkLastPage.responses(), .expect_link("prev", |url| { assert_eq!(url.path(), "/items"); assert_eq!(url.query(), Some("page=8")); }));
+    asserhttp_test!(link_should_fail, "link/paginated.json", LinkPaginated.responses(), "", .expect_link("next", |url| assert_eq!(url.query(), Some("page=3"))));
+    asserhttp_test!(link_should_fail_when_absent, "link/last-page.json", LinkLastPage.responses(), "expected a link with rel 'next' but none was present", .expect_link("next", |_| {}));
+    asserhttp_test!(fallible_link_should_succeed, "link/paginated.json", LinkPaginated.responses(), .try_expect_link("first", |url| { assert_eq!(url.query(), Some("page=1&per_page=10")); Ok(()) }).unwrap());
+    asserhttp_test!(fallible_link_should_fail_when_no_header, "header/one.json", HeaderOne.responses(), AsserhttpError::LinkAbsent { rel: "next".to_string() }, .try_expect_link("next", |_| Ok(())));
+    asserhttp_test!(fallible_link_should_fail_when_invalid, "link/invalid.json", LinkInvalid.responses(), AsserhttpError::InvalidLink { value: "https://api.example/items; rel=\"next\"".to_string() }, .try_expect_link("next", |_| Ok(())));
+
+    asserhttp_test!(no_link_should_succeed, "link/last-page.json", LinkLastPage.responses(), .expect_no_link("next"));
+    asserhttp_test!(no_link_should_succeed_when_no_header, "header/one.json", HeaderOne.responses(), .expect_no_link("next"));
+    asserhttp_test!(no_link_should_fail, "link/paginated.json", LinkPaginated.responses(), "expected no link with rel 'next' but found 'https://api.example/items?page=4&per_page=10'", .expect_no_link("next"));
+    asserhttp_test!(fallible_no_link_should_fail, "link/last-page.json", LinkLastPage.responses(), AsserhttpError::LinkPresent { rel: "prev".to_string(), actual: "http://localhost/items?page=8".to_string() }, .try_expect_no_link("prev"));
+
+    asserhttp_test!(links_should_succeed, "link/paginated.json", LinkPaginated.responses(), .expect_links(|links| {
+        assert_eq!(links.len(), 5);
+        let alternate = links.iter().find(|l| l.has_rel("alternate")).unwrap();
+        assert_eq!(alternate.media_type.as_deref(), Some("text/csv"));
+        assert_eq!(alternate.title.as_deref(), Some("Items, as CSV"));
+        assert_eq!(alternate.url.as_str(), "https://api.example/items.csv");
+    }));
+    asserhttp_test!(links_should_fail, "link/last-page.json", LinkLastPage.responses(), "", .expect_links(|links| assert_eq!(links.len(), 3)));
+    asserhttp_test!(fallible_links_should_succeed, "link/last-page.json", LinkLastPage.responses(), .try_expect_links(|links| { assert_eq!(links[0].rel, vec!["first".to_string()]); Ok(()) }).unwrap());
+    asserhttp_test!(fallible_links_should_fail_when_absent, "header/one.json", HeaderOne.responses(), AsserhttpError::HeaderAbsent { key: HeaderKey::from("link") }, .try_expect_links(|_| Ok(())));
+
+    asserhttp_test!(pagination_consistent_should_succeed, "link/paginated.json", LinkPaginated.responses(), .expect_pagination_consistent());
+    asserhttp_test!(pagination_consistent_should_succeed_on_last_page, "link/last-page.json", LinkLastPage.responses(), .expect_pagination_consistent());
+    asserhttp_test!(pagination_consistent_should_fail, "link/inconsistent.json", LinkInconsistent.responses(), "expected pagination links to be consistent but:\n- 'last' link appears 2 times\n- 'last' link '/other?page=9' targets another resource than 'prev' link '/items?page=5'\n- 'prev' link has page=5 which does not come before page=3 of 'next' link", .expect_pagination_consistent());
+    asserhttp_test!(pagination_consistent_should_succeed_when_relative_and_absolute, "link/mixed.json", LinkMixed.responses(), .expect_pagination_consistent());
+    asserhttp_test!(pagination_consistent_should_fail_when_another_origin, "link/cross-origin.json", LinkCrossOrigin.responses(), "expected pagination links to be consistent but:\n- 'last' link 'https://evil.example/items?page=9' targets another resource than 'first' link 'https://api.example/items?page=1'", .expect_pagination_consistent());
+    asserhttp_test!(fallible_pagination_consistent_should_fail_when_no_pagination, "header/one.json", HeaderOne.responses(), AsserhttpError::PaginationInconsistent { violations: vec!["no 'first', 'prev', 'next' nor 'last' link".to_string()] }, .try_expect_pagination_consistent());
+}
+
+mod content_disposition {
+    use super::Stub::*;
+
+    asserhttp_test!(content_disposition_should_succeed, "content-disposition/encoded.json", ContentDispositionEncoded.responses(), .expect_content_disposition(|cd| { assert!(cd.is_attachment()); assert_eq!(cd.filename.as_deref(), Some("naïve file.txt")); assert_eq!(cd.params.get("filename").map(String::as_str), Some("naive file.txt")); }));
+    asserhttp_test!(content_disposition_should_fail, "content-disposition/inline.json", ContentDispositionInline.responses(), "assertion failed: cd.is_attachment()", .expect_content_disposition(|cd| assert!(cd.is_attachment())));
+    asserhttp_test!(fallible_content_disposition_should_succeed, "content-disposition/inline.json", ContentDispositionInline.responses(), .try_expect_content_disposition(|cd| { assert!(cd.is_inline() && cd.filename.is_none()); Ok(()) }).unwrap());
+    asserhttp_test!(fallible_content_disposition_should_fail_when_absent, "header/one.json", HeaderOne.responses(), AsserhttpError::HeaderAbsent { key: HeaderKey::from("content-disposition") }, .try_expect_content_disposition(|_| Ok(())));
+    asserhttp_test!(fallible_content_disposition_should_fall_back_when_unknown_charset, "content-disposition/unknown-charset.json", ContentDispositionUnknownCharset.responses(), .try_expect_content_disposition(|cd| { assert_eq!(cd.filename.as_deref(), Some("report.pdf")); assert_eq!(cd.params.get("filename*").map(String::as_str), Some("KOI8-R''%F2%C1%D0%CF%D2%D4.pdf")); Ok(()) }).unwrap());
+    asserhttp_test!(fallible_content_disposition_should_fail_when_escape_not_hex, "content-disposition/signed-escape.json", ContentDispositionSignedEscape.responses(), AsserhttpError::InvalidContentDisposition { value: "attachment; filename*=UTF-8''report%+f.pdf".to_string() }, .try_expect_content_disposition(|_| Ok(())));
+    asserhttp_test!(fallible_content_disposition_should_fail_when_invalid, "content-disposition/invalid.json", ContentDispositionInvalid.responses(), AsserhttpError::InvalidContentDisposition { value: "attachment; filename*=UTF-8''%E2%82".to_string() }, .try_expect_content_disposition(|_| Ok(())));
+
+    asserhttp_test!(attachment_should_succeed, "content-disposition/attachment.json", ContentDispositionAttachment.responses(), .expect_attachment("report.pdf"));
+    asserhttp_test!(attachment_should_succeed_when_token, "content-disposition/token.json", ContentDispositionToken.responses(), .expect_attachment("report.pdf"));
+    asserhttp_test!(attachment_should_succeed_preferring_encoded, "content-disposition/encoded.json", ContentDispositionEncoded.responses(), .expect_attachment("naïve file.txt"));
+    asserhttp_test!(attachment_should_succeed_falling_back_when_unknown_charset, "content-disposition/unknown-charset.json", ContentDispositionUnknownCharset.responses(), .expect_attachment("report.pdf"));
+    asserhttp_test!(attachment_should_fail_when_other_name, "content-disposition/attachment.json", ContentDispositionAttachment.responses(), "expected content-disposition to be an attachment named 'invoice.pdf' but was 'attachment; filename=\"report.pdf\"'", .expect_attachment("invoice.pdf"));
+    asserhttp_test!(attachment_should_fail_when_inline, "content-disposition/inline.json", ContentDispositionInline.responses(), "expected content-disposition to be an attachment named 'report.pdf' but was 'inline'", .expect_attachment("report.pdf"));
+    asserhttp_test!(fallible_attachment_should_succeed, "content-disposition/token.json", ContentDispositionToken.responses(), .try_expect_attachment("report.pdf").unwrap());
+    asserhttp_test!(fallible_attachment_should_fail, "content-disposition/encoded.json", ContentDispositionEncoded.responses(), AsserhttpError::ContentDispositionMismatch { expected: "an attachment named 'naive file.txt'".to_string(), actual: "attachment; filename=\"naive file.txt\"; filename*=UTF-8''na%C3%AFve%20file.txt".to_string() }, .try_expect_attachment("naive file.txt"));
+
+    asserhttp_test!(inline_should_succeed, "content-disposition/inline.json", ContentDispositionInline.responses(), .expect_inline());
+    asserhttp_test!(inline_should_fail, "content-disposition/attachment.json", ContentDispositionAttachment.responses(), "expected content-disposition to be inline but was 'attachment; filename=\"report.pdf\"'", .expect_inline());
+    asserhttp_test!(fallible_inline_should_fail_when_absent, "header/one.json", HeaderOne.responses(), AsserhttpError::HeaderAbsent { key: HeaderKey::from("content-disposition") }, .try_expect_inline());
+}
+
+mod range {
+    use super::Stub::*;
+
+    asserhttp_test!(content_range_should_succeed, "range/partial.json", RangePartial.responses(), .expect_content_range(0, 4, 26));
+    asserhttp_test!(content_range_should_succeed_when_length_unknown, "range/unknown-length.json", RangeUnknownLength.responses(), .expect_content_range(5, 9, None));
+    asserhttp_test!(content_range_should_fail, "range/partial.json", RangePartial.responses(), "expected content-range to be 'bytes 0-4/27' but was 'bytes 0-4/26'", .expect_content_range(0, 4, 27));
+    asserhttp_test!(fallible_content_range_should_succeed, "range/partial.json", RangePartial.responses(), .try_expect_content_range(0, 4, Some(26)).unwrap());
+    asserhttp_test!(fallible_content_range_should_fail, "range/unknown-length.json", RangeUnknownLength.responses(), AsserhttpError::ContentRangeMismatch { expected: "bytes 5-9/10".to_string(), actual: "bytes 5-9/*".to_string() }, .try_expect_content_range(5, 9, 10));
+    asserhttp_test!(fallible_content_range_should_fail_when_absent, "header/one.json", HeaderOne.responses(), AsserhttpError::HeaderAbsent { key: HeaderKey::from("content-range") }, .try_expect_content_range(0, 4, 26));
+
+    asserhttp_test!(accept_ranges_bytes_should_succeed, "range/partial.json", RangePartial.responses(), .expect_accept_ranges_bytes());
+    asserhttp_test!(accept_ranges_bytes_should_fail, "range/unknown-length.json", RangeUnknownLength.responses(), "expected header 'accept-ranges' to be equal to 'bytes' but was 'none'", .expect_accept_ranges_bytes());
+    asserhttp_test!(fallible_accept_ranges_bytes_should_fail_when_absent, "header/one.json", HeaderOne.responses(), AsserhttpError::HeaderAbsent { key: HeaderKey::from("accept-ranges") }, .try_expect_accept_ranges_bytes());
+
+    asserhttp_test!(range_consistent_should_succeed, "range/partial.json", RangePartial.responses(), .expect_status_partial_content().expect_range_consistent());
+    asserhttp_test!(range_consistent_should_succeed_when_length_unknown, "range/unknown-length.json", RangeUnknownLength.responses(), .expect_range_consistent());
+    asserhttp_test!(range_consistent_should_succeed_when_multipart, "range/multipart.json", RangeMultipart.responses(), .expect_range_consistent());
+    asserhttp_test!(range_consistent_should_succeed_when_boundary_within_content, "range/multipart-boundary-in-content.json", RangeMultipartBoundaryInContent.responses(), .expect_range_consistent());
+    asserhttp_test!(range_consistent_should_succeed_when_not_satisfiable, "range/not-satisfiable.json", RangeNotSatisfiable.responses(), .expect_status(416).expect_range_consistent());
+    asserhttp_test!(range_consistent_should_fail, "range/inconsistent.json", RangeInconsistent.responses(), "expected range response to be consistent but:\n- 'bytes 0-9/5' ends beyond the complete length 5\n- 'bytes 0-9/5' spans 10 bytes but the content has 5", .expect_range_consistent());
+    asserhttp_test!(range_consistent_should_fail_when_multipart, "range/multipart-inconsistent.json", RangeMultipartInconsistent.responses(), "expected range response to be consistent but:\n- part 0: 'bytes 0-4/26' spans 5 bytes but the content has 3\n- part 1 has no 'content-range'", .expect_range_consistent());
+    asserhttp_test!(range_consistent_should_fail_when_unsatisfied_partial_content, "range/unsatisfied.json", RangeUnsatisfied.responses(), "expected range response to be consistent but:\n- 'bytes */26' has no satisfied range", .expect_range_consistent());
+    asserhttp_test!(fallible_range_consistent_should_succeed, "range/multipart.json", RangeMultipart.responses(), .try_expect_range_consistent().unwrap());
+    asserhttp_test!(fallible_range_consistent_should_fail_when_absent, "header/one.json", HeaderOne.responses(), AsserhttpError::HeaderAbsent { key: HeaderKey::from("content-range") }, .try_expect_range_consistent());
+
+    // only the clients failing to read a body cut short can report it: awc accepts it as is whereas async reqwest and
+    // hyper keep waiting for its end
+    fn assert_read_failure<T>(result: Result<T, asserhttp::AsserhttpError>) {
+        match result {
+            Err(asserhttp::AsserhttpError::RangeInconsistent { violations }) => panic!("expected a read failure but got {violations:?}"),
+            Err(_) => {},
+            Ok(_) => panic!("expected a read failure"),
+        }
+    }
+
+    #[test]
+    fn reqwest_blocking_fallible_range_consistent_should_fail_when_body_truncated() {
+        use asserhttp::*;
+        assert_read_failure(
+            reqwest::blocking::get(crate::raw_stub("range/truncated.http"))
+                .unwrap()
+                .try_expect_range_consistent(),
+        );
+    }
+
+    #[test]
+    fn isahc_blocking_fallible_range_consistent_should_fail_when_body_truncated() {
+        use asserhttp::*;
+        assert_read_failure(
+            isahc::get(crate::raw_stub("range/truncated.http"))
+                .unwrap()
+                .try_expect_range_consistent(),
+        );
+    }
+
+    #[tokio::test]
+    async fn isahc_async_fallible_range_consistent_should_fail_when_body_truncated() {
+        use asserhttp::*;
+        assert_read_failure(
+            isahc::get_async(crate::raw_stub("range/truncated.http"))
+                .await
+                .unwrap()
+                .try_expect_range_consistent(),
+        );
+    }
+
+    #[tokio::test]
+    async fn surf_fallible_range_consistent_should_fail_when_body_truncated() {
+        use asserhttp::*;
+        assert_read_failure(
+            surf::get(crate::raw_stub("range/truncated.http"))
+                .await
+                .unwrap()
+                .try_expect_range_consistent(),
+        );
+    }
+
+    #[test]
+    fn ureq_fallible_range_consistent_should_fail_when_body_truncated() {
+        use asserhttp::*;
+        assert_read_failure(
+            ureq::get(&crate::raw_stub("range/truncated.http"))
+                .call()
+                .unwrap()
+                .try_expect_range_consistent(),
+        );
+    }
+}
+
+mod rate_limit {
+    use super::Stub::*;
+    use std::time::Duration;
+
+    asserhttp_test!(rate_limit_should_succeed_when_combined, "rate-limit/combined.json", RateLimitCombined.responses(), .expect_rate_limit(|rl| assert_eq!(rl, RateLimit { limit: 100, remaining: 50, reset: Some(Duration::from_secs(30)), window: None })));
+    asserhttp_test!(rate_limit_should_succeed_when_structured, "rate-limit/structured.json", RateLimitStructured.responses(), .expect_rate_limit(|rl| assert_eq!(rl, RateLimit { limit: 100, remaining: 0, reset: Some(Duration::from_secs(30)), window: Some(Duration::from_secs(60)) })));
+    asserhttp_test!(rate_limit_should_succeed_when_separate, "rate-limit/separate.json", RateLimitSeparate.responses(), .expect_rate_limit(|rl| assert!(rl.remaining < rl.limit && rl.reset == Some(Duration::from_secs(60)))));
+    asserhttp_test!(rate_limit_should_succeed_when_legacy_timestamp, "rate-limit/legacy.json", RateLimitLegacy.responses(), .expect_rate_limit(|rl| { assert_eq!((rl.limit, rl.remaining), (5000, 4999)); assert!(rl.reset.unwrap() > Duration::from_secs(365 * 24 * 3600)); }));
+    asserhttp_test!(rate_limit_should_succeed_when_several_policies, "rate-limit/multiple.json", RateLimitMultiple.responses(), .expect_rate_limit(|rl| assert_eq!(rl, RateLimit { limit: 10, remaining: 5, reset: Some(Duration::from_secs(1)), window: Some(Duration::from_secs(1)) })));
+    asserhttp_test!(rate_limit_should_succeed_when_several_unnamed_policies, "rate-limit/policies.json", RateLimitPolicies.responses(), .expect_rate_limit(|rl| assert_eq!(rl, RateLimit { limit: 100, remaining: 50, reset: Some(Duration::from_secs(30)), window: None })));
+    asserhttp_test!(rate_limit_should_fail, "rate-limit/combined.json", RateLimitCombined.responses(), "assertion `left == right` failed\n  left: 50\n right: 0", .expect_rate_limit(|rl| assert_eq!(rl.remaining, 0)));
+    asserhttp_test!(rate_limit_should_fail_when_invalid, "rate-limit/invalid.json", RateLimitInvalid.responses(), "'x-ratelimit-limit' has an invalid rate limit value 'many'", .expect_rate_limit(|_| {}));
+    asserhttp_test!(rate_limit_should_fail_when_reset_elapsed, "rate-limit/elapsed.json", RateLimitElapsed.responses(), "'x-ratelimit-reset' resets at the Unix timestamp 1500000000 which has already elapsed", .expect_rate_limit(|_| {}));
+    asserhttp_test!(fallible_rate_limit_should_succeed, "rate-limit/separate.json", RateLimitSeparate.responses(), .try_expect_rate_limit(|rl| { assert_eq!(rl.limit, 100); Ok(()) }).unwrap());
+    asserhttp_test!(fallible_rate_limit_should_fail_when_absent, "header/one.json", HeaderOne.responses(), AsserhttpError::RateLimitAbsent { field: "limit".to_string() }, .try_expect_rate_limit(|_| Ok(())));
+    asserhttp_test!(fallible_rate_limit_should_fail_when_reset_elapsed, "rate-limit/elapsed.json", RateLimitElapsed.responses(), AsserhttpError::RateLimitResetElapsed { key: HeaderKey::from("x-ratelimit-reset"), timestamp: 1_500_000_000 }, .try_expect_rate_limit(|_| Ok(())));
+    asserhttp_test!(rate_limit_should_fail_when_policy_unknown, "rate-limit/unknown-policy.json", RateLimitUnknownPolicy.responses(), "expected rate limit headers to advertise the limit but none found", .expect_rate_limit(|_| {}));
+    asserhttp_test!(fallible_rate_limit_should_fail_when_policy_unknown, "rate-limit/unknown-policy.json", RateLimitUnknownPolicy.responses(), AsserhttpError::RateLimitAbsent { field: "limit".to_string() }, .try_expect_rate_limit(|_| Ok(())));
+    asserhttp_test!(fallible_rate_limit_should_fail_when_invalid, "rate-limit/invalid.json", RateLimitInvalid.responses(), AsserhttpError::InvalidRateLimit { key: HeaderKey::from("x-ratelimit-limit"), value: "many".to_string() }, .try_expect_rate_limit(|_| Ok(())));
+
+    asserhttp_test!(rate_limited_should_succeed, "rate-limit/limited.json", RateLimitLimited.responses(), .expect_rate_limited());
+    asserhttp_test!(rate_limited_should_fail_when_not_429, "rate-limit/combined.json", RateLimitCombined.responses(), "expected status to be '429' but was '200'", .expect_rate_limited());
+    asserhttp_test!(rate_limited_should_fail_without_retry_after, "rate-limit/exhausted.json", RateLimitExhausted.responses(), "expected one header named 'retry-after' but none found", .expect_rate_limited());
+    asserhttp_test!(fallible_rate_limited_should_succeed, "rate-limit/limited.json", RateLimitLimited.responses(), .try_expect_rate_limited().unwrap().expect_rate_limit(|rl| assert_eq!(rl.remaining, 0)));
+    asserhttp_test!(fallible_rate_limited_should_fail_without_retry_after, "rate-limit/exhausted.json", RateLimitExhausted.responses(), AsserhttpError::HeaderAbsent { key: HeaderKey::from("retry-after") }, .try_expect_rate_limited());
+}
+
 mod body {
     use serde_json::{json, Value};
 
@@ -469,6 +1890,9 @@ mod body {
     asserhttp_test!(fallible_body_json_should_succeed, "body/json/value.json", BodyJson.responses(), .try_expect_body_json(|b: Value| { assert_eq!(b, json!({"a": "b"})); Ok(()) }).unwrap());
     asserhttp_test!(fallible_body_json_struct_should_succeed, "body/json/value.json", BodyJson.responses(), .try_expect_body_json(|b: TestBody| { assert_eq!(b, TestBody { a: String::from("b") }); Ok(()) }).unwrap());
     asserhttp_test!(fallible_body_json_should_fail_when_closure_fails, "body/json/value.json", BodyJson.responses(), "", .try_expect_body_json(|b: Value| { assert_eq!(b, json!({"a": "c"})); Ok(()) }).unwrap());
+    asserhttp_test!(body_json_struct_should_fail_when_field_invalid, "body/json/items.json", BodyJsonItems.responses(), "failed to deserialize json body at items[1].price: invalid type: string \"12\", expected u32\n{\n  \"name\": \"pear\",\n  \"price\": \"12\"\n}", .expect_body_json(|_: TestOrder| {}));
+    asserhttp_test!(fallible_body_json_struct_should_fail_when_field_invalid, "body/json/items.json", BodyJsonItems.responses(), AsserhttpError::JsonBodyDeserialization { path: "items[1].price".to_string(), message: "invalid type: string \"12\", expected u32".to_string(), excerpt: "{\n  \"name\": \"pear\",\n  \"price\": \"12\"\n}".to_string() }, .try_expect_body_json(|_: TestOrder| Ok(())));
+    asserhttp_test!(fallible_body_json_struct_should_fail_when_field_missing, "body/json/value.json", BodyJson.responses(), AsserhttpError::JsonBodyDeserialization { path: ".".to_string(), message: "missing field `items`".to_string(), excerpt: "{\n  \"a\": \"b\"\n}".to_string() }, .try_expect_body_json(|_: TestOrder| Ok(())));
 
     asserhttp_test!(body_json_eq_should_succeed, "body/json/value.json", BodyJson.responses(), .expect_body_json_eq(json!({"a": "b"})));
     asserhttp_test!(body_json_struct_eq_should_succeed, "body/json/value.json", BodyJson.responses(), .expect_body_json_eq(TestBody { a: String::from("b") }));
@@ -480,6 +1904,14 @@ mod body {
     asserhttp_test!(fallible_body_json_eq_should_fail_when_not_eq, "body/json/value.json", BodyJson.responses(), AsserhttpError::JsonBodyMismatch("json atoms at path \".a\" are not equal:\n    lhs:\n        \"b\"\n    rhs:\n        \"c\"".to_string()), .try_expect_body_json_eq(json!({"a": "c"})));
     asserhttp_test!(fallible_body_json_eq_should_fail_when_absent, "body/json/absent.json", BodyJsonAbsent.responses(), AsserhttpError::BodyAbsent, .try_expect_body_json_eq(json!({"a": "b"})));
 
+    asserhttp_test!(body_json_eq_file_should_succeed, "body/json/value.json", BodyJson.responses(), .expect_body_json_eq_file("tests/fixtures/body/value.json"));
+    asserhttp_test!(body_json_eq_file_should_fail_when_not_eq, "body/json/value.json", BodyJson.responses(), "json atoms at path \".a\" are not equal:\n    lhs:\n        \"b\"\n    rhs:\n        \"c\"", .expect_body_json_eq_file(negative_fixture("other.json", r#"{"a": "c"}"#)));
+    asserhttp_test!(body_json_eq_file_should_fail_when_fixture_missing, "body/json/value.json", BodyJson.responses(), "missing.json': No such file or directory", .expect_body_json_eq_file(missing_fixture("missing.json")));
+
+    asserhttp_test!(fallible_body_json_eq_file_should_succeed, "body/json/value.json", BodyJson.responses(), .try_expect_body_json_eq_file("tests/fixtures/body/value.json").unwrap());
+    asserhttp_test!(fallible_body_json_eq_file_should_fail_when_fixture_invalid, "body/json/value.json", BodyJson.responses(), AsserhttpError::FixtureRead { path: negative_fixture("invalid.json", "not json").display().to_string(), reason: "expected ident at line 1 column 2".to_string() }, .try_expect_body_json_eq_file(negative_fixture("invalid.json", "not json")));
+    asserhttp_test!(fallible_body_json_eq_file_should_fail_when_absent, "body/json/absent.json", BodyJsonAbsent.responses(), AsserhttpError::BodyAbsent, .try_expect_body_json_eq_file("tests/fixtures/body/value.json"));
+
     // text
     asserhttp_test!(body_text_should_succeed, "body/text/value.json", BodyText.responses(), .expect_body_text(|b| assert_eq!(b, String::from("abcd"))));
     asserhttp_test!(body_text_should_fail_when_closure_fails, "body/text/value.json", BodyText.responses(), "", .expect_body_text(|b| assert_eq!(b, String::from("dcba"))));
@@ -495,6 +1927,14 @@ mod body {
     asserhttp_test!(fallible_body_text_eq_should_fail_when_not_eq, "body/text/value.json", BodyText.responses(), AsserhttpError::TextBodyMismatch { actual: "abcd".to_string(), expected: "dcba".to_string() }, .try_expect_body_text_eq("dcba"));
     asserhttp_test!(fallible_body_text_eq_should_fail_when_absent, "body/text/absent.json", BodyTextAbsent.responses(), AsserhttpError::BodyAbsent, .try_expect_body_text_eq("abcd"));
 
+    asserhttp_test!(body_text_eq_file_should_succeed, "body/text/value.json", BodyText.responses(), .expect_body_text_eq_file("tests/fixtures/body/value.txt"));
+    asserhttp_test!(body_text_eq_file_should_fail_when_not_eq, "body/text/value.json", BodyText.responses(), "expected body to be 'dcba' but was 'abcd'", .expect_body_text_eq_file(negative_fixture("other.txt", "dcba")));
+    asserhttp_test!(body_text_eq_file_should_fail_when_fixture_missing, "body/text/value.json", BodyText.responses(), "missing.txt': No such file or directory", .expect_body_text_eq_file(missing_fixture("missing.txt")));
+
+    asserhttp_test!(fallible_body_text_eq_file_should_succeed, "body/text/value.json", BodyText.responses(), .try_expect_body_text_eq_file("tests/fixtures/body/value.txt").unwrap());
+    asserhttp_test!(fallible_body_text_eq_file_should_fail_when_not_eq, "body/text/value.json", BodyText.responses(), AsserhttpError::TextBodyMismatch { actual: "abcd".to_string(), expected: "dcba".to_string() }, .try_expect_body_text_eq_file(negative_fixture("other.txt", "dcba")));
+    asserhttp_test!(fallible_body_text_eq_file_should_fail_when_absent, "body/text/absent.json", BodyTextAbsent.responses(), AsserhttpError::BodyAbsent, .try_expect_body_text_eq_file("tests/fixtures/body/value.txt"));
+
     // regex
     asserhttp_test!(body_text_regex_should_succeed, "body/text/value.json", BodyText.responses(), .expect_body_text_matches("[a-d]+"));
     asserhttp_test!(body_text_regex_should_fail_when_does_not_match, "body/text/value.json", BodyText.responses(), "expected body to match regex '[e-h]+' but was 'abcd'", .expect_body_text_matches("[e-h]+"));
@@ -504,6 +1944,42 @@ mod body {
     asserhttp_test!(fallible_body_text_regex_should_fail_when_does_not_match, "body/text/value.json", BodyText.responses(), AsserhttpError::RegexBodyMismatch { actual: "abcd".to_string(), regex: "[e-h]+".to_string() }, .try_expect_body_text_matches("[e-h]+"));
     asserhttp_test!(fallible_body_text_regex_should_fail_when_absent, "body/text/absent.json", BodyTextAbsent.responses(), AsserhttpError::BodyAbsent, .try_expect_body_text_matches("[a-d]+"));
 
+    // contains
+    asserhttp_test!(body_text_contains_should_succeed, "body/text/value.json", BodyText.responses(), .expect_body_text_contains("bc"));
+    asserhttp_test!(body_text_contains_should_fail_when_missing, "body/text/value.json", BodyText.responses(), "expected body to contain 'cb' but was 'abcd'", .expect_body_text_contains("cb"));
+    asserhttp_test!(body_text_contains_should_fail_when_absent, "body/text/absent.json", BodyTextAbsent.responses(), "expected a response body but none was present", .expect_body_text_contains("bc"));
+
+    asserhttp_test!(fallible_body_text_contains_should_succeed, "body/text/value.json", BodyText.responses(), .try_expect_body_text_contains("bc").unwrap());
+    asserhttp_test!(fallible_body_text_contains_should_fail_when_missing, "body/text/value.json", BodyText.responses(), AsserhttpError::TextBodyNotContaining { actual: "abcd".to_string(), expected: "cb".to_string() }, .try_expect_body_text_contains("cb"));
+    asserhttp_test!(fallible_body_text_contains_should_fail_when_absent, "body/text/absent.json", BodyTextAbsent.responses(), AsserhttpError::BodyAbsent, .try_expect_body_text_contains("bc"));
+
+    // not contains
+    asserhttp_test!(body_text_not_contains_should_succeed, "body/text/value.json", BodyText.responses(), .expect_body_text_not_contains("cb"));
+    asserhttp_test!(body_text_not_contains_should_fail_when_present, "body/text/value.json", BodyText.responses(), "expected body not to contain 'bc' but found it in 'abcd'", .expect_body_text_not_contains("bc"));
+    asserhttp_test!(body_text_not_contains_should_fail_when_absent, "body/text/absent.json", BodyTextAbsent.responses(), "expected a response body but none was present", .expect_body_text_not_contains("bc"));
+
+    asserhttp_test!(fallible_body_text_not_contains_should_succeed, "body/text/value.json", BodyText.responses(), .try_expect_body_text_not_contains("cb").unwrap());
+    asserhttp_test!(fallible_body_text_not_contains_should_fail_when_present, "body/text/value.json", BodyText.responses(), AsserhttpError::TextBodyContaining { actual: "abcd".to_string(), unexpected: "bc".to_string() }, .try_expect_body_text_not_contains("bc"));
+    asserhttp_test!(fallible_body_text_not_contains_should_fail_when_absent, "body/text/absent.json", BodyTextAbsent.responses(), AsserhttpError::BodyAbsent, .try_expect_body_text_not_contains("bc"));
+
+    // starts with
+    asserhttp_test!(body_text_starts_with_should_succeed, "body/text/value.json", BodyText.responses(), .expect_body_text_starts_with("ab"));
+    asserhttp_test!(body_text_starts_with_should_fail_when_not_prefix, "body/text/value.json", BodyText.responses(), "expected body to start with 'cd' but was 'abcd'", .expect_body_text_starts_with("cd"));
+    asserhttp_test!(body_text_starts_with_should_fail_when_absent, "body/text/absent.json", BodyTextAbsent.responses(), "expected a response body but none was present", .expect_body_text_starts_with("ab"));
+
+    asserhttp_test!(fallible_body_text_starts_with_should_succeed, "body/text/value.json", BodyText.responses(), .try_expect_body_text_starts_with("ab").unwrap());
+    asserhttp_test!(fallible_body_text_starts_with_should_fail_when_not_prefix, "body/text/value.json", BodyText.responses(), AsserhttpError::TextBodyPrefixMismatch { actual: "abcd".to_string(), prefix: "cd".to_string() }, .try_expect_body_text_starts_with("cd"));
+    asserhttp_test!(fallible_body_text_starts_with_should_fail_when_absent, "body/text/absent.json", BodyTextAbsent.responses(), AsserhttpError::BodyAbsent, .try_expect_body_text_starts_with("ab"));
+
+    // ends with
+    asserhttp_test!(body_text_ends_with_should_succeed, "body/text/value.json", BodyText.responses(), .expect_body_text_ends_with("cd"));
+    asserhttp_test!(body_text_ends_with_should_fail_when_not_suffix, "body/text/value.json", BodyText.responses(), "expected body to end with 'ab' but was 'abcd'", .expect_body_text_ends_with("ab"));
+    asserhttp_test!(body_text_ends_with_should_fail_when_absent, "body/text/absent.json", BodyTextAbsent.responses(), "expected a response body but none was present", .expect_body_text_ends_with("cd"));
+
+    asserhttp_test!(fallible_body_text_ends_with_should_succeed, "body/text/value.json", BodyText.responses(), .try_expect_body_text_ends_with("cd").unwrap());
+    asserhttp_test!(fallible_body_text_ends_with_should_fail_when_not_suffix, "body/text/value.json", BodyText.responses(), AsserhttpError::TextBodySuffixMismatch { actual: "abcd".to_string(), suffix: "ab".to_string() }, .try_expect_body_text_ends_with("ab"));
+    asserhttp_test!(fallible_body_text_ends_with_should_fail_when_absent, "body/text/absent.json", BodyTextAbsent.responses(), AsserhttpError::BodyAbsent, .try_expect_body_text_ends_with("cd"));
+
     // bytes
     asserhttp_test!(body_bytes_should_succeed, "body/bytes/value.json", BodyBytes.responses(), .expect_body_bytes(|b| assert_eq!(b, b"abcd")));
     asserhttp_test!(body_bytes_should_fail_when_closure_fails, "body/bytes/value.json", BodyBytes.responses(), "", .expect_body_bytes(|b| assert_eq!(b, b"dcba")));
@@ -512,7 +1988,9 @@ mod body {
     asserhttp_test!(fallible_body_bytes_should_fail_when_closure_fails, "body/bytes/value.json", BodyBytes.responses(), "", .try_expect_body_bytes(|b| { assert_eq!(b, b"dcba"); Ok(()) } ).unwrap());
 
     asserhttp_test!(body_bytes_eq_should_succeed, "body/bytes/value.json", BodyBytes.responses(), .expect_body_bytes_eq(b"abcd"));
-    asserhttp_test!(body_bytes_eq_should_fail_when_not_eq, "body/bytes/value.json", BodyBytes.responses(), "expected body to be '[100, 99, 98, 97]' but was '[97, 98, 99, 100]'", .expect_body_bytes_eq(b"dcba"));
+    asserhttp_test!(body_bytes_eq_should_fail_when_not_eq, "body/bytes/value.json", BodyBytes.responses(), "expected body bytes to be equal but they differ at offset 0 (actual length: 4, expected length: 4)", .expect_body_bytes_eq(b"dcba"));
+    asserhttp_test!(body_bytes_eq_should_fail_when_longer, "body/bytes/value.json", BodyBytes.responses(), "expected body bytes to be equal but they differ at offset 4 (actual length: 4, expected length: 5)", .expect_body_bytes_eq(b"abcde"));
+    asserhttp_test!(body_bytes_eq_should_fail_with_hexdump, "body/bytes/binary.json", BodyBytesBinary.responses(), "they differ at offset 19 (actual length: 32, expected length: 32)\noffset     actual                                                              expected\n00000000   47 45 54 20 2f 69 6e 64 65 78 2e 68 74 6d 6c 0d |GET /index.html.|  47 45 54 20 2f 69 6e 64 65 78 2e 68 74 6d 6c 0d |GET /index.html.|\n00000010 > 0a 00 01 02 7f 48 6f 73 74 3a 20 61 09 62 ff fe |.....Host: a.b..|  0a 00 01 03 7f 48 6f 73 74 3a 20 61 09 62 ff fe |.....Host: a.b..|\n                    ^^                                                                  ^^", .expect_body_bytes_eq(b"GET /index.html\r\n\x00\x01\x03\x7fHost: a\tb\xff\xfe"));
     asserhttp_test!(body_bytes_eq_should_fail_when_absent, "body/bytes/absent.json", BodyBytesAbsent.responses(), "expected a response body but none was present", .expect_body_bytes_eq(b"abcd"));
 
     asserhttp_test!(fallible_body_bytes_eq_should_succeed, "body/bytes/value.json", BodyBytes.responses(), .try_expect_body_bytes_eq(b"abcd").unwrap());
@@ -536,6 +2014,301 @@ mod body {
     asserhttp_test!(expect_body_first_should_not_be_destructive, "full.json", Full.responses(), .expect_body_json_eq(json!({"a": "b"})).expect_status_ok().expect_content_type_json());
 }
 
+mod problem {
+    use super::Stub::*;
+
+    asserhttp_test!(problem_should_succeed, "problem/out-of-credit.json", ProblemOutOfCredit.responses(), .expect_problem(|p| assert_eq!(p.status, Some(403))));
+    asserhttp_test!(problem_should_expose_extensions, "problem/out-of-credit.json", ProblemOutOfCredit.responses(), .expect_problem(|p| assert_eq!(p.extensions.get("balance"), Some(&serde_json::json!(30)))));
+    asserhttp_test!(problem_should_default_type_to_about_blank, "problem/blank.json", ProblemBlank.responses(), .expect_problem(|p| assert_eq!(p.problem_type, ProblemDetails::ABOUT_BLANK)));
+    asserhttp_test!(problem_should_fail_when_closure_fails, "problem/out-of-credit.json", ProblemOutOfCredit.responses(), "", .expect_problem(|p| assert_eq!(p.status, Some(404))));
+    asserhttp_test!(problem_should_fail_when_absent, "body/json/absent.json", BodyJsonAbsent.responses(), "expected a response body but none was present", .expect_problem(|_| {}));
+
+    asserhttp_test!(fallible_problem_should_succeed, "problem/out-of-credit.json", ProblemOutOfCredit.responses(), .try_expect_problem(|p| { assert_eq!(p.status, Some(403)); Ok(()) }).unwrap());
+    asserhttp_test!(fallible_problem_should_fail_when_absent, "body/json/absent.json", BodyJsonAbsent.responses(), AsserhttpError::BodyAbsent, .try_expect_problem(|_| Ok(())));
+
+    asserhttp_test!(problem_type_should_succeed, "problem/out-of-credit.json", ProblemOutOfCredit.responses(), .expect_problem_type("https://example.com/probs/out-of-credit"));
+    asserhttp_test!(problem_type_should_succeed_when_about_blank, "problem/blank.json", ProblemBlank.responses(), .expect_problem_type("about:blank"));
+    asserhttp_test!(problem_type_should_fail_when_not_eq, "problem/out-of-credit.json", ProblemOutOfCredit.responses(), "expected problem type to be 'about:blank' but was 'https://example.com/probs/out-of-credit'", .expect_problem_type("about:blank"));
+    asserhttp_test!(problem_type_should_fail_when_status_inconsistent, "problem/inconsistent.json", ProblemInconsistent.responses(), "expected problem 'status' member to be the response status '400' but was '403'", .expect_problem_type("https://example.com/probs/out-of-credit"));
+
+    asserhttp_test!(fallible_problem_type_should_succeed, "problem/out-of-credit.json", ProblemOutOfCredit.responses(), .try_expect_problem_type("https://example.com/probs/out-of-credit").unwrap());
+    asserhttp_test!(fallible_problem_type_should_fail_when_not_eq, "problem/out-of-credit.json", ProblemOutOfCredit.responses(), AsserhttpError::ProblemTypeMismatch { actual: "https://example.com/probs/out-of-credit".to_string(), expected: "about:blank".to_string() }, .try_expect_problem_type("about:blank"));
+    asserhttp_test!(fallible_problem_type_should_fail_when_status_inconsistent, "problem/inconsistent.json", ProblemInconsistent.responses(), AsserhttpError::ProblemStatusMismatch { status: 400, member: 403 }, .try_expect_problem_type("https://example.com/probs/out-of-credit"));
+
+    asserhttp_test!(problem_title_should_succeed, "problem/out-of-credit.json", ProblemOutOfCredit.responses(), .expect_problem_title("You do not have enough credit."));
+    asserhttp_test!(problem_title_should_fail_when_not_eq, "problem/out-of-credit.json", ProblemOutOfCredit.responses(), "expected problem title to be 'Not Found' but was 'You do not have enough credit.'", .expect_problem_title("Not Found"));
+    asserhttp_test!(problem_title_should_fail_when_missing, "problem/blank.json", ProblemBlank.responses(), "expected problem member 'title' but none found", .expect_problem_title("Not Found"));
+    asserhttp_test!(problem_title_should_fail_when_status_inconsistent, "problem/inconsistent.json", ProblemInconsistent.responses(), "expected problem 'status' member to be the response status '400' but was '403'", .expect_problem_title("You do not have enough credit."));
+
+    asserhttp_test!(fallible_problem_title_should_succeed, "problem/out-of-credit.json", ProblemOutOfCredit.responses(), .try_expect_problem_title("You do not have enough credit.").unwrap());
+    asserhttp_test!(fallible_problem_title_should_fail_when_not_eq, "problem/out-of-credit.json", ProblemOutOfCredit.responses(), AsserhttpError::ProblemTitleMismatch { actual: "You do not have enough credit.".to_string(), expected: "Not Found".to_string() }, .try_expect_problem_title("Not Found"));
+    asserhttp_test!(fallible_problem_title_should_fail_when_missing, "problem/blank.json", ProblemBlank.responses(), AsserhttpError::ProblemMemberAbsent { member: "title".to_string() }, .try_expect_problem_title("Not Found"));
+
+    asserhttp_test!(problem_detail_should_succeed, "problem/out-of-credit.json", ProblemOutOfCredit.responses(), .expect_problem_detail_matches("balance is [0-9]+"));
+    asserhttp_test!(problem_detail_should_fail_when_not_matching, "problem/out-of-credit.json", ProblemOutOfCredit.responses(), "expected problem detail to match regex '^[0-9]+$' but was 'Your current balance is 30, but that costs 50.'", .expect_problem_detail_matches("^[0-9]+$"));
+    asserhttp_test!(problem_detail_should_fail_when_missing, "problem/blank.json", ProblemBlank.responses(), "expected problem member 'detail' but none found", .expect_problem_detail_matches("balance"));
+    asserhttp_test!(problem_detail_should_fail_when_status_inconsistent, "problem/inconsistent.json", ProblemInconsistent.responses(), "expected problem 'status' member to be the response status '400' but was '403'", .expect_problem_detail_matches("balance"));
+
+    asserhttp_test!(fallible_problem_detail_should_succeed, "problem/out-of-credit.json", ProblemOutOfCredit.responses(), .try_expect_problem_detail_matches("balance is [0-9]+").unwrap());
+    asserhttp_test!(fallible_problem_detail_should_fail_when_not_matching, "problem/out-of-credit.json", ProblemOutOfCredit.responses(), AsserhttpError::ProblemDetailMismatch { actual: "Your current balance is 30, but that costs 50.".to_string(), regex: "^[0-9]+$".to_string() }, .try_expect_problem_detail_matches("^[0-9]+$"));
+    asserhttp_test!(fallible_problem_detail_should_fail_when_missing, "problem/blank.json", ProblemBlank.responses(), AsserhttpError::ProblemMemberAbsent { member: "detail".to_string() }, .try_expect_problem_detail_matches("balance"));
+}
+
+mod graphql {
+    use super::Stub::*;
+    use serde_json::json;
+
+    asserhttp_test!(graphql_should_succeed, "graphql/errors.json", GraphqlErrors.responses(), .expect_graphql(|r| assert_eq!(r.errors[0].locations[0].line, 3)));
+    asserhttp_test!(graphql_should_fail_when_closure_fails, "graphql/ok.json", GraphqlOk.responses(), "", .expect_graphql(|r| assert!(r.data.is_none())));
+    asserhttp_test!(graphql_should_fail_when_absent, "body/json/absent.json", BodyJsonAbsent.responses(), "expected a response body but none was present", .expect_graphql(|_| {}));
+
+    asserhttp_test!(fallible_graphql_should_succeed, "graphql/ok.json", GraphqlOk.responses(), .try_expect_graphql(|r| { assert!(r.errors.is_empty()); Ok(()) }).unwrap());
+    asserhttp_test!(fallible_graphql_should_fail_when_absent, "body/json/absent.json", BodyJsonAbsent.responses(), AsserhttpError::BodyAbsent, .try_expect_graphql(|_| Ok(())));
+
+    asserhttp_test!(graphql_data_should_succeed, "graphql/ok.json", GraphqlOk.responses(), .expect_graphql_data(json!({"user": {"name": "jdoe"}})));
+    asserhttp_test!(graphql_data_should_fail_when_not_eq, "graphql/ok.json", GraphqlOk.responses(), "expected graphql data to be equal but json atoms at path \".user.name\" are not equal:\n    lhs:\n        \"jdoe\"\n    rhs:\n        \"alice\"", .expect_graphql_data(json!({"user": {"name": "alice"}})));
+
+    asserhttp_test!(fallible_graphql_data_should_succeed, "graphql/ok.json", GraphqlOk.responses(), .try_expect_graphql_data(json!({"user": {"name": "jdoe"}})).unwrap());
+    asserhttp_test!(fallible_graphql_data_should_fail_when_not_eq, "graphql/ok.json", GraphqlOk.responses(), AsserhttpError::GraphqlDataMismatch("json atoms at path \".user.name\" are not equal:\n    lhs:\n        \"jdoe\"\n    rhs:\n        \"alice\"".to_string()), .try_expect_graphql_data(json!({"user": {"name": "alice"}})));
+
+    asserhttp_test!(graphql_no_errors_should_succeed, "graphql/ok.json", GraphqlOk.responses(), .expect_graphql_no_errors());
+    asserhttp_test!(graphql_no_errors_should_fail_when_errors, "graphql/errors.json", GraphqlErrors.responses(), "expected no graphql errors but found [\"Not allowed to read email\"]", .expect_graphql_no_errors());
+
+    asserhttp_test!(fallible_graphql_no_errors_should_succeed, "graphql/ok.json", GraphqlOk.responses(), .try_expect_graphql_no_errors().unwrap());
+    asserhttp_test!(fallible_graphql_no_errors_should_fail_when_errors, "graphql/errors.json", GraphqlErrors.responses(), AsserhttpError::GraphqlErrorsPresent { messages: vec!["Not allowed to read email".to_string()] }, .try_expect_graphql_no_errors());
+
+    asserhttp_test!(graphql_error_code_should_succeed, "graphql/errors.json", GraphqlErrors.responses(), .expect_graphql_error_code("FORBIDDEN"));
+    asserhttp_test!(graphql_error_code_should_fail_when_absent, "graphql/errors.json", GraphqlErrors.responses(), "expected a graphql error with code 'NOT_FOUND' but found codes [\"FORBIDDEN\"]", .expect_graphql_error_code("NOT_FOUND"));
+    asserhttp_test!(graphql_error_code_should_fail_when_no_errors, "graphql/ok.json", GraphqlOk.responses(), "expected a graphql error with code 'FORBIDDEN' but found codes []", .expect_graphql_error_code("FORBIDDEN"));
+
+    asserhttp_test!(fallible_graphql_error_code_should_succeed, "graphql/errors.json", GraphqlErrors.responses(), .try_expect_graphql_error_code("FORBIDDEN").unwrap());
+    asserhttp_test!(fallible_graphql_error_code_should_fail_when_absent, "graphql/errors.json", GraphqlErrors.responses(), AsserhttpError::GraphqlErrorCodeAbsent { expected: "NOT_FOUND".to_string(), actual: vec!["FORBIDDEN".to_string()] }, .try_expect_graphql_error_code("NOT_FOUND"));
+
+    asserhttp_test!(graphql_error_path_should_succeed, "graphql/errors.json", GraphqlErrors.responses(), .expect_graphql_error_path(["user", "email"]));
+    asserhttp_test!(graphql_error_path_should_fail_when_absent, "graphql/errors.json", GraphqlErrors.responses(), "expected a graphql error at path 'user.name' but found paths [\"user.email\"]", .expect_graphql_error_path(["user", "name"]));
+
+    asserhttp_test!(fallible_graphql_error_path_should_succeed, "graphql/errors.json", GraphqlErrors.responses(), .try_expect_graphql_error_path(vec!["user", "email"]).unwrap());
+    asserhttp_test!(fallible_graphql_error_path_should_fail_when_absent, "graphql/errors.json", GraphqlErrors.responses(), AsserhttpError::GraphqlErrorPathAbsent { expected: "user.name".to_string(), actual: vec!["user.email".to_string()] }, .try_expect_graphql_error_path(["user", "name"]));
+}
+
+mod jsonrpc {
+    use super::Stub::*;
+    use serde_json::json;
+
+    asserhttp_test!(jsonrpc_should_succeed, "jsonrpc/error.json", JsonRpcErrorResponse.responses(), .expect_jsonrpc(|r| assert_eq!(r.error.unwrap().message, "Method not found")));
+    asserhttp_test!(jsonrpc_should_fail_when_closure_fails, "jsonrpc/result.json", JsonRpcResult.responses(), "", .expect_jsonrpc(|r| assert!(r.error.is_some())));
+    asserhttp_test!(jsonrpc_should_fail_when_batch, "jsonrpc/batch.json", JsonRpcBatchResponses.responses(), "expected a jsonrpc single response but was a batch one", .expect_jsonrpc(|_| {}));
+    asserhttp_test!(jsonrpc_should_fail_when_version_mismatch, "jsonrpc/legacy.json", JsonRpcLegacy.responses(), "expected jsonrpc member 'jsonrpc' to be '2.0' but was '1.0'", .expect_jsonrpc(|_| {}));
+    asserhttp_test!(jsonrpc_should_fail_when_absent, "body/json/absent.json", BodyJsonAbsent.responses(), "expected a response body but none was present", .expect_jsonrpc(|_| {}));
+
+    asserhttp_test!(fallible_jsonrpc_should_succeed, "jsonrpc/result.json", JsonRpcResult.responses(), .try_expect_jsonrpc(|r| { assert_eq!(r.id, json!(1)); Ok(()) }).unwrap());
+    asserhttp_test!(fallible_jsonrpc_should_fail_when_batch, "jsonrpc/batch.json", JsonRpcBatchResponses.responses(), AsserhttpError::JsonRpcShapeMismatch { expected: "single".to_string(), actual: "batch".to_string() }, .try_expect_jsonrpc(|_| Ok(())));
+    asserhttp_test!(fallible_jsonrpc_should_fail_when_version_mismatch, "jsonrpc/legacy.json", JsonRpcLegacy.responses(), AsserhttpError::JsonRpcMemberMismatch { member: "jsonrpc".to_string(), actual: "1.0".to_string(), expected: "2.0".to_string() }, .try_expect_jsonrpc(|_| Ok(())));
+
+    asserhttp_test!(jsonrpc_should_fail_when_no_version, "jsonrpc/no-version.json", JsonRpcNoVersion.responses(), "expected jsonrpc member 'jsonrpc' but none found", .expect_jsonrpc(|_| {}));
+    asserhttp_test!(fallible_jsonrpc_should_fail_when_no_version, "jsonrpc/no-version.json", JsonRpcNoVersion.responses(), AsserhttpError::JsonRpcMemberAbsent { member: "jsonrpc".to_string() }, .try_expect_jsonrpc(|_| Ok(())));
+    asserhttp_test!(jsonrpc_should_fail_when_no_id, "jsonrpc/no-id.json", JsonRpcNoId.responses(), "expected jsonrpc member 'id' but none found", .expect_jsonrpc(|_| {}));
+    asserhttp_test!(fallible_jsonrpc_should_fail_when_no_id, "jsonrpc/no-id.json", JsonRpcNoId.responses(), AsserhttpError::JsonRpcMemberAbsent { member: "id".to_string() }, .try_expect_jsonrpc(|_| Ok(())));
+    asserhttp_test!(jsonrpc_should_fail_when_invalid_id, "jsonrpc/invalid-id.json", JsonRpcInvalidId.responses(), "expected jsonrpc member 'id' to be a string, a number or null but was '{\"a\":1}'", .expect_jsonrpc(|_| {}));
+    asserhttp_test!(jsonrpc_should_fail_when_no_outcome, "jsonrpc/no-outcome.json", JsonRpcNoOutcome.responses(), "expected jsonrpc member 'result' or 'error' but none found", .expect_jsonrpc(|_| {}));
+    asserhttp_test!(fallible_jsonrpc_should_fail_when_no_outcome, "jsonrpc/no-outcome.json", JsonRpcNoOutcome.responses(), AsserhttpError::JsonRpcOutcomeAbsent { prefix: "".to_string() }, .try_expect_jsonrpc(|_| Ok(())));
+    asserhttp_test!(jsonrpc_should_fail_when_both_outcomes, "jsonrpc/both-outcomes.json", JsonRpcBothOutcomes.responses(), "expected only one of jsonrpc members 'result' and 'error' but both found", .expect_jsonrpc(|_| {}));
+    asserhttp_test!(fallible_jsonrpc_should_fail_when_both_outcomes, "jsonrpc/both-outcomes.json", JsonRpcBothOutcomes.responses(), AsserhttpError::JsonRpcOutcomeConflict { prefix: "".to_string() }, .try_expect_jsonrpc(|_| Ok(())));
+    asserhttp_test!(jsonrpc_should_fail_when_no_error_code, "jsonrpc/no-error-code.json", JsonRpcNoErrorCode.responses(), "expected jsonrpc member 'error.code' but none found", .expect_jsonrpc(|_| {}));
+    asserhttp_test!(jsonrpc_should_fail_when_invalid_error_code, "jsonrpc/invalid-error-code.json", JsonRpcInvalidErrorCode.responses(), "expected jsonrpc member 'error.code' to be an integer but was '\"-32601\"'", .expect_jsonrpc(|_| {}));
+    asserhttp_test!(jsonrpc_should_fail_when_batch_no_version, "jsonrpc/batch-no-version.json", JsonRpcBatchNoVersion.responses(), "expected jsonrpc member '[id=2].jsonrpc' but none found", .expect_jsonrpc_batch(|_| {}));
+    asserhttp_test!(fallible_jsonrpc_should_fail_when_batch_no_version, "jsonrpc/batch-no-version.json", JsonRpcBatchNoVersion.responses(), AsserhttpError::JsonRpcMemberAbsent { member: "[id=2].jsonrpc".to_string() }, .try_expect_jsonrpc_batch(|_| Ok(())));
+
+    asserhttp_test!(jsonrpc_result_should_succeed, "jsonrpc/result.json", JsonRpcResult.responses(), .expect_jsonrpc_result(json!({"balance": 30})));
+    asserhttp_test!(jsonrpc_result_should_fail_when_not_eq, "jsonrpc/result.json", JsonRpcResult.responses(), "expected jsonrpc member 'result' to be '{\"balance\":50}' but was '{\"balance\":30}'", .expect_jsonrpc_result(json!({"balance": 50})));
+    asserhttp_test!(jsonrpc_result_should_fail_when_error, "jsonrpc/error.json", JsonRpcErrorResponse.responses(), "expected jsonrpc member 'result' but none found", .expect_jsonrpc_result(json!({"balance": 30})));
+
+    asserhttp_test!(fallible_jsonrpc_result_should_succeed, "jsonrpc/result.json", JsonRpcResult.responses(), .try_expect_jsonrpc_result(json!({"balance": 30})).unwrap());
+    asserhttp_test!(fallible_jsonrpc_result_should_fail_when_not_eq, "jsonrpc/result.json", JsonRpcResult.responses(), AsserhttpError::JsonRpcMemberMismatch { member: "result".to_string(), actual: "{\"balance\":30}".to_string(), expected: "{\"balance\":50}".to_string() }, .try_expect_jsonrpc_result(json!({"balance": 50})));
+    asserhttp_test!(fallible_jsonrpc_result_should_fail_when_error, "jsonrpc/error.json", JsonRpcErrorResponse.responses(), AsserhttpError::JsonRpcMemberAbsent { member: "result".to_string() }, .try_expect_jsonrpc_result(json!({"balance": 30})));
+
+    asserhttp_test!(jsonrpc_error_should_succeed, "jsonrpc/error.json", JsonRpcErrorResponse.responses(), .expect_jsonrpc_error(-32601));
+    asserhttp_test!(jsonrpc_error_should_fail_when_not_eq, "jsonrpc/error.json", JsonRpcErrorResponse.responses(), "expected jsonrpc member 'error.code' to be '-32600' but was '-32601'", .expect_jsonrpc_error(-32600));
+    asserhttp_test!(jsonrpc_error_should_fail_when_result, "jsonrpc/result.json", JsonRpcResult.responses(), "expected jsonrpc member 'error' but none found", .expect_jsonrpc_error(-32601));
+
+    asserhttp_test!(fallible_jsonrpc_error_should_succeed, "jsonrpc/error.json", JsonRpcErrorResponse.responses(), .try_expect_jsonrpc_error(-32601).unwrap());
+    asserhttp_test!(fallible_jsonrpc_error_should_fail_when_not_eq, "jsonrpc/error.json", JsonRpcErrorResponse.responses(), AsserhttpError::JsonRpcMemberMismatch { member: "error.code".to_string(), actual: "-32601".to_string(), expected: "-32600".to_string() }, .try_expect_jsonrpc_error(-32600));
+
+    asserhttp_test!(jsonrpc_id_should_succeed, "jsonrpc/result.json", JsonRpcResult.responses(), .expect_jsonrpc_id(1));
+    asserhttp_test!(jsonrpc_id_should_succeed_when_string, "jsonrpc/error.json", JsonRpcErrorResponse.responses(), .expect_jsonrpc_id("abc"));
+    asserhttp_test!(jsonrpc_id_should_fail_when_not_same_type, "jsonrpc/result.json", JsonRpcResult.responses(), "expected jsonrpc member 'id' to be '\"1\"' but was '1'", .expect_jsonrpc_id("1"));
+
+    asserhttp_test!(fallible_jsonrpc_id_should_succeed, "jsonrpc/result.json", JsonRpcResult.responses(), .try_expect_jsonrpc_id(1).unwrap());
+    asserhttp_test!(fallible_jsonrpc_id_should_fail_when_not_eq, "jsonrpc/result.json", JsonRpcResult.responses(), AsserhttpError::JsonRpcMemberMismatch { member: "id".to_string(), actual: "1".to_string(), expected: "2".to_string() }, .try_expect_jsonrpc_id(2));
+
+    asserhttp_test!(jsonrpc_batch_should_succeed, "jsonrpc/batch.json", JsonRpcBatchResponses.responses(), .expect_jsonrpc_batch(|b| assert_eq!(b.entry(1).unwrap().result, Some(json!({"balance": 30})))));
+    asserhttp_test!(jsonrpc_batch_should_fail_when_single, "jsonrpc/result.json", JsonRpcResult.responses(), "expected a jsonrpc batch response but was a single one", .expect_jsonrpc_batch(|_| {}));
+
+    asserhttp_test!(fallible_jsonrpc_batch_should_succeed, "jsonrpc/batch.json", JsonRpcBatchResponses.responses(), .try_expect_jsonrpc_batch(|b| { assert_eq!(b.0.len(), 2); Ok(()) }).unwrap());
+    asserhttp_test!(fallible_jsonrpc_batch_should_fail_when_single, "jsonrpc/result.json", JsonRpcResult.responses(), AsserhttpError::JsonRpcShapeMismatch { expected: "batch".to_string(), actual: "single".to_string() }, .try_expect_jsonrpc_batch(|_| Ok(())));
+
+    asserhttp_test!(jsonrpc_batch_result_should_succeed, "jsonrpc/batch.json", JsonRpcBatchResponses.responses(), .expect_jsonrpc_batch_result(1, json!({"balance": 30})));
+    asserhttp_test!(jsonrpc_batch_result_should_fail_when_not_eq, "jsonrpc/batch.json", JsonRpcBatchResponses.responses(), "expected jsonrpc member '[id=1].result' to be '{\"balance\":50}' but was '{\"balance\":30}'", .expect_jsonrpc_batch_result(1, json!({"balance": 50})));
+    asserhttp_test!(jsonrpc_batch_result_should_fail_when_error, "jsonrpc/batch.json", JsonRpcBatchResponses.responses(), "expected jsonrpc member '[id=2].result' but none found", .expect_jsonrpc_batch_result(2, json!({"balance": 30})));
+    asserhttp_test!(jsonrpc_batch_result_should_fail_when_entry_absent, "jsonrpc/batch.json", JsonRpcBatchResponses.responses(), "expected a jsonrpc batch entry with id '3' but none found", .expect_jsonrpc_batch_result(3, json!({"balance": 30})));
+
+    asserhttp_test!(fallible_jsonrpc_batch_result_should_succeed, "jsonrpc/batch.json", JsonRpcBatchResponses.responses(), .try_expect_jsonrpc_batch_result(1, json!({"balance": 30})).unwrap());
+    asserhttp_test!(fallible_jsonrpc_batch_result_should_fail_when_entry_absent, "jsonrpc/batch.json", JsonRpcBatchResponses.responses(), AsserhttpError::JsonRpcEntryAbsent { id: "3".to_string() }, .try_expect_jsonrpc_batch_result(3, json!({"balance": 30})));
+
+    asserhttp_test!(jsonrpc_batch_error_should_succeed, "jsonrpc/batch.json", JsonRpcBatchResponses.responses(), .expect_jsonrpc_batch_error(2, -32601));
+    asserhttp_test!(jsonrpc_batch_error_should_fail_when_not_eq, "jsonrpc/batch.json", JsonRpcBatchResponses.responses(), "expected jsonrpc member '[id=2].error.code' to be '-32600' but was '-32601'", .expect_jsonrpc_batch_error(2, -32600));
+
+    asserhttp_test!(fallible_jsonrpc_batch_error_should_succeed, "jsonrpc/batch.json", JsonRpcBatchResponses.responses(), .try_expect_jsonrpc_batch_error(2, -32601).unwrap());
+    asserhttp_test!(fallible_jsonrpc_batch_error_should_fail_when_result, "jsonrpc/batch.json", JsonRpcBatchResponses.responses(), AsserhttpError::JsonRpcMemberAbsent { member: "[id=1].error".to_string() }, .try_expect_jsonrpc_batch_error(1, -32601));
+}
+
+mod capture {
+    use super::Stub::*;
+    use asserhttp::*;
+    use serde_json::{json, Value};
+
+    #[test]
+    #[stubr::mock("body/json/items.json")]
+    fn capture_json_path_should_capture() {
+        let (mut name, mut price) = (String::new(), 0u32);
+        isahc::get(stubr.uri())
+            .capture_json_path("$.items[1].name", &mut name)
+            .capture_json_path("$['items'][0]['price']", &mut price);
+        assert_eq!(name, "pear");
+        assert_eq!(price, 10);
+    }
+
+    #[test]
+    #[stubr::mock("header/one.json")]
+    fn capture_header_should_capture() {
+        let mut value = String::new();
+        isahc::get(stubr.uri()).capture_header("x-a", &mut value);
+        assert_eq!(value, "a");
+    }
+
+    #[tokio::test]
+    #[stubr::mock("body/json/value.json")]
+    async fn extract_json_should_follow_body_assertions() {
+        let body: Value = reqwest::get(stubr.uri())
+            .await
+            .expect_body_json_eq(json!({"a": "b"}))
+            .extract_json();
+        assert_eq!(body, json!({"a": "b"}));
+    }
+
+    #[tokio::test]
+    #[stubr::mock("body/json/value.json")]
+    async fn reqwest_should_keep_url_and_remote_address_after_body_assertions() {
+        let mut response = reqwest::get(stubr.uri()).await.unwrap();
+        let (url, remote_addr) = (response.url().clone(), response.remote_addr());
+        response.expect_body_json_eq(json!({"a": "b"}));
+        assert_eq!(response.url(), &url);
+        assert_eq!(response.remote_addr(), remote_addr);
+        assert!(remote_addr.is_some());
+    }
+
+    #[test]
+    #[stubr::mock("body/json/value.json")]
+    fn reqwest_blocking_should_keep_url_and_remote_address_after_body_assertions() {
+        let mut response = reqwest::blocking::get(stubr.uri()).unwrap();
+        let (url, remote_addr) = (response.url().clone(), response.remote_addr());
+        response.expect_body_json_eq(json!({"a": "b"}));
+        assert_eq!(response.url(), &url);
+        assert_eq!(response.remote_addr(), remote_addr);
+        assert!(remote_addr.is_some());
+    }
+
+    #[actix_web::test]
+    #[stubr::mock("body/json/value.json")]
+    async fn awc_extract_json_should_follow_body_assertions() {
+        let body: Value = awc::Client::default()
+            .get(stubr.uri())
+            .send()
+            .await
+            .expect_body_json_eq(json!({"a": "b"}))
+            .extract_json();
+        assert_eq!(body, json!({"a": "b"}));
+    }
+
+    #[test]
+    #[should_panic(expected = "expected a response body but none was present")]
+    #[stubr::mock("body/json/absent.json")]
+    fn extract_json_should_fail_when_absent() {
+        isahc::get(stubr.uri()).extract_json::<Value>();
+    }
+
+    #[test]
+    #[stubr::mock("body/json/absent.json")]
+    fn fallible_extract_json_should_fail_when_absent() {
+        let result = ureq::get(&stubr.uri()).call().try_extract_json::<Value>();
+        assert_eq!(result.unwrap_err(), AsserhttpError::BodyAbsent);
+    }
+
+    macro_rules! rereadable_test {
+        ($fn_name:ident, $stub:literal, $resp:expr, $(.$meth:ident($( $arg:expr ),*))+) => {
+            crate::reqwest_test!($fn_name, $stub, $( .$meth($($arg),*) )+);
+            crate::surf_test!($fn_name, $stub, $( .$meth($($arg),*) )+);
+            crate::ureq_test!($fn_name, $stub, $( .$meth($($arg),*) )+);
+            crate::hyper_test!($fn_name, $stub, $( .$meth($($arg),*) )+);
+            crate::awc_test!($fn_name, $stub, $( .$meth($($arg),*) )+);
+            crate::isahc_test!($fn_name, $stub, $( .$meth($($arg),*) )+);
+            crate::actix_test!($fn_name, $resp.0, $( .$meth($($arg),*) )+);
+            crate::axum_test!($fn_name, $resp.2, $( .$meth($($arg),*) )+);
+        };
+    }
+
+    // Rocket's local responses body can only be read once
+    rereadable_test!(capture_should_follow_body_assertions, "body/json/value.json", BodyJson.responses(), .expect_body_json_eq(json!({"a": "b"})).capture_json_path("$.a", &mut String::new()).expect_body_text_eq(r#"{"a":"b"}"#));
+
+    asserhttp_test!(capture_json_path_should_succeed, "body/json/value.json", BodyJson.responses(), .capture_json_path("$.a", &mut String::new()));
+    asserhttp_test!(capture_json_path_should_fail_when_absent, "body/json/value.json", BodyJson.responses(), "expected a value at json path '$.b' but none found", .capture_json_path("$.b", &mut String::new()));
+    asserhttp_test!(capture_json_path_should_fail_when_invalid, "body/json/value.json", BodyJson.responses(), "'a.b' is not a supported json path. Expected one selecting a single value e.g. '$.items[0].id'", .capture_json_path("a.b", &mut String::new()));
+    asserhttp_test!(capture_json_path_should_fail_when_type_mismatch, "body/json/value.json", BodyJson.responses(), "failed to deserialize json body at $.a: invalid type: string \"b\", expected u32\n\"b\"", .capture_json_path("$.a", &mut 0u32));
+    asserhttp_test!(capture_json_path_should_fail_when_absent_body, "body/json/absent.json", BodyJsonAbsent.responses(), "expected a response body but none was present", .capture_json_path("$.a", &mut String::new()));
+
+    asserhttp_test!(fallible_capture_json_path_should_succeed, "body/json/value.json", BodyJson.responses(), .try_capture_json_path("$.a", &mut String::new()).unwrap());
+    asserhttp_test!(fallible_capture_json_path_should_fail_when_absent, "body/json/value.json", BodyJson.responses(), AsserhttpError::JsonPathAbsent { path: "$.b".to_string() }, .try_capture_json_path("$.b", &mut String::new()));
+    asserhttp_test!(fallible_capture_json_path_should_fail_when_invalid, "body/json/value.json", BodyJson.responses(), AsserhttpError::JsonPathInvalid { path: "$[a]".to_string() }, .try_capture_json_path("$[a]", &mut String::new()));
+    asserhttp_test!(fallible_capture_json_path_should_fail_when_type_mismatch, "body/json/value.json", BodyJson.responses(), AsserhttpError::JsonBodyDeserialization { path: "$.a".to_string(), message: "invalid type: string \"b\", expected u32".to_string(), excerpt: "\"b\"".to_string() }, .try_capture_json_path("$.a", &mut 0u32));
+
+    asserhttp_test!(capture_header_should_succeed, "header/one.json", HeaderOne.responses(), .capture_header("x-a", &mut String::new()));
+    asserhttp_test!(capture_header_should_fail_when_absent, "header/one.json", HeaderOne.responses(), "expected one header named 'x-b' but none found", .capture_header("x-b", &mut String::new()));
+
+    asserhttp_test!(fallible_capture_header_should_succeed, "header/one.json", HeaderOne.responses(), .try_capture_header("x-a", &mut String::new()).unwrap());
+    asserhttp_test!(fallible_capture_header_should_fail_when_absent, "header/one.json", HeaderOne.responses(), AsserhttpError::HeaderAbsent { key: "x-b".into() }, .try_capture_header("x-b", &mut String::new()));
+}
+
+mod snapshot {
+    use super::{missing_fixture, negative_fixture, Stub::*};
+    use asserhttp::*;
+    use std::path::PathBuf;
+
+    fn settings() -> SnapshotSettings {
+        SnapshotSettings::default()
+            .only_headers(["content-type"])
+            .redact_json_path("$.id")
+    }
+
+    /// A stored snapshot differing from the response, generated on each run so that blessing cannot update it for good
+    fn renamed() -> PathBuf {
+        negative_fixture("create_user_renamed.snap", RENAMED)
+    }
+
+    const RENAMED: &str = "status: 201\nheaders:\n  content-type: application/json\nbody:\n{\n  \"id\": \"[redacted]\",\n  \"name\": \"alice\",\n  \"roles\": [\n    \"admin\"\n  ]\n}\n";
+
+    asserhttp_test!(snapshot_should_succeed, "snapshot/user.json", SnapshotUser.responses(), .expect_snapshot_with("create_user", settings()));
+    asserhttp_test!(snapshot_should_fail_when_different, "snapshot/user.json", SnapshotUser.responses(), "create_user_renamed.snap' differs", .expect_snapshot_with("create_user_renamed", settings().dir(renamed().parent().unwrap())));
+    asserhttp_test!(snapshot_should_fail_when_absent, "snapshot/user.json", SnapshotUser.responses(), "missing.snap', run with ASSERHTTP_BLESS=1 to create it", .expect_snapshot_with("missing", SnapshotSettings::default().dir(missing_fixture("missing.snap").parent().unwrap())));
+    asserhttp_test!(snapshot_should_fail_when_redaction_unmatched, "snapshot/user.json", SnapshotUser.responses(), "cannot redact json path '$.uuid' from the snapshot since it selects nothing in the body", .expect_snapshot_with("create_user", settings().redact_json_path("$.uuid")));
+    asserhttp_test!(snapshot_should_fail_when_redacting_text, "body/text/value.json", BodyText.responses(), "cannot redact json path '$.id' from the snapshot since the body is not json", .expect_snapshot_with("text", settings()));
+
+    asserhttp_test!(fallible_snapshot_should_succeed, "snapshot/user.json", SnapshotUser.responses(), .try_expect_snapshot_with("create_user", settings()).unwrap());
+    asserhttp_test!(fallible_snapshot_should_fail_when_different, "snapshot/user.json", SnapshotUser.responses(), AsserhttpError::SnapshotMismatch { path: renamed().display().to_string(), diff: "  status: 201\n  headers:\n    content-type: application/json\n  body:\n  {\n    \"id\": \"[redacted]\",\n-   \"name\": \"alice\",\n+   \"name\": \"jdoe\",\n    \"roles\": [\n      \"admin\"\n    ]\n  }".to_string() }, .try_expect_snapshot_with("create_user_renamed", settings().dir(renamed().parent().unwrap())));
+    asserhttp_test!(fallible_snapshot_should_fail_when_json_path_invalid, "snapshot/user.json", SnapshotUser.responses(), AsserhttpError::JsonPathInvalid { path: "id".to_string() }, .try_expect_snapshot_with("create_user", settings().redact_json_path("id")));
+    asserhttp_test!(fallible_snapshot_should_fail_when_redaction_unmatched, "snapshot/user.json", SnapshotUser.responses(), AsserhttpError::SnapshotRedactionUnmatched { path: "$.uuid".to_string() }, .try_expect_snapshot_with("create_user", settings().redact_json_path("$.uuid")));
+    asserhttp_test!(fallible_snapshot_should_fail_when_redacting_absent_body, "body/text/absent.json", BodyTextAbsent.responses(), AsserhttpError::SnapshotRedactionNotJson { path: "$.id".to_string() }, .try_expect_snapshot_with("absent", settings()));
+}
+
 mod customizable {
     use super::Stub::*;
     use asserhttp::AsserhttpResult;
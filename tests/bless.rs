@@ -0,0 +1,36 @@
+use asserhttp::*;
+
+// runs in its own process since blessing is enabled for the whole process
+#[test]
+#[stubr::mock("body/json/value.json")]
+fn bless_should_write_actual_bodies_and_snapshots() {
+    std::env::set_var("ASSERHTTP_BLESS", "1");
+    let dir = std::env::temp_dir().join("asserhttp-bless");
+    let _ = std::fs::remove_dir_all(&dir);
+
+    let json = dir.join("body/value.json");
+    isahc::get(stubr.uri()).expect_body_json_eq_file(&json);
+    assert_eq!(std::fs::read_to_string(&json).unwrap(), "{\n  \"a\": \"b\"\n}\n");
+
+    let text = dir.join("body/value.txt");
+    isahc::get(stubr.uri()).expect_body_text_eq_file(&text);
+    assert_eq!(std::fs::read_to_string(&text).unwrap(), r#"{"a":"b"}"#);
+
+    let snapshots = || {
+        SnapshotSettings::default()
+            .dir(dir.join("snapshots"))
+            .only_headers(["content-type"])
+    };
+    isahc::get(stubr.uri()).expect_snapshot_with("value", snapshots());
+    assert_eq!(
+        std::fs::read_to_string(dir.join("snapshots/value.snap")).unwrap(),
+        "status: 200\nheaders:\n  content-type: application/json\nbody:\n{\n  \"a\": \"b\"\n}\n"
+    );
+
+    // blessed fixtures then pass when blessing is disabled
+    std::env::set_var("ASSERHTTP_BLESS", "0");
+    isahc::get(stubr.uri())
+        .expect_body_json_eq_file(&json)
+        .expect_body_text_eq_file(&text)
+        .expect_snapshot_with("value", snapshots());
+}